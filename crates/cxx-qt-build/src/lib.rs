@@ -31,6 +31,8 @@ mod qml_modules;
 use qml_modules::OwningQmlModule;
 pub use qml_modules::QmlModule;
 
+mod translations;
+
 pub use qt_build_utils::MocArguments;
 use qt_build_utils::SemVer;
 use quote::ToTokens;
@@ -367,6 +369,7 @@ pub struct CxxQtBuilder {
     init_files: Vec<qt_build_utils::Initializer>,
     qt_modules: HashSet<String>,
     qml_modules: Vec<OwningQmlModule>,
+    translation_files: Vec<PathBuf>,
     cc_builder: cc::Build,
     public_interface: Option<Interface>,
     include_prefix: String,
@@ -407,6 +410,7 @@ impl CxxQtBuilder {
             init_files: vec![],
             qt_modules,
             qml_modules: vec![],
+            translation_files: vec![],
             cc_builder: cc::Build::new(),
             public_interface: None,
             include_prefix: crate_name(),
@@ -476,6 +480,30 @@ impl CxxQtBuilder {
         self
     }
 
+    /// Compile a [Qt Linguist](https://doc.qt.io/qt-6/qtlinguist-index.html) .ts file with lrelease and embed
+    /// the resulting .qm file into the binary in the `:/i18n` resource directory, so that it can be loaded
+    /// with `QTranslator::load`. Use a file name which is unique across crates, such as `myapp_de.ts`.
+    /// ```no_run
+    /// # use cxx_qt_build::CxxQtBuilder;
+    /// CxxQtBuilder::new()
+    ///     .file("src/cxxqt_module.rs")
+    ///     .translation("i18n/myapp_de.ts")
+    ///     .build();
+    /// ```
+    ///
+    /// When the `CXX_QT_UPDATE_TRANSLATIONS` environment variable is set to `1`, lupdate is run first to
+    /// update the .ts files with the strings of the `qs_tr!` macro in the Rust files of the `src` directory
+    /// and the `qsTr` strings of the QML files of the QML modules.
+    /// Only set this variable for the build which updates the .ts files, as the updated files cause another build.
+    ///
+    /// The context of the strings from Rust is the module path, modules declared with a `#[path]` attribute are not followed.
+    pub fn translation(mut self, ts_file: impl AsRef<Path>) -> Self {
+        let ts_file = ts_file.as_ref();
+        self.translation_files.push(ts_file.to_path_buf());
+        println!("cargo::rerun-if-changed={}", ts_file.display());
+        self
+    }
+
     /// Link additional [Qt modules](https://doc.qt.io/qt-6/qtmodules.html).
     /// Specify their names without the `Qt` prefix, for example `"Widgets"`.
     /// The `Core` module and any modules from dependencies are linked automatically; there is no need to specify them.
@@ -1037,6 +1065,50 @@ extern "C" bool {init_fun}() {{
             .collect()
     }
 
+    fn generate_cpp_from_translations(
+        &self,
+        qtbuild: &mut qt_build_utils::QtBuild,
+    ) -> Option<qt_build_utils::Initializer> {
+        if self.translation_files.is_empty() {
+            return None;
+        }
+
+        let translations_dir = dir::out().join("cxx-qt-build").join("translations");
+
+        println!(
+            "cargo::rerun-if-env-changed={}",
+            translations::UPDATE_TRANSLATIONS_ENV
+        );
+        if env::var(translations::UPDATE_TRANSLATIONS_ENV).is_ok_and(|value| value == "1") {
+            let src_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+            println!("cargo::rerun-if-changed={}", src_dir.display());
+
+            // lupdate cannot parse Rust, so the strings are written as C++ translate calls
+            let lupdate_source = translations_dir.join("translations.cpp");
+            translations::write_lupdate_source(&src_dir, &crate_name(), &lupdate_source);
+
+            let sources = std::iter::once(lupdate_source)
+                .chain(
+                    self.qml_modules
+                        .iter()
+                        .flat_map(|qml_module| qml_module.qml_files.iter().cloned()),
+                )
+                .collect::<Vec<_>>();
+            qtbuild.lupdate(&sources, &self.translation_files);
+        }
+
+        let qm_files = self
+            .translation_files
+            .iter()
+            .map(|ts_file| qtbuild.lrelease(ts_file))
+            .collect::<Vec<_>>();
+
+        // The resource name is derived from the file name, so it must be unique across crates
+        let qrc_path = translations_dir.join(format!("{}_translations.qrc", crate_init_key()));
+        translations::write_qrc(&qm_files, &qrc_path);
+        Some(qtbuild.qrc(&qrc_path))
+    }
+
     fn write_manifest(
         &self,
         dependencies: &[Dependency],
@@ -1147,11 +1219,13 @@ extern "C" bool {init_fun}() {{
             self.build_qml_modules(&mut qtbuild, &header_root, &self.include_prefix.clone());
 
         let qrc_files = self.generate_cpp_from_qrc_files(&mut qtbuild);
+        let translation_initializer = self.generate_cpp_from_translations(&mut qtbuild);
 
         let dependency_initializers = dependencies::initializers(&dependencies);
         let private_initializers = dependency_initializers
             .into_iter()
             .chain(qrc_files)
+            .chain(translation_initializer)
             .chain(module_initializers)
             .chain(self.init_files.iter().cloned())
             .collect::<Vec<_>>();
@@ -0,0 +1,441 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This Rust module contains helpers for extracting the strings of the `qs_tr!` macro
+//! and embedding compiled translations.
+
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use std::path::{Path, PathBuf};
+
+/// The environment variable which, when set to `1`, runs lupdate to update the .ts files
+pub(crate) const UPDATE_TRANSLATIONS_ENV: &str = "CXX_QT_UPDATE_TRANSLATIONS";
+
+/// A string marked for translation with the `qs_tr!` macro
+struct TranslatableString {
+    context: String,
+    source_text: String,
+    disambiguation: String,
+    numerus: bool,
+}
+
+/// Find the `qs_tr!` strings in the Rust files of the src directory and write them as
+/// `QCoreApplication::translate` calls into a C++ file that lupdate can read.
+///
+/// The context of each string is the module path, which matches `module_path!()` in the macro.
+/// Modules declared with a `#[path]` attribute are not followed.
+pub(crate) fn write_lupdate_source(src_dir: &Path, crate_name: &str, output_path: &Path) {
+    let mut strings = vec![];
+    for file in rust_files(src_dir) {
+        let contents = std::fs::read_to_string(&file)
+            .unwrap_or_else(|err| panic!("Failed to read {}: {err}", file.display()));
+        // Files which do not tokenize also fail to compile, so there are no strings to extract
+        if let Ok(tokens) = contents.parse::<TokenStream>() {
+            let module_path = module_path(src_dir, &file, crate_name);
+            extract_strings(tokens, &module_path, &mut strings);
+        }
+    }
+
+    let mut source = String::from(
+        "// Generated by cxx-qt-build for lupdate, this file is not compiled\n\
+         #include <QtCore/QCoreApplication>\n\
+         \n\
+         void cxx_qt_translations()\n\
+         {\n",
+    );
+    for string in strings {
+        source.push_str(&format!(
+            "  QCoreApplication::translate({context}, {source_text}, {disambiguation}{n});\n",
+            context = cpp_string_literal(&string.context),
+            source_text = cpp_string_literal(&string.source_text),
+            disambiguation = cpp_string_literal(&string.disambiguation),
+            n = if string.numerus { ", 0" } else { "" },
+        ));
+    }
+    source.push_str("}\n");
+
+    if let Some(parent) = output_path.parent() {
+        std::fs::create_dir_all(parent).expect("Failed to create the translations directory");
+    }
+    std::fs::write(output_path, source).expect("Failed to write the translations source file");
+}
+
+/// Write a .qrc file which embeds the .qm files into the `/i18n` resource directory
+pub(crate) fn write_qrc(qm_files: &[PathBuf], output_path: &Path) {
+    let qrc = qrc_contents(qm_files);
+
+    if let Some(parent) = output_path.parent() {
+        std::fs::create_dir_all(parent).expect("Failed to create the translations directory");
+    }
+    std::fs::write(output_path, qrc).expect("Failed to write the translations qrc file");
+}
+
+/// Generate the contents of a .qrc file which embeds the .qm files
+///
+/// The files are embedded with their file name as the alias, so this panics if two files have the same name.
+fn qrc_contents(qm_files: &[PathBuf]) -> String {
+    for (index, qm_file) in qm_files.iter().enumerate() {
+        if let Some(other) = qm_files[..index]
+            .iter()
+            .find(|other| other.file_name() == qm_file.file_name())
+        {
+            panic!(
+                "Translations {} and {} would both be embedded as :/i18n/{}, the .ts files must have unique file names",
+                other.display(),
+                qm_file.display(),
+                qm_file.file_name().unwrap().to_string_lossy()
+            );
+        }
+    }
+
+    let files = qm_files
+        .iter()
+        .map(|qm_file| {
+            format!(
+                "        <file alias=\"{alias}\">{path}</file>\n",
+                alias = xml_escape(&qm_file.file_name().unwrap().to_string_lossy()),
+                path = xml_escape(&qm_file.display().to_string()),
+            )
+        })
+        .collect::<String>();
+    format!(
+        "<!DOCTYPE RCC>\n<RCC version=\"1.0\">\n    <qresource prefix=\"/i18n\">\n{files}    </qresource>\n</RCC>\n"
+    )
+}
+
+/// Recursively find the Rust files of a directory, sorted so that the output is stable
+fn rust_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return vec![];
+    };
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    let mut files = vec![];
+    for path in paths {
+        if path.is_dir() {
+            files.extend(rust_files(&path));
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+    files
+}
+
+/// Determine the module path of a Rust file from its location in the src directory
+///
+/// For example `src/lib.rs` is the crate root, `src/a/mod.rs` is `crate::a`,
+/// and `src/bin/app.rs` is the root of the `app` binary crate.
+fn module_path(src_dir: &Path, file: &Path, crate_name: &str) -> String {
+    let components = file
+        .strip_prefix(src_dir)
+        .unwrap_or(file)
+        .iter()
+        .map(|component| component.to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+
+    let (crate_name, modules) = match components.as_slice() {
+        [bin, target, modules @ ..] if bin == "bin" => (
+            target.strip_suffix(".rs").unwrap_or(target).to_owned(),
+            modules,
+        ),
+        modules => (crate_name.to_owned(), modules),
+    };
+
+    let mut path = vec![crate_name.replace('-', "_")];
+    if let Some((file_name, parents)) = modules.split_last() {
+        path.extend(parents.iter().cloned());
+
+        let is_root = parents.is_empty() && (file_name == "lib.rs" || file_name == "main.rs");
+        if !is_root && file_name != "mod.rs" {
+            path.push(
+                file_name
+                    .strip_suffix(".rs")
+                    .unwrap_or(file_name)
+                    .to_owned(),
+            );
+        }
+    }
+    path.join("::")
+}
+
+/// Find the `qs_tr!` invocations with literal arguments, following inline modules
+fn extract_strings(tokens: TokenStream, module_path: &str, strings: &mut Vec<TranslatableString>) {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let mut index = 0;
+    while index < tokens.len() {
+        match (&tokens[index], tokens.get(index + 1), tokens.get(index + 2)) {
+            // An inline module, eg mod name { ... }
+            (
+                TokenTree::Ident(ident),
+                Some(TokenTree::Ident(name)),
+                Some(TokenTree::Group(group)),
+            ) if ident == "mod" && group.delimiter() == Delimiter::Brace => {
+                let module_path = format!(
+                    "{module_path}::{}",
+                    name.to_string().trim_start_matches("r#")
+                );
+                extract_strings(group.stream(), &module_path, strings);
+                index += 3;
+            }
+            // A translation, eg qs_tr!("text")
+            (
+                TokenTree::Ident(ident),
+                Some(TokenTree::Punct(punct)),
+                Some(TokenTree::Group(group)),
+            ) if ident == "qs_tr" && punct.as_char() == '!' => {
+                if let Some(string) = parse_arguments(group.stream(), module_path) {
+                    strings.push(string);
+                }
+                index += 3;
+            }
+            // Any other group, which may contain a translation
+            (TokenTree::Group(group), _, _) => {
+                extract_strings(group.stream(), module_path, strings);
+                index += 1;
+            }
+            _ => index += 1,
+        }
+    }
+}
+
+/// Parse the arguments of a `qs_tr!` macro, which are a source text, an optional
+/// disambiguation, and an optional count
+fn parse_arguments(tokens: TokenStream, module_path: &str) -> Option<TranslatableString> {
+    let mut arguments: Vec<Vec<TokenTree>> = vec![vec![]];
+    for token in tokens {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => arguments.push(vec![]),
+            token => arguments.last_mut().unwrap().push(token),
+        }
+    }
+    // Allow for a trailing comma
+    if arguments.last().is_some_and(Vec::is_empty) {
+        arguments.pop();
+    }
+
+    let string_literal = |argument: &Vec<TokenTree>| match argument.as_slice() {
+        [TokenTree::Literal(literal)] => match syn::Lit::new(literal.clone()) {
+            syn::Lit::Str(string) => Some(string.value()),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    let source_text = string_literal(arguments.first()?)?;
+    let disambiguation = match arguments.get(1) {
+        Some(argument) => string_literal(argument)?,
+        None => String::new(),
+    };
+    Some(TranslatableString {
+        context: module_path.to_owned(),
+        source_text,
+        disambiguation,
+        numerus: arguments.len() > 2,
+    })
+}
+
+/// Escape a string as a C++ string literal
+fn cpp_string_literal(value: &str) -> String {
+    let mut literal = String::from('"');
+    for character in value.chars() {
+        match character {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            character if character.is_ascii_control() => {
+                literal.push_str(&format!("\\{:03o}", character as u32))
+            }
+            character => literal.push(character),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// Escape a string for use as XML text or in a double quoted attribute
+fn xml_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            character => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extract(source: &str) -> Vec<TranslatableString> {
+        let mut strings = vec![];
+        extract_strings(source.parse().unwrap(), "my_crate", &mut strings);
+        strings
+    }
+
+    #[test]
+    fn test_module_path_crate_root() {
+        let src = Path::new("src");
+        assert_eq!(
+            module_path(src, &src.join("lib.rs"), "my-crate"),
+            "my_crate"
+        );
+        assert_eq!(
+            module_path(src, &src.join("main.rs"), "my-crate"),
+            "my_crate"
+        );
+    }
+
+    #[test]
+    fn test_module_path_modules() {
+        let src = Path::new("src");
+        assert_eq!(
+            module_path(src, &src.join("a.rs"), "my_crate"),
+            "my_crate::a"
+        );
+        assert_eq!(
+            module_path(src, &src.join("a").join("mod.rs"), "my_crate"),
+            "my_crate::a"
+        );
+        assert_eq!(
+            module_path(src, &src.join("a").join("b.rs"), "my_crate"),
+            "my_crate::a::b"
+        );
+        // Only the crate root is named lib.rs or main.rs
+        assert_eq!(
+            module_path(src, &src.join("a").join("main.rs"), "my_crate"),
+            "my_crate::a::main"
+        );
+    }
+
+    #[test]
+    fn test_module_path_bin() {
+        let src = Path::new("src");
+        let bin = src.join("bin");
+        assert_eq!(
+            module_path(src, &bin.join("my-app.rs"), "my_crate"),
+            "my_app"
+        );
+        assert_eq!(
+            module_path(src, &bin.join("app").join("main.rs"), "my_crate"),
+            "app"
+        );
+        assert_eq!(
+            module_path(src, &bin.join("app").join("ui.rs"), "my_crate"),
+            "app::ui"
+        );
+    }
+
+    #[test]
+    fn test_extract_strings() {
+        let strings = extract(
+            r#"
+            fn text() {
+                let simple = qs_tr!("Hello");
+                let disambiguated = qs_tr!("Open", "menu",);
+                let plural = qs_tr!("%n files", "", count);
+                let trailing = qs_tr!("Trailing",);
+            }
+            "#,
+        );
+        assert_eq!(strings.len(), 4);
+
+        assert_eq!(strings[0].context, "my_crate");
+        assert_eq!(strings[0].source_text, "Hello");
+        assert_eq!(strings[0].disambiguation, "");
+        assert!(!strings[0].numerus);
+
+        assert_eq!(strings[1].source_text, "Open");
+        assert_eq!(strings[1].disambiguation, "menu");
+        assert!(!strings[1].numerus);
+
+        assert_eq!(strings[2].source_text, "%n files");
+        assert!(strings[2].numerus);
+
+        assert_eq!(strings[3].source_text, "Trailing");
+        assert_eq!(strings[3].disambiguation, "");
+        assert!(!strings[3].numerus);
+    }
+
+    #[test]
+    fn test_extract_strings_inline_modules() {
+        let strings = extract(
+            r#"
+            mod outer {
+                mod r#type {
+                    fn text() -> QString {
+                        qs_tr!("Inner")
+                    }
+                }
+
+                const OUTER: &str = qs_tr!("Outer");
+            }
+
+            mod external;
+            "#,
+        );
+        assert_eq!(strings.len(), 2);
+        assert_eq!(strings[0].context, "my_crate::outer::type");
+        assert_eq!(strings[0].source_text, "Inner");
+        assert_eq!(strings[1].context, "my_crate::outer");
+        assert_eq!(strings[1].source_text, "Outer");
+    }
+
+    #[test]
+    fn test_extract_strings_non_literal() {
+        let strings = extract(
+            r#"
+            fn text(source: &str) {
+                qs_tr!(source);
+                qs_tr!("Text", disambiguation);
+                qs_tr!(concat!("a", "b"));
+                qs_tr!();
+            }
+            "#,
+        );
+        assert!(strings.is_empty());
+    }
+
+    #[test]
+    fn test_cpp_string_literal() {
+        assert_eq!(cpp_string_literal("Hello"), r#""Hello""#);
+        assert_eq!(cpp_string_literal(""), r#""""#);
+        assert_eq!(
+            cpp_string_literal("\"quoted\" \\ path"),
+            r#""\"quoted\" \\ path""#
+        );
+        assert_eq!(cpp_string_literal("a\nb\r\tc"), r#""a\nb\r\tc""#);
+        assert_eq!(cpp_string_literal("\u{7}"), r#""\007""#);
+        assert_eq!(cpp_string_literal("Grüße"), r#""Grüße""#);
+    }
+
+    #[test]
+    fn test_qrc_contents_escaped() {
+        let qrc = qrc_contents(&[PathBuf::from("/tmp/a&b <c>/\"app\"_de.qm")]);
+        assert_eq!(
+            qrc,
+            "<!DOCTYPE RCC>\n<RCC version=\"1.0\">\n    <qresource prefix=\"/i18n\">\n        \
+             <file alias=\"&quot;app&quot;_de.qm\">/tmp/a&amp;b &lt;c&gt;/&quot;app&quot;_de.qm</file>\n    \
+             </qresource>\n</RCC>\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "must have unique file names")]
+    fn test_qrc_contents_duplicate_alias() {
+        qrc_contents(&[
+            PathBuf::from("/tmp/a/app_de.qm"),
+            PathBuf::from("/tmp/b/app_de.qm"),
+        ]);
+    }
+}
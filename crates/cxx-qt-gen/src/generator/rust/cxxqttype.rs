@@ -7,12 +7,17 @@ use crate::{
     generator::{naming::qobject::QObjectNames, rust::fragment::GeneratedRustFragment},
     naming::TypeNames,
 };
+use quote::quote;
 use syn::{parse_quote, Attribute, Result};
 
+/// Generate the [cxx_qt::CxxQtType] implementation of the QObject
+///
+/// When `thread_checks` is true, `rust_mut` asserts the thread affinity of the QObject.
 pub fn generate(
     qobject_names: &QObjectNames,
     type_names: &TypeNames,
     cfgs: &[Attribute],
+    thread_checks: bool,
 ) -> Result<GeneratedRustFragment> {
     let cpp_struct_ident = &qobject_names.name.rust_unqualified();
     let rust_struct_ident = &qobject_names.rust_struct.rust_unqualified();
@@ -26,25 +31,49 @@ pub fn generate(
 
     let qualified_impl = type_names.rust_qualified(cpp_struct_ident)?;
 
+    let mut cxx_mod_contents = vec![
+        parse_quote! {
+            #(#cfgs)*
+            unsafe extern "C++" {
+                #[doc(hidden)]
+                #(#rust_fn_attrs)*
+                fn #rust_fn_name(outer: &#cpp_struct_ident) -> &#rust_struct_ident;
+            }
+        },
+        parse_quote! {
+            #(#cfgs)*
+            unsafe extern "C++" {
+                #[doc(hidden)]
+                #(#rust_mut_fn_attrs)*
+                fn #rust_mut_fn_name(outer: Pin<&mut #cpp_struct_ident>) -> Pin<&mut #rust_struct_ident>;
+            }
+        },
+    ];
+
+    let thread_check = if thread_checks {
+        let (mismatch_fn_name, mismatch_fn_attrs, mismatch_fn_qualified) = qobject_names
+            .cxx_qt_ffi_method("threadAffinityMismatch")
+            .into_cxx_parts();
+        let type_name_str = cpp_struct_ident.to_string();
+
+        cxx_mod_contents.push(parse_quote! {
+            #(#cfgs)*
+            unsafe extern "C++" {
+                #[doc(hidden)]
+                #(#mismatch_fn_attrs)*
+                fn #mismatch_fn_name(outer: &#cpp_struct_ident) -> String;
+            }
+        });
+
+        Some(quote! {
+            ::cxx_qt::assert_thread_affinity(#type_name_str, &*self, #mismatch_fn_qualified);
+        })
+    } else {
+        None
+    };
+
     Ok(GeneratedRustFragment {
-        cxx_mod_contents: vec![
-            parse_quote! {
-                #(#cfgs)*
-                unsafe extern "C++" {
-                    #[doc(hidden)]
-                    #(#rust_fn_attrs)*
-                    fn #rust_fn_name(outer: &#cpp_struct_ident) -> &#rust_struct_ident;
-                }
-            },
-            parse_quote! {
-                #(#cfgs)*
-                unsafe extern "C++" {
-                    #[doc(hidden)]
-                    #(#rust_mut_fn_attrs)*
-                    fn #rust_mut_fn_name(outer: Pin<&mut #cpp_struct_ident>) -> Pin<&mut #rust_struct_ident>;
-                }
-            },
-        ],
+        cxx_mod_contents,
         cxx_qt_mod_contents: vec![
             parse_quote! {
                 #(#cfgs)*
@@ -66,6 +95,7 @@ pub fn generate(
                     }
 
                     fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+                        #thread_check
                         #rust_mut_fn_qualified(self)
                     }
                 }
@@ -87,7 +117,7 @@ mod tests {
         let qobject = create_parsed_qobject();
         let qobject_names = QObjectNames::from_qobject(&qobject, &TypeNames::mock()).unwrap();
 
-        let generated = generate(&qobject_names, &TypeNames::mock(), &vec![]).unwrap();
+        let generated = generate(&qobject_names, &TypeNames::mock(), &vec![], false).unwrap();
 
         assert_eq!(generated.cxx_mod_contents.len(), 2);
         assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
@@ -147,4 +177,44 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generate_rust_cxxqttype_thread_checks() {
+        let qobject = create_parsed_qobject();
+        let qobject_names = QObjectNames::from_qobject(&qobject, &TypeNames::mock()).unwrap();
+
+        let generated = generate(&qobject_names, &TypeNames::mock(), &vec![], true).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 3);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[2],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[cxx_name = "threadAffinityMismatch"]
+                    #[namespace = "rust::cxxqt1"]
+                    fn cxx_qt_ffi_MyObject_threadAffinityMismatch(outer: &MyObject) -> String;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl ::cxx_qt::CxxQtType for qobject::MyObject {
+                    type Rust = MyObjectRust;
+
+                    fn rust(&self) -> &Self::Rust {
+                        qobject::cxx_qt_ffi_MyObject_unsafeRust(self)
+                    }
+
+                    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+                        ::cxx_qt::assert_thread_affinity("MyObject", &*self, qobject::cxx_qt_ffi_MyObject_threadAffinityMismatch);
+                        qobject::cxx_qt_ffi_MyObject_unsafeRustMut(self)
+                    }
+                }
+            },
+        );
+    }
 }
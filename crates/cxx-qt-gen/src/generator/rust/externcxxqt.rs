@@ -86,6 +86,7 @@ impl GeneratedRustFragment {
                 type_names,
                 // Copy the same safety as the extern C++Qt block into the generated extern C++
                 extern_cxxqt_block.unsafety.map(|_| quote! { unsafe }),
+                None,
             )?);
         }
 
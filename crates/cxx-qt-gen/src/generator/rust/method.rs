@@ -6,16 +6,71 @@
 use crate::generator::rust::get_params_tokens;
 use crate::{
     generator::{naming::qobject::QObjectNames, rust::fragment::GeneratedRustFragment},
+    naming::{rust::syn_type_cxx_bridge_to_qualified, TypeNames},
     parser::method::ParsedMethod,
+    syntax::foreignmod,
+};
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, parse_quote_spanned, spanned::Spanned, GenericArgument, Lifetime, PathArguments,
+    Result, ReturnType, Type,
 };
-use quote::quote;
-use syn::{parse_quote_spanned, spanned::Spanned, Result};
+
+/// Find the lifetime of the `self` receiver, eg `'a` from `&'a T` or `Pin<&'a mut T>`
+fn receiver_lifetime(ty: &Type) -> Option<&Lifetime> {
+    match ty {
+        Type::Reference(reference) => reference.lifetime.as_ref(),
+        Type::Path(ty_path) => {
+            let segment = ty_path.path.segments.last()?;
+            if let PathArguments::AngleBracketed(angled) = &segment.arguments {
+                if let Some(GenericArgument::Type(ty)) = angled.args.first() {
+                    return receiver_lifetime(ty);
+                }
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+/// Qualify the return type of a method for use outside of the CXX bridge
+///
+/// A CXX `Result<T>` becomes a `Result` with the error type of the implementation,
+/// which only needs to implement `Display` for CXX.
+fn qualify_return_type(output: &ReturnType, type_names: &TypeNames) -> Result<ReturnType> {
+    let ReturnType::Type(arrow, ty) = output else {
+        return Ok(ReturnType::Default);
+    };
+
+    if let Type::Path(ty_path) = ty.as_ref() {
+        if let Some(segment) = ty_path.path.segments.last() {
+            if let PathArguments::AngleBracketed(angled) = &segment.arguments {
+                if segment.ident == "Result" && angled.args.len() == 1 {
+                    if let Some(GenericArgument::Type(ok)) = angled.args.first() {
+                        let ok = syn_type_cxx_bridge_to_qualified(ok, type_names)?;
+                        return Ok(parse_quote! {
+                            #arrow ::core::result::Result<#ok, impl ::core::fmt::Display>
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(ReturnType::Type(
+        *arrow,
+        Box::new(syn_type_cxx_bridge_to_qualified(ty, type_names)?),
+    ))
+}
 
 pub fn generate_rust_methods(
     invokables: &[&ParsedMethod],
     qobject_names: &QObjectNames,
+    type_names: &TypeNames,
 ) -> Result<GeneratedRustFragment> {
     let cpp_class_name_rust = &qobject_names.name.rust_unqualified();
+    let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+    let type_name_str = cpp_class_name_rust.to_string();
 
     let generated = invokables
         .iter()
@@ -25,13 +80,17 @@ pub fn generate_rust_methods(
             let invokable_ident_cpp = invokable.name.cxx_unqualified();
             let invokable_ident_rust = invokable.name.rust_unqualified();
 
+            let receiver = foreignmod::self_type_from_foreign_fn(&invokable.method.sig)?;
+            let lifetime = receiver_lifetime(&receiver.ty);
             let parameter_signatures = get_params_tokens(
                 invokable.mutable,
                 &invokable.parameters,
                 cpp_class_name_rust,
+                lifetime,
             );
 
             let return_type = &invokable.method.sig.output;
+            let generics = &invokable.method.sig.generics;
 
             let cfgs = &invokable.cfgs;
             let cxx_namespace = qobject_names.namespace_tokens();
@@ -54,26 +113,87 @@ pub fn generate_rust_methods(
                 Some(quote! { unsafe })
             };
 
-            GeneratedRustFragment::from_cxx_item(parse_quote_spanned! {
-                invokable.method.span() =>
-                // Note: extern "Rust" block does not need to be unsafe
-                #block_safety extern #block_type {
-                    // Note that we are exposing a Rust method on the C++ type to C++
-                    //
-                    // CXX ends up generating the source, then we generate the matching header.
-                    #[cxx_name = #invokable_ident_cpp]
-                    // Needed for QObjects to have a namespace on their type or extern block
-                    //
-                    // A Namespace from cxx_qt::bridge would be automatically applied to all children
-                    // but to apply it to only certain types, it is needed here too
-                    #cxx_namespace
-                    #(#cfgs)*
-                    #[doc(hidden)]
-                    #unsafe_call fn #invokable_ident_rust(#parameter_signatures) #return_type;
+            let cxx_item = |ident| {
+                parse_quote_spanned! {
+                    invokable.method.span() =>
+                    // Note: extern "Rust" block does not need to be unsafe
+                    #block_safety extern #block_type {
+                        // Note that we are exposing a Rust method on the C++ type to C++
+                        //
+                        // CXX ends up generating the source, then we generate the matching header.
+                        #[cxx_name = #invokable_ident_cpp]
+                        // Needed for QObjects to have a namespace on their type or extern block
+                        //
+                        // A Namespace from cxx_qt::bridge would be automatically applied to all children
+                        // but to apply it to only certain types, it is needed here too
+                        #cxx_namespace
+                        #(#cfgs)*
+                        #[doc(hidden)]
+                        #unsafe_call fn #ident #generics(#parameter_signatures) #return_type;
+                    }
                 }
+            };
+
+            // Pure virtual methods are implemented in C++, so there is no Rust to guard
+            if invokable.is_pure {
+                return Ok(GeneratedRustFragment::from_cxx_item(cxx_item(
+                    invokable_ident_rust.clone(),
+                )));
+            }
+
+            // C++ calls a generated method which catches any panic from the implementation,
+            // so that it does not unwind into C++
+            let guarded_ident_rust = format_ident!("cxx_qt_ffi_{invokable_ident_rust}");
+            let invokable_name_str = invokable_ident_rust.to_string();
+
+            let self_type_qualified: Type = if invokable.mutable {
+                parse_quote! { core::pin::Pin<&#lifetime mut #qualified_impl> }
+            } else {
+                parse_quote! { &#lifetime #qualified_impl }
+            };
+            let parameters_qualified = invokable
+                .parameters
+                .iter()
+                .map(|parameter| {
+                    let ident = &parameter.ident;
+                    let ty = syn_type_cxx_bridge_to_qualified(&parameter.ty, type_names)?;
+                    Ok(quote! { #ident: #ty })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let parameters_name = invokable
+                .parameters
+                .iter()
+                .map(|parameter| &parameter.ident)
+                .collect::<Vec<_>>();
+            let return_type_qualified = qualify_return_type(return_type, type_names)?;
+
+            let call = quote! { self.#invokable_ident_rust(#(#parameters_name),*) };
+            let call = if invokable.safe {
+                call
+            } else {
+                quote! { unsafe { #call } }
+            };
+            let guarded_call = if matches!(return_type, ReturnType::Default) {
+                quote! { ::cxx_qt::panic::call_guarded(#type_name_str, #invokable_name_str, move || #call) }
+            } else {
+                quote! { ::cxx_qt::panic::call_guarded_returning(#type_name_str, #invokable_name_str, move || #call) }
+            };
+
+            Ok(GeneratedRustFragment {
+                cxx_mod_contents: vec![cxx_item(guarded_ident_rust.clone())],
+                cxx_qt_mod_contents: vec![parse_quote_spanned! {
+                    invokable.method.span() =>
+                    #(#cfgs)*
+                    impl #qualified_impl {
+                        #[doc(hidden)]
+                        #unsafe_call fn #guarded_ident_rust #generics(self: #self_type_qualified, #(#parameters_qualified),*) #return_type_qualified {
+                            #guarded_call
+                        }
+                    }
+                }],
             })
         })
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(GeneratedRustFragment::flatten(generated))
 }
@@ -112,11 +232,18 @@ mod tests {
         ];
         let qobject_names = create_qobjectname();
 
-        let generated =
-            generate_rust_methods(&invokables.iter().collect::<Vec<_>>(), &qobject_names).unwrap();
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QColor", None, None, None);
+        type_names.mock_insert("T", None, None, None);
+        let generated = generate_rust_methods(
+            &invokables.iter().collect::<Vec<_>>(),
+            &qobject_names,
+            &type_names,
+        )
+        .unwrap();
 
         assert_eq!(generated.cxx_mod_contents.len(), 4);
-        assert_eq!(generated.cxx_qt_mod_contents.len(), 0);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 4);
 
         // void_invokable
         assert_tokens_eq(
@@ -125,7 +252,18 @@ mod tests {
                 extern "Rust" {
                     #[cxx_name = "voidInvokable"]
                     #[doc(hidden)]
-                    fn void_invokable(self: &MyObject);
+                    fn cxx_qt_ffi_void_invokable(self: &MyObject);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_void_invokable(self: &qobject::MyObject, ) {
+                        ::cxx_qt::panic::call_guarded("MyObject", "void_invokable", move | | self.void_invokable())
+                    }
                 }
             },
         );
@@ -137,7 +275,18 @@ mod tests {
                 extern "Rust" {
                     #[cxx_name = "trivialInvokable"]
                     #[doc(hidden)]
-                    fn trivial_invokable(self: &MyObject, param: i32) -> i32;
+                    fn cxx_qt_ffi_trivial_invokable(self: &MyObject, param: i32) -> i32;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_trivial_invokable(self: &qobject::MyObject, param: i32) -> i32 {
+                        ::cxx_qt::panic::call_guarded_returning("MyObject", "trivial_invokable", move | | self.trivial_invokable(param))
+                    }
                 }
             },
         );
@@ -149,7 +298,18 @@ mod tests {
                 extern "Rust" {
                     #[cxx_name = "opaqueInvokable"]
                     #[doc(hidden)]
-                    fn opaque_invokable(self: Pin<&mut MyObject>, param: &QColor) -> UniquePtr<QColor>;
+                    fn cxx_qt_ffi_opaque_invokable(self: Pin<&mut MyObject>, param: &QColor) -> UniquePtr<QColor>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[2],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_opaque_invokable(self: core::pin::Pin<&mut qobject::MyObject>, param: &QColor) -> cxx::UniquePtr<QColor> {
+                        ::cxx_qt::panic::call_guarded_returning("MyObject", "opaque_invokable", move | | self.opaque_invokable(param))
+                    }
                 }
             },
         );
@@ -161,9 +321,113 @@ mod tests {
                 extern "Rust" {
                     #[cxx_name = "unsafeInvokable"]
                     #[doc(hidden)]
-                    unsafe fn unsafe_invokable(self:&MyObject, param: *mut T) -> *mut T;
+                    unsafe fn cxx_qt_ffi_unsafe_invokable(self:&MyObject, param: *mut T) -> *mut T;
                 }
             },
         );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[3],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    unsafe fn cxx_qt_ffi_unsafe_invokable(self: &qobject::MyObject, param: *mut T) -> *mut T {
+                        ::cxx_qt::panic::call_guarded_returning("MyObject", "unsafe_invokable", move | | unsafe { self.unsafe_invokable(param) })
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_invokables_lifetime() {
+        let method1: ForeignItemFn = parse_quote! {
+            #[cxx_name = "refInvokable"]
+            fn ref_invokable<'a>(self: &'a MyObject) -> &'a QColor;
+        };
+        let method2: ForeignItemFn = parse_quote! {
+            #[cxx_name = "refMutInvokable"]
+            fn ref_mut_invokable<'a>(self: Pin<&'a mut MyObject>) -> &'a QColor;
+        };
+        let invokables = vec![
+            ParsedMethod::mock_qinvokable(&method1),
+            ParsedMethod::mock_qinvokable(&method2).make_mutable(),
+        ];
+        let qobject_names = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QColor", None, None, None);
+        let generated = generate_rust_methods(
+            &invokables.iter().collect::<Vec<_>>(),
+            &qobject_names,
+            &type_names,
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        // ref_invokable
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "refInvokable"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_ref_invokable<'a>(self: &'a MyObject) -> &'a QColor;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_ref_invokable<'a>(self: &'a qobject::MyObject, ) -> &'a QColor {
+                        ::cxx_qt::panic::call_guarded_returning("MyObject", "ref_invokable", move | | self.ref_invokable())
+                    }
+                }
+            },
+        );
+
+        // ref_mut_invokable
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "refMutInvokable"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_ref_mut_invokable<'a>(self: Pin<&'a mut MyObject>) -> &'a QColor;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_ref_mut_invokable<'a>(self: core::pin::Pin<&'a mut qobject::MyObject>, ) -> &'a QColor {
+                        ::cxx_qt::panic::call_guarded_returning("MyObject", "ref_mut_invokable", move | | self.ref_mut_invokable())
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_qualify_return_type() {
+        let type_names = TypeNames::mock();
+
+        assert_tokens_eq(
+            &qualify_return_type(&ReturnType::Default, &type_names).unwrap(),
+            quote! {},
+        );
+        assert_tokens_eq(
+            &qualify_return_type(&parse_quote! { -> UniquePtr<i32> }, &type_names).unwrap(),
+            quote! { -> cxx::UniquePtr<i32> },
+        );
+        assert_tokens_eq(
+            &qualify_return_type(&parse_quote! { -> Result<UniquePtr<i32>> }, &type_names).unwrap(),
+            quote! { -> ::core::result::Result<cxx::UniquePtr<i32>, impl ::core::fmt::Display> },
+        );
     }
 }
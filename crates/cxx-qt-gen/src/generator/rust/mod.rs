@@ -9,6 +9,7 @@ pub mod externcxxqt;
 pub mod fragment;
 pub mod inherit;
 pub mod method;
+pub mod overrides;
 pub mod property;
 pub mod qenum;
 pub mod qobject;
@@ -19,7 +20,7 @@ use crate::generator::{rust::fragment::GeneratedRustFragment, structuring};
 use crate::parser::{parameter::ParsedFunctionParameter, qobject::ParsedQObject, Parser};
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::{parse_quote, Item, ItemMod, Result};
+use syn::{parse_quote, Item, ItemMod, Lifetime, Result};
 
 /// Representation of the generated Rust code for a QObject
 pub struct GeneratedRustBlocks {
@@ -120,16 +121,27 @@ fn add_qobject_import(qobjects: &[ParsedQObject]) -> GeneratedRustFragment {
     }
 }
 
+/// Whether the generated code asserts the thread affinity of a QObject when it is mutated
+///
+/// This is enabled by the thread-checks feature, but never in our own tests
+/// so that the expected outputs do not depend on the enabled features.
+pub(crate) fn thread_checks() -> bool {
+    cfg!(all(feature = "thread-checks", not(test)))
+}
+
 /// Return the [TokenStream] of the parsed parameters for use in generation
+///
+/// The `self` receiver is given the `lifetime` if there is one.
 pub fn get_params_tokens(
     mutable: bool,
     parameters: &[ParsedFunctionParameter],
     class_name: &Ident,
+    lifetime: Option<&Lifetime>,
 ) -> TokenStream {
     let struct_sig = if mutable {
-        quote! { Pin<&mut #class_name> }
+        quote! { Pin<&#lifetime mut #class_name> }
     } else {
-        quote! { &#class_name }
+        quote! { &#lifetime #class_name }
     };
     if parameters.is_empty() {
         quote! { self: #struct_sig }
@@ -0,0 +1,213 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{naming::qobject::QObjectNames, rust::fragment::GeneratedRustFragment},
+    parser::overrides::{Overrides, VirtualParameters},
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, Attribute, Result};
+
+/// Generate the methods which forward the overridden virtual methods to the trait from cxx-qt-lib,
+/// and the implementation of the trait which calls the methods of the base class
+pub fn generate(
+    overrides: Overrides,
+    qobject_names: &QObjectNames,
+    cfgs: &[Attribute],
+) -> Result<GeneratedRustFragment> {
+    let qobject_name = qobject_names.name.rust_qualified();
+
+    let mut override_methods = vec![];
+    let mut base_methods = vec![];
+    for method in overrides.methods() {
+        let cfg = method.cfg();
+        let name = format_ident!("{}", method.name);
+        let override_ident = method.override_ident();
+        let trait_path = Overrides::trait_path(method);
+
+        let (parameters, call): (TokenStream, TokenStream) = match method.parameters {
+            VirtualParameters::Geometry => (
+                quote! { new_geometry: &::cxx_qt_lib::QRectF, old_geometry: &::cxx_qt_lib::QRectF },
+                quote! { <Self as #trait_path>::#name(self, new_geometry, old_geometry) },
+            ),
+            VirtualParameters::MouseEvent => (
+                quote! { event: *mut ::cxx_qt_lib::QMouseEvent },
+                quote! {
+                    if let Some(event) = unsafe { event.as_mut() } {
+                        <Self as #trait_path>::#name(self, unsafe { ::core::pin::Pin::new_unchecked(event) })
+                    }
+                },
+            ),
+            VirtualParameters::Painter => (
+                quote! { painter: *mut ::cxx_qt_lib::QPainter },
+                quote! {
+                    if let Some(painter) = unsafe { painter.as_mut() } {
+                        <Self as #trait_path>::#name(self, unsafe { ::core::pin::Pin::new_unchecked(painter) })
+                    }
+                },
+            ),
+        };
+        // Only the methods taking pointers are unsafe in the bridge
+        let unsafety =
+            (method.parameters != VirtualParameters::Geometry).then(|| quote! { unsafe });
+
+        override_methods.push(quote! {
+            #cfg
+            #[doc(hidden)]
+            #unsafety fn #override_ident(self: ::core::pin::Pin<&mut Self>, #parameters) {
+                #call
+            }
+        });
+
+        if method.has_base {
+            let base_name = format_ident!("base_{}", method.name);
+            let base_ident = method.base_ident();
+            let (parameters, call) = match method.parameters {
+                VirtualParameters::Geometry => (
+                    quote! { new_geometry: &::cxx_qt_lib::QRectF, old_geometry: &::cxx_qt_lib::QRectF },
+                    quote! { self.#base_ident(new_geometry, old_geometry) },
+                ),
+                VirtualParameters::MouseEvent => (
+                    quote! { event: ::core::pin::Pin<&mut ::cxx_qt_lib::QMouseEvent> },
+                    quote! { unsafe { self.#base_ident(event.get_unchecked_mut()) } },
+                ),
+                VirtualParameters::Painter => (
+                    quote! { painter: ::core::pin::Pin<&mut ::cxx_qt_lib::QPainter> },
+                    quote! { unsafe { self.#base_ident(painter.get_unchecked_mut()) } },
+                ),
+            };
+
+            base_methods.push(quote! {
+                #cfg
+                fn #base_name(self: ::core::pin::Pin<&mut Self>, #parameters) {
+                    #call
+                }
+            });
+        }
+    }
+
+    Ok(GeneratedRustFragment {
+        cxx_mod_contents: vec![],
+        cxx_qt_mod_contents: vec![
+            parse_quote! {
+                #(#cfgs)*
+                impl #qobject_name {
+                    #(#override_methods)*
+                }
+            },
+            parse_quote! {
+                #(#cfgs)*
+                impl ::cxx_qt_lib::QQuickItemBase for #qobject_name {
+                    #(#base_methods)*
+                }
+            },
+        ],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::tests::assert_tokens_eq;
+
+    #[test]
+    fn test_generate_rust_overrides() {
+        let qobject_names = create_qobjectname();
+        let generated = generate(Overrides::QQuickPaintedItem, &qobject_names, &[]).unwrap();
+
+        assert!(generated.cxx_mod_contents.is_empty());
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    #[cfg(not(cxxqt_qt_version_major = "5"))]
+                    #[doc(hidden)]
+                    fn cxx_qt_override_geometry_change(self: ::core::pin::Pin<&mut Self>, new_geometry: &::cxx_qt_lib::QRectF, old_geometry: &::cxx_qt_lib::QRectF) {
+                        <Self as ::cxx_qt_lib::QQuickItemOverrides>::geometry_change(self, new_geometry, old_geometry)
+                    }
+
+                    #[cfg(cxxqt_qt_version_major = "5")]
+                    #[doc(hidden)]
+                    fn cxx_qt_override_geometry_changed(self: ::core::pin::Pin<&mut Self>, new_geometry: &::cxx_qt_lib::QRectF, old_geometry: &::cxx_qt_lib::QRectF) {
+                        <Self as ::cxx_qt_lib::QQuickItemOverrides>::geometry_change(self, new_geometry, old_geometry)
+                    }
+
+                    #[doc(hidden)]
+                    unsafe fn cxx_qt_override_mouse_double_click_event(self: ::core::pin::Pin<&mut Self>, event: *mut ::cxx_qt_lib::QMouseEvent) {
+                        if let Some(event) = unsafe { event.as_mut() } {
+                            <Self as ::cxx_qt_lib::QQuickItemOverrides>::mouse_double_click_event(self, unsafe { ::core::pin::Pin::new_unchecked(event) })
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    unsafe fn cxx_qt_override_mouse_move_event(self: ::core::pin::Pin<&mut Self>, event: *mut ::cxx_qt_lib::QMouseEvent) {
+                        if let Some(event) = unsafe { event.as_mut() } {
+                            <Self as ::cxx_qt_lib::QQuickItemOverrides>::mouse_move_event(self, unsafe { ::core::pin::Pin::new_unchecked(event) })
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    unsafe fn cxx_qt_override_mouse_press_event(self: ::core::pin::Pin<&mut Self>, event: *mut ::cxx_qt_lib::QMouseEvent) {
+                        if let Some(event) = unsafe { event.as_mut() } {
+                            <Self as ::cxx_qt_lib::QQuickItemOverrides>::mouse_press_event(self, unsafe { ::core::pin::Pin::new_unchecked(event) })
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    unsafe fn cxx_qt_override_mouse_release_event(self: ::core::pin::Pin<&mut Self>, event: *mut ::cxx_qt_lib::QMouseEvent) {
+                        if let Some(event) = unsafe { event.as_mut() } {
+                            <Self as ::cxx_qt_lib::QQuickItemOverrides>::mouse_release_event(self, unsafe { ::core::pin::Pin::new_unchecked(event) })
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    unsafe fn cxx_qt_override_paint(self: ::core::pin::Pin<&mut Self>, painter: *mut ::cxx_qt_lib::QPainter) {
+                        if let Some(painter) = unsafe { painter.as_mut() } {
+                            <Self as ::cxx_qt_lib::QQuickPaintedItemOverrides>::paint(self, unsafe { ::core::pin::Pin::new_unchecked(painter) })
+                        }
+                    }
+                }
+            },
+        );
+
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl ::cxx_qt_lib::QQuickItemBase for qobject::MyObject {
+                    #[cfg(not(cxxqt_qt_version_major = "5"))]
+                    fn base_geometry_change(self: ::core::pin::Pin<&mut Self>, new_geometry: &::cxx_qt_lib::QRectF, old_geometry: &::cxx_qt_lib::QRectF) {
+                        self.cxx_qt_base_geometry_change(new_geometry, old_geometry)
+                    }
+
+                    #[cfg(cxxqt_qt_version_major = "5")]
+                    fn base_geometry_change(self: ::core::pin::Pin<&mut Self>, new_geometry: &::cxx_qt_lib::QRectF, old_geometry: &::cxx_qt_lib::QRectF) {
+                        self.cxx_qt_base_geometry_changed(new_geometry, old_geometry)
+                    }
+
+                    fn base_mouse_double_click_event(self: ::core::pin::Pin<&mut Self>, event: ::core::pin::Pin<&mut ::cxx_qt_lib::QMouseEvent>) {
+                        unsafe { self.cxx_qt_base_mouse_double_click_event(event.get_unchecked_mut()) }
+                    }
+
+                    fn base_mouse_move_event(self: ::core::pin::Pin<&mut Self>, event: ::core::pin::Pin<&mut ::cxx_qt_lib::QMouseEvent>) {
+                        unsafe { self.cxx_qt_base_mouse_move_event(event.get_unchecked_mut()) }
+                    }
+
+                    fn base_mouse_press_event(self: ::core::pin::Pin<&mut Self>, event: ::core::pin::Pin<&mut ::cxx_qt_lib::QMouseEvent>) {
+                        unsafe { self.cxx_qt_base_mouse_press_event(event.get_unchecked_mut()) }
+                    }
+
+                    fn base_mouse_release_event(self: ::core::pin::Pin<&mut Self>, event: ::core::pin::Pin<&mut ::cxx_qt_lib::QMouseEvent>) {
+                        unsafe { self.cxx_qt_base_mouse_release_event(event.get_unchecked_mut()) }
+                    }
+                }
+            },
+        );
+    }
+}
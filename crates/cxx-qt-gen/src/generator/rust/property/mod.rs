@@ -370,7 +370,9 @@ mod tests {
                     handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuretrivialPropertyChanged>,
                     self_value: core::pin::Pin<&mut qobject::MyObject>,
                 ) {
-                    handler.closure()(self_value, );
+                    ::cxx_qt::panic::call_guarded("MyObject", "trivial_property_changed", move | | {
+                        handler.closure()(self_value, )
+                    });
                 }
             },
         );
@@ -495,7 +497,9 @@ mod tests {
                     handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureopaquePropertyChanged>,
                     self_value: core::pin::Pin<&mut qobject::MyObject>,
                 ) {
-                    handler.closure()(self_value, );
+                    ::cxx_qt::panic::call_guarded("MyObject", "opaque_property_changed", move | | {
+                        handler.closure()(self_value, )
+                    });
                 }
             },
         );
@@ -620,7 +624,9 @@ mod tests {
                     handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureunsafePropertyChanged>,
                     self_value: core::pin::Pin<&mut qobject::MyObject>,
                 ) {
-                    handler.closure()(self_value, );
+                    ::cxx_qt::panic::call_guarded("MyObject", "unsafe_property_changed", move | | {
+                        handler.closure()(self_value, )
+                    });
                 }
             },
         );
@@ -9,8 +9,8 @@ use crate::{
         naming::{namespace::NamespaceName, qobject::QObjectNames},
         rust::{
             constructor, cxxqttype, fragment::GeneratedRustFragment, inherit,
-            method::generate_rust_methods, property::generate_rust_properties,
-            signals::generate_rust_signals, threading,
+            method::generate_rust_methods, overrides, property::generate_rust_properties,
+            signals::generate_rust_signals, thread_checks, threading,
         },
     },
     naming::TypeNames,
@@ -37,7 +37,7 @@ impl GeneratedRustFragment {
                 type_names,
                 structured_qobject,
             )?,
-            generate_rust_methods(&structured_qobject.methods, &qobject_names)?,
+            generate_rust_methods(&structured_qobject.methods, &qobject_names, type_names)?,
             inherit::generate(&qobject_names, &structured_qobject.inherited_methods)?,
             generate_rust_signals(&structured_qobject.signals, &qobject_names, type_names)?,
         ];
@@ -63,6 +63,15 @@ impl GeneratedRustFragment {
             )?);
         }
 
+        // If this type overrides virtual methods with a trait from cxx-qt-lib then add generation
+        if let Some(overrides) = structured_qobject.overrides {
+            generated.push(overrides::generate(
+                overrides,
+                &qobject_names,
+                &qobject.cfgs,
+            )?);
+        }
+
         // Generate casting impl
         let base = structured_qobject
             .declaration
@@ -118,7 +127,7 @@ impl GeneratedRustFragment {
                 type_names,
                 &qobject.cfgs,
             )?,
-            cxxqttype::generate(&qobject_names, type_names, &qobject.cfgs)?,
+            cxxqttype::generate(&qobject_names, type_names, &qobject.cfgs, thread_checks())?,
         ]);
 
         Ok(GeneratedRustFragment::flatten(generated))
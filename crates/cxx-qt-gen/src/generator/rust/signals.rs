@@ -3,7 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::generator::rust::get_params_tokens;
+use crate::generator::rust::{get_params_tokens, thread_checks};
 use crate::{
     generator::{
         naming::{
@@ -16,15 +16,21 @@ use crate::{
     parser::signals::ParsedSignal,
 };
 use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned};
+use quote::{format_ident, quote, quote_spanned};
 use syn::spanned::Spanned;
-use syn::{parse_quote, parse_quote_spanned, FnArg, Ident, Result, Type};
+use syn::{parse_quote, parse_quote_spanned, FnArg, Ident, Item, Path, Result, Type};
 
+/// Generate the Rust side of a signal
+///
+/// When `thread_affinity_mismatch` is given, the emitter in the CXX bridge is hidden and a
+/// wrapper which asserts the thread affinity of the QObject with the given function is
+/// generated instead.
 pub fn generate_rust_signal(
     signal: &ParsedSignal,
     qobject_name: &Name,
     type_names: &TypeNames,
     unsafety_block: Option<TokenStream>,
+    thread_affinity_mismatch: Option<&Path>,
 ) -> Result<GeneratedRustFragment> {
     let span = signal.method.span();
     let idents = QSignalNames::from(signal);
@@ -110,7 +116,7 @@ pub fn generate_rust_signal(
 
     let signal_ident_cpp = idents.name.rust_unqualified();
     let parameter_signatures =
-        get_params_tokens(signal.mutable, &signal.parameters, rust_class_name);
+        get_params_tokens(signal.mutable, &signal.parameters, rust_class_name, None);
 
     let return_type = &signal.method.sig.output;
 
@@ -121,20 +127,57 @@ pub fn generate_rust_signal(
     let signal_handler_drop = idents_helper.function_drop;
     let namespace_str = idents_helper.namespace.to_string();
 
+    let qobject_name_str = qobject_name_rust.to_string();
+    let signal_name_str = signal_ident_cpp.to_string();
+
     let mut cxx_mod_contents = vec![];
+    let mut cxx_qt_mod_contents: Vec<Item> = vec![];
 
     // TODO: what happens with RustQt signals, can they be private yet?
     if !signal.private {
-        cxx_mod_contents.push(parse_quote_spanned! {
-            span=>
-            #unsafety_block extern "C++" {
-                #[cxx_name = #cpp_ident]
+        if let Some(thread_affinity_mismatch) = thread_affinity_mismatch {
+            let emit_ident_rust = format_ident!("cxx_qt_ffi_{signal_ident_cpp}");
+
+            cxx_mod_contents.push(parse_quote_spanned! {
+                span=>
+                #unsafety_block extern "C++" {
+                    #[doc(hidden)]
+                    #[cxx_name = #cpp_ident]
+                    #(#cfgs)*
+                    #namespace
+                    #unsafe_call fn #emit_ident_rust(#parameter_signatures) #return_type;
+                }
+            });
+
+            let emit_call = quote_spanned! { span=> self.#emit_ident_rust(#(#parameters_name),*) };
+            let emit_call = if signal.safe {
+                emit_call
+            } else {
+                quote_spanned! { span=> unsafe { #emit_call } }
+            };
+            cxx_qt_mod_contents.push(parse_quote_spanned! {
+                span=>
                 #(#cfgs)*
-                #(#doc_comments)*
-                #namespace
-                #unsafe_call fn #signal_ident_cpp(#parameter_signatures) #return_type;
-            }
-        });
+                impl #qualified_impl {
+                    #(#doc_comments)*
+                    pub #unsafe_call fn #signal_ident_cpp(self: #self_type_qualified, #(#parameters_qualified_arg),*) {
+                        ::cxx_qt::assert_thread_affinity(#qobject_name_str, &*self, #thread_affinity_mismatch);
+                        #emit_call
+                    }
+                }
+            });
+        } else {
+            cxx_mod_contents.push(parse_quote_spanned! {
+                span=>
+                #unsafety_block extern "C++" {
+                    #[cxx_name = #cpp_ident]
+                    #(#cfgs)*
+                    #(#doc_comments)*
+                    #namespace
+                    #unsafe_call fn #signal_ident_cpp(#parameter_signatures) #return_type;
+                }
+            });
+        }
     }
 
     cxx_mod_contents.extend(vec![
@@ -164,86 +207,90 @@ pub fn generate_rust_signal(
             }
         }]);
 
-    Ok(GeneratedRustFragment {
-        cxx_mod_contents,
-        cxx_qt_mod_contents: vec![
-            parse_quote_spanned! {
-                span=>
-                #(#cfgs)*
-                impl #qualified_impl {
-                    #[doc = "Connect the given function pointer to the signal "]
-                    #[doc = #signal_name_cpp]
-                    #[doc = ", so that when the signal is emitted the function pointer is executed."]
-                    pub fn #connect_ident_rust<F: FnMut(#self_type_qualified, #(#parameters_qualified_type),*) + 'static + Send>(self: #self_type_qualified, closure: F, conn_type: cxx_qt::ConnectionType) -> cxx_qt::QMetaObjectConnectionGuard
-                    {
-                        cxx_qt::QMetaObjectConnectionGuard::from(#module_ident::#free_connect_ident_rust(
-                            self,
-                            cxx_qt::signalhandler::CxxQtSignalHandler::<#closure_struct>::new(Box::new(closure)),
-                            conn_type,
-                        ))
-                    }
-                }
-            },
-            parse_quote_spanned! {
-                span=>
-                #(#cfgs)*
-                impl #qualified_impl {
-                    #[doc = "Connect the given function pointer to the signal "]
-                    #[doc = #signal_name_cpp]
-                    #[doc = ", so that when the signal is emitted the function pointer is executed."]
-                    #[doc = "\n"]
-                    #[doc = "Note that this method uses a AutoConnection connection type."]
-                    pub fn #on_ident_rust<F: FnMut(#self_type_qualified, #(#parameters_qualified_type),*) + 'static + Send>(self: #self_type_qualified, closure: F) -> cxx_qt::QMetaObjectConnectionGuard
-                    {
-                        cxx_qt::QMetaObjectConnectionGuard::from(#module_ident::#free_connect_ident_rust(
-                            self,
-                            cxx_qt::signalhandler::CxxQtSignalHandler::<#closure_struct>::new(Box::new(closure)),
-                            cxx_qt::ConnectionType::AutoConnection,
-                        ))
-                    }
-                }
-            },
-            parse_quote_spanned! {
-                span =>
-                #(#cfgs)*
-                #[doc(hidden)]
-                pub struct #closure_struct {}
-            },
-            parse_quote_spanned! {
-                span =>
-                #(#cfgs)*
-                impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for #closure_struct {
-                    type Id = cxx::type_id!(#signal_handler_alias_namespaced_str);
-                    type FnType = dyn FnMut(#self_type_qualified, #(#parameters_qualified_type),*) + Send;
+    cxx_qt_mod_contents.extend(vec![
+        parse_quote_spanned! {
+            span=>
+            #(#cfgs)*
+            impl #qualified_impl {
+                #[doc = "Connect the given function pointer to the signal "]
+                #[doc = #signal_name_cpp]
+                #[doc = ", so that when the signal is emitted the function pointer is executed."]
+                pub fn #connect_ident_rust<F: FnMut(#self_type_qualified, #(#parameters_qualified_type),*) + 'static + Send>(self: #self_type_qualified, closure: F, conn_type: cxx_qt::ConnectionType) -> cxx_qt::QMetaObjectConnectionGuard
+                {
+                    cxx_qt::QMetaObjectConnectionGuard::from(#module_ident::#free_connect_ident_rust(
+                        self,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<#closure_struct>::new(Box::new(closure)),
+                        conn_type,
+                    ))
                 }
-            },
-            parse_quote_spanned! {
-                span =>
-                #(#cfgs)*
-                use core::mem::drop as #signal_handler_drop;
-            },
-            parse_quote_spanned! {
-                span =>
-                #(#cfgs)*
-                fn #signal_handler_call(
-                    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<#closure_struct>,
-                    self_value: #self_type_qualified,
-                    #(#parameters_qualified_arg),*
-                ) {
-                    handler.closure()(self_value, #(#parameters_name),*);
+            }
+        },
+        parse_quote_spanned! {
+            span=>
+            #(#cfgs)*
+            impl #qualified_impl {
+                #[doc = "Connect the given function pointer to the signal "]
+                #[doc = #signal_name_cpp]
+                #[doc = ", so that when the signal is emitted the function pointer is executed."]
+                #[doc = "\n"]
+                #[doc = "Note that this method uses a AutoConnection connection type."]
+                pub fn #on_ident_rust<F: FnMut(#self_type_qualified, #(#parameters_qualified_type),*) + 'static + Send>(self: #self_type_qualified, closure: F) -> cxx_qt::QMetaObjectConnectionGuard
+                {
+                    cxx_qt::QMetaObjectConnectionGuard::from(#module_ident::#free_connect_ident_rust(
+                        self,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<#closure_struct>::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    ))
                 }
-            },
-            parse_quote_spanned! {
-                span =>
-                #(#cfgs)*
-                cxx_qt::static_assertions::assert_eq_align!(cxx_qt::signalhandler::CxxQtSignalHandler<#closure_struct>, usize);
-            },
-            parse_quote_spanned! {
-                span =>
-                #(#cfgs)*
-                cxx_qt::static_assertions::assert_eq_size!(cxx_qt::signalhandler::CxxQtSignalHandler<#closure_struct>, [usize; 2]);
-            },
-        ],
+            }
+        },
+        parse_quote_spanned! {
+            span =>
+            #(#cfgs)*
+            #[doc(hidden)]
+            pub struct #closure_struct {}
+        },
+        parse_quote_spanned! {
+            span =>
+            #(#cfgs)*
+            impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for #closure_struct {
+                type Id = cxx::type_id!(#signal_handler_alias_namespaced_str);
+                type FnType = dyn FnMut(#self_type_qualified, #(#parameters_qualified_type),*) + Send;
+            }
+        },
+        parse_quote_spanned! {
+            span =>
+            #(#cfgs)*
+            use core::mem::drop as #signal_handler_drop;
+        },
+        parse_quote_spanned! {
+            span =>
+            #(#cfgs)*
+            fn #signal_handler_call(
+                handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<#closure_struct>,
+                self_value: #self_type_qualified,
+                #(#parameters_qualified_arg),*
+            ) {
+                ::cxx_qt::panic::call_guarded(#qobject_name_str, #signal_name_str, move || {
+                    handler.closure()(self_value, #(#parameters_name),*)
+                });
+            }
+        },
+        parse_quote_spanned! {
+            span =>
+            #(#cfgs)*
+            cxx_qt::static_assertions::assert_eq_align!(cxx_qt::signalhandler::CxxQtSignalHandler<#closure_struct>, usize);
+        },
+        parse_quote_spanned! {
+            span =>
+            #(#cfgs)*
+            cxx_qt::static_assertions::assert_eq_size!(cxx_qt::signalhandler::CxxQtSignalHandler<#closure_struct>, [usize; 2]);
+        },
+    ]);
+
+    Ok(GeneratedRustFragment {
+        cxx_mod_contents,
+        cxx_qt_mod_contents,
     })
 }
 
@@ -252,6 +299,13 @@ pub fn generate_rust_signals(
     qobject_names: &QObjectNames,
     type_names: &TypeNames,
 ) -> Result<GeneratedRustFragment> {
+    let thread_affinity_mismatch = thread_checks().then(|| {
+        let (_, _, thread_affinity_mismatch) = qobject_names
+            .cxx_qt_ffi_method("threadAffinityMismatch")
+            .into_cxx_parts();
+        thread_affinity_mismatch
+    });
+
     let generated = signals
         .iter()
         .map(|signal| {
@@ -266,6 +320,7 @@ pub fn generate_rust_signals(
                 } else {
                     None
                 },
+                thread_affinity_mismatch.as_ref(),
             )
         })
         .collect::<Result<Vec<_>>>()?;
@@ -383,7 +438,9 @@ mod tests {
                     handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureready>,
                     self_value: core::pin::Pin<&mut qobject::MyObject>,
                 ) {
-                    handler.closure()(self_value, );
+                    ::cxx_qt::panic::call_guarded("MyObject", "ready", move | | {
+                        handler.closure()(self_value, )
+                    });
                 }
             },
         );
@@ -420,6 +477,7 @@ mod tests {
             &qobject_name,
             &type_names,
             Some(quote! { unsafe }),
+            None,
         )
         .unwrap();
 
@@ -441,6 +499,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_rust_signal_thread_checks() {
+        let method: ForeignItemFn = parse_quote! {
+            fn ready(self: Pin<&mut MyObject>);
+        };
+        let qsignal = ParsedSignal::mock(&method);
+
+        let type_names = TypeNames::mock();
+
+        let qobject_name = type_names.lookup(&qsignal.qobject_ident).unwrap().clone();
+        let generated = generate_rust_signal(
+            &qsignal,
+            &qobject_name,
+            &type_names,
+            Some(quote! { unsafe }),
+            Some(&parse_quote! { qobject::cxx_qt_ffi_MyObject_threadAffinityMismatch }),
+        )
+        .unwrap();
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[cxx_name = "ready"]
+                    fn cxx_qt_ffi_ready(self: Pin<&mut MyObject>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    pub fn ready(self: core::pin::Pin<&mut qobject::MyObject>, ) {
+                        ::cxx_qt::assert_thread_affinity("MyObject", &*self, qobject::cxx_qt_ffi_MyObject_threadAffinityMismatch);
+                        self.cxx_qt_ffi_ready()
+                    }
+                }
+            },
+        );
+
+        common_asserts(
+            &generated.cxx_mod_contents[1..].into(),
+            &generated.cxx_qt_mod_contents[1..].into(),
+        );
+    }
+
     #[test]
     fn test_generate_rust_signal_parameters() {
         let method: ForeignItemFn = parse_quote! {
@@ -565,7 +670,9 @@ mod tests {
                     trivial: i32,
                     opaque: cxx::UniquePtr<QColor>
                 ) {
-                    handler.closure()(self_value, trivial, opaque);
+                    ::cxx_qt::panic::call_guarded("MyObject", "data_changed", move | | {
+                        handler.closure()(self_value, trivial, opaque)
+                    });
                 }
             },
         );
@@ -706,7 +813,9 @@ mod tests {
                     self_value: core::pin::Pin<&mut qobject::MyObject>,
                     param: *mut T
                 ) {
-                    handler.closure()(self_value, param);
+                    ::cxx_qt::panic::call_guarded("MyObject", "unsafe_signal", move | | {
+                        handler.closure()(self_value, param)
+                    });
                 }
             },
         );
@@ -848,7 +957,9 @@ mod tests {
                     handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurebaseName>,
                     self_value: core::pin::Pin<&mut qobject::MyObject>,
                 ) {
-                    handler.closure()(self_value, );
+                    ::cxx_qt::panic::call_guarded("MyObject", "existing_signal", move | | {
+                        handler.closure()(self_value, )
+                    });
                 }
             },
         );
@@ -889,6 +1000,7 @@ mod tests {
             &qobject_name,
             &type_names,
             Some(quote! { unsafe }),
+            None,
         )
         .unwrap();
 
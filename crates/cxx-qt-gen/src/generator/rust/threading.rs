@@ -23,12 +23,20 @@ pub fn generate(
     let module_ident = qobject_names.name.require_module()?;
 
     let cpp_struct_ident = qobject_names.name.rust_unqualified();
+    let type_name_str = cpp_struct_ident.to_string();
     let cxx_qt_thread_ident = &qobject_names.cxx_qt_thread_class;
     let cxx_qt_thread_queued_fn_ident = &qobject_names.cxx_qt_thread_queued_fn_struct;
 
     let (thread_queue_name, thread_queue_attrs, thread_queue_qualified) = qobject_names
         .cxx_qt_ffi_method("cxxQtThreadQueue")
         .into_cxx_parts();
+    let (
+        thread_queue_with_options_name,
+        thread_queue_with_options_attrs,
+        thread_queue_with_options_qualified,
+    ) = qobject_names
+        .cxx_qt_ffi_method("cxxQtThreadQueueWithOptions")
+        .into_cxx_parts();
     let (thread_clone_name, thread_clone_attrs, thread_clone_qualified) = qobject_names
         .cxx_qt_ffi_method("cxxQtThreadClone")
         .into_cxx_parts();
@@ -41,6 +49,17 @@ pub fn generate(
         qobject_names
             .cxx_qt_ffi_method("cxxQtThreadIsDestroyed")
             .into_cxx_parts();
+    let (
+        thread_is_current_thread_name,
+        thread_is_current_thread_attrs,
+        thread_is_current_thread_qualified,
+    ) = qobject_names
+        .cxx_qt_ffi_method("cxxQtThreadIsCurrentThread")
+        .into_cxx_parts();
+    let (thread_pending_count_name, thread_pending_count_attrs, thread_pending_count_qualified) =
+        qobject_names
+            .cxx_qt_ffi_method("cxxQtThreadPendingCount")
+            .into_cxx_parts();
 
     let cxx_qt_thread_namespace = &namespace_ident.namespace;
     let namespace_internals = &namespace_ident.internal;
@@ -81,6 +100,18 @@ pub fn generate(
                         arg: Box<#cxx_qt_thread_queued_fn_ident>,
                     ) -> u8;
 
+                    #[doc(hidden)]
+                    #(#thread_queue_with_options_attrs)*
+                    #(#cfgs)*
+                    fn #thread_queue_with_options_name(
+                        cxx_qt_thread: &#cxx_qt_thread_ident,
+                        func: fn(Pin<&mut #cpp_struct_ident>, Box<#cxx_qt_thread_queued_fn_ident>),
+                        arg: Box<#cxx_qt_thread_queued_fn_ident>,
+                        coalesce: bool,
+                        coalesce_key: &str,
+                        priority: i32,
+                    ) -> u8;
+
                     #[doc(hidden)]
                     #(#thread_clone_attrs)*
                     #(#cfgs)*
@@ -95,6 +126,16 @@ pub fn generate(
                     #(#thread_is_destroyed_attrs)*
                     #(#cfgs)*
                     fn #thread_is_destroyed_name(cxx_qt_thread: &#cxx_qt_thread_ident) -> bool;
+
+                    #[doc(hidden)]
+                    #(#thread_is_current_thread_attrs)*
+                    #(#cfgs)*
+                    fn #thread_is_current_thread_name(cxx_qt_thread: &#cxx_qt_thread_ident) -> bool;
+
+                    #[doc(hidden)]
+                    #(#thread_pending_count_attrs)*
+                    #(#cfgs)*
+                    fn #thread_pending_count_name(cxx_qt_thread: &#cxx_qt_thread_ident) -> usize;
                 }
             },
             parse_quote! {
@@ -117,12 +158,24 @@ pub fn generate(
                         #thread_fn_qualified(self)
                     }
 
+                    #[doc(hidden)]
+                    fn is_current_thread(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident) -> bool
+                    {
+                        #thread_is_current_thread_qualified(cxx_qt_thread)
+                    }
+
                     #[doc(hidden)]
                     fn is_destroyed(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident) -> bool
                     {
                         #thread_is_destroyed_qualified(cxx_qt_thread)
                     }
 
+                    #[doc(hidden)]
+                    fn pending_count(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident) -> usize
+                    {
+                        #thread_pending_count_qualified(cxx_qt_thread)
+                    }
+
                     #[doc(hidden)]
                     fn queue<F>(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident, f: F) -> std::result::Result<(), cxx_qt::ThreadingQueueError>
                     where
@@ -138,7 +191,7 @@ pub fn generate(
                             obj: core::pin::Pin<&mut #qualified_impl>,
                             arg: std::boxed::Box<#cxx_qt_thread_queued_fn_ident>,
                         ) {
-                            (arg.inner)(obj)
+                            ::cxx_qt::panic::call_guarded(#type_name_str, "queue", move || (arg.inner)(obj))
                         }
                         let arg = #cxx_qt_thread_queued_fn_ident { inner: std::boxed::Box::new(f) };
                         match #thread_queue_qualified(cxx_qt_thread, func, std::boxed::Box::new(arg)) {
@@ -147,6 +200,34 @@ pub fn generate(
                         }
                     }
 
+                    #[doc(hidden)]
+                    fn queue_with_options<F>(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident, coalesce_key: Option<&str>, priority: i32, f: F) -> std::result::Result<(), cxx_qt::ThreadingQueueError>
+                    where
+                        F: FnOnce(core::pin::Pin<&mut #qualified_impl>),
+                        F: Send + 'static,
+                    {
+                        #[allow(clippy::boxed_local)]
+                        #[doc(hidden)]
+                        fn func(
+                            obj: core::pin::Pin<&mut #qualified_impl>,
+                            arg: std::boxed::Box<#cxx_qt_thread_queued_fn_ident>,
+                        ) {
+                            ::cxx_qt::panic::call_guarded(#type_name_str, "queue", move || (arg.inner)(obj))
+                        }
+                        let arg = #cxx_qt_thread_queued_fn_ident { inner: std::boxed::Box::new(f) };
+                        match #thread_queue_with_options_qualified(
+                            cxx_qt_thread,
+                            func,
+                            std::boxed::Box::new(arg),
+                            coalesce_key.is_some(),
+                            coalesce_key.unwrap_or_default(),
+                            priority,
+                        ) {
+                            0 => Ok(()),
+                            others => Err(others.into()),
+                        }
+                    }
+
                     #[doc(hidden)]
                     fn threading_clone(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident) -> #module_ident::#cxx_qt_thread_ident
                     {
@@ -228,6 +309,18 @@ mod tests {
                         arg: Box<MyObjectCxxQtThreadQueuedFn>,
                     ) -> u8;
 
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtThreadQueueWithOptions"]
+                    #[namespace = "rust::cxxqt1"]
+                    fn cxx_qt_ffi_MyObject_cxxQtThreadQueueWithOptions(
+                        cxx_qt_thread: &MyObjectCxxQtThread,
+                        func: fn(Pin<&mut MyObject>, Box<MyObjectCxxQtThreadQueuedFn>),
+                        arg: Box<MyObjectCxxQtThreadQueuedFn>,
+                        coalesce: bool,
+                        coalesce_key: &str,
+                        priority: i32,
+                    ) -> u8;
+
                     #[doc(hidden)]
                     #[cxx_name = "cxxQtThreadClone"]
                     #[namespace = "rust::cxxqt1"]
@@ -242,6 +335,16 @@ mod tests {
                     #[cxx_name = "cxxQtThreadIsDestroyed"]
                     #[namespace = "rust::cxxqt1"]
                     fn cxx_qt_ffi_MyObject_cxxQtThreadIsDestroyed(cxx_qt_thread: &MyObjectCxxQtThread) -> bool;
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtThreadIsCurrentThread"]
+                    #[namespace = "rust::cxxqt1"]
+                    fn cxx_qt_ffi_MyObject_cxxQtThreadIsCurrentThread(cxx_qt_thread: &MyObjectCxxQtThread) -> bool;
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtThreadPendingCount"]
+                    #[namespace = "rust::cxxqt1"]
+                    fn cxx_qt_ffi_MyObject_cxxQtThreadPendingCount(cxx_qt_thread: &MyObjectCxxQtThread) -> usize;
                 }
             },
         );
@@ -268,11 +371,22 @@ mod tests {
                         qobject::cxx_qt_ffi_MyObject_qtThread(self)
                     }
 
+                    #[doc(hidden)]
+                    fn is_current_thread(cxx_qt_thread: &qobject::MyObjectCxxQtThread) -> bool {
+                        qobject::cxx_qt_ffi_MyObject_cxxQtThreadIsCurrentThread(cxx_qt_thread)
+                    }
+
                     #[doc(hidden)]
                     fn is_destroyed(cxx_qt_thread: &qobject::MyObjectCxxQtThread) -> bool {
                         qobject::cxx_qt_ffi_MyObject_cxxQtThreadIsDestroyed(cxx_qt_thread)
                     }
 
+                    #[doc(hidden)]
+                    fn pending_count(cxx_qt_thread: &qobject::MyObjectCxxQtThread) -> usize
+                    {
+                        qobject::cxx_qt_ffi_MyObject_cxxQtThreadPendingCount(cxx_qt_thread)
+                    }
+
                     #[doc(hidden)]
                     fn queue<F>(cxx_qt_thread: &qobject::MyObjectCxxQtThread, f: F) -> std::result::Result<(), cxx_qt::ThreadingQueueError>
                     where
@@ -288,7 +402,7 @@ mod tests {
                             obj: core::pin::Pin<&mut qobject::MyObject>,
                             arg: std::boxed::Box<MyObjectCxxQtThreadQueuedFn>,
                         ) {
-                            (arg.inner)(obj)
+                            ::cxx_qt::panic::call_guarded("MyObject", "queue", move | | (arg.inner)(obj))
                         }
                         let arg = MyObjectCxxQtThreadQueuedFn { inner: std::boxed::Box::new(f) };
                         match qobject::cxx_qt_ffi_MyObject_cxxQtThreadQueue(cxx_qt_thread, func, std::boxed::Box::new(arg)) {
@@ -297,6 +411,34 @@ mod tests {
                         }
                     }
 
+                    #[doc(hidden)]
+                    fn queue_with_options<F>(cxx_qt_thread: &qobject::MyObjectCxxQtThread, coalesce_key: Option<&str>, priority: i32, f: F) -> std::result::Result<(), cxx_qt::ThreadingQueueError>
+                    where
+                        F: FnOnce(core::pin::Pin<&mut qobject::MyObject>),
+                        F: Send + 'static,
+                    {
+                        #[allow(clippy::boxed_local)]
+                        #[doc(hidden)]
+                        fn func(
+                            obj: core::pin::Pin<&mut qobject::MyObject>,
+                            arg: std::boxed::Box<MyObjectCxxQtThreadQueuedFn>,
+                        ) {
+                            ::cxx_qt::panic::call_guarded("MyObject", "queue", move | | (arg.inner)(obj))
+                        }
+                        let arg = MyObjectCxxQtThreadQueuedFn { inner: std::boxed::Box::new(f) };
+                        match qobject::cxx_qt_ffi_MyObject_cxxQtThreadQueueWithOptions(
+                            cxx_qt_thread,
+                            func,
+                            std::boxed::Box::new(arg),
+                            coalesce_key.is_some(),
+                            coalesce_key.unwrap_or_default(),
+                            priority,
+                        ) {
+                            0 => Ok(()),
+                            others => Err(others.into()),
+                        }
+                    }
+
                     #[doc(hidden)]
                     fn threading_clone(cxx_qt_thread: &qobject::MyObjectCxxQtThread) -> qobject::MyObjectCxxQtThread
                     {
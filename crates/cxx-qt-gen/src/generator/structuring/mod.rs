@@ -70,6 +70,18 @@ impl<'a> Structures<'a> {
                 }
                 // TODO: Check for duplicate declarations?
                 TraitKind::Constructor(ref constructor) => qobject.constructors.push(constructor),
+                TraitKind::Overrides(overrides) => {
+                    if qobject.overrides.is_some() {
+                        return Err(Error::new_spanned(
+                            &imp.declaration,
+                            format!(
+                                "Overrides already implemented on QObject {qobject}!",
+                                qobject = imp.qobject
+                            ),
+                        ));
+                    }
+                    qobject.overrides = Some(overrides);
+                }
             }
         }
         Ok(())
@@ -118,6 +130,7 @@ impl<'a> Structures<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parser::overrides::Overrides;
     use crate::tests::assert_parse_errors;
     use crate::Parser;
     use quote::format_ident;
@@ -263,6 +276,32 @@ mod tests {
         assert!(Structures::new(&parser.cxx_qt_data).is_err());
     }
 
+    #[test]
+    fn test_overrides() {
+        let mut bridge = mock_bridge();
+        bridge.content.as_mut().unwrap().1.push(parse_quote! {
+            impl cxx_qt_lib::QQuickItemOverrides for MyObject {}
+        });
+        let parser = Parser::from(bridge).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let qobject = &structures.qobjects[0];
+        assert_eq!(qobject.overrides, Some(Overrides::QQuickItem));
+        assert!(qobject
+            .method_lookup(&format_ident!("cxx_qt_override_mouse_press_event"))
+            .is_ok());
+        assert_eq!(qobject.inherited_methods.len(), 6);
+
+        // Overrides can only be implemented once
+        let mut bridge = mock_bridge();
+        bridge.content.as_mut().unwrap().1.extend([
+            parse_quote! {impl cxx_qt_lib::QQuickItemOverrides for MyObject {}},
+            parse_quote! {impl cxx_qt_lib::QQuickPaintedItemOverrides for MyObject {}},
+        ]);
+        let parser = Parser::from(bridge).unwrap();
+        assert!(Structures::new(&parser.cxx_qt_data).is_err());
+    }
+
     #[test]
     fn test_create_invalid_structures() {
         assert_parse_errors! {
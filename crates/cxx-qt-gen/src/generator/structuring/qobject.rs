@@ -8,6 +8,7 @@ use crate::naming::Name;
 use crate::parser::constructor::Constructor;
 use crate::parser::inherit::ParsedInheritedMethod;
 use crate::parser::method::ParsedMethod;
+use crate::parser::overrides::Overrides;
 use crate::parser::signals::ParsedSignal;
 use crate::parser::{qenum::ParsedQEnum, qobject::ParsedQObject};
 use proc_macro2::Ident;
@@ -23,6 +24,7 @@ pub struct StructuredQObject<'a> {
     pub signals: Vec<&'a ParsedSignal>,
     pub constructors: Vec<&'a Constructor>,
     pub threading: bool,
+    pub overrides: Option<Overrides>,
 }
 
 fn lookup<T>(invokables: &[T], id: &Ident, name_getter: impl Fn(&T) -> &Name) -> Option<Name> {
@@ -48,6 +50,7 @@ impl<'a> StructuredQObject<'a> {
             signals: vec![],
             constructors: vec![],
             threading: false,
+            overrides: None,
         }
     }
 
@@ -3,8 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use super::overrides::Overrides;
 use super::qnamespace::ParsedQNamespace;
-use super::trait_impl::TraitImpl;
+use super::trait_impl::{TraitImpl, TraitKind};
 use crate::naming::cpp::err_unsupported_item;
 use crate::parser::CaseConversion;
 use crate::{
@@ -214,8 +215,25 @@ impl ParsedCxxQtData {
         // If it is a trait impl compared to a regular impl block
         // This allows the cxx shim trait feature
         if imp.trait_.is_some() {
-            self.trait_impls.push(TraitImpl::parse(imp)?);
-            Ok(None)
+            let trait_impl = TraitImpl::parse(imp)?;
+
+            // Overrides declare the virtual methods of the base class on the QObject,
+            // the types they use are declared once for the bridge
+            let item = if let TraitKind::Overrides(overrides) = trait_impl.kind {
+                let declared = self
+                    .trait_impls
+                    .iter()
+                    .any(|imp| matches!(imp.kind, TraitKind::Overrides(_)));
+                self.parse_foreign_mod_rust_qt(
+                    overrides.extern_rust_qt_block(&trait_impl.qobject),
+                )?;
+                (!declared).then(|| Item::ForeignMod(Overrides::extern_cxx_block()))
+            } else {
+                None
+            };
+
+            self.trait_impls.push(trait_impl);
+            Ok(item)
         } else {
             Ok(Some(Item::Impl(imp)))
         }
@@ -643,6 +661,35 @@ mod tests {
         assert!(!cxxqtdata.trait_impls.is_empty());
     }
 
+    #[test]
+    fn test_parse_overrides() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+
+        let result = cxxqtdata
+            .parse_cxx_qt_item(parse_quote! {
+                impl cxx_qt_lib::QQuickPaintedItemOverrides for MyObject {}
+            })
+            .unwrap();
+        // The types used by the overrides are passed through to CXX
+        assert!(matches!(result, Some(Item::ForeignMod(_))));
+        assert_eq!(cxxqtdata.trait_impls.len(), 1);
+        assert_eq!(cxxqtdata.methods.len(), 7);
+        assert_eq!(cxxqtdata.inherited_methods.len(), 6);
+        assert_eq!(
+            cxxqtdata.methods[6].name,
+            Name::mock_name_with_cxx("cxx_qt_override_paint", "paint")
+        );
+
+        // The types are only declared once
+        let result = cxxqtdata
+            .parse_cxx_qt_item(parse_quote! {
+                impl cxx_qt_lib::QQuickItemOverrides for OtherObject {}
+            })
+            .unwrap();
+        assert!(result.is_none());
+        assert_eq!(cxxqtdata.methods.len(), 13);
+    }
+
     #[test]
     fn test_passthrough_non_trait_impl() {
         let mut cxxqtdata = create_parsed_cxx_qt_data();
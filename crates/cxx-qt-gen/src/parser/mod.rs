@@ -9,6 +9,7 @@ pub mod externcxxqt;
 pub mod externqobject;
 pub mod inherit;
 pub mod method;
+pub mod overrides;
 pub mod parameter;
 pub mod property;
 pub mod qenum;
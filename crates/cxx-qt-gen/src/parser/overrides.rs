@@ -0,0 +1,291 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::syntax::path::path_compare_str;
+use quote::{format_ident, quote};
+use syn::{parse_quote, FnArg, ForeignItemFn, Ident, ItemForeignMod, Path};
+
+/// The parameters of a virtual method which can be overridden with a trait from cxx-qt-lib
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VirtualParameters {
+    /// `const QRectF& newGeometry, const QRectF& oldGeometry`
+    Geometry,
+    /// `QMouseEvent* event`
+    MouseEvent,
+    /// `QPainter* painter`
+    Painter,
+}
+
+/// A virtual method of a base class which is overridden with a trait from cxx-qt-lib
+#[derive(Debug, PartialEq, Eq)]
+pub struct VirtualMethod {
+    /// The name of the method in the trait
+    pub name: &'static str,
+    /// The name of the method in C++
+    pub cxx_name: &'static str,
+    /// A unique Rust name for the method in the bridge, as the C++ name can differ between Qt versions
+    pub ident: &'static str,
+    /// The Qt version which has this method, as a cfg predicate
+    pub qt_version: Option<&'static str>,
+    /// The parameters of the method
+    pub parameters: VirtualParameters,
+    /// Whether the base class implements the method, otherwise the method is pure virtual
+    pub has_base: bool,
+}
+
+impl VirtualMethod {
+    /// The Rust name of the method in the bridge which overrides the virtual method
+    pub fn override_ident(&self) -> Ident {
+        format_ident!("cxx_qt_override_{}", self.ident)
+    }
+
+    /// The Rust name of the method in the bridge which calls the implementation of the base class
+    pub fn base_ident(&self) -> Ident {
+        format_ident!("cxx_qt_base_{}", self.ident)
+    }
+
+    /// The cfg attribute for the Qt version which has this method, if any
+    pub fn cfg(&self) -> Option<syn::Attribute> {
+        self.qt_version.map(|predicate| {
+            let predicate: proc_macro2::TokenStream = predicate.parse().unwrap();
+            parse_quote! { #[cfg(#predicate)] }
+        })
+    }
+
+    fn declaration(&self, qobject: &Ident, ident: &Ident, attribute: &Ident) -> ForeignItemFn {
+        let cxx_name = self.cxx_name;
+        let cfg = self.cfg();
+        let parameters: Vec<FnArg> = match self.parameters {
+            VirtualParameters::Geometry => vec![
+                parse_quote! { new_geometry: &CxxQtQRectF },
+                parse_quote! { old_geometry: &CxxQtQRectF },
+            ],
+            VirtualParameters::MouseEvent => vec![parse_quote! { event: *mut CxxQtQMouseEvent }],
+            VirtualParameters::Painter => vec![parse_quote! { painter: *mut CxxQtQPainter }],
+        };
+        // Only the methods taking pointers need to be unsafe
+        let unsafety = (self.parameters != VirtualParameters::Geometry).then(|| quote! { unsafe });
+
+        parse_quote! {
+            #[#attribute]
+            #[cxx_name = #cxx_name]
+            #cfg
+            #unsafety fn #ident(self: Pin<&mut #qobject>, #(#parameters),*);
+        }
+    }
+}
+
+const QQUICKITEM_METHODS: &[VirtualMethod] = &[
+    VirtualMethod {
+        name: "geometry_change",
+        cxx_name: "geometryChange",
+        ident: "geometry_change",
+        qt_version: Some("not(cxxqt_qt_version_major = \"5\")"),
+        parameters: VirtualParameters::Geometry,
+        has_base: true,
+    },
+    VirtualMethod {
+        name: "geometry_change",
+        cxx_name: "geometryChanged",
+        ident: "geometry_changed",
+        qt_version: Some("cxxqt_qt_version_major = \"5\""),
+        parameters: VirtualParameters::Geometry,
+        has_base: true,
+    },
+    VirtualMethod {
+        name: "mouse_double_click_event",
+        cxx_name: "mouseDoubleClickEvent",
+        ident: "mouse_double_click_event",
+        qt_version: None,
+        parameters: VirtualParameters::MouseEvent,
+        has_base: true,
+    },
+    VirtualMethod {
+        name: "mouse_move_event",
+        cxx_name: "mouseMoveEvent",
+        ident: "mouse_move_event",
+        qt_version: None,
+        parameters: VirtualParameters::MouseEvent,
+        has_base: true,
+    },
+    VirtualMethod {
+        name: "mouse_press_event",
+        cxx_name: "mousePressEvent",
+        ident: "mouse_press_event",
+        qt_version: None,
+        parameters: VirtualParameters::MouseEvent,
+        has_base: true,
+    },
+    VirtualMethod {
+        name: "mouse_release_event",
+        cxx_name: "mouseReleaseEvent",
+        ident: "mouse_release_event",
+        qt_version: None,
+        parameters: VirtualParameters::MouseEvent,
+        has_base: true,
+    },
+];
+
+const QQUICKPAINTEDITEM_METHODS: &[VirtualMethod] = &[VirtualMethod {
+    name: "paint",
+    cxx_name: "paint",
+    ident: "paint",
+    qt_version: None,
+    parameters: VirtualParameters::Painter,
+    has_base: false,
+}];
+
+/// A base class whose virtual methods are overridden with a trait from cxx-qt-lib,
+/// eg `impl cxx_qt_lib::QQuickItemOverrides for MyObject {}` in the bridge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overrides {
+    /// `cxx_qt_lib::QQuickItemOverrides`
+    QQuickItem,
+    /// `cxx_qt_lib::QQuickPaintedItemOverrides`, which also overrides the methods of QQuickItem
+    QQuickPaintedItem,
+}
+
+impl Overrides {
+    /// Find the overrides for the path of a trait, if there are any
+    pub fn from_path(path: &Path) -> Option<Self> {
+        if path_compare_str(path, &["cxx_qt_lib", "QQuickItemOverrides"]) {
+            Some(Self::QQuickItem)
+        } else if path_compare_str(path, &["cxx_qt_lib", "QQuickPaintedItemOverrides"]) {
+            Some(Self::QQuickPaintedItem)
+        } else {
+            None
+        }
+    }
+
+    /// The path of the trait in cxx-qt-lib which declares the given method
+    pub fn trait_path(method: &VirtualMethod) -> Path {
+        if QQUICKPAINTEDITEM_METHODS.contains(method) {
+            parse_quote! { ::cxx_qt_lib::QQuickPaintedItemOverrides }
+        } else {
+            parse_quote! { ::cxx_qt_lib::QQuickItemOverrides }
+        }
+    }
+
+    /// The virtual methods which are overridden
+    pub fn methods(self) -> impl Iterator<Item = &'static VirtualMethod> {
+        let painted_item_methods = match self {
+            Self::QQuickItem => [].iter(),
+            Self::QQuickPaintedItem => QQUICKPAINTEDITEM_METHODS.iter(),
+        };
+        QQUICKITEM_METHODS.iter().chain(painted_item_methods)
+    }
+
+    /// The types from cxx-qt-lib which are used by the virtual methods
+    ///
+    /// These have their own Rust names so that they do not clash with declarations in the bridge,
+    /// so they only need to be declared once per bridge.
+    pub fn extern_cxx_block() -> ItemForeignMod {
+        parse_quote! {
+            unsafe extern "C++" {
+                include!("cxx-qt-lib/qmouseevent.h");
+                #[doc(hidden)]
+                #[namespace = ""]
+                #[rust_name = "CxxQtQMouseEvent"]
+                #[allow(dead_code)]
+                type QMouseEvent = cxx_qt_lib::QMouseEvent;
+
+                include!("cxx-qt-lib/qpainter.h");
+                #[doc(hidden)]
+                #[namespace = ""]
+                #[rust_name = "CxxQtQPainter"]
+                #[allow(dead_code)]
+                type QPainter = cxx_qt_lib::QPainter;
+
+                include!("cxx-qt-lib/qrectf.h");
+                #[doc(hidden)]
+                #[namespace = ""]
+                #[rust_name = "CxxQtQRectF"]
+                #[allow(dead_code)]
+                type QRectF = cxx_qt_lib::QRectF;
+            }
+        }
+    }
+
+    /// The `#[cxx_override]` declarations of the virtual methods for the given QObject,
+    /// followed by the `#[inherit]` declarations to call the implementations of the base class
+    pub fn extern_rust_qt_block(self, qobject: &Ident) -> ItemForeignMod {
+        let cxx_override = format_ident!("cxx_override");
+        let inherit = format_ident!("inherit");
+        let overrides = self
+            .methods()
+            .map(|method| method.declaration(qobject, &method.override_ident(), &cxx_override));
+        let bases = self
+            .methods()
+            .filter(|method| method.has_base)
+            .map(|method| method.declaration(qobject, &method.base_ident(), &inherit));
+
+        parse_quote! {
+            unsafe extern "RustQt" {
+                #(#overrides)*
+                #(#bases)*
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_path() {
+        assert_eq!(
+            Overrides::from_path(&parse_quote! { cxx_qt_lib::QQuickItemOverrides }),
+            Some(Overrides::QQuickItem)
+        );
+        assert_eq!(
+            Overrides::from_path(&parse_quote! { cxx_qt_lib::QQuickPaintedItemOverrides }),
+            Some(Overrides::QQuickPaintedItem)
+        );
+        // The trait must be fully-qualified
+        assert_eq!(
+            Overrides::from_path(&parse_quote! { QQuickItemOverrides }),
+            None
+        );
+    }
+
+    #[test]
+    fn test_methods() {
+        assert_eq!(Overrides::QQuickItem.methods().count(), 6);
+        assert!(Overrides::QQuickItem
+            .methods()
+            .all(|method| method.has_base && method.name != "paint"));
+
+        let paint = Overrides::QQuickPaintedItem.methods().last().unwrap();
+        assert_eq!(paint.name, "paint");
+        assert!(!paint.has_base);
+        assert_eq!(
+            Overrides::trait_path(paint),
+            parse_quote! { ::cxx_qt_lib::QQuickPaintedItemOverrides }
+        );
+    }
+
+    #[test]
+    fn test_extern_rust_qt_block() {
+        let block = Overrides::QQuickPaintedItem.extern_rust_qt_block(&format_ident!("MyObject"));
+        // Every method is overridden, and every method with a base implementation is inherited
+        assert_eq!(block.items.len(), 7 + 6);
+
+        let expected: syn::ForeignItem = parse_quote! {
+            #[cxx_override]
+            #[cxx_name = "mousePressEvent"]
+            unsafe fn cxx_qt_override_mouse_press_event(self: Pin<&mut MyObject>, event: *mut CxxQtQMouseEvent);
+        };
+        assert_eq!(block.items[4], expected);
+
+        let expected: syn::ForeignItem = parse_quote! {
+            #[inherit]
+            #[cxx_name = "geometryChange"]
+            #[cfg(not(cxxqt_qt_version_major = "5"))]
+            fn cxx_qt_base_geometry_change(self: Pin<&mut MyObject>, new_geometry: &CxxQtQRectF, old_geometry: &CxxQtQRectF);
+        };
+        assert_eq!(block.items[7], expected);
+    }
+}
@@ -5,13 +5,17 @@ use indoc::formatdoc;
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use syn::{Error, Ident, ItemImpl, Path, Result, Token, Type, TypePath};
 
-use crate::{parser::constructor::Constructor, syntax::path::path_compare_str};
+use crate::{
+    parser::{constructor::Constructor, overrides::Overrides},
+    syntax::path::path_compare_str,
+};
 
 /// The kind of marker trait implementation.
 #[derive(Debug, PartialEq, Eq)]
 pub enum TraitKind {
     Threading,
     Constructor(Constructor),
+    Overrides(Overrides),
 }
 
 impl TraitKind {
@@ -31,6 +35,27 @@ impl TraitKind {
         Ok(Self::Threading)
     }
 
+    fn parse_overrides(
+        not: &Option<Token![!]>,
+        path: &Path,
+        imp: &ItemImpl,
+        overrides: Overrides,
+    ) -> Result<Self> {
+        if let Some(unsafety) = imp.unsafety.as_ref() {
+            return Err(Error::new_spanned(
+                unsafety,
+                "Unnecessary unsafe, overrides from cxx_qt_lib are safe to implement!",
+            ));
+        }
+        if not.is_some() {
+            return Err(Error::new_spanned(
+                path,
+                "Negative impls for overrides from cxx_qt_lib are not allowed!",
+            ));
+        }
+        Ok(Self::Overrides(overrides))
+    }
+
     fn parse_constructor(imp: &ItemImpl) -> Result<Self> {
         let constructor = Constructor::parse(imp.clone())?;
         Ok(Self::Constructor(constructor))
@@ -46,6 +71,8 @@ impl TraitKind {
             Self::parse_threading(not, path, imp)
         } else if path_compare_str(path, &["cxx_qt", "Constructor"]) {
             Self::parse_constructor(imp)
+        } else if let Some(overrides) = Overrides::from_path(path) {
+            Self::parse_overrides(not, path, imp, overrides)
         } else {
             // TODO: Give suggestions on which trait might have been meant
             Err(Error::new_spanned(
@@ -55,6 +82,8 @@ impl TraitKind {
                     CXX-Qt currently only supports:
                       - cxx_qt::Threading
                       - cxx_qt::Constructor
+                      - cxx_qt_lib::QQuickItemOverrides
+                      - cxx_qt_lib::QQuickPaintedItemOverrides
                       - (cxx_qt::Locking has been removed as of CXX-Qt 0.7)
                     Note that the trait must always be fully-qualified.
                     "},
@@ -130,6 +159,19 @@ mod tests {
         assert!(matches!(marker.kind, TraitKind::Constructor(_)))
     }
 
+    #[test]
+    fn parse_overrides() {
+        let imp = parse_quote! {
+            impl cxx_qt_lib::QQuickPaintedItemOverrides for MyObject {}
+        };
+        let marker = TraitImpl::parse(imp).unwrap();
+        assert_eq!(marker.qobject, format_ident!("MyObject"));
+        assert_eq!(
+            marker.kind,
+            TraitKind::Overrides(Overrides::QQuickPaintedItem)
+        );
+    }
+
     use crate::tests::assert_parse_errors;
 
     #[test]
@@ -141,6 +183,12 @@ mod tests {
             { unsafe impl cxx_qt::Threading for QObject {} }
             // Threading cannot be negated
             { impl !cxx_qt::Threading for QObject {} }
+            // Overrides are safe to implement
+            { unsafe impl cxx_qt_lib::QQuickItemOverrides for QObject {} }
+            // Overrides cannot be negated
+            { impl !cxx_qt_lib::QQuickItemOverrides for QObject {} }
+            // Overrides must be fully-qualified
+            { impl QQuickItemOverrides for QObject {} }
             // Invalid QObject name
             { impl cxx_qt::Locking for my::path {} }
             // Invalid trait name
@@ -72,13 +72,13 @@ mod ffi {
         #[cxx_name = "invokable_disabled"]
         #[cfg(not(enabled))]
         #[doc(hidden)]
-        unsafe fn invokable_disabled(self: &QObjectEnabled);
+        unsafe fn cxx_qt_ffi_invokable_disabled(self: &QObjectEnabled);
     }
     extern "Rust" {
         #[cxx_name = "invokable_enabled"]
         #[cfg(enabled)]
         #[doc(hidden)]
-        unsafe fn invokable_enabled(self: &QObjectEnabled);
+        unsafe fn cxx_qt_ffi_invokable_enabled(self: &QObjectEnabled);
     }
     unsafe extern "C++" {
         #[cxx_name = "inherit_disabledCxxQtInherit"]
@@ -212,13 +212,13 @@ mod ffi {
         #[cxx_name = "invokable_disabled"]
         #[cfg(not(enabled))]
         #[doc(hidden)]
-        unsafe fn invokable_disabled(self: &QObjectDisabled);
+        unsafe fn cxx_qt_ffi_invokable_disabled(self: &QObjectDisabled);
     }
     extern "Rust" {
         #[cxx_name = "invokable_enabled"]
         #[cfg(enabled)]
         #[doc(hidden)]
-        unsafe fn invokable_enabled(self: &QObjectDisabled);
+        unsafe fn cxx_qt_ffi_invokable_enabled(self: &QObjectDisabled);
     }
     unsafe extern "C++" {
         #[cxx_name = "inherit_disabledCxxQtInherit"]
@@ -485,6 +485,24 @@ mod ffi {
     }
 }
 #[cfg(not(enabled))]
+impl ffi::QObjectEnabled {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_invokable_disabled(self: &ffi::QObjectEnabled) {
+        ::cxx_qt::panic::call_guarded("QObjectEnabled", "invokable_disabled", move || {
+            self.invokable_disabled()
+        })
+    }
+}
+#[cfg(enabled)]
+impl ffi::QObjectEnabled {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_invokable_enabled(self: &ffi::QObjectEnabled) {
+        ::cxx_qt::panic::call_guarded("QObjectEnabled", "invokable_enabled", move || {
+            self.invokable_enabled()
+        })
+    }
+}
+#[cfg(not(enabled))]
 impl ffi::QObjectEnabled {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "signal_disabled"]
@@ -546,7 +564,9 @@ fn call_QObjectEnabled_signal_handler_signal_disabled(
     >,
     self_value: core::pin::Pin<&mut ffi::QObjectEnabled>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("QObjectEnabled", "signal_disabled", move || {
+        handler.closure()(self_value)
+    });
 }
 #[cfg(not(enabled))]
 cxx_qt::static_assertions::assert_eq_align!(
@@ -620,7 +640,9 @@ fn call_QObjectEnabled_signal_handler_signal_enabled(
     >,
     self_value: core::pin::Pin<&mut ffi::QObjectEnabled>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("QObjectEnabled", "signal_enabled", move || {
+        handler.closure()(self_value)
+    });
 }
 #[cfg(enabled)]
 cxx_qt::static_assertions::assert_eq_align!(
@@ -664,6 +686,24 @@ impl ::cxx_qt::CxxQtType for ffi::QObjectEnabled {
     }
 }
 #[cfg(not(enabled))]
+impl ffi::QObjectDisabled {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_invokable_disabled(self: &ffi::QObjectDisabled) {
+        ::cxx_qt::panic::call_guarded("QObjectDisabled", "invokable_disabled", move || {
+            self.invokable_disabled()
+        })
+    }
+}
+#[cfg(enabled)]
+impl ffi::QObjectDisabled {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_invokable_enabled(self: &ffi::QObjectDisabled) {
+        ::cxx_qt::panic::call_guarded("QObjectDisabled", "invokable_enabled", move || {
+            self.invokable_enabled()
+        })
+    }
+}
+#[cfg(not(enabled))]
 impl ffi::QObjectDisabled {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "signal_disabled"]
@@ -725,7 +765,9 @@ fn call_QObjectDisabled_signal_handler_signal_disabled(
     >,
     self_value: core::pin::Pin<&mut ffi::QObjectDisabled>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("QObjectDisabled", "signal_disabled", move || {
+        handler.closure()(self_value)
+    });
 }
 #[cfg(not(enabled))]
 cxx_qt::static_assertions::assert_eq_align!(
@@ -799,7 +841,9 @@ fn call_QObjectDisabled_signal_handler_signal_enabled(
     >,
     self_value: core::pin::Pin<&mut ffi::QObjectDisabled>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("QObjectDisabled", "signal_enabled", move || {
+        handler.closure()(self_value)
+    });
 }
 #[cfg(enabled)]
 cxx_qt::static_assertions::assert_eq_align!(
@@ -909,7 +953,9 @@ fn call_QObjectExternEnabled_signal_handler_signal_disabled1(
     >,
     self_value: core::pin::Pin<&mut ffi::QObjectExternEnabled>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("QObjectExternEnabled", "signal_disabled1", move || {
+        handler.closure()(self_value)
+    });
 }
 #[cfg(not(enabled))]
 cxx_qt::static_assertions::assert_eq_align!(
@@ -988,7 +1034,9 @@ fn call_QObjectExternEnabled_signal_handler_signal_enabled1(
     >,
     self_value: core::pin::Pin<&mut ffi::QObjectExternEnabled>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("QObjectExternEnabled", "signal_enabled1", move || {
+        handler.closure()(self_value)
+    });
 }
 #[cfg(enabled)]
 cxx_qt::static_assertions::assert_eq_align!(
@@ -1071,7 +1119,9 @@ fn call_QObjectExternDisabled_signal_handler_signal_disabled2(
     >,
     self_value: core::pin::Pin<&mut ffi::QObjectExternDisabled>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("QObjectExternDisabled", "signal_disabled2", move || {
+        handler.closure()(self_value)
+    });
 }
 #[cfg(not(enabled))]
 cxx_qt::static_assertions::assert_eq_align!(
@@ -1154,7 +1204,9 @@ fn call_QObjectExternDisabled_signal_handler_signal_enabled2(
     >,
     self_value: core::pin::Pin<&mut ffi::QObjectExternDisabled>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("QObjectExternDisabled", "signal_enabled2", move || {
+        handler.closure()(self_value)
+    });
 }
 #[cfg(enabled)]
 cxx_qt::static_assertions::assert_eq_align!(
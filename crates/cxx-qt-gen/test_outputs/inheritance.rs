@@ -40,12 +40,12 @@ mod inheritance {
     extern "Rust" {
         #[cxx_name = "data"]
         #[doc(hidden)]
-        unsafe fn data(self: &MyObject, _index: &QModelIndex, _role: i32) -> QVariant;
+        unsafe fn cxx_qt_ffi_data(self: &MyObject, _index: &QModelIndex, _role: i32) -> QVariant;
     }
     extern "Rust" {
         #[cxx_name = "has_children"]
         #[doc(hidden)]
-        unsafe fn has_children(self: &MyObject, _parent: &QModelIndex) -> bool;
+        unsafe fn cxx_qt_ffi_has_children(self: &MyObject, _parent: &QModelIndex) -> bool;
     }
     unsafe extern "C++" {
         #[cxx_name = "hasChildrenCxxQtInherit"]
@@ -92,6 +92,29 @@ mod inheritance {
         fn cxx_qt_ffi_MyObject_unsafeRustMut(outer: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
     }
 }
+impl inheritance::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_data(
+        self: &inheritance::MyObject,
+        _index: &inheritance::QModelIndex,
+        _role: i32,
+    ) -> inheritance::QVariant {
+        ::cxx_qt::panic::call_guarded_returning("MyObject", "data", move || {
+            self.data(_index, _role)
+        })
+    }
+}
+impl inheritance::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_has_children(
+        self: &inheritance::MyObject,
+        _parent: &inheritance::QModelIndex,
+    ) -> bool {
+        ::cxx_qt::panic::call_guarded_returning("MyObject", "has_children", move || {
+            self.has_children(_parent)
+        })
+    }
+}
 impl ::cxx_qt::Upcast<inheritance::QAbstractItemModel> for inheritance::MyObject {
     unsafe fn upcast_ptr(this: *const Self) -> *const inheritance::QAbstractItemModel {
         inheritance::cxx_qt_ffi_MyObject_upcastPtr(this)
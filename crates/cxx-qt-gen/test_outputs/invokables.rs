@@ -48,25 +48,25 @@ mod ffi {
         #[cxx_name = "cpp_method"]
         #[namespace = "cxx_qt::my_object"]
         #[doc(hidden)]
-        unsafe fn cpp_method(self: &MyObject);
+        unsafe fn cxx_qt_ffi_cpp_method(self: &MyObject);
     }
     extern "Rust" {
         #[cxx_name = "invokable"]
         #[namespace = "cxx_qt::my_object"]
         #[doc(hidden)]
-        unsafe fn invokable(self: &MyObject);
+        unsafe fn cxx_qt_ffi_invokable(self: &MyObject);
     }
     extern "Rust" {
         #[cxx_name = "invokable_mutable"]
         #[namespace = "cxx_qt::my_object"]
         #[doc(hidden)]
-        unsafe fn invokable_mutable(self: Pin<&mut MyObject>);
+        unsafe fn cxx_qt_ffi_invokable_mutable(self: Pin<&mut MyObject>);
     }
     extern "Rust" {
         #[cxx_name = "invokable_parameters"]
         #[namespace = "cxx_qt::my_object"]
         #[doc(hidden)]
-        unsafe fn invokable_parameters(
+        unsafe fn cxx_qt_ffi_invokable_parameters(
             self: &MyObject,
             opaque: &QColor,
             trivial: &QPoint,
@@ -77,31 +77,32 @@ mod ffi {
         #[cxx_name = "invokable_return_opaque"]
         #[namespace = "cxx_qt::my_object"]
         #[doc(hidden)]
-        unsafe fn invokable_return_opaque(self: Pin<&mut MyObject>) -> UniquePtr<Opaque>;
+        unsafe fn cxx_qt_ffi_invokable_return_opaque(self: Pin<&mut MyObject>)
+            -> UniquePtr<Opaque>;
     }
     extern "Rust" {
         #[cxx_name = "invokable_return_trivial"]
         #[namespace = "cxx_qt::my_object"]
         #[doc(hidden)]
-        unsafe fn invokable_return_trivial(self: Pin<&mut MyObject>) -> QPoint;
+        unsafe fn cxx_qt_ffi_invokable_return_trivial(self: Pin<&mut MyObject>) -> QPoint;
     }
     extern "Rust" {
         #[cxx_name = "invokable_final"]
         #[namespace = "cxx_qt::my_object"]
         #[doc(hidden)]
-        unsafe fn invokable_final(self: &MyObject);
+        unsafe fn cxx_qt_ffi_invokable_final(self: &MyObject);
     }
     extern "Rust" {
         #[cxx_name = "invokable_override"]
         #[namespace = "cxx_qt::my_object"]
         #[doc(hidden)]
-        unsafe fn invokable_override(self: &MyObject);
+        unsafe fn cxx_qt_ffi_invokable_override(self: &MyObject);
     }
     extern "Rust" {
         #[cxx_name = "invokable_virtual"]
         #[namespace = "cxx_qt::my_object"]
         #[doc(hidden)]
-        unsafe fn invokable_virtual(self: &MyObject);
+        unsafe fn cxx_qt_ffi_invokable_virtual(self: &MyObject);
     }
     unsafe extern "C++" {
         #[cxx_name = "invokable_pure_virtual"]
@@ -113,13 +114,13 @@ mod ffi {
         #[cxx_name = "invokable_result_tuple"]
         #[namespace = "cxx_qt::my_object"]
         #[doc(hidden)]
-        unsafe fn invokable_result_tuple(self: &MyObject) -> Result<()>;
+        unsafe fn cxx_qt_ffi_invokable_result_tuple(self: &MyObject) -> Result<()>;
     }
     extern "Rust" {
         #[cxx_name = "invokable_result_type"]
         #[namespace = "cxx_qt::my_object"]
         #[doc(hidden)]
-        unsafe fn invokable_result_type(self: &MyObject) -> Result<String>;
+        unsafe fn cxx_qt_ffi_invokable_result_type(self: &MyObject) -> Result<String>;
     }
     unsafe extern "C++" {
         #[doc(hidden)]
@@ -139,6 +140,17 @@ mod ffi {
             arg: Box<MyObjectCxxQtThreadQueuedFn>,
         ) -> u8;
         #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadQueueWithOptions"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_cxxQtThreadQueueWithOptions(
+            cxx_qt_thread: &MyObjectCxxQtThread,
+            func: fn(Pin<&mut MyObject>, Box<MyObjectCxxQtThreadQueuedFn>),
+            arg: Box<MyObjectCxxQtThreadQueuedFn>,
+            coalesce: bool,
+            coalesce_key: &str,
+            priority: i32,
+        ) -> u8;
+        #[doc(hidden)]
         #[cxx_name = "cxxQtThreadClone"]
         #[namespace = "rust::cxxqt1"]
         fn cxx_qt_ffi_MyObject_cxxQtThreadClone(
@@ -152,6 +164,18 @@ mod ffi {
         #[cxx_name = "cxxQtThreadIsDestroyed"]
         #[namespace = "rust::cxxqt1"]
         fn cxx_qt_ffi_MyObject_cxxQtThreadIsDestroyed(cxx_qt_thread: &MyObjectCxxQtThread) -> bool;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadIsCurrentThread"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_cxxQtThreadIsCurrentThread(
+            cxx_qt_thread: &MyObjectCxxQtThread,
+        ) -> bool;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadPendingCount"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_cxxQtThreadPendingCount(
+            cxx_qt_thread: &MyObjectCxxQtThread,
+        ) -> usize;
     }
     extern "Rust" {
         #[namespace = "cxx_qt::my_object::cxx_qt_MyObject"]
@@ -274,6 +298,103 @@ mod ffi {
         type QObject = cxx_qt::QObject;
     }
 }
+impl ffi::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_cpp_method(self: &ffi::MyObject) {
+        ::cxx_qt::panic::call_guarded("MyObject", "cpp_method", move || self.cpp_method())
+    }
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_invokable(self: &ffi::MyObject) {
+        ::cxx_qt::panic::call_guarded("MyObject", "invokable", move || self.invokable())
+    }
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_invokable_mutable(self: core::pin::Pin<&mut ffi::MyObject>) {
+        ::cxx_qt::panic::call_guarded("MyObject", "invokable_mutable", move || {
+            self.invokable_mutable()
+        })
+    }
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_invokable_parameters(
+        self: &ffi::MyObject,
+        opaque: &ffi::QColor,
+        trivial: &ffi::QPoint,
+        primitive: i32,
+    ) {
+        ::cxx_qt::panic::call_guarded("MyObject", "invokable_parameters", move || {
+            self.invokable_parameters(opaque, trivial, primitive)
+        })
+    }
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_invokable_return_opaque(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+    ) -> cxx::UniquePtr<ffi::Opaque> {
+        ::cxx_qt::panic::call_guarded_returning("MyObject", "invokable_return_opaque", move || {
+            self.invokable_return_opaque()
+        })
+    }
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_invokable_return_trivial(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+    ) -> ffi::QPoint {
+        ::cxx_qt::panic::call_guarded_returning("MyObject", "invokable_return_trivial", move || {
+            self.invokable_return_trivial()
+        })
+    }
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_invokable_final(self: &ffi::MyObject) {
+        ::cxx_qt::panic::call_guarded("MyObject", "invokable_final", move || {
+            self.invokable_final()
+        })
+    }
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_invokable_override(self: &ffi::MyObject) {
+        ::cxx_qt::panic::call_guarded("MyObject", "invokable_override", move || {
+            self.invokable_override()
+        })
+    }
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_invokable_virtual(self: &ffi::MyObject) {
+        ::cxx_qt::panic::call_guarded("MyObject", "invokable_virtual", move || {
+            self.invokable_virtual()
+        })
+    }
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_invokable_result_tuple(
+        self: &ffi::MyObject,
+    ) -> ::core::result::Result<(), impl ::core::fmt::Display> {
+        ::cxx_qt::panic::call_guarded_returning("MyObject", "invokable_result_tuple", move || {
+            self.invokable_result_tuple()
+        })
+    }
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_invokable_result_type(
+        self: &ffi::MyObject,
+    ) -> ::core::result::Result<String, impl ::core::fmt::Display> {
+        ::cxx_qt::panic::call_guarded_returning("MyObject", "invokable_result_type", move || {
+            self.invokable_result_type()
+        })
+    }
+}
 impl cxx_qt::Threading for ffi::MyObject {
     type BoxedQueuedFn = MyObjectCxxQtThreadQueuedFn;
     type ThreadingTypeId = cxx::type_id!("cxx_qt::my_object::MyObjectCxxQtThread");
@@ -281,10 +402,18 @@ impl cxx_qt::Threading for ffi::MyObject {
         ffi::cxx_qt_ffi_MyObject_qtThread(self)
     }
     #[doc(hidden)]
+    fn is_current_thread(cxx_qt_thread: &ffi::MyObjectCxxQtThread) -> bool {
+        ffi::cxx_qt_ffi_MyObject_cxxQtThreadIsCurrentThread(cxx_qt_thread)
+    }
+    #[doc(hidden)]
     fn is_destroyed(cxx_qt_thread: &ffi::MyObjectCxxQtThread) -> bool {
         ffi::cxx_qt_ffi_MyObject_cxxQtThreadIsDestroyed(cxx_qt_thread)
     }
     #[doc(hidden)]
+    fn pending_count(cxx_qt_thread: &ffi::MyObjectCxxQtThread) -> usize {
+        ffi::cxx_qt_ffi_MyObject_cxxQtThreadPendingCount(cxx_qt_thread)
+    }
+    #[doc(hidden)]
     fn queue<F>(
         cxx_qt_thread: &ffi::MyObjectCxxQtThread,
         f: F,
@@ -299,7 +428,7 @@ impl cxx_qt::Threading for ffi::MyObject {
             obj: core::pin::Pin<&mut ffi::MyObject>,
             arg: std::boxed::Box<MyObjectCxxQtThreadQueuedFn>,
         ) {
-            (arg.inner)(obj)
+            ::cxx_qt::panic::call_guarded("MyObject", "queue", move || (arg.inner)(obj))
         }
         let arg = MyObjectCxxQtThreadQueuedFn {
             inner: std::boxed::Box::new(f),
@@ -314,6 +443,40 @@ impl cxx_qt::Threading for ffi::MyObject {
         }
     }
     #[doc(hidden)]
+    fn queue_with_options<F>(
+        cxx_qt_thread: &ffi::MyObjectCxxQtThread,
+        coalesce_key: Option<&str>,
+        priority: i32,
+        f: F,
+    ) -> std::result::Result<(), cxx_qt::ThreadingQueueError>
+    where
+        F: FnOnce(core::pin::Pin<&mut ffi::MyObject>),
+        F: Send + 'static,
+    {
+        #[allow(clippy::boxed_local)]
+        #[doc(hidden)]
+        fn func(
+            obj: core::pin::Pin<&mut ffi::MyObject>,
+            arg: std::boxed::Box<MyObjectCxxQtThreadQueuedFn>,
+        ) {
+            ::cxx_qt::panic::call_guarded("MyObject", "queue", move || (arg.inner)(obj))
+        }
+        let arg = MyObjectCxxQtThreadQueuedFn {
+            inner: std::boxed::Box::new(f),
+        };
+        match ffi::cxx_qt_ffi_MyObject_cxxQtThreadQueueWithOptions(
+            cxx_qt_thread,
+            func,
+            std::boxed::Box::new(arg),
+            coalesce_key.is_some(),
+            coalesce_key.unwrap_or_default(),
+            priority,
+        ) {
+            0 => Ok(()),
+            others => Err(others.into()),
+        }
+    }
+    #[doc(hidden)]
     fn threading_clone(cxx_qt_thread: &ffi::MyObjectCxxQtThread) -> ffi::MyObjectCxxQtThread {
         ffi::cxx_qt_ffi_MyObject_cxxQtThreadClone(cxx_qt_thread)
     }
@@ -134,7 +134,7 @@ pub mod ffi {
         #[cxx_name = "invokable_name"]
         #[namespace = "cxx_qt::multi_object"]
         #[doc(hidden)]
-        unsafe fn invokable_name(self: Pin<&mut MyObject>);
+        unsafe fn cxx_qt_ffi_invokable_name(self: Pin<&mut MyObject>);
     }
     unsafe extern "C++" {
         #[cxx_name = "ready"]
@@ -255,13 +255,13 @@ pub mod ffi {
         #[cxx_name = "invokableName"]
         #[namespace = "second_object"]
         #[doc(hidden)]
-        unsafe fn invokable_name(self: Pin<&mut SecondObject>);
+        unsafe fn cxx_qt_ffi_invokable_name(self: Pin<&mut SecondObject>);
     }
     extern "Rust" {
         #[cxx_name = "myRenamedFunction"]
         #[namespace = "second_object"]
         #[doc(hidden)]
-        unsafe fn my_function(self: &SecondObject);
+        unsafe fn cxx_qt_ffi_my_function(self: &SecondObject);
     }
     unsafe extern "C++" {
         #[cxx_name = "ready"]
@@ -553,7 +553,9 @@ fn call_MyObject_signal_handler_propertyNameChanged(
     >,
     self_value: core::pin::Pin<&mut ffi::MyObject>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("MyObject", "property_name_changed", move || {
+        handler.closure()(self_value)
+    });
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurepropertyNameChanged>,
@@ -563,6 +565,12 @@ cxx_qt::static_assertions::assert_eq_size!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurepropertyNameChanged>,
     [usize; 2]
 );
+impl ffi::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_invokable_name(self: core::pin::Pin<&mut ffi::MyObject>) {
+        ::cxx_qt::panic::call_guarded("MyObject", "invokable_name", move || self.invokable_name())
+    }
+}
 impl ffi::MyObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "ready"]
@@ -612,7 +620,7 @@ fn call_MyObject_signal_handler_ready(
     handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureready>,
     self_value: core::pin::Pin<&mut ffi::MyObject>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("MyObject", "ready", move || handler.closure()(self_value));
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureready>,
@@ -727,7 +735,9 @@ fn call_SecondObject_signal_handler_propertyNameChanged(
     >,
     self_value: core::pin::Pin<&mut ffi::SecondObject>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("SecondObject", "property_name_changed", move || {
+        handler.closure()(self_value)
+    });
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<SecondObjectCxxQtSignalClosurepropertyNameChanged>,
@@ -737,6 +747,20 @@ cxx_qt::static_assertions::assert_eq_size!(
     cxx_qt::signalhandler::CxxQtSignalHandler<SecondObjectCxxQtSignalClosurepropertyNameChanged>,
     [usize; 2]
 );
+impl ffi::SecondObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_invokable_name(self: core::pin::Pin<&mut ffi::SecondObject>) {
+        ::cxx_qt::panic::call_guarded("SecondObject", "invokable_name", move || {
+            self.invokable_name()
+        })
+    }
+}
+impl ffi::SecondObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_my_function(self: &ffi::SecondObject) {
+        ::cxx_qt::panic::call_guarded("SecondObject", "my_function", move || self.my_function())
+    }
+}
 impl ffi::SecondObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "ready"]
@@ -786,7 +810,9 @@ fn call_SecondObject_signal_handler_ready(
     handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<SecondObjectCxxQtSignalClosureready>,
     self_value: core::pin::Pin<&mut ffi::SecondObject>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("SecondObject", "ready", move || {
+        handler.closure()(self_value)
+    });
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<SecondObjectCxxQtSignalClosureready>,
@@ -905,7 +931,9 @@ fn call_QPushButton_signal_handler_clicked(
     self_value: core::pin::Pin<&mut ffi::QPushButton>,
     checked: bool,
 ) {
-    handler.closure()(self_value, checked);
+    ::cxx_qt::panic::call_guarded("QPushButton", "clicked", move || {
+        handler.closure()(self_value, checked)
+    });
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<QPushButtonCxxQtSignalClosureclicked>,
@@ -954,7 +982,9 @@ fn call_ExternObject_signal_handler_dataReady(
     >,
     self_value: core::pin::Pin<&mut ffi::ExternObject>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("ExternObject", "data_ready", move || {
+        handler.closure()(self_value)
+    });
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<ExternObjectCxxQtSignalClosuredataReady>,
@@ -1024,7 +1054,9 @@ fn call_ExternObject_signal_handler_errorOccurred(
     >,
     self_value: core::pin::Pin<&mut ffi::ExternObject>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("ExternObject", "error_occurred", move || {
+        handler.closure()(self_value)
+    });
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<ExternObjectCxxQtSignalClosureerrorOccurred>,
@@ -343,19 +343,19 @@ mod ffi {
         #[cxx_name = "myGetter"]
         #[namespace = "cxx_qt::my_object"]
         #[doc(hidden)]
-        unsafe fn my_getter(self: &MyObject) -> i32;
+        unsafe fn cxx_qt_ffi_my_getter(self: &MyObject) -> i32;
     }
     extern "Rust" {
         #[cxx_name = "MyCustomSetter"]
         #[namespace = "cxx_qt::my_object"]
         #[doc(hidden)]
-        unsafe fn my_setter(self: Pin<&mut MyObject>, value: i32);
+        unsafe fn cxx_qt_ffi_my_setter(self: Pin<&mut MyObject>, value: i32);
     }
     extern "Rust" {
         #[cxx_name = "myResetFn"]
         #[namespace = "cxx_qt::my_object"]
         #[doc(hidden)]
-        unsafe fn myResetFn(self: Pin<&mut MyObject>);
+        unsafe fn cxx_qt_ffi_myResetFn(self: Pin<&mut MyObject>);
     }
     unsafe extern "C++" {
         #[cxx_name = "my_on_changed"]
@@ -664,7 +664,9 @@ fn call_MyObject_signal_handler_primitiveChanged(
     >,
     self_value: core::pin::Pin<&mut ffi::MyObject>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("MyObject", "primitive_changed", move || {
+        handler.closure()(self_value)
+    });
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureprimitiveChanged>,
@@ -716,7 +718,9 @@ fn call_MyObject_signal_handler_trivialChanged(
     >,
     self_value: core::pin::Pin<&mut ffi::MyObject>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("MyObject", "trivial_changed", move || {
+        handler.closure()(self_value)
+    });
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuretrivialChanged>,
@@ -784,7 +788,9 @@ fn call_MyObject_signal_handler_propAutoCxxNameChanged(
     >,
     self_value: core::pin::Pin<&mut ffi::MyObject>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("MyObject", "prop_auto_cxx_name_changed", move || {
+        handler.closure()(self_value)
+    });
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurepropAutoCxxNameChanged>,
@@ -856,7 +862,9 @@ fn call_MyObject_signal_handler_customFunctionPropChanged(
     >,
     self_value: core::pin::Pin<&mut ffi::MyObject>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("MyObject", "custom_function_prop_changed", move || {
+        handler.closure()(self_value)
+    });
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurecustomFunctionPropChanged>,
@@ -924,7 +932,9 @@ fn call_MyObject_signal_handler_renamedPropertyChanged(
     >,
     self_value: core::pin::Pin<&mut ffi::MyObject>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("MyObject", "renamed_property_changed", move || {
+        handler.closure()(self_value)
+    });
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurerenamedPropertyChanged>,
@@ -992,7 +1002,9 @@ fn call_MyObject_signal_handler_named_prop_2Changed(
     >,
     self_value: core::pin::Pin<&mut ffi::MyObject>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("MyObject", "renamed_property_2_changed", move || {
+        handler.closure()(self_value)
+    });
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurenamed_prop_2Changed>,
@@ -1002,6 +1014,24 @@ cxx_qt::static_assertions::assert_eq_size!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurenamed_prop_2Changed>,
     [usize; 2]
 );
+impl ffi::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_my_getter(self: &ffi::MyObject) -> i32 {
+        ::cxx_qt::panic::call_guarded_returning("MyObject", "my_getter", move || self.my_getter())
+    }
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_my_setter(self: core::pin::Pin<&mut ffi::MyObject>, value: i32) {
+        ::cxx_qt::panic::call_guarded("MyObject", "my_setter", move || self.my_setter(value))
+    }
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_myResetFn(self: core::pin::Pin<&mut ffi::MyObject>) {
+        ::cxx_qt::panic::call_guarded("MyObject", "myResetFn", move || self.myResetFn())
+    }
+}
 impl ffi::MyObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "my_on_changed"]
@@ -1042,7 +1072,9 @@ fn call_MyObject_signal_handler_my_on_changed(
     >,
     self_value: core::pin::Pin<&mut ffi::MyObject>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("MyObject", "my_on_changed", move || {
+        handler.closure()(self_value)
+    });
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuremy_on_changed>,
@@ -88,7 +88,7 @@ mod ffi {
         #[cxx_name = "my_invokable"]
         #[namespace = "cxx_qt::my_object"]
         #[doc(hidden)]
-        unsafe fn my_invokable(self: &MyObject, qenum: MyEnum, other_qenum: MyOtherEnum);
+        unsafe fn cxx_qt_ffi_my_invokable(self: &MyObject, qenum: MyEnum, other_qenum: MyOtherEnum);
     }
     extern "C++" {
         #[doc(hidden)]
@@ -169,6 +169,18 @@ mod ffi {
         type QObject = cxx_qt::QObject;
     }
 }
+impl ffi::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_my_invokable(
+        self: &ffi::MyObject,
+        qenum: ffi::MyEnum,
+        other_qenum: ffi::MyOtherEnum,
+    ) {
+        ::cxx_qt::panic::call_guarded("MyObject", "my_invokable", move || {
+            self.my_invokable(qenum, other_qenum)
+        })
+    }
+}
 impl ::cxx_qt::Upcast<::cxx_qt::QObject> for ffi::MyObject {
     unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
         ffi::cxx_qt_ffi_MyObject_upcastPtr(this)
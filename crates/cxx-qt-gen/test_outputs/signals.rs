@@ -42,7 +42,7 @@ mod ffi {
         #[cxx_name = "invokable"]
         #[namespace = "cxx_qt::my_object"]
         #[doc(hidden)]
-        unsafe fn invokable(self: Pin<&mut MyObject>);
+        unsafe fn cxx_qt_ffi_invokable(self: Pin<&mut MyObject>);
     }
     unsafe extern "C++" {
         #[cxx_name = "ready"]
@@ -217,6 +217,12 @@ mod ffi {
         type QObject = cxx_qt::QObject;
     }
 }
+impl ffi::MyObject {
+    #[doc(hidden)]
+    unsafe fn cxx_qt_ffi_invokable(self: core::pin::Pin<&mut ffi::MyObject>) {
+        ::cxx_qt::panic::call_guarded("MyObject", "invokable", move || self.invokable())
+    }
+}
 impl ffi::MyObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "ready"]
@@ -266,7 +272,7 @@ fn call_MyObject_signal_handler_ready(
     handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureready>,
     self_value: core::pin::Pin<&mut ffi::MyObject>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("MyObject", "ready", move || handler.closure()(self_value));
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureready>,
@@ -344,7 +350,9 @@ fn call_MyObject_signal_handler_data_changed(
     third: ffi::QPoint,
     fourth: &ffi::QPoint,
 ) {
-    handler.closure()(self_value, first, second, third, fourth);
+    ::cxx_qt::panic::call_guarded("MyObject", "data_changed", move || {
+        handler.closure()(self_value, first, second, third, fourth)
+    });
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuredata_changed>,
@@ -433,7 +441,9 @@ fn call_MyObject_signal_handler_newData(
     third: ffi::QPoint,
     fourth: &'a ffi::QPoint,
 ) {
-    handler.closure()(self_value, first, second, third, fourth);
+    ::cxx_qt::panic::call_guarded("MyObject", "base_class_new_data", move || {
+        handler.closure()(self_value, first, second, third, fourth)
+    });
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurenewData>,
@@ -520,7 +530,7 @@ fn call_QTimer_signal_handler_timeout(
     handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<QTimerCxxQtSignalClosuretimeout>,
     self_value: core::pin::Pin<&mut ffi::QTimer>,
 ) {
-    handler.closure()(self_value);
+    ::cxx_qt::panic::call_guarded("QTimer", "timeout", move || handler.closure()(self_value));
 }
 cxx_qt::static_assertions::assert_eq_align!(
     cxx_qt::signalhandler::CxxQtSignalHandler<QTimerCxxQtSignalClosuretimeout>,
@@ -13,6 +13,10 @@ fn qt_qml_enabled() -> bool {
     std::env::var("CARGO_FEATURE_QT_QML").is_ok()
 }
 
+fn qt_quick_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_QUICK").is_ok()
+}
+
 fn qt_quickcontrols_enabled() -> bool {
     std::env::var("CARGO_FEATURE_QT_QUICKCONTROLS").is_ok()
 }
@@ -63,6 +67,10 @@ fn write_definitions_header() {
         definitions.push_str("#define CXX_QT_QML_FEATURE\n");
     }
 
+    if qt_quick_enabled() {
+        definitions.push_str("#define CXX_QT_QUICK_FEATURE\n");
+    }
+
     if qt_quickcontrols_enabled() {
         definitions.push_str("#define CXX_QT_QUICKCONTROLS_FEATURE\n");
     }
@@ -91,6 +99,9 @@ fn write_headers() {
     if qt_qml_enabled() {
         write_headers_in("qml");
     }
+    if qt_quick_enabled() {
+        write_headers_in("quick");
+    }
     if qt_quickcontrols_enabled() {
         write_headers_in("quickcontrols");
     }
@@ -149,7 +160,12 @@ fn main() {
         "core/qmap/qmap_qstring_qvariant",
         "core/qmargins",
         "core/qmarginsf",
+        "core/qmetaenum",
+        "core/qmetamethod",
+        "core/qmetaobject",
+        "core/qmetaproperty",
         "core/qmodelindex",
+        "core/qobject",
         "core/qpersistentmodelindex",
         "core/qpoint",
         "core/qpointf",
@@ -173,12 +189,16 @@ fn main() {
         "core/qset/qset_u16",
         "core/qset/qset_u32",
         "core/qset/qset_u64",
+        "core/qsettings",
         "core/qsize",
         "core/qsizef",
         "core/qstring",
         "core/qstringlist",
         "core/qt",
+        "core/qthread",
         "core/qtime",
+        "core/qtimer",
+        "core/qtranslator",
         "core/qtypes",
         "core/qurl",
         "core/quuid",
@@ -253,6 +273,7 @@ fn main() {
             "gui/qfont",
             "gui/qguiapplication",
             "gui/qimage",
+            "gui/qmouseevent",
             "gui/qpainterpath",
             "gui/qpainter",
             "gui/qpen",
@@ -266,7 +287,32 @@ fn main() {
     }
 
     if qt_qml_enabled() {
-        rust_bridges.extend(["qml/qqmlapplicationengine", "qml/qqmlengine"]);
+        rust_bridges.extend([
+            "qml/qjscallback",
+            "qml/qjsengine",
+            "qml/qjsvalue",
+            "qml/qqmlapplicationengine",
+            "qml/qqmlcomponent",
+            "qml/qqmlcontext",
+            "qml/qqmlengine",
+            "qml/qqmlerror",
+            "qml/qqmlincubator",
+        ]);
+    }
+
+    if qt_quick_enabled() {
+        rust_bridges.extend([
+            "quick/qquickimageprovider",
+            "quick/qquickitem",
+            "quick/qquickpainteditem",
+            "quick/qquickview",
+            "quick/qquickwindow",
+            "quick/qsgflatcolormaterial",
+            "quick/qsggeometry",
+            "quick/qsggeometrynode",
+            "quick/qsgnode",
+            "quick/qsgsimplerectnode",
+        ]);
     }
 
     if qt_quickcontrols_enabled() {
@@ -295,18 +341,27 @@ fn main() {
         "core/qmap/qmap",
         "core/qmargins",
         "core/qmarginsf",
+        "core/qmetaenum",
+        "core/qmetamethod",
+        "core/qmetaobject",
+        "core/qmetaproperty",
         "core/qmodelindex",
+        "core/qobject",
         "core/qpersistentmodelindex",
         "core/qpoint",
         "core/qpointf",
         "core/qrect",
         "core/qrectf",
         "core/qset/qset",
+        "core/qsettings",
         "core/qsize",
         "core/qsizef",
         "core/qstring",
         "core/qstringlist",
+        "core/qthread",
         "core/qtime",
+        "core/qtimer",
+        "core/qtranslator",
         "core/qtypes",
         "core/qurl",
         "core/quuid",
@@ -324,6 +379,7 @@ fn main() {
             "gui/qfont",
             "gui/qguiapplication",
             "gui/qimage",
+            "gui/qmouseevent",
             "gui/qpainterpath",
             "gui/qpainter",
             "gui/qpen",
@@ -337,7 +393,31 @@ fn main() {
     }
 
     if qt_qml_enabled() {
-        cpp_files.extend(["qml/qqmlapplicationengine", "qml/qqmlengine"]);
+        cpp_files.extend([
+            "qml/qjscallback",
+            "qml/qjsengine",
+            "qml/qjsvalue",
+            "qml/qqmlapplicationengine",
+            "qml/qqmlcomponent",
+            "qml/qqmlengine",
+            "qml/qqmlerror",
+            "qml/qqmlincubator",
+        ]);
+    }
+
+    if qt_quick_enabled() {
+        cpp_files.extend([
+            "quick/qquickimageprovider",
+            "quick/qquickitem",
+            "quick/qquickpainteditem",
+            "quick/qquickview",
+            "quick/qquickwindow",
+            "quick/qsgflatcolormaterial",
+            "quick/qsggeometry",
+            "quick/qsggeometrynode",
+            "quick/qsgnode",
+            "quick/qsgsimplerectnode",
+        ]);
     }
 
     if qt_quickcontrols_enabled() {
@@ -373,6 +453,10 @@ fn main() {
         builder = builder.qt_module("Qml");
     }
 
+    if qt_quick_enabled() {
+        builder = builder.qt_module("Quick");
+    }
+
     if qt_quickcontrols_enabled() {
         builder = builder.qt_module("QuickControls2");
     }
@@ -0,0 +1,33 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QMetaEnum>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QByteArray
+qmetaenumEnumName(const QMetaEnum& metaEnum);
+QByteArray
+qmetaenumKey(const QMetaEnum& metaEnum, ::std::int32_t index);
+::std::int32_t
+qmetaenumKeyToValue(const QMetaEnum& metaEnum, ::rust::Str key, bool& ok);
+QByteArray
+qmetaenumName(const QMetaEnum& metaEnum);
+QByteArray
+qmetaenumScope(const QMetaEnum& metaEnum);
+QByteArray
+qmetaenumValueToKey(const QMetaEnum& metaEnum, ::std::int32_t value);
+
+}
+}
@@ -0,0 +1,31 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QByteArray>
+#include <QtCore/QList>
+#include <QtCore/QMetaMethod>
+#include <QtCore/QObject>
+#include <QtCore/QVariant>
+
+using QMetaMethodAccess = QMetaMethod::Access;
+using QMetaMethodType = QMetaMethod::MethodType;
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qmetamethodInvoke(const QMetaMethod& method,
+                  QObject& object,
+                  Qt::ConnectionType type,
+                  const QList<QVariant>& args,
+                  QVariant& returnValue);
+QByteArray
+qmetamethodTypeName(const QMetaMethod& method);
+
+}
+}
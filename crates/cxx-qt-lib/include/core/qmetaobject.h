@@ -0,0 +1,40 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QMetaObject>
+#include <QtCore/QString>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qmetaobjectClassName(const QMetaObject& metaObject);
+::std::int32_t
+qmetaobjectIndexOfEnumerator(const QMetaObject& metaObject, ::rust::Str name);
+::std::int32_t
+qmetaobjectIndexOfMethod(const QMetaObject& metaObject, ::rust::Str method);
+::std::int32_t
+qmetaobjectIndexOfNormalizedMethod(const QMetaObject& metaObject,
+                                   ::rust::Str method);
+::std::int32_t
+qmetaobjectIndexOfProperty(const QMetaObject& metaObject, ::rust::Str name);
+::std::int32_t
+qmetaobjectIndexOfSignal(const QMetaObject& metaObject, ::rust::Str signal);
+::std::int32_t
+qmetaobjectIndexOfSlot(const QMetaObject& metaObject, ::rust::Str slot);
+bool
+qmetaobjectInherits(const QMetaObject& metaObject, const QMetaObject& other);
+const QMetaObject*
+qmetaobjectSuperClass(const QMetaObject& metaObject);
+
+}
+}
@@ -0,0 +1,31 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QByteArray>
+#include <QtCore/QMetaProperty>
+#include <QtCore/QObject>
+#include <QtCore/QVariant>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QByteArray
+qmetapropertyName(const QMetaProperty& property);
+QVariant
+qmetapropertyRead(const QMetaProperty& property, const QObject& object);
+bool
+qmetapropertyReset(const QMetaProperty& property, QObject& object);
+QByteArray
+qmetapropertyTypeName(const QMetaProperty& property);
+bool
+qmetapropertyWrite(const QMetaProperty& property,
+                   QObject& object,
+                   const QVariant& value);
+
+}
+}
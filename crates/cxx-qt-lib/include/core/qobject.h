@@ -0,0 +1,53 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QByteArray>
+#include <QtCore/QList>
+#include <QtCore/QMetaObject>
+#include <QtCore/QObject>
+#include <QtCore/QString>
+#include <QtCore/QThread>
+#include <QtCore/QVariant>
+
+#include "cxx-qt/connection.h"
+#include "cxx-qt/signalhandler.h"
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QObjectDynamicSignalHandler =
+  ::rust::cxxqt1::SignalHandler<struct QObjectDynamicSignalParams*>;
+
+bool
+qobjectConnectDynamic(QObject& qobject,
+                      ::rust::Str signal,
+                      QObjectDynamicSignalHandler closure,
+                      Qt::ConnectionType type,
+                      ::QMetaObject::Connection& connection);
+QList<QByteArray>
+qobjectDynamicPropertyNames(const QObject& qobject);
+const QMetaObject&
+qobjectMetaObject(const QObject& qobject);
+bool
+qobjectMoveToThread(QObject& qobject, QThread* thread);
+QString
+qobjectObjectName(const QObject& qobject);
+QVariant
+qobjectProperty(const QObject& qobject, ::rust::Str name);
+void
+qobjectSetObjectName(QObject& qobject, const QString& name);
+bool
+qobjectSetProperty(QObject& qobject,
+                   ::rust::Str name,
+                   const QVariant& value);
+QThread*
+qobjectThread(const QObject& qobject);
+
+}
+}
@@ -0,0 +1,54 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QSettings>
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+
+using QSettingsFormat = QSettings::Format;
+using QSettingsScope = QSettings::Scope;
+using QSettingsStatus = QSettings::Status;
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qsettingsBeginGroup(QSettings& settings, const QString& prefix);
+::std::int32_t
+qsettingsBeginReadArray(QSettings& settings, const QString& prefix);
+void
+qsettingsBeginWriteArray(QSettings& settings,
+                         const QString& prefix,
+                         ::std::int32_t size);
+bool
+qsettingsContains(const QSettings& settings, const QString& key);
+::std::unique_ptr<QSettings>
+qsettingsNew();
+::std::unique_ptr<QSettings>
+qsettingsNewWithFile(const QString& fileName, QSettingsFormat format);
+::std::unique_ptr<QSettings>
+qsettingsNewWithFormat(QSettingsFormat format,
+                       QSettingsScope scope,
+                       const QString& organization,
+                       const QString& application);
+::std::unique_ptr<QSettings>
+qsettingsNewWithNames(const QString& organization, const QString& application);
+void
+qsettingsRemove(QSettings& settings, const QString& key);
+void
+qsettingsSetValue(QSettings& settings,
+                  const QString& key,
+                  const QVariant& value);
+QVariant
+qsettingsValue(const QSettings& settings, const QString& key);
+
+}
+}
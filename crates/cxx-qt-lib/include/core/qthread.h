@@ -0,0 +1,29 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QThread>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QThread*
+qthreadCurrentThread();
+::std::int32_t
+qthreadIdealThreadCount();
+::std::unique_ptr<QThread>
+qthreadNew();
+void
+qthreadStart(QThread& thread);
+bool
+qthreadWait(QThread& thread, ::std::int64_t msecs);
+
+}
+}
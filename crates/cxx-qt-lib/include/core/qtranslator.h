@@ -0,0 +1,39 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtCore/QTranslator>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qtranslatorLoad(QTranslator& translator,
+                const QString& filename,
+                const QString& directory);
+bool
+qtranslatorLoadLocale(QTranslator& translator,
+                      const QString& filename,
+                      const QString& prefix,
+                      const QString& directory);
+::std::unique_ptr<QTranslator>
+qtranslatorNew();
+QString
+qtranslatorTranslate(const QTranslator& translator,
+                     ::rust::Str context,
+                     ::rust::Str sourceText,
+                     ::rust::Str disambiguation,
+                     ::std::int32_t n);
+
+}
+}
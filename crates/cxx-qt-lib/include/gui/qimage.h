@@ -30,5 +30,11 @@ qimageInitFromData(const rust::Slice<std::uint8_t const> data,
 ::std::int64_t
 qimageCacheKey(const QImage& image);
 
+QImage
+qimageConvertToFormat(const QImage& image, QImageFormat format);
+
+::rust::Vec<::std::uint8_t>
+qimageToRgba8888Bytes(const QImage& image);
+
 } // namespace cxxqtlib1
 } // namespace rust
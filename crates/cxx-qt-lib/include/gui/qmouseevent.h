@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QPointF>
+#include <QtGui/QMouseEvent>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qmouseeventIsButtonPressed(const QMouseEvent& event, Qt::MouseButton button);
+QPointF
+qmouseeventPosition(const QMouseEvent& event);
+QPointF
+qmouseeventScenePosition(const QMouseEvent& event);
+
+QPointF
+qhovereventOldPosition(const QHoverEvent& event);
+QPointF
+qhovereventPosition(const QHoverEvent& event);
+
+}
+}
@@ -0,0 +1,46 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <memory>
+
+#include <QtCore/QObject>
+#include <QtCore/QPointer>
+#include <QtQml/QJSEngine>
+#include <QtQml/QJSValue>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// A JavaScript function together with a guarded pointer to the engine it
+// belongs to, so that it is not called once the engine has been destroyed.
+class CxxQtJSCallback
+{
+public:
+  CxxQtJSCallback(QJSEngine* engine, const QJSValue& callback);
+
+  ::std::unique_ptr<QJSValue> call(const QJSValueList& args) const;
+  QJSEngine* engine() const;
+  bool isCallable() const;
+
+private:
+  QPointer<QJSEngine> m_engine;
+  QJSValue m_callback;
+};
+
+::std::unique_ptr<CxxQtJSCallback>
+qjscallbackNew(const QObject& context, const QJSValue& callback);
+
+}
+}
+
+#endif
@@ -0,0 +1,47 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QObject>
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+#include <QtQml/QJSEngine>
+#include <QtQml/QJSValue>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QJSValue>
+qjsengineEvaluate(QJSEngine& engine,
+                  const QString& program,
+                  const QString& fileName,
+                  ::std::int32_t lineNumber);
+::std::unique_ptr<QJSValue>
+qjsengineGlobalObject(const QJSEngine& engine);
+::std::unique_ptr<QJSEngine>
+qjsengineNew();
+::std::unique_ptr<QJSValue>
+qjsengineNewArray(QJSEngine& engine, ::std::uint32_t length);
+::std::unique_ptr<QJSValue>
+qjsengineNewObject(QJSEngine& engine);
+::std::unique_ptr<QJSValue>
+qjsengineNewQObject(QJSEngine& engine, QObject* object);
+::std::unique_ptr<QJSValue>
+qjsengineToScriptValue(QJSEngine& engine, const QVariant& value);
+
+}
+}
+
+#endif
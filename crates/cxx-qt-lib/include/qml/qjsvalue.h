@@ -0,0 +1,62 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+#include <QtQml/QJSValue>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QJSValue>
+qjsvalueCall(const QJSValue& value, const QJSValueList& args);
+::std::unique_ptr<QJSValue>
+qjsvalueCallAsConstructor(const QJSValue& value, const QJSValueList& args);
+::std::unique_ptr<QJSValue>
+qjsvalueCallWithInstance(const QJSValue& value,
+                         const QJSValue& instance,
+                         const QJSValueList& args);
+::std::unique_ptr<QJSValue>
+qjsvalueClone(const QJSValue& value);
+QStringList
+qjsvalueKeys(const QJSValue& value);
+void
+qjsvalueListAppend(QJSValueList& list, const QJSValue& value);
+::std::unique_ptr<QJSValueList>
+qjsvalueListNew();
+::std::unique_ptr<QJSValue>
+qjsvalueNewBool(bool value);
+::std::unique_ptr<QJSValue>
+qjsvalueNewF64(double value);
+::std::unique_ptr<QJSValue>
+qjsvalueNewI32(::std::int32_t value);
+::std::unique_ptr<QJSValue>
+qjsvalueNewNull();
+::std::unique_ptr<QJSValue>
+qjsvalueNewQString(const QString& value);
+::std::unique_ptr<QJSValue>
+qjsvalueNewU32(::std::uint32_t value);
+::std::unique_ptr<QJSValue>
+qjsvalueNewUndefined();
+::std::unique_ptr<QJSValue>
+qjsvalueProperty(const QJSValue& value, const QString& name);
+::std::unique_ptr<QJSValue>
+qjsvaluePropertyAt(const QJSValue& value, ::std::uint32_t index);
+
+}
+}
+
+#endif
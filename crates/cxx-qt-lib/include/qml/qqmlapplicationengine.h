@@ -11,6 +11,7 @@
 
 #ifdef CXX_QT_QML_FEATURE
 
+#include <cstddef>
 #include <memory>
 
 #include <QtQml/QQmlApplicationEngine>
@@ -21,6 +22,11 @@ namespace cxxqtlib1 {
 
 ::std::unique_ptr<QQmlApplicationEngine>
 qqmlapplicationengineNew();
+QObject*
+qqmlapplicationengineRootObjectAt(const QQmlApplicationEngine& engine,
+                                  ::std::size_t index);
+::std::size_t
+qqmlapplicationengineRootObjectsLen(const QQmlApplicationEngine& engine);
 
 }
 }
@@ -0,0 +1,44 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <memory>
+
+#include <QtCore/QObject>
+#include <QtCore/QVariant>
+#include <QtQml/QQmlComponent>
+#include <QtQml/QQmlEngine>
+
+#include "cxx-qt-lib/qqmlerror.h"
+#include "cxx-qt-lib/qqmlincubator.h"
+
+using QQmlComponentStatus = QQmlComponent::Status;
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QObject*
+qqmlcomponentCreate(QQmlComponent& component,
+                    const QVariantMap& initialProperties,
+                    QObject* parent);
+void
+qqmlcomponentCreateIncubated(QQmlComponent& component,
+                             CxxQtQmlIncubator& incubator);
+::std::unique_ptr<QList_QQmlError>
+qqmlcomponentErrors(const QQmlComponent& component);
+::std::unique_ptr<QQmlComponent>
+qqmlcomponentNew(QQmlEngine& engine);
+
+}
+}
+
+#endif
@@ -13,6 +13,7 @@
 
 #include <memory>
 
+#include <QtQml/QQmlContext>
 #include <QtQml/QQmlEngine>
 
 namespace rust {
@@ -21,6 +22,9 @@ namespace cxxqtlib1 {
 ::std::unique_ptr<QQmlEngine>
 qqmlengineNew();
 
+QQmlContext&
+qqmlengineRootContext(QQmlEngine& engine);
+
 }
 }
 
@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <cstddef>
+
+#include <QtCore/QList>
+#include <QtQml/QQmlError>
+
+using QList_QQmlError = QList<QQmlError>;
+
+namespace rust {
+namespace cxxqtlib1 {
+
+const QQmlError&
+qqmlerrorListAt(const QList_QQmlError& list, ::std::size_t index);
+::std::size_t
+qqmlerrorListLen(const QList_QQmlError& list);
+
+}
+}
+
+#endif
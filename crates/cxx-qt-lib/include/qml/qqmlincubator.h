@@ -0,0 +1,84 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <functional>
+#include <memory>
+
+#include <QtQml/QQmlIncubator>
+
+#include "cxx-qt-lib/qqmlerror.h"
+#include "rust/cxx.h"
+
+using QQmlIncubatorIncubationMode = QQmlIncubator::IncubationMode;
+using QQmlIncubatorStatus = QQmlIncubator::Status;
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// A QQmlIncubator which notifies a Rust closure when the status changes, as
+// QQmlIncubator itself can only be notified by overriding statusChanged.
+class CxxQtQmlIncubator : public QQmlIncubator
+{
+public:
+  using StatusChanged =
+    ::std::function<void(const CxxQtQmlIncubator&, QQmlIncubatorStatus)>;
+
+  explicit CxxQtQmlIncubator(QQmlIncubatorIncubationMode mode)
+    : QQmlIncubator(mode)
+  {
+  }
+
+  void setStatusChanged(StatusChanged statusChanged)
+  {
+    m_statusChanged = ::std::move(statusChanged);
+  }
+
+protected:
+  void statusChanged(QQmlIncubatorStatus status) override
+  {
+    if (m_statusChanged) {
+      m_statusChanged(*this, status);
+    }
+  }
+
+private:
+  StatusChanged m_statusChanged;
+};
+
+::std::unique_ptr<QList_QQmlError>
+qqmlincubatorErrors(const CxxQtQmlIncubator& incubator);
+::std::unique_ptr<CxxQtQmlIncubator>
+qqmlincubatorNew(QQmlIncubatorIncubationMode mode);
+
+template<typename A>
+void
+qqmlincubatorSetStatusChanged(
+  CxxQtQmlIncubator& incubator,
+  ::rust::Fn<void(A& arg,
+                  const CxxQtQmlIncubator& incubator,
+                  QQmlIncubatorStatus status)> func,
+  ::rust::Box<A> arg)
+{
+  // std::function must be copyable, so share the Box between copies
+  auto shared = ::std::make_shared<::rust::Box<A>>(::std::move(arg));
+  incubator.setStatusChanged(
+    [func, shared = ::std::move(shared)](const CxxQtQmlIncubator& incubator,
+                                         QQmlIncubatorStatus status) {
+      func(**shared, incubator, status);
+    });
+}
+
+}
+}
+
+#endif
@@ -0,0 +1,171 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QUICK_FEATURE
+
+#include <atomic>
+#include <functional>
+#include <memory>
+
+#include <QtCore/QMutex>
+#include <QtCore/QSize>
+#include <QtCore/QString>
+#include <QtGui/QImage>
+#include <QtQml/QQmlEngine>
+#include <QtQuick/QQuickImageProvider>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+class CxxQtQuickImageResponseObject;
+
+// The state of an image response which is shared with Rust.
+//
+// Rust may finish the response from any thread, and may still hold the state
+// after Qt Quick has deleted the response object, eg if the request was
+// cancelled. If the state is dropped without finishing then the response is
+// finished with an error so that Qt Quick does not wait forever.
+class CxxQtQuickImageResponse
+{
+public:
+  ~CxxQtQuickImageResponse();
+
+  // The state is shared between threads, so finishing is const and protected
+  // by the mutex
+  void finish(const QImage& image, const QString& errorString) const;
+  bool isCancelled() const;
+  bool isFinished() const;
+
+private:
+  friend class CxxQtQuickImageResponseObject;
+
+  mutable QMutex m_mutex;
+  CxxQtQuickImageResponseObject* m_object = nullptr;
+  mutable bool m_finished = false;
+  ::std::atomic<bool> m_cancelled = false;
+};
+
+// The QQuickImageResponse which is returned to Qt Quick.
+class CxxQtQuickImageResponseObject : public QQuickImageResponse
+{
+public:
+  explicit CxxQtQuickImageResponseObject(
+    const ::std::shared_ptr<CxxQtQuickImageResponse>& state);
+  ~CxxQtQuickImageResponseObject() override;
+
+  void cancel() override;
+  QString errorString() const override;
+  QQuickTextureFactory* textureFactory() const override;
+
+private:
+  friend class CxxQtQuickImageResponse;
+
+  // Only the Rust side owns the state, so that dropping it can finish the
+  // response
+  ::std::weak_ptr<CxxQtQuickImageResponse> m_state;
+  QImage m_image;
+  QString m_errorString;
+};
+
+// A QQuickImageProvider which requests images from Rust.
+class CxxQtQuickImageProvider : public QQuickImageProvider
+{
+public:
+  using RequestImage = ::std::function<QImage(const QString&, const QSize&)>;
+
+  explicit CxxQtQuickImageProvider(RequestImage requestImage);
+
+  QImage requestImage(const QString& id,
+                      QSize* size,
+                      const QSize& requestedSize) override;
+
+private:
+  RequestImage m_requestImage;
+};
+
+// A QQuickAsyncImageProvider which passes the response to Rust, so that it can
+// be finished later from any thread.
+class CxxQtQuickAsyncImageProvider : public QQuickAsyncImageProvider
+{
+public:
+  using RequestImageResponse =
+    ::std::function<void(const QString&,
+                         const QSize&,
+                         ::std::shared_ptr<CxxQtQuickImageResponse>)>;
+
+  explicit CxxQtQuickAsyncImageProvider(
+    RequestImageResponse requestImageResponse);
+
+  QQuickImageResponse* requestImageResponse(
+    const QString& id,
+    const QSize& requestedSize) override;
+
+private:
+  RequestImageResponse m_requestImageResponse;
+};
+
+template<typename A>
+void
+qqmlengineAddAsyncImageProvider(
+  QQmlEngine& engine,
+  const QString& providerId,
+  ::rust::Fn<void(const A& arg,
+                  const QString& id,
+                  const QSize& requestedSize,
+                  ::std::shared_ptr<CxxQtQuickImageResponse> response)> func,
+  ::rust::Box<A> arg)
+{
+  // std::function must be copyable, so share the Box between copies
+  auto shared = ::std::make_shared<::rust::Box<A>>(::std::move(arg));
+  // The engine takes ownership of the provider
+  engine.addImageProvider(
+    providerId,
+    new CxxQtQuickAsyncImageProvider(
+      [func, shared = ::std::move(shared)](
+        const QString& id,
+        const QSize& requestedSize,
+        ::std::shared_ptr<CxxQtQuickImageResponse> response) {
+        func(**shared, id, requestedSize, ::std::move(response));
+      }));
+}
+
+template<typename A>
+void
+qqmlengineAddImageProvider(
+  QQmlEngine& engine,
+  const QString& providerId,
+  ::rust::Fn<QImage(const A& arg, const QString& id, const QSize& requestedSize)>
+    func,
+  ::rust::Box<A> arg)
+{
+  // std::function must be copyable, so share the Box between copies
+  auto shared = ::std::make_shared<::rust::Box<A>>(::std::move(arg));
+  // The engine takes ownership of the provider
+  engine.addImageProvider(
+    providerId,
+    new CxxQtQuickImageProvider(
+      [func, shared = ::std::move(shared)](const QString& id,
+                                           const QSize& requestedSize) {
+        return func(**shared, id, requestedSize);
+      }));
+}
+
+void
+qquickimageresponseFinish(const CxxQtQuickImageResponse& response,
+                          const QImage& image,
+                          const QString& errorString);
+
+}
+}
+
+#endif
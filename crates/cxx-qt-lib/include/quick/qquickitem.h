@@ -0,0 +1,71 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QUICK_FEATURE
+
+#include <memory>
+
+#include <QtGui/QImage>
+#include <QtQuick/QQuickItem>
+#include <QtQuick/QQuickItemGrabResult>
+
+#include "rust/cxx.h"
+
+using QQuickItemFlag = QQuickItem::Flag;
+using QQuickItemUpdatePaintNodeData = QQuickItem::UpdatePaintNodeData;
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qquickitemAcceptsMouseButton(const QQuickItem& item, Qt::MouseButton button);
+bool
+qquickitemHasFlag(const QQuickItem& item, QQuickItemFlag flag);
+void
+qquickitemSetAcceptedMouseButton(QQuickItem& item,
+                                 Qt::MouseButton button,
+                                 bool enabled);
+
+template<typename A>
+bool
+qquickitemGrabToImage(
+  QQuickItem& item,
+  const QSize& targetSize,
+  ::rust::Fn<void(::rust::Box<A> arg, const QImage& image)> func,
+  ::rust::Box<A> arg)
+{
+  QSharedPointer<QQuickItemGrabResult> result = item.grabToImage(targetSize);
+  if (result.isNull()) {
+    return false;
+  }
+
+  // The connection holds the result alive until it is ready, once ready the
+  // connection is disconnected which then releases the result
+  auto* resultPtr = result.data();
+  auto connection = ::std::make_shared<QMetaObject::Connection>();
+  *connection = QObject::connect(
+    resultPtr,
+    &QQuickItemGrabResult::ready,
+    resultPtr,
+    [func = ::std::move(func),
+     arg = ::std::move(arg),
+     result = ::std::move(result),
+     connection]() mutable {
+      QObject::disconnect(*connection);
+      func(::std::move(arg), result->image());
+    });
+  return true;
+}
+
+}
+}
+
+#endif
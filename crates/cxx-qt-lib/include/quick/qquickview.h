@@ -0,0 +1,40 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QUICK_FEATURE
+
+#include <memory>
+
+#include <QtQml/QQmlContext>
+#include <QtQml/QQmlEngine>
+#include <QtQuick/QQuickView>
+
+#include "cxx-qt-lib/qqmlerror.h"
+
+using QQuickViewResizeMode = QQuickView::ResizeMode;
+using QQuickViewStatus = QQuickView::Status;
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QQmlEngine&
+qquickviewEngine(QQuickView& view);
+::std::unique_ptr<QList_QQmlError>
+qquickviewErrors(const QQuickView& view);
+::std::unique_ptr<QQuickView>
+qquickviewNew();
+QQmlContext&
+qquickviewRootContext(QQuickView& view);
+
+}
+}
+
+#endif
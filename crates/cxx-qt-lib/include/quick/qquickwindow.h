@@ -0,0 +1,29 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QUICK_FEATURE
+
+#include <memory>
+
+#include <QtQuick/QQuickWindow>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qquickwindowHasFlag(const QQuickWindow& window, Qt::WindowType flag);
+::std::unique_ptr<QQuickWindow>
+qquickwindowNew();
+
+}
+}
+
+#endif
@@ -0,0 +1,43 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QUICK_FEATURE
+
+#include <memory>
+
+#include <QtCore/QPointF>
+#include <QtQuick/QSGGeometry>
+
+#include "rust/cxx.h"
+
+using QSGGeometryDrawingMode = QSGGeometry::DrawingMode;
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QSGGeometryDrawingMode
+qsggeometryDrawingMode(const QSGGeometry& geometry);
+bool
+qsggeometryIsPoint2D(const QSGGeometry& geometry);
+::std::unique_ptr<QSGGeometry>
+qsggeometryNewPoint2D(::std::int32_t vertexCount);
+QPointF
+qsggeometryPoint2DAt(const QSGGeometry& geometry, ::std::int32_t index);
+void
+qsggeometrySetDrawingMode(QSGGeometry& geometry, QSGGeometryDrawingMode mode);
+void
+qsggeometrySetPoints2D(QSGGeometry& geometry,
+                       ::rust::Slice<const QPointF> points);
+
+}
+}
+
+#endif
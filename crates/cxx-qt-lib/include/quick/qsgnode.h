@@ -0,0 +1,40 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// The definitions file is auto-generated by the build script
+#include <cxx-qt-lib/definitions.h>
+
+#ifdef CXX_QT_QUICK_FEATURE
+
+#include <memory>
+
+#include <QtQuick/QSGNode>
+
+using QSGNodeDirtyStateBit = QSGNode::DirtyStateBit;
+using QSGNodeFlag = QSGNode::Flag;
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qsgnodeAppendChildNode(QSGNode& parent, QSGNode* node);
+void
+qsgnodeDeleteAllChildNodes(QSGNode& node);
+bool
+qsgnodeHasFlag(const QSGNode& node, QSGNodeFlag flag);
+void
+qsgnodeMarkDirty(QSGNode& node, QSGNodeDirtyStateBit bit);
+::std::unique_ptr<QSGNode>
+qsgnodeNew();
+void
+qsgnodePrependChildNode(QSGNode& parent, QSGNode* node);
+
+}
+}
+
+#endif
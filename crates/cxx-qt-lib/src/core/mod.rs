@@ -38,12 +38,30 @@ pub use qmargins::QMargins;
 mod qmarginsf;
 pub use qmarginsf::QMarginsF;
 
+mod qmetaenum;
+pub use qmetaenum::QMetaEnum;
+
+mod qmetamethod;
+pub use qmetamethod::{QMetaMethod, QMetaMethodAccess, QMetaMethodType};
+
+mod qmetaobject;
+pub use qmetaobject::QMetaObject;
+
 // Reexport QMetaObjectConnection and guard from cxx-qt
 pub use cxx_qt::{QMetaObjectConnection, QMetaObjectConnectionGuard};
 
+mod qmetaproperty;
+pub use qmetaproperty::QMetaProperty;
+
 mod qmodelindex;
 pub use qmodelindex::QModelIndex;
 
+mod qobject;
+pub use qobject::QObjectExt;
+
+// Reexport QObject from cxx-qt
+pub use cxx_qt::QObject;
+
 mod qpersistentmodelindex;
 pub use qpersistentmodelindex::QPersistentModelIndex;
 
@@ -56,6 +74,13 @@ pub use qrectf::QRectF;
 mod qset;
 pub use qset::{QSet, QSetElement};
 
+mod qsettings;
+pub use qsettings::{QSettings, QSettingsFormat, QSettingsScope, QSettingsStatus};
+#[cfg(feature = "serde")]
+mod qsettings_serde;
+#[cfg(feature = "serde")]
+pub use qsettings_serde::QSettingsSerdeError;
+
 mod qsize;
 pub use qsize::QSize;
 
@@ -76,13 +101,22 @@ pub use qstringlist::QStringList;
 mod qt;
 pub use qt::{
     AspectRatioMode, BGMode, CaseSensitivity, ClipOperation, ConnectionType, DateFormat, FillRule,
-    LayoutDirection, PenCapStyle, PenJoinStyle, PenStyle, SizeMode, SplitBehaviorFlags, TimeSpec,
-    TransformationMode,
+    FindChildOption, KeyboardModifier, LayoutDirection, MouseButton, PenCapStyle, PenJoinStyle,
+    PenStyle, SizeMode, SplitBehaviorFlags, TimeSpec, TimerType, TransformationMode, WindowType,
 };
 
+mod qthread;
+pub use qthread::QThread;
+
 mod qtime;
 pub use qtime::QTime;
 
+mod qtimer;
+pub use qtimer::QTimer;
+
+mod qtranslator;
+pub use qtranslator::QTranslator;
+
 mod qtypes;
 pub use qtypes::{qint64, qintptr, qreal, qsizetype, quint64, quintptr};
 
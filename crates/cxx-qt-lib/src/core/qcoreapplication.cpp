@@ -50,5 +50,24 @@ qcoreapplicationNew(const QVector<QByteArray>& args)
   return ptr;
 }
 
+QString
+qcoreapplicationTranslate(::rust::Str context,
+                          ::rust::Str sourceText,
+                          ::rust::Str disambiguation,
+                          ::std::int32_t n)
+{
+  // QCoreApplication::translate requires null terminated strings
+  const auto contextBytes =
+    QByteArray(context.data(), static_cast<qsizetype>(context.size()));
+  const auto sourceTextBytes =
+    QByteArray(sourceText.data(), static_cast<qsizetype>(sourceText.size()));
+  const auto disambiguationBytes = QByteArray(
+    disambiguation.data(), static_cast<qsizetype>(disambiguation.size()));
+  return QCoreApplication::translate(contextBytes.constData(),
+                                     sourceTextBytes.constData(),
+                                     disambiguationBytes.constData(),
+                                     static_cast<int>(n));
+}
+
 }
 }
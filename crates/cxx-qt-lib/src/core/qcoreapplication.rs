@@ -4,7 +4,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{QByteArray, QString, QStringList, QVector};
+use crate::{QByteArray, QString, QStringList, QTranslator, QVector};
 use core::pin::Pin;
 
 #[cxx::bridge]
@@ -16,6 +16,8 @@ mod ffi {
         type QString = crate::QString;
         include!("cxx-qt-lib/qstringlist.h");
         type QStringList = crate::QStringList;
+        include!("cxx-qt-lib/qtranslator.h");
+        type QTranslator = crate::QTranslator;
         include!("cxx-qt-lib/qvector.h");
         type QVector_QByteArray = crate::QVector<QByteArray>;
 
@@ -28,6 +30,14 @@ mod ffi {
         #[doc(hidden)]
         #[rust_name = "qcoreapplication_new"]
         fn qcoreapplicationNew(args: &QVector_QByteArray) -> UniquePtr<QCoreApplication>;
+        #[doc(hidden)]
+        #[rust_name = "qcoreapplication_translate"]
+        fn qcoreapplicationTranslate(
+            context: &str,
+            source_text: &str,
+            disambiguation: &str,
+            n: i32,
+        ) -> QString;
     }
 
     // These are all static, so we need to create bindings until CXX supports statics
@@ -46,6 +56,12 @@ mod ffi {
         #[rust_name = "qcoreapplication_exec"]
         fn qapplicationExec(app: Pin<&mut QCoreApplication>) -> i32;
         #[doc(hidden)]
+        #[rust_name = "qcoreapplication_install_translator"]
+        fn qapplicationInstallTranslator(
+            app: Pin<&mut QCoreApplication>,
+            translator: Pin<&mut QTranslator>,
+        ) -> bool;
+        #[doc(hidden)]
         #[rust_name = "qcoreapplication_library_paths"]
         fn qapplicationLibraryPaths(app: &QCoreApplication) -> QStringList;
         #[doc(hidden)]
@@ -58,6 +74,12 @@ mod ffi {
         #[rust_name = "qcoreapplication_remove_library_path"]
         fn qapplicationRemoveLibraryPath(app: &QCoreApplication, path: &QString);
         #[doc(hidden)]
+        #[rust_name = "qcoreapplication_remove_translator"]
+        fn qapplicationRemoveTranslator(
+            app: Pin<&mut QCoreApplication>,
+            translator: Pin<&mut QTranslator>,
+        ) -> bool;
+        #[doc(hidden)]
         #[rust_name = "qcoreapplication_set_application_name"]
         fn qapplicationSetApplicationName(app: Pin<&mut QCoreApplication>, name: &QString);
         #[doc(hidden)]
@@ -107,6 +129,15 @@ impl QCoreApplication {
         ffi::qcoreapplication_exec(self)
     }
 
+    /// Adds the translator to the list of translation files to be used for translations,
+    /// returning true if the translator was installed.
+    ///
+    /// Translators are searched in the reverse order in which they were installed, so the most recently
+    /// installed translator is searched first. A translator is removed automatically when it is destroyed.
+    pub fn install_translator(self: Pin<&mut Self>, translator: Pin<&mut QTranslator>) -> bool {
+        ffi::qcoreapplication_install_translator(self, translator)
+    }
+
     /// Returns a list of paths that the application will search when dynamically loading libraries.
     pub fn library_paths(&self) -> QStringList {
         ffi::qcoreapplication_library_paths(self)
@@ -151,6 +182,11 @@ impl QCoreApplication {
         ffi::qcoreapplication_remove_library_path(self, path)
     }
 
+    /// Removes the translator from the list of translation files used, returning true if it was removed.
+    pub fn remove_translator(self: Pin<&mut Self>, translator: Pin<&mut QTranslator>) -> bool {
+        ffi::qcoreapplication_remove_translator(self, translator)
+    }
+
     /// Set the name of this application
     pub fn set_application_name(self: Pin<&mut Self>, name: &QString) {
         ffi::qcoreapplication_set_application_name(self, name);
@@ -176,4 +212,57 @@ impl QCoreApplication {
     pub fn set_organization_name(self: Pin<&mut Self>, name: &QString) {
         ffi::qcoreapplication_set_organization_name(self, name);
     }
+
+    /// Returns the translation of the source text in the given context from the installed translators,
+    /// or the source text if there is no translation.
+    ///
+    /// When n is not -1 the plural form for n is chosen and `%n` is replaced with n, otherwise pass -1.
+    /// Prefer the [qs_tr](crate::qs_tr) macro, which uses the module path as the context
+    /// so that the strings can be extracted by cxx-qt-build.
+    ///
+    /// This can be called without an application, in which case the source text is returned.
+    pub fn translate(
+        context: &str,
+        source_text: &str,
+        disambiguation: Option<&str>,
+        n: i32,
+    ) -> QString {
+        ffi::qcoreapplication_translate(context, source_text, disambiguation.unwrap_or_default(), n)
+    }
+}
+
+/// Translates the source text with [QCoreApplication::translate], using the module path of the caller as the context.
+///
+/// An optional disambiguation can be given for identical source texts with different meanings,
+/// followed by an optional count n which chooses the plural form and replaces `%n`.
+///
+/// ```ignore
+/// let greeting: QString = qs_tr!("Hello");
+/// let open: QString = qs_tr!("Open", "verb");
+/// let files: QString = qs_tr!("%n file(s)", "", count);
+/// ```
+///
+/// The arguments must be literals so that the strings can be extracted into `.ts` files,
+/// see `CxxQtBuilder::translation` in cxx-qt-build.
+#[macro_export]
+macro_rules! qs_tr {
+    ($source_text:literal $(,)?) => {
+        $crate::QCoreApplication::translate(::core::module_path!(), $source_text, None, -1)
+    };
+    ($source_text:literal, $disambiguation:literal $(,)?) => {
+        $crate::QCoreApplication::translate(
+            ::core::module_path!(),
+            $source_text,
+            Some($disambiguation),
+            -1,
+        )
+    };
+    ($source_text:literal, $disambiguation:literal, $n:expr $(,)?) => {
+        $crate::QCoreApplication::translate(
+            ::core::module_path!(),
+            $source_text,
+            Some($disambiguation),
+            $n,
+        )
+    };
 }
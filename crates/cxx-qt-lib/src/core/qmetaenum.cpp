@@ -0,0 +1,74 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qmetaenum.h"
+
+#include <cstddef>
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QMetaEnum has a pointer to the meta object and either a uint handle (Qt 5)
+// or a pointer to the data (Qt 6).
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/kernel/qmetaobject.h?h=v5.15.6-lts-lgpl#n251
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/kernel/qmetaobject.h?h=v6.2.4#n274
+#if (QT_VERSION < QT_VERSION_CHECK(6, 0, 0))
+assert_alignment_and_size(QMetaEnum, {
+  ::std::size_t a0;
+  ::std::uint32_t a1;
+});
+#else
+assert_alignment_and_size(QMetaEnum, {
+  ::std::size_t a0;
+  ::std::size_t a1;
+});
+#endif
+
+static_assert(::std::is_trivially_copyable<QMetaEnum>::value);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QByteArray
+qmetaenumEnumName(const QMetaEnum& metaEnum)
+{
+  return QByteArray(metaEnum.enumName());
+}
+
+QByteArray
+qmetaenumKey(const QMetaEnum& metaEnum, ::std::int32_t index)
+{
+  return QByteArray(metaEnum.key(static_cast<int>(index)));
+}
+
+::std::int32_t
+qmetaenumKeyToValue(const QMetaEnum& metaEnum, ::rust::Str key, bool& ok)
+{
+  // Note that rust::Str is not null terminated, so copy into a QByteArray
+  const auto keyBytes = QByteArray(key.data(), key.size());
+  return static_cast<::std::int32_t>(
+    metaEnum.keyToValue(keyBytes.constData(), &ok));
+}
+
+QByteArray
+qmetaenumName(const QMetaEnum& metaEnum)
+{
+  return QByteArray(metaEnum.name());
+}
+
+QByteArray
+qmetaenumScope(const QMetaEnum& metaEnum)
+{
+  return QByteArray(metaEnum.scope());
+}
+
+QByteArray
+qmetaenumValueToKey(const QMetaEnum& metaEnum, ::std::int32_t value)
+{
+  return QByteArray(metaEnum.valueToKey(static_cast<int>(value)));
+}
+
+}
+}
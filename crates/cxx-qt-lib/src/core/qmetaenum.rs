@@ -0,0 +1,126 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+
+        include!("cxx-qt-lib/qmetaenum.h");
+        type QMetaEnum = super::QMetaEnum;
+
+        /// Returns true if this enumerator is used as a flag; otherwise returns false.
+        #[rust_name = "is_flag"]
+        fn isFlag(self: &QMetaEnum) -> bool;
+
+        /// Returns true if this enumerator is declared as a C++11 enum class; otherwise returns false.
+        #[rust_name = "is_scoped"]
+        fn isScoped(self: &QMetaEnum) -> bool;
+
+        /// Returns true if this enum is valid (has a name); otherwise returns false.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QMetaEnum) -> bool;
+
+        /// Returns the number of keys.
+        #[rust_name = "key_count"]
+        fn keyCount(self: &QMetaEnum) -> i32;
+
+        /// Returns the value with the given index, or returns -1 if there is no such value.
+        fn value(self: &QMetaEnum, index: i32) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_enum_name"]
+        fn qmetaenumEnumName(meta_enum: &QMetaEnum) -> QByteArray;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_key"]
+        fn qmetaenumKey(meta_enum: &QMetaEnum, index: i32) -> QByteArray;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_key_to_value"]
+        fn qmetaenumKeyToValue(meta_enum: &QMetaEnum, key: &str, ok: &mut bool) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_name"]
+        fn qmetaenumName(meta_enum: &QMetaEnum) -> QByteArray;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_scope"]
+        fn qmetaenumScope(meta_enum: &QMetaEnum) -> QByteArray;
+        #[doc(hidden)]
+        #[rust_name = "qmetaenum_value_to_key"]
+        fn qmetaenumValueToKey(meta_enum: &QMetaEnum, value: i32) -> QByteArray;
+    }
+}
+
+use crate::QByteArray;
+
+/// The QMetaEnum class provides meta-data about an enumerator.
+#[derive(Clone)]
+#[repr(C)]
+pub struct QMetaEnum {
+    _mobj: MaybeUninit<usize>,
+    #[cfg(cxxqt_qt_version_major = "5")]
+    _handle: MaybeUninit<u32>,
+    #[cfg(cxxqt_qt_version_major = "6")]
+    _data: MaybeUninit<usize>,
+}
+
+impl QMetaEnum {
+    /// Returns the enum name of the flag (without the scope).
+    ///
+    /// For example, the Qt::AlignmentFlag flag has AlignmentFlag as the enum name, but Alignment as the type name.
+    /// Non flag enums has the same type and enum names.
+    pub fn enum_name(&self) -> QByteArray {
+        ffi::qmetaenum_enum_name(self)
+    }
+
+    /// Returns the key with the given index, or an empty QByteArray if no such key exists.
+    pub fn key(&self, index: i32) -> QByteArray {
+        ffi::qmetaenum_key(self, index)
+    }
+
+    /// Returns an iterator over the key and value pairs of this enumerator.
+    pub fn keys(&self) -> impl Iterator<Item = (QByteArray, i32)> + '_ {
+        (0..self.key_count()).map(|index| (self.key(index), self.value(index)))
+    }
+
+    /// Returns the integer value of the given enumeration key, or None if key is not defined.
+    pub fn key_to_value(&self, key: &str) -> Option<i32> {
+        let mut ok = false;
+        let value = ffi::qmetaenum_key_to_value(self, key, &mut ok);
+        if ok {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the name of the type (without the scope).
+    pub fn name(&self) -> QByteArray {
+        ffi::qmetaenum_name(self)
+    }
+
+    /// Returns the scope this enumerator was declared in.
+    pub fn scope(&self) -> QByteArray {
+        ffi::qmetaenum_scope(self)
+    }
+
+    /// Returns the string that is used as the name of the given enumeration value,
+    /// or an empty QByteArray if value is not defined.
+    pub fn value_to_key(&self, value: i32) -> QByteArray {
+        ffi::qmetaenum_value_to_key(self, value)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QMetaEnum {
+    type Id = type_id!("QMetaEnum");
+    type Kind = cxx::kind::Trivial;
+}
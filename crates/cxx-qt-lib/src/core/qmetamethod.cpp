@@ -0,0 +1,186 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qmetamethod.h"
+
+#include <cstdint>
+#include <vector>
+
+#include <QtCore/QDebug>
+#include <QtCore/QThread>
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QMetaMethod has a pointer to the meta object and either a uint handle (Qt 5)
+// or a pointer to the data (Qt 6).
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/kernel/qmetaobject.h?h=v5.15.6-lts-lgpl#n193
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/kernel/qmetaobject.h?h=v6.2.4#n216
+#if (QT_VERSION < QT_VERSION_CHECK(6, 0, 0))
+assert_alignment_and_size(QMetaMethod, {
+  ::std::size_t a0;
+  ::std::uint32_t a1;
+});
+#else
+assert_alignment_and_size(QMetaMethod, {
+  ::std::size_t a0;
+  ::std::size_t a1;
+});
+#endif
+
+static_assert(::std::is_trivially_copyable<QMetaMethod>::value);
+
+namespace {
+
+QVariant
+qvariantForType(int typeId)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return QVariant(QMetaType(typeId));
+#else
+  return QVariant(typeId, nullptr);
+#endif
+}
+
+bool
+qvariantConvertToType(QVariant& variant, int typeId)
+{
+  // A QVariant parameter accepts any value so no conversion is required
+  if (typeId == QMetaType::QVariant || variant.userType() == typeId) {
+    return true;
+  }
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return variant.convert(QMetaType(typeId));
+#else
+  return variant.convert(typeId);
+#endif
+}
+
+// Stores everything that is needed to call the method later, so that the
+// invocation can be moved to another thread for queued connections.
+class MethodInvocation
+{
+public:
+  MethodInvocation(const QMetaMethod& method, QList<QVariant> args)
+    : m_index(method.methodIndex())
+    , m_returnType(method.returnType())
+    , m_args(::std::move(args))
+  {
+    for (int i = 0; i < method.parameterCount(); i++) {
+      m_parameterTypes.push_back(method.parameterType(i));
+    }
+  }
+
+  QVariant invoke(QObject* object)
+  {
+    QVariant returnValue;
+
+    // The first argument is where the return value is written to
+    ::std::vector<void*> argv;
+    if (m_returnType == QMetaType::QVariant) {
+      argv.push_back(&returnValue);
+    } else if (m_returnType != QMetaType::Void &&
+               m_returnType != QMetaType::UnknownType) {
+      returnValue = qvariantForType(m_returnType);
+      argv.push_back(returnValue.data());
+    } else {
+      argv.push_back(nullptr);
+    }
+
+    for (::std::size_t i = 0; i < m_parameterTypes.size(); i++) {
+      auto& arg = m_args[static_cast<int>(i)];
+      if (m_parameterTypes[i] == QMetaType::QVariant) {
+        argv.push_back(&arg);
+      } else {
+        argv.push_back(arg.data());
+      }
+    }
+
+    QMetaObject::metacall(
+      object, QMetaObject::InvokeMetaMethod, m_index, argv.data());
+    return returnValue;
+  }
+
+private:
+  int m_index;
+  int m_returnType;
+  ::std::vector<int> m_parameterTypes;
+  QList<QVariant> m_args;
+};
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qmetamethodInvoke(const QMetaMethod& method,
+                  QObject& object,
+                  Qt::ConnectionType type,
+                  const QList<QVariant>& args,
+                  QVariant& returnValue)
+{
+  if (!method.isValid() || method.parameterCount() != args.size()) {
+    return false;
+  }
+
+  // Convert the arguments to the types that the method expects
+  QList<QVariant> converted;
+  for (int i = 0; i < method.parameterCount(); i++) {
+    auto arg = args.at(i);
+    if (!qvariantConvertToType(arg, method.parameterType(i))) {
+      return false;
+    }
+    converted.append(arg);
+  }
+
+  auto invocation = MethodInvocation(method, ::std::move(converted));
+  auto* objectPtr = &object;
+  const bool sameThread = object.thread() == QThread::currentThread();
+
+  switch (type) {
+    case Qt::AutoConnection:
+      if (sameThread) {
+        returnValue = invocation.invoke(objectPtr);
+        return true;
+      }
+      [[fallthrough]];
+    case Qt::QueuedConnection:
+      return QMetaObject::invokeMethod(
+        objectPtr,
+        [invocation = ::std::move(invocation), objectPtr]() mutable {
+          invocation.invoke(objectPtr);
+        },
+        Qt::QueuedConnection);
+    case Qt::DirectConnection:
+      returnValue = invocation.invoke(objectPtr);
+      return true;
+    case Qt::BlockingQueuedConnection:
+      if (sameThread) {
+        qWarning() << "Cannot use a BlockingQueuedConnection to invoke"
+                   << method.methodSignature()
+                   << "as the object lives in the current thread";
+        return false;
+      }
+      return QMetaObject::invokeMethod(
+        objectPtr,
+        [&invocation, &returnValue, objectPtr]() {
+          returnValue = invocation.invoke(objectPtr);
+        },
+        Qt::BlockingQueuedConnection);
+    default:
+      return false;
+  }
+}
+
+QByteArray
+qmetamethodTypeName(const QMetaMethod& method)
+{
+  return QByteArray(method.typeName());
+}
+
+}
+}
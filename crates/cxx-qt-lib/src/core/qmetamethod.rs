@@ -0,0 +1,196 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use core::pin::Pin;
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the access level of a method, following the conventions used in C++.
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum QMetaMethodAccess {
+        Private,
+        Protected,
+        Public,
+    }
+
+    /// This enum describes the type of a method.
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum QMetaMethodType {
+        /// The function is a plain member function.
+        Method,
+        /// The function is a signal.
+        Signal,
+        /// The function is a slot.
+        Slot,
+        /// The function is a constructor.
+        Constructor,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QByteArray = crate::QList<crate::QByteArray>;
+        type QList_QVariant = crate::QList<crate::QVariant>;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt::QObject;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        include!("cxx-qt-lib/qmetamethod.h");
+        type QMetaMethod = super::QMetaMethod;
+        type QMetaMethodAccess;
+        type QMetaMethodType;
+
+        /// Returns the access specification of this method (private, protected, or public).
+        fn access(self: &QMetaMethod) -> QMetaMethodAccess;
+
+        /// Returns true if this method is valid (can be introspected and invoked), otherwise returns false.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QMetaMethod) -> bool;
+
+        /// Returns this method's index.
+        #[rust_name = "method_index"]
+        fn methodIndex(self: &QMetaMethod) -> i32;
+
+        /// Returns the signature of this method (e.g., `setValue(double)`).
+        #[rust_name = "method_signature"]
+        fn methodSignature(self: &QMetaMethod) -> QByteArray;
+
+        /// Returns the type of this method (signal, slot, or method).
+        #[rust_name = "method_type"]
+        fn methodType(self: &QMetaMethod) -> QMetaMethodType;
+
+        /// Returns the name of this method.
+        fn name(self: &QMetaMethod) -> QByteArray;
+
+        /// Returns the number of parameters of this method.
+        #[rust_name = "parameter_count"]
+        fn parameterCount(self: &QMetaMethod) -> i32;
+
+        /// Returns a list of parameter names.
+        #[rust_name = "parameter_names"]
+        fn parameterNames(self: &QMetaMethod) -> QList_QByteArray;
+
+        /// Returns the type of the parameter at the given index as a meta type id.
+        #[rust_name = "parameter_type"]
+        fn parameterType(self: &QMetaMethod, index: i32) -> i32;
+
+        /// Returns a list of parameter types.
+        #[rust_name = "parameter_types"]
+        fn parameterTypes(self: &QMetaMethod) -> QList_QByteArray;
+
+        /// Returns the return type of this method as a meta type id.
+        #[rust_name = "return_type"]
+        fn returnType(self: &QMetaMethod) -> i32;
+
+        /// Returns the method revision if one was specified by Q_REVISION, otherwise returns 0.
+        fn revision(self: &QMetaMethod) -> i32;
+    }
+
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type ConnectionType = crate::ConnectionType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qmetamethod_default"]
+        fn construct() -> QMetaMethod;
+        #[doc(hidden)]
+        #[rust_name = "qmetamethod_eq"]
+        fn operatorEq(a: &QMetaMethod, b: &QMetaMethod) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qmetamethod_invoke"]
+        fn qmetamethodInvoke(
+            method: &QMetaMethod,
+            object: Pin<&mut QObject>,
+            connection_type: ConnectionType,
+            args: &QList_QVariant,
+            return_value: &mut QVariant,
+        ) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmetamethod_type_name"]
+        fn qmetamethodTypeName(method: &QMetaMethod) -> QByteArray;
+    }
+}
+
+pub use ffi::{QMetaMethodAccess, QMetaMethodType};
+
+use crate::{ConnectionType, QByteArray, QList, QObject, QVariant};
+
+/// The QMetaMethod class provides meta-data about a member function.
+///
+/// A QMetaMethod has a method type, a signature, a list of parameter types, a list of parameter names,
+/// a return type, a tag, and an access specifier.
+#[derive(Clone)]
+#[repr(C)]
+pub struct QMetaMethod {
+    _mobj: MaybeUninit<usize>,
+    #[cfg(cxxqt_qt_version_major = "5")]
+    _handle: MaybeUninit<u32>,
+    #[cfg(cxxqt_qt_version_major = "6")]
+    _data: MaybeUninit<usize>,
+}
+
+impl Default for QMetaMethod {
+    /// Constructs an invalid QMetaMethod.
+    fn default() -> Self {
+        ffi::qmetamethod_default()
+    }
+}
+
+impl std::cmp::PartialEq for QMetaMethod {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qmetamethod_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QMetaMethod {}
+
+impl QMetaMethod {
+    /// Invokes this method on the object, converting each of the arguments to the type of the parameter.
+    ///
+    /// Returns the return value of the method, or an invalid QVariant if the method has no return value
+    /// or the invocation was queued. None is returned if the method could not be invoked,
+    /// for example when the argument count does not match or an argument could not be converted.
+    ///
+    /// Note that a [ConnectionType::BlockingQueuedConnection] to an object in the current thread fails
+    /// instead of deadlocking.
+    pub fn invoke(
+        &self,
+        object: Pin<&mut QObject>,
+        connection_type: ConnectionType,
+        args: &QList<QVariant>,
+    ) -> Option<QVariant> {
+        let mut return_value = QVariant::default();
+        if ffi::qmetamethod_invoke(self, object, connection_type, args, &mut return_value) {
+            Some(return_value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the return type name of this method.
+    pub fn type_name(&self) -> QByteArray {
+        ffi::qmetamethod_type_name(self)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QMetaMethod {
+    type Id = type_id!("QMetaMethod");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,85 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qmetaobject.h"
+
+#include <QtCore/QByteArray>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qmetaobjectClassName(const QMetaObject& metaObject)
+{
+  return QString::fromLatin1(metaObject.className());
+}
+
+::std::int32_t
+qmetaobjectIndexOfEnumerator(const QMetaObject& metaObject, ::rust::Str name)
+{
+  // Note that rust::Str is not null terminated, so copy into a QByteArray
+  const auto nameBytes = QByteArray(name.data(), name.size());
+  return static_cast<::std::int32_t>(
+    metaObject.indexOfEnumerator(nameBytes.constData()));
+}
+
+::std::int32_t
+qmetaobjectIndexOfMethod(const QMetaObject& metaObject, ::rust::Str method)
+{
+  const auto methodBytes = QByteArray(method.data(), method.size());
+  return static_cast<::std::int32_t>(
+    metaObject.indexOfMethod(methodBytes.constData()));
+}
+
+::std::int32_t
+qmetaobjectIndexOfNormalizedMethod(const QMetaObject& metaObject,
+                                   ::rust::Str method)
+{
+  const auto methodBytes = QByteArray(method.data(), method.size());
+  const auto normalized =
+    QMetaObject::normalizedSignature(methodBytes.constData());
+  return static_cast<::std::int32_t>(
+    metaObject.indexOfMethod(normalized.constData()));
+}
+
+::std::int32_t
+qmetaobjectIndexOfProperty(const QMetaObject& metaObject, ::rust::Str name)
+{
+  const auto nameBytes = QByteArray(name.data(), name.size());
+  return static_cast<::std::int32_t>(
+    metaObject.indexOfProperty(nameBytes.constData()));
+}
+
+::std::int32_t
+qmetaobjectIndexOfSignal(const QMetaObject& metaObject, ::rust::Str signal)
+{
+  const auto signalBytes = QByteArray(signal.data(), signal.size());
+  return static_cast<::std::int32_t>(
+    metaObject.indexOfSignal(signalBytes.constData()));
+}
+
+::std::int32_t
+qmetaobjectIndexOfSlot(const QMetaObject& metaObject, ::rust::Str slot)
+{
+  const auto slotBytes = QByteArray(slot.data(), slot.size());
+  return static_cast<::std::int32_t>(
+    metaObject.indexOfSlot(slotBytes.constData()));
+}
+
+bool
+qmetaobjectInherits(const QMetaObject& metaObject, const QMetaObject& other)
+{
+  return metaObject.inherits(&other);
+}
+
+const QMetaObject*
+qmetaobjectSuperClass(const QMetaObject& metaObject)
+{
+  return metaObject.superClass();
+}
+
+}
+}
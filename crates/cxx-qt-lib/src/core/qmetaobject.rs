@@ -0,0 +1,201 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmetaenum.h");
+        type QMetaEnum = crate::QMetaEnum;
+        include!("cxx-qt-lib/qmetamethod.h");
+        type QMetaMethod = crate::QMetaMethod;
+        include!("cxx-qt-lib/qmetaproperty.h");
+        type QMetaProperty = crate::QMetaProperty;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        include!("cxx-qt-lib/qmetaobject.h");
+        /// The QMetaObject class contains meta-information about Qt objects.
+        type QMetaObject;
+
+        /// Returns the meta-data for the enumerator with the given index.
+        ///
+        /// If no such enumerator exists, a null QMetaEnum is returned.
+        fn enumerator(self: &QMetaObject, index: i32) -> QMetaEnum;
+
+        /// Returns the number of enumerators in this class, including those of the super classes.
+        #[rust_name = "enumerator_count"]
+        fn enumeratorCount(self: &QMetaObject) -> i32;
+
+        /// Returns the enumerator offset for this class; i.e. the index position of this class's first enumerator.
+        #[rust_name = "enumerator_offset"]
+        fn enumeratorOffset(self: &QMetaObject) -> i32;
+
+        /// Returns the meta-data for the method with the given index.
+        fn method(self: &QMetaObject, index: i32) -> QMetaMethod;
+
+        /// Returns the number of methods in this class, including the number of methods provided by each base class.
+        ///
+        /// These include signals and slots as well as normal member functions.
+        #[rust_name = "method_count"]
+        fn methodCount(self: &QMetaObject) -> i32;
+
+        /// Returns the method offset for this class; i.e. the index position of this class's first member function.
+        #[rust_name = "method_offset"]
+        fn methodOffset(self: &QMetaObject) -> i32;
+
+        /// Returns the meta-data for the property with the given index.
+        ///
+        /// If no such property exists, a null QMetaProperty is returned.
+        fn property(self: &QMetaObject, index: i32) -> QMetaProperty;
+
+        /// Returns the number of properties in this class, including the number of properties provided by each base class.
+        #[rust_name = "property_count"]
+        fn propertyCount(self: &QMetaObject) -> i32;
+
+        /// Returns the property offset for this class; i.e. the index position of this class's first property.
+        #[rust_name = "property_offset"]
+        fn propertyOffset(self: &QMetaObject) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_class_name"]
+        fn qmetaobjectClassName(meta_object: &QMetaObject) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_index_of_enumerator"]
+        fn qmetaobjectIndexOfEnumerator(meta_object: &QMetaObject, name: &str) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_index_of_method"]
+        fn qmetaobjectIndexOfMethod(meta_object: &QMetaObject, method: &str) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_index_of_normalized_method"]
+        fn qmetaobjectIndexOfNormalizedMethod(meta_object: &QMetaObject, method: &str) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_index_of_property"]
+        fn qmetaobjectIndexOfProperty(meta_object: &QMetaObject, name: &str) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_index_of_signal"]
+        fn qmetaobjectIndexOfSignal(meta_object: &QMetaObject, signal: &str) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_index_of_slot"]
+        fn qmetaobjectIndexOfSlot(meta_object: &QMetaObject, slot: &str) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_inherits"]
+        fn qmetaobjectInherits(meta_object: &QMetaObject, other: &QMetaObject) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_super_class"]
+        fn qmetaobjectSuperClass(meta_object: &QMetaObject) -> *const QMetaObject;
+    }
+}
+
+use crate::{
+    ConnectionType, QByteArray, QList, QMetaEnum, QMetaMethod, QMetaProperty, QObject, QObjectExt,
+    QVariant,
+};
+use core::pin::Pin;
+
+pub use ffi::QMetaObject;
+
+impl QMetaObject {
+    /// Returns the class name.
+    pub fn class_name(&self) -> crate::QString {
+        ffi::qmetaobject_class_name(self)
+    }
+
+    /// Returns an iterator over all of the enumerators of this class, including those of the super classes.
+    pub fn enumerators(&self) -> impl Iterator<Item = QMetaEnum> + '_ {
+        (0..self.enumerator_count()).map(|index| self.enumerator(index))
+    }
+
+    /// Finds enumerator name and returns its index; otherwise returns -1.
+    pub fn index_of_enumerator(&self, name: &str) -> i32 {
+        ffi::qmetaobject_index_of_enumerator(self, name)
+    }
+
+    /// Finds method and returns its index; otherwise returns -1.
+    ///
+    /// Note that the method has to be in normalized form, e.g. `setValue(int)`.
+    pub fn index_of_method(&self, method: &str) -> i32 {
+        ffi::qmetaobject_index_of_method(self, method)
+    }
+
+    /// Finds property name and returns its index; otherwise returns -1.
+    pub fn index_of_property(&self, name: &str) -> i32 {
+        ffi::qmetaobject_index_of_property(self, name)
+    }
+
+    /// Finds signal and returns its index; otherwise returns -1.
+    ///
+    /// Note that the signal has to be in normalized form, e.g. `valueChanged(int)`.
+    pub fn index_of_signal(&self, signal: &str) -> i32 {
+        ffi::qmetaobject_index_of_signal(self, signal)
+    }
+
+    /// Finds slot and returns its index; otherwise returns -1.
+    ///
+    /// Note that the slot has to be in normalized form, e.g. `setValue(int)`.
+    pub fn index_of_slot(&self, slot: &str) -> i32 {
+        ffi::qmetaobject_index_of_slot(self, slot)
+    }
+
+    /// Returns true if the class described by this QMetaObject inherits the type described by other; otherwise returns false.
+    ///
+    /// A type is considered to inherit itself.
+    pub fn inherits(&self, other: &QMetaObject) -> bool {
+        ffi::qmetaobject_inherits(self, other)
+    }
+
+    /// Invokes the member (a signal, slot or invokable method) on the object with the given arguments.
+    ///
+    /// The member is either a signature, such as `setValue(int)`, which is normalized before it is looked up,
+    /// or a name, in which case the method with that name and number of parameters is used.
+    /// None is returned if no method matches or if a name matches several overloads with the same
+    /// number of parameters, use a signature to choose between them.
+    ///
+    /// The arguments are converted to the types of the parameters. See [QMetaMethod::invoke] for the returned value.
+    pub fn invoke_method(
+        object: Pin<&mut QObject>,
+        member: &str,
+        connection_type: ConnectionType,
+        args: &QList<QVariant>,
+    ) -> Option<QVariant> {
+        let meta_object = object.meta_object();
+        let method = if member.contains('(') {
+            let index = ffi::qmetaobject_index_of_normalized_method(meta_object, member);
+            if index < 0 {
+                return None;
+            }
+            meta_object.method(index)
+        } else {
+            let name = QByteArray::from(member);
+            let mut candidates = meta_object.methods().filter(|method| {
+                method.name() == name && method.parameter_count() as isize == args.len()
+            });
+            let method = candidates.next()?;
+            if candidates.next().is_some() {
+                return None;
+            }
+            method
+        };
+        method.invoke(object, connection_type, args)
+    }
+
+    /// Returns an iterator over all of the methods of this class, including those of the super classes.
+    pub fn methods(&self) -> impl Iterator<Item = QMetaMethod> + '_ {
+        (0..self.method_count()).map(|index| self.method(index))
+    }
+
+    /// Returns an iterator over all of the properties of this class, including those of the super classes.
+    pub fn properties(&self) -> impl Iterator<Item = QMetaProperty> + '_ {
+        (0..self.property_count()).map(|index| self.property(index))
+    }
+
+    /// Returns the meta-object of the superclass, or None if there is no such object.
+    pub fn super_class(&self) -> Option<&QMetaObject> {
+        // Safety: meta objects are static data which outlive any object that refers to them
+        unsafe { ffi::qmetaobject_super_class(self).as_ref() }
+    }
+}
@@ -0,0 +1,71 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qmetaproperty.h"
+
+#include <cstddef>
+#include <cstdint>
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QMetaProperty has a pointer to the meta object, a uint handle and int index
+// (Qt 5) or a pointer to the data (Qt 6), followed by a QMetaEnum.
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/kernel/qmetaobject.h?h=v5.15.6-lts-lgpl#n334
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/kernel/qmetaobject.h?h=v6.2.4#n365
+#if (QT_VERSION < QT_VERSION_CHECK(6, 0, 0))
+assert_alignment_and_size(QMetaProperty, {
+  ::std::size_t a0;
+  ::std::uint32_t a1;
+  ::std::int32_t a2;
+  QMetaEnum a3;
+});
+#else
+assert_alignment_and_size(QMetaProperty, {
+  ::std::size_t a0;
+  ::std::size_t a1;
+  QMetaEnum a2;
+});
+#endif
+
+static_assert(::std::is_trivially_copyable<QMetaProperty>::value);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QByteArray
+qmetapropertyName(const QMetaProperty& property)
+{
+  return QByteArray(property.name());
+}
+
+QVariant
+qmetapropertyRead(const QMetaProperty& property, const QObject& object)
+{
+  return property.read(&object);
+}
+
+bool
+qmetapropertyReset(const QMetaProperty& property, QObject& object)
+{
+  return property.reset(&object);
+}
+
+QByteArray
+qmetapropertyTypeName(const QMetaProperty& property)
+{
+  return QByteArray(property.typeName());
+}
+
+bool
+qmetapropertyWrite(const QMetaProperty& property,
+                   QObject& object,
+                   const QVariant& value)
+{
+  return property.write(&object, value);
+}
+
+}
+}
@@ -0,0 +1,162 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qmetaenum.h");
+        type QMetaEnum = crate::QMetaEnum;
+        include!("cxx-qt-lib/qmetamethod.h");
+        type QMetaMethod = crate::QMetaMethod;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt::QObject;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        include!("cxx-qt-lib/qmetaproperty.h");
+        type QMetaProperty = super::QMetaProperty;
+
+        /// Returns the enumerator if this property's type is an enumerator type; otherwise the returned value is undefined.
+        fn enumerator(self: &QMetaProperty) -> QMetaEnum;
+
+        /// Returns true if the property has a corresponding change notify signal; otherwise returns false.
+        #[rust_name = "has_notify_signal"]
+        fn hasNotifySignal(self: &QMetaProperty) -> bool;
+
+        /// Returns true if the property is constant; otherwise returns false.
+        ///
+        /// A property is constant if the Q_PROPERTY()'s CONSTANT attribute is set.
+        #[rust_name = "is_constant"]
+        fn isConstant(self: &QMetaProperty) -> bool;
+
+        /// Returns true if the property's type is an enumeration value; otherwise returns false.
+        #[rust_name = "is_enum_type"]
+        fn isEnumType(self: &QMetaProperty) -> bool;
+
+        /// Returns true if the property is final; otherwise returns false.
+        ///
+        /// A property is final if the Q_PROPERTY()'s FINAL attribute is set.
+        #[rust_name = "is_final"]
+        fn isFinal(self: &QMetaProperty) -> bool;
+
+        /// Returns true if the property's type is an enumeration value that is used as a flag; otherwise returns false.
+        #[rust_name = "is_flag_type"]
+        fn isFlagType(self: &QMetaProperty) -> bool;
+
+        /// Returns true if this property is readable; otherwise returns false.
+        #[rust_name = "is_readable"]
+        fn isReadable(self: &QMetaProperty) -> bool;
+
+        /// Returns true if this property can be reset to a default value; otherwise returns false.
+        #[rust_name = "is_resettable"]
+        fn isResettable(self: &QMetaProperty) -> bool;
+
+        /// Returns true if the property is valid (readable); otherwise returns false.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QMetaProperty) -> bool;
+
+        /// Returns true if this property is writable; otherwise returns false.
+        #[rust_name = "is_writable"]
+        fn isWritable(self: &QMetaProperty) -> bool;
+
+        /// Returns the QMetaMethod instance of the property change notifying signal if one was specified,
+        /// otherwise returns an invalid QMetaMethod.
+        #[rust_name = "notify_signal"]
+        fn notifySignal(self: &QMetaProperty) -> QMetaMethod;
+
+        /// Returns this property's index.
+        #[rust_name = "property_index"]
+        fn propertyIndex(self: &QMetaProperty) -> i32;
+
+        /// Returns this property's user type as a meta type id.
+        #[rust_name = "user_type"]
+        fn userType(self: &QMetaProperty) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_name"]
+        fn qmetapropertyName(property: &QMetaProperty) -> QByteArray;
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_read"]
+        fn qmetapropertyRead(property: &QMetaProperty, object: &QObject) -> QVariant;
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_reset"]
+        fn qmetapropertyReset(property: &QMetaProperty, object: Pin<&mut QObject>) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_type_name"]
+        fn qmetapropertyTypeName(property: &QMetaProperty) -> QByteArray;
+        #[doc(hidden)]
+        #[rust_name = "qmetaproperty_write"]
+        fn qmetapropertyWrite(
+            property: &QMetaProperty,
+            object: Pin<&mut QObject>,
+            value: &QVariant,
+        ) -> bool;
+    }
+}
+
+use crate::{QByteArray, QObject, QVariant};
+use core::pin::Pin;
+
+/// The QMetaProperty class provides meta-data about a property.
+#[derive(Clone)]
+#[repr(C)]
+pub struct QMetaProperty {
+    _mobj: MaybeUninit<usize>,
+    #[cfg(cxxqt_qt_version_major = "5")]
+    _handle: MaybeUninit<u32>,
+    #[cfg(cxxqt_qt_version_major = "5")]
+    _idx: MaybeUninit<i32>,
+    #[cfg(cxxqt_qt_version_major = "6")]
+    _data: MaybeUninit<usize>,
+    _menum: MaybeUninit<crate::QMetaEnum>,
+}
+
+impl QMetaProperty {
+    /// Returns this property's name.
+    pub fn name(&self) -> QByteArray {
+        ffi::qmetaproperty_name(self)
+    }
+
+    /// Reads the property's value from the given object.
+    ///
+    /// Returns the value if it was able to read it; otherwise returns an invalid QVariant.
+    pub fn read(&self, object: &QObject) -> QVariant {
+        ffi::qmetaproperty_read(self, object)
+    }
+
+    /// Resets the property for the given object with a reset method.
+    ///
+    /// Returns true if the reset worked; otherwise returns false.
+    pub fn reset(&self, object: Pin<&mut QObject>) -> bool {
+        ffi::qmetaproperty_reset(self, object)
+    }
+
+    /// Returns the name of this property's type.
+    pub fn type_name(&self) -> QByteArray {
+        ffi::qmetaproperty_type_name(self)
+    }
+
+    /// Writes value as the property's value to the given object.
+    ///
+    /// Returns true if the write succeeded; otherwise returns false.
+    pub fn write(&self, object: Pin<&mut QObject>, value: &QVariant) -> bool {
+        ffi::qmetaproperty_write(self, object, value)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QMetaProperty {
+    type Id = type_id!("QMetaProperty");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,228 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qobject.h"
+
+#include <QtCore/QAtomicInteger>
+#include <QtCore/QMetaMethod>
+
+#include "cxx-qt-lib-internals/src/core/qobject.cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust::cxxqt1 {
+template<>
+SignalHandler<
+  ::rust::cxxqtlib1::QObjectDynamicSignalParams*>::~SignalHandler() noexcept
+{
+  if (data[0] == nullptr && data[1] == nullptr) {
+    return;
+  }
+
+  ::rust::cxxqtlib1::qobjectDynamicSignalHandlerDrop(::std::move(*this));
+}
+
+template<>
+template<>
+void
+SignalHandler<::rust::cxxqtlib1::QObjectDynamicSignalParams*>::operator()<
+  const QList<QVariant>&>(const QList<QVariant>& args)
+{
+  ::rust::cxxqtlib1::qobjectDynamicSignalHandlerCall(*this, args);
+}
+
+static_assert(
+  alignof(SignalHandler<::rust::cxxqtlib1::QObjectDynamicSignalParams*>) <=
+    alignof(::std::size_t),
+  "unexpected aligment");
+static_assert(
+  sizeof(SignalHandler<::rust::cxxqtlib1::QObjectDynamicSignalParams*>) ==
+    sizeof(::std::size_t[2]),
+  "unexpected size");
+} // namespace rust::cxxqt1
+
+namespace {
+
+// A receiver which converts the arguments of any signal to QVariants and
+// passes them to the Rust closure.
+//
+// There is no Q_OBJECT macro as moc is not available, instead the single slot
+// of the receiver is handled manually in qt_metacall. The connection is made
+// with QMetaObject::connect which always calls qt_metacall for a receiver
+// without a static meta call function.
+//
+// Qt does not notify the receiver when a connection to it is disconnected, so
+// the connection which is returned is a connection from the objectNameChanged
+// signal of the receiver to itself. The receiver is notified when this is
+// disconnected and then deletes itself, which disconnects the signal and drops
+// the closure.
+class DynamicSignalReceiver : public QObject
+{
+public:
+  DynamicSignalReceiver(const QMetaMethod& signal,
+                        ::rust::cxxqtlib1::QObjectDynamicSignalHandler closure)
+    : m_signal(signal)
+    , m_closure(::std::move(closure))
+  {
+  }
+
+  // The index of the slot comes after all of the methods of QObject
+  static int slotIndex() { return QObject::staticMetaObject.methodCount(); }
+
+  ::QMetaObject::Connection connectLifetime()
+  {
+    return QObject::connect(
+      this, &QObject::objectNameChanged, this, [](const QString&) {});
+  }
+
+  int qt_metacall(QMetaObject::Call call, int id, void** argv) override
+  {
+    id = QObject::qt_metacall(call, id, argv);
+    if (id < 0 || call != QMetaObject::InvokeMetaMethod) {
+      return id;
+    }
+
+    if (id == 0 && !m_disconnected.loadAcquire()) {
+      // The first argument is the return value, which a signal does not have
+      QList<QVariant> args;
+      for (int i = 0; i < m_signal.parameterCount(); i++) {
+        const int typeId = m_signal.parameterType(i);
+        if (typeId == QMetaType::QVariant) {
+          args.append(*reinterpret_cast<const QVariant*>(argv[i + 1]));
+        } else {
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+          args.append(QVariant(QMetaType(typeId), argv[i + 1]));
+#else
+          args.append(QVariant(typeId, argv[i + 1]));
+#endif
+        }
+      }
+
+      m_closure.template operator()<const QList<QVariant>&>(args);
+    }
+
+    return id - 1;
+  }
+
+protected:
+  void disconnectNotify(const QMetaMethod& signal) override
+  {
+    // This can be called with internal mutexes of Qt locked, so only stop
+    // calling the closure and post the deletion of the receiver
+    if (signal == QMetaMethod::fromSignal(&QObject::objectNameChanged) &&
+        !m_disconnected.fetchAndStoreAcquire(true)) {
+      deleteLater();
+    }
+  }
+
+private:
+  QMetaMethod m_signal;
+  ::rust::cxxqtlib1::QObjectDynamicSignalHandler m_closure;
+  QAtomicInteger<bool> m_disconnected{ false };
+};
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qobjectConnectDynamic(QObject& qobject,
+                      ::rust::Str signal,
+                      QObjectDynamicSignalHandler closure,
+                      Qt::ConnectionType type,
+                      ::QMetaObject::Connection& connection)
+{
+  // Note that rust::Str is not null terminated, so copy into a QByteArray
+  const auto signalBytes = QByteArray(signal.data(), signal.size());
+  const auto normalized =
+    QMetaObject::normalizedSignature(signalBytes.constData());
+  const auto* metaObject = qobject.metaObject();
+  const int signalIndex = metaObject->indexOfSignal(normalized.constData());
+  if (signalIndex < 0) {
+    return false;
+  }
+
+  auto* receiver = new DynamicSignalReceiver(metaObject->method(signalIndex),
+                                             ::std::move(closure));
+  // The receiver lives in the thread of the object so that the closure is
+  // executed in the thread of the object
+  receiver->moveToThread(qobject.thread());
+
+  if (!QMetaObject::connect(&qobject,
+                            signalIndex,
+                            receiver,
+                            DynamicSignalReceiver::slotIndex(),
+                            static_cast<int>(type))) {
+    delete receiver;
+    return false;
+  }
+
+  // Drop the receiver, and therefore the closure, when the returned connection
+  // is disconnected or with the object
+  connection = receiver->connectLifetime();
+  QObject::connect(
+    &qobject, &QObject::destroyed, receiver, &QObject::deleteLater);
+  return true;
+}
+
+QList<QByteArray>
+qobjectDynamicPropertyNames(const QObject& qobject)
+{
+  return qobject.dynamicPropertyNames();
+}
+
+const QMetaObject&
+qobjectMetaObject(const QObject& qobject)
+{
+  return *qobject.metaObject();
+}
+
+bool
+qobjectMoveToThread(QObject& qobject, QThread* thread)
+{
+  // Qt 6.7 returns a bool from moveToThread, so check the affinity manually
+  // to support all versions of Qt
+  qobject.moveToThread(thread);
+  return qobject.thread() == thread;
+}
+
+QString
+qobjectObjectName(const QObject& qobject)
+{
+  return qobject.objectName();
+}
+
+QVariant
+qobjectProperty(const QObject& qobject, ::rust::Str name)
+{
+  // Note that rust::Str is not null terminated, so copy into a QByteArray
+  const auto nameBytes = QByteArray(name.data(), name.size());
+  return qobject.property(nameBytes.constData());
+}
+
+void
+qobjectSetObjectName(QObject& qobject, const QString& name)
+{
+  qobject.setObjectName(name);
+}
+
+bool
+qobjectSetProperty(QObject& qobject, ::rust::Str name, const QVariant& value)
+{
+  // Note that rust::Str is not null terminated, so copy into a QByteArray
+  const auto nameBytes = QByteArray(name.data(), name.size());
+  return qobject.setProperty(nameBytes.constData(), value);
+}
+
+QThread*
+qobjectThread(const QObject& qobject)
+{
+  return qobject.thread();
+}
+
+}
+}
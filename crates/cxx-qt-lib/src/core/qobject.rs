@@ -0,0 +1,356 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    ConnectionType, FindChildOption, QByteArray, QList, QMetaObject, QMetaObjectConnection,
+    QString, QThread, QVariant,
+};
+use core::pin::Pin;
+use cxx_qt::signalhandler::{CxxQtSignalHandler, CxxQtSignalHandlerClosure};
+use cxx_qt::{Downcast, QObject, Upcast};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QByteArray = crate::QList<crate::QByteArray>;
+        type QList_QVariant = crate::QList<crate::QVariant>;
+        include!("cxx-qt-lib/qmetaobject.h");
+        type QMetaObject = crate::QMetaObject;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qthread.h");
+        type QThread = crate::QThread;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt::QObject;
+    }
+
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type ConnectionType = crate::ConnectionType;
+    }
+
+    #[namespace = "rust::cxxqt1"]
+    unsafe extern "C++" {
+        include!("cxx-qt/connection.h");
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        type QObjectDynamicSignalHandler =
+            cxx_qt::signalhandler::CxxQtSignalHandler<super::QObjectDynamicSignalClosure>;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_connect_dynamic"]
+        fn qobjectConnectDynamic(
+            qobject: Pin<&mut QObject>,
+            signal: &str,
+            signal_handler: QObjectDynamicSignalHandler,
+            conn_type: ConnectionType,
+            connection: &mut QMetaObjectConnection,
+        ) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qobject_dynamic_property_names"]
+        fn qobjectDynamicPropertyNames(qobject: &QObject) -> QList_QByteArray;
+        #[doc(hidden)]
+        #[rust_name = "qobject_meta_object"]
+        fn qobjectMetaObject(qobject: &QObject) -> &QMetaObject;
+        #[doc(hidden)]
+        #[rust_name = "qobject_move_to_thread"]
+        unsafe fn qobjectMoveToThread(qobject: Pin<&mut QObject>, thread: *mut QThread) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qobject_object_name"]
+        fn qobjectObjectName(qobject: &QObject) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qobject_property"]
+        fn qobjectProperty(qobject: &QObject, name: &str) -> QVariant;
+        #[doc(hidden)]
+        #[rust_name = "qobject_set_object_name"]
+        fn qobjectSetObjectName(qobject: Pin<&mut QObject>, name: &QString);
+        #[doc(hidden)]
+        #[rust_name = "qobject_set_property"]
+        fn qobjectSetProperty(qobject: Pin<&mut QObject>, name: &str, value: &QVariant) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qobject_thread"]
+        fn qobjectThread(qobject: &QObject) -> *mut QThread;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        #[cxx_name = "qobjectDynamicSignalHandlerDrop"]
+        fn qobject_dynamic_signal_handler_drop(handler: QObjectDynamicSignalHandler);
+        #[doc(hidden)]
+        #[cxx_name = "qobjectDynamicSignalHandlerCall"]
+        fn qobject_dynamic_signal_handler_call(
+            handler: &mut QObjectDynamicSignalHandler,
+            args: &QList_QVariant,
+        );
+    }
+}
+
+/// Methods of [QObject] which require types from CXX-Qt-lib.
+///
+/// As every CXX-Qt generated QObject can be upcast to [QObject], these are available on any QObject
+/// by first calling `upcast()` or `upcast_pin()`.
+pub trait QObjectExt {
+    /// Connect the given closure to the signal with the given signature, such as `valueChanged(int)`,
+    /// so that when the signal is emitted the closure is executed with the arguments of the signal.
+    ///
+    /// The signal is looked up at runtime using the meta-object of the object, so this can be used
+    /// with objects whose type is not known to Rust, such as those loaded from QML or plugins.
+    /// None is returned if the object has no such signal.
+    ///
+    /// The closure is executed in the thread of the object, it is dropped by the event loop of that thread
+    /// once the returned connection is disconnected or the object is destroyed.
+    ///
+    /// Note that the returned connection is a proxy rather than the connection to the signal,
+    /// as Qt does not notify a receiver when a connection to it is disconnected. It is a connection
+    /// owned by the internal receiver of the signal, disconnecting it stops the closure from being called
+    /// and deletes the receiver, which then disconnects the signal.
+    fn connect_dynamic<F>(
+        self: Pin<&mut Self>,
+        signal: &str,
+        closure: F,
+        conn_type: ConnectionType,
+    ) -> Option<QMetaObjectConnection>
+    where
+        F: FnMut(&[QVariant]) + 'static + Send;
+
+    /// Returns the names of all properties that were dynamically added to the object using [set_property](Self::set_property).
+    fn dynamic_property_names(&self) -> QList<QByteArray>;
+
+    /// Returns the first child of this object that can be cast to type `T` and that is called name,
+    /// or a null pointer if there is no such object.
+    ///
+    /// A null name matches all objects. The search is performed recursively, unless options specifies
+    /// the option [FindChildOption::FindDirectChildrenOnly].
+    fn find_child<T>(&self, name: &QString, options: FindChildOption) -> *mut T
+    where
+        T: Upcast<QObject>;
+
+    /// Returns all children of this object with the given name that can be cast to type `T`,
+    /// or an empty list if there are no such objects.
+    ///
+    /// A null name matches all objects. The search is performed recursively, unless options specifies
+    /// the option [FindChildOption::FindDirectChildrenOnly].
+    fn find_children<T>(&self, name: &QString, options: FindChildOption) -> Vec<*mut T>
+    where
+        T: Upcast<QObject>;
+
+    /// Returns a reference to the meta-object of this object.
+    fn meta_object(&self) -> &QMetaObject;
+
+    /// Connect the given closure to the signal with the given signature, as with [connect_dynamic](Self::connect_dynamic).
+    ///
+    /// Note that this method uses a AutoConnection connection type.
+    fn on_dynamic<F>(
+        self: Pin<&mut Self>,
+        signal: &str,
+        closure: F,
+    ) -> Option<QMetaObjectConnection>
+    where
+        F: FnMut(&[QVariant]) + 'static + Send;
+
+    /// Changes the thread affinity for this object and its children and returns true on success.
+    ///
+    /// The object cannot be moved if it has a parent. Event processing will continue in the target thread.
+    ///
+    /// For CXX-Qt objects the closures queued with `CxxQtThread`, queued signal connections and timers
+    /// are then executed on the target thread.
+    ///
+    /// # Safety
+    ///
+    /// This must be called from the thread the object currently lives in and thread must either be
+    /// null or a valid pointer to a QThread.
+    ///
+    /// For CXX-Qt objects the Rust struct is then used from the target thread,
+    /// so it must be safe to send the Rust struct to the target thread.
+    unsafe fn move_to_thread(self: Pin<&mut Self>, thread: *mut QThread) -> bool;
+
+    /// This property holds the name of this object.
+    fn object_name(&self) -> QString;
+
+    /// Returns the value of the object's name property.
+    ///
+    /// If no such property exists, the returned variant is invalid.
+    fn property(&self, name: &str) -> QVariant;
+
+    /// Sets the name of this object.
+    fn set_object_name(self: Pin<&mut Self>, name: &QString);
+
+    /// Sets the value of the object's name property to value.
+    ///
+    /// If the property is defined in the class using Q_PROPERTY then true is returned on success and false otherwise.
+    /// If the property is not defined using Q_PROPERTY, and therefore not listed in the meta-object,
+    /// it is added as a dynamic property and false is returned.
+    fn set_property(self: Pin<&mut Self>, name: &str, value: &QVariant) -> bool;
+
+    /// Returns a pointer to the thread in which the object lives.
+    fn thread(&self) -> *mut QThread;
+}
+
+impl QObjectExt for QObject {
+    fn connect_dynamic<F>(
+        self: Pin<&mut Self>,
+        signal: &str,
+        closure: F,
+        conn_type: ConnectionType,
+    ) -> Option<QMetaObjectConnection>
+    where
+        F: FnMut(&[QVariant]) + 'static + Send,
+    {
+        let mut connection = QMetaObjectConnection::default();
+        if ffi::qobject_connect_dynamic(
+            self,
+            signal,
+            CxxQtSignalHandler::<QObjectDynamicSignalClosure>::new(Box::new(closure)),
+            conn_type,
+            &mut connection,
+        ) {
+            Some(connection)
+        } else {
+            None
+        }
+    }
+
+    fn dynamic_property_names(&self) -> QList<QByteArray> {
+        ffi::qobject_dynamic_property_names(self)
+    }
+
+    fn find_child<T>(&self, name: &QString, options: FindChildOption) -> *mut T
+    where
+        T: Upcast<QObject>,
+    {
+        let children = self.children();
+
+        // Match the order of QObject::findChild, direct children are checked before any grand children
+        if let Some(child) = children
+            .iter()
+            .find_map(|child| child_matching::<T>(*child, name))
+        {
+            return child;
+        }
+
+        if options == FindChildOption::FindChildrenRecursively {
+            for child in children {
+                // Safety: children of a QObject are valid for as long as the parent is borrowed
+                let child = unsafe { &*child }.find_child::<T>(name, options);
+                if !child.is_null() {
+                    return child;
+                }
+            }
+        }
+
+        core::ptr::null_mut()
+    }
+
+    fn find_children<T>(&self, name: &QString, options: FindChildOption) -> Vec<*mut T>
+    where
+        T: Upcast<QObject>,
+    {
+        let mut found = vec![];
+        for child in self.children() {
+            if let Some(matching) = child_matching::<T>(child, name) {
+                found.push(matching);
+            }
+
+            if options == FindChildOption::FindChildrenRecursively {
+                // Safety: children of a QObject are valid for as long as the parent is borrowed
+                found.extend(unsafe { &*child }.find_children::<T>(name, options));
+            }
+        }
+        found
+    }
+
+    fn meta_object(&self) -> &QMetaObject {
+        ffi::qobject_meta_object(self)
+    }
+
+    fn on_dynamic<F>(
+        self: Pin<&mut Self>,
+        signal: &str,
+        closure: F,
+    ) -> Option<QMetaObjectConnection>
+    where
+        F: FnMut(&[QVariant]) + 'static + Send,
+    {
+        self.connect_dynamic(signal, closure, ConnectionType::AutoConnection)
+    }
+
+    unsafe fn move_to_thread(self: Pin<&mut Self>, thread: *mut QThread) -> bool {
+        ffi::qobject_move_to_thread(self, thread)
+    }
+
+    fn object_name(&self) -> QString {
+        ffi::qobject_object_name(self)
+    }
+
+    fn property(&self, name: &str) -> QVariant {
+        ffi::qobject_property(self, name)
+    }
+
+    fn set_object_name(self: Pin<&mut Self>, name: &QString) {
+        ffi::qobject_set_object_name(self, name);
+    }
+
+    fn set_property(self: Pin<&mut Self>, name: &str, value: &QVariant) -> bool {
+        ffi::qobject_set_property(self, name, value)
+    }
+
+    fn thread(&self) -> *mut QThread {
+        ffi::qobject_thread(self)
+    }
+}
+
+/// Returns the child as a `T` if it can be cast to `T` and its name matches
+fn child_matching<T>(child: *mut QObject, name: &QString) -> Option<*mut T>
+where
+    T: Upcast<QObject>,
+{
+    // Safety: children of a QObject are valid for as long as the parent is borrowed
+    let child = unsafe { &*child };
+    if name.is_null() || child.object_name() == *name {
+        child
+            .downcast::<T>()
+            .map(|child| child as *const T as *mut T)
+    } else {
+        None
+    }
+}
+
+#[doc(hidden)]
+pub struct QObjectDynamicSignalClosure {}
+
+impl CxxQtSignalHandlerClosure for QObjectDynamicSignalClosure {
+    type Id = cxx::type_id!("rust::cxxqtlib1::QObjectDynamicSignalHandler");
+    type FnType = dyn FnMut(&[QVariant]) + Send;
+}
+
+use core::mem::drop as qobject_dynamic_signal_handler_drop;
+
+fn qobject_dynamic_signal_handler_call(
+    handler: &mut CxxQtSignalHandler<QObjectDynamicSignalClosure>,
+    args: &QList<QVariant>,
+) {
+    // QList is not guaranteed to be contiguous in Qt 5, so collect the arguments into a Vec
+    let args: Vec<QVariant> = args.iter().cloned().collect();
+    // The closure is called from C++, so a panic must not unwind out of it
+    cxx_qt::panic::call_guarded("QObject", "connect_dynamic", || handler.closure()(&args));
+}
+
+cxx_qt::static_assertions::assert_eq_align!(CxxQtSignalHandler<QObjectDynamicSignalClosure>, usize);
+cxx_qt::static_assertions::assert_eq_size!(
+    CxxQtSignalHandler<QObjectDynamicSignalClosure>,
+    [usize; 2]
+);
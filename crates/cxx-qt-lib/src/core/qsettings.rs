@@ -0,0 +1,336 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// The storage format used by a [QSettings].
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum QSettingsFormat {
+        /// Store the settings using the most appropriate storage format for the platform,
+        /// this is the registry on Windows, property lists on Apple platforms and INI files elsewhere.
+        NativeFormat = 0,
+        /// Store the settings in INI files. Note that type information is not preserved
+        /// when reading settings from INI files, all values will be returned as strings.
+        IniFormat = 1,
+        /// Returned by [QSettings::format] if the storage format is not valid.
+        InvalidFormat = 16,
+    }
+
+    /// Whether the settings of a [QSettings] are user-specific or shared by all users of the same system.
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum QSettingsScope {
+        /// Store settings in a location specific to the current user.
+        UserScope,
+        /// Store settings in a global location, so that all users on the same machine access the same set of settings.
+        SystemScope,
+    }
+
+    /// The status of a [QSettings].
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum QSettingsStatus {
+        /// No error occurred.
+        NoError,
+        /// An access error occurred, for example trying to write to a read-only file.
+        AccessError,
+        /// A format error occurred, for example loading a malformed INI file.
+        FormatError,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qsettings.h");
+        /// The QSettings class provides persistent platform-independent application settings.
+        ///
+        /// Keys are strings with `/` separating groups, for example `window/size`.
+        /// Values are stored as a [QVariant](crate::QVariant), use [QSettings::get] and [QSettings::set]
+        /// for typed access or, with the `serde` feature, `QSettings::serialize_group` and
+        /// `QSettings::deserialize_group` to store a Rust struct as a group.
+        ///
+        /// Changes are written to permanent storage when the QSettings is destroyed and periodically
+        /// by the event loop, call [QSettings::sync] to write them immediately.
+        #[qobject]
+        type QSettings;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        type QSettingsFormat;
+        type QSettingsScope;
+        type QSettingsStatus;
+
+        /// Returns a list of all keys, including subkeys, that can be read using the QSettings object.
+        ///
+        /// If a group is set, only the keys in the group are returned, without the group prefix.
+        #[rust_name = "all_keys"]
+        fn allKeys(self: &QSettings) -> QStringList;
+
+        /// Returns the application name used for storing the settings.
+        #[rust_name = "application_name"]
+        fn applicationName(self: &QSettings) -> QString;
+
+        /// Returns a list of all top-level groups that contain keys that can be read using the QSettings object.
+        #[rust_name = "child_groups"]
+        fn childGroups(self: &QSettings) -> QStringList;
+
+        /// Returns a list of all top-level keys that can be read using the QSettings object.
+        #[rust_name = "child_keys"]
+        fn childKeys(self: &QSettings) -> QStringList;
+
+        /// Removes all entries in the primary location associated to this QSettings object.
+        ///
+        /// Entries in fallback locations are not removed.
+        fn clear(self: Pin<&mut QSettings>);
+
+        /// Closes the array that was started using [QSettings::begin_read_array] or [QSettings::begin_write_array].
+        #[rust_name = "end_array"]
+        fn endArray(self: Pin<&mut QSettings>);
+
+        /// Resets the group to what it was before the corresponding [QSettings::begin_group] call.
+        #[rust_name = "end_group"]
+        fn endGroup(self: Pin<&mut QSettings>);
+
+        /// Returns true if fallbacks are enabled, by default this is true.
+        #[rust_name = "fallbacks_enabled"]
+        fn fallbacksEnabled(self: &QSettings) -> bool;
+
+        /// Returns the path where settings written using this QSettings object are stored.
+        ///
+        /// On Windows, if the format is [QSettingsFormat::NativeFormat], the return value is a system registry path, not a file path.
+        #[rust_name = "file_name"]
+        fn fileName(self: &QSettings) -> QString;
+
+        /// Returns the format used for storing the settings.
+        fn format(self: &QSettings) -> QSettingsFormat;
+
+        /// Returns the current group, which is prepended to keys.
+        fn group(self: &QSettings) -> QString;
+
+        /// Returns true if settings can be written using this QSettings object, otherwise returns false.
+        #[rust_name = "is_writable"]
+        fn isWritable(self: &QSettings) -> bool;
+
+        /// Returns the organization name used for storing the settings.
+        #[rust_name = "organization_name"]
+        fn organizationName(self: &QSettings) -> QString;
+
+        /// Returns the scope used for storing the settings.
+        fn scope(self: &QSettings) -> QSettingsScope;
+
+        /// Sets the current array index to i.
+        ///
+        /// Calls to functions such as [QSettings::set_value], [QSettings::value], [QSettings::remove],
+        /// and [QSettings::contains] will operate on the array entry at that index.
+        #[rust_name = "set_array_index"]
+        fn setArrayIndex(self: Pin<&mut QSettings>, i: i32);
+
+        /// Sets whether fallbacks are enabled to b.
+        ///
+        /// When enabled, keys which are not found for the application are searched for the organization
+        /// and then in the system scope.
+        #[rust_name = "set_fallbacks_enabled"]
+        fn setFallbacksEnabled(self: Pin<&mut QSettings>, b: bool);
+
+        /// Returns a status code indicating the first error that was met by QSettings, or [QSettingsStatus::NoError] if no error occurred.
+        ///
+        /// The status is updated by [QSettings::sync], which is where errors are usually found.
+        fn status(self: &QSettings) -> QSettingsStatus;
+
+        /// Writes any unsaved changes to permanent storage, and reloads any settings that have been changed in the meantime by another application.
+        fn sync(self: Pin<&mut QSettings>);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        type QObject = cxx_qt::QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "upcast_qsettings"]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn upcast(thiz: *const QSettings) -> *const QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "downcast_qobject"]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn downcast(base: *const QObject) -> *const QSettings;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsettings_begin_group"]
+        fn qsettingsBeginGroup(settings: Pin<&mut QSettings>, prefix: &QString);
+        #[doc(hidden)]
+        #[rust_name = "qsettings_begin_read_array"]
+        fn qsettingsBeginReadArray(settings: Pin<&mut QSettings>, prefix: &QString) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_begin_write_array"]
+        fn qsettingsBeginWriteArray(settings: Pin<&mut QSettings>, prefix: &QString, size: i32);
+        #[doc(hidden)]
+        #[rust_name = "qsettings_contains"]
+        fn qsettingsContains(settings: &QSettings, key: &QString) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_new"]
+        fn qsettingsNew() -> UniquePtr<QSettings>;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_new_with_file"]
+        fn qsettingsNewWithFile(
+            file_name: &QString,
+            format: QSettingsFormat,
+        ) -> UniquePtr<QSettings>;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_new_with_format"]
+        fn qsettingsNewWithFormat(
+            format: QSettingsFormat,
+            scope: QSettingsScope,
+            organization: &QString,
+            application: &QString,
+        ) -> UniquePtr<QSettings>;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_new_with_names"]
+        fn qsettingsNewWithNames(
+            organization: &QString,
+            application: &QString,
+        ) -> UniquePtr<QSettings>;
+        #[doc(hidden)]
+        #[rust_name = "qsettings_remove"]
+        fn qsettingsRemove(settings: Pin<&mut QSettings>, key: &QString);
+        #[doc(hidden)]
+        #[rust_name = "qsettings_set_value"]
+        fn qsettingsSetValue(settings: Pin<&mut QSettings>, key: &QString, value: &QVariant);
+        #[doc(hidden)]
+        #[rust_name = "qsettings_value"]
+        fn qsettingsValue(settings: &QSettings, key: &QString) -> QVariant;
+    }
+
+    // QSettings is not trivial to CXX and is not relocatable in Qt
+    // so we need to use references or pointers.
+    impl UniquePtr<QSettings> {}
+}
+
+use crate::{QString, QVariant, QVariantValue};
+use core::pin::Pin;
+use cxx_qt::{QObject, Upcast};
+
+pub use ffi::{QSettings, QSettingsFormat, QSettingsScope, QSettingsStatus};
+
+impl Upcast<QObject> for QSettings {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QObject {
+        ffi::upcast_qsettings(this)
+    }
+
+    unsafe fn from_base_ptr(base: *const QObject) -> *const Self {
+        ffi::downcast_qobject(base)
+    }
+}
+
+impl QSettings {
+    /// Constructs a QSettings object for accessing the settings of the application, in the user scope
+    /// with the native format.
+    ///
+    /// The organization and application names are those set on the [QCoreApplication](crate::QCoreApplication),
+    /// so they must be set before the QSettings is created.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qsettings_new()
+    }
+
+    /// Constructs a QSettings object for accessing the settings of the application called application
+    /// from the organization called organization, in the user scope with the native format.
+    pub fn new_with_names(organization: &QString, application: &QString) -> cxx::UniquePtr<Self> {
+        ffi::qsettings_new_with_names(organization, application)
+    }
+
+    /// Constructs a QSettings object for accessing the settings of the application called application
+    /// from the organization called organization, with the given format and scope.
+    ///
+    /// If the format is [QSettingsFormat::IniFormat], the settings are stored in an INI file in the
+    /// platform's location for settings of the scope.
+    pub fn new_with_format(
+        format: QSettingsFormat,
+        scope: QSettingsScope,
+        organization: &QString,
+        application: &QString,
+    ) -> cxx::UniquePtr<Self> {
+        ffi::qsettings_new_with_format(format, scope, organization, application)
+    }
+
+    /// Constructs a QSettings object for accessing the settings stored in the file called file_name,
+    /// which is created if it does not exist, with the given format.
+    pub fn from_file(file_name: &QString, format: QSettingsFormat) -> cxx::UniquePtr<Self> {
+        ffi::qsettings_new_with_file(file_name, format)
+    }
+
+    /// Appends prefix to the current group, until the corresponding [QSettings::end_group].
+    ///
+    /// Groups can be nested.
+    pub fn begin_group(self: Pin<&mut Self>, prefix: &QString) {
+        ffi::qsettings_begin_group(self, prefix)
+    }
+
+    /// Adds prefix to the current group and starts reading from an array, returning the size of the array.
+    ///
+    /// Use [QSettings::set_array_index] to select each entry and [QSettings::end_array] when done.
+    pub fn begin_read_array(self: Pin<&mut Self>, prefix: &QString) -> i32 {
+        ffi::qsettings_begin_read_array(self, prefix)
+    }
+
+    /// Adds prefix to the current group and starts writing an array of size size.
+    ///
+    /// If size is -1, the size is determined by the indexes of the entries written.
+    /// Use [QSettings::set_array_index] to select each entry and [QSettings::end_array] when done.
+    pub fn begin_write_array(self: Pin<&mut Self>, prefix: &QString, size: i32) {
+        ffi::qsettings_begin_write_array(self, prefix, size)
+    }
+
+    /// Returns true if there exists a setting called key, otherwise returns false.
+    ///
+    /// If a group is set, the key is relative to the group.
+    pub fn contains(&self, key: &QString) -> bool {
+        ffi::qsettings_contains(self, key)
+    }
+
+    /// Returns the value for the setting key converted to T, or None if the setting doesn't exist
+    /// or cannot be converted.
+    ///
+    /// Values read from INI files are strings, which are converted when possible.
+    pub fn get<T: QVariantValue>(&self, key: &QString) -> Option<T> {
+        if self.contains(key) {
+            self.value(key).value()
+        } else {
+            None
+        }
+    }
+
+    /// Removes the setting key and any sub-settings of key.
+    ///
+    /// If key is an empty string, all keys in the current group are removed.
+    pub fn remove(self: Pin<&mut Self>, key: &QString) {
+        ffi::qsettings_remove(self, key)
+    }
+
+    /// Sets the value of setting key to value.
+    pub fn set<T: QVariantValue>(self: Pin<&mut Self>, key: &QString, value: &T) {
+        self.set_value(key, &QVariant::from(value))
+    }
+
+    /// Sets the value of setting key to value, if the key already exists the previous value is overwritten.
+    pub fn set_value(self: Pin<&mut Self>, key: &QString, value: &QVariant) {
+        ffi::qsettings_set_value(self, key, value)
+    }
+
+    /// Returns the value for setting key, or an invalid [QVariant] if the setting doesn't exist.
+    pub fn value(&self, key: &QString) -> QVariant {
+        ffi::qsettings_value(self, key)
+    }
+}
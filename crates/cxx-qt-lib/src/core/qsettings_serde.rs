@@ -0,0 +1,1261 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Conversions between Rust types implementing serde traits and the groups of a [QSettings]
+//!
+//! The fields of a struct or the entries of a map are the keys of the group, nested structs and maps
+//! become subgroups, and sequences become settings arrays, with a scalar element stored under the `value` key.
+//! Enums use the externally tagged representation, so a unit variant is a string
+//! and other variants are a group with the variant name as the only key.
+//!
+//! Values are read back as strings and parsed into the requested type, as settings read from
+//! INI files do not preserve their types.
+
+use crate::{QByteArray, QSettings, QString, QVariant};
+use core::pin::Pin;
+use serde::de::{self, DeserializeOwned, IntoDeserializer};
+use serde::ser::{self, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The key which a scalar element of a settings array is stored under.
+const ELEMENT_KEY: &str = "value";
+
+/// The error returned when a Rust value cannot be stored in or read from a [QSettings] group.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QSettingsSerdeError {
+    message: String,
+}
+
+impl QSettingsSerdeError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for QSettingsSerdeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for QSettingsSerdeError {}
+
+impl ser::Error for QSettingsSerdeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::new(msg.to_string())
+    }
+}
+
+impl de::Error for QSettingsSerdeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::new(msg.to_string())
+    }
+}
+
+impl QSettings {
+    /// Store a Rust struct or map in the group, which is relative to the current group.
+    ///
+    /// The existing keys of the group are replaced, so that fields which are `None`
+    /// and entries which no longer exist are not read back.
+    /// If the value cannot be serialized then the group is left unchanged.
+    ///
+    /// This is available with the `serde` feature.
+    pub fn serialize_group<T>(
+        mut self: Pin<&mut Self>,
+        group: &QString,
+        value: &T,
+    ) -> Result<(), QSettingsSerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let mut entries = Entries::new();
+        value.serialize(Serializer {
+            entries: &mut entries,
+            group: String::new(),
+            target: Target::Group,
+        })?;
+
+        // Only clear the group once the value has been serialized successfully
+        self.as_mut().begin_group(group);
+        self.as_mut().remove(&QString::default());
+        for (key, value) in &entries {
+            self.as_mut().set_value(key, value);
+        }
+        self.end_group();
+        Ok(())
+    }
+
+    /// Read a Rust struct or map from the group, which is relative to the current group.
+    ///
+    /// Missing keys deserialize into `None` for optional fields, or the default value
+    /// for fields with `#[serde(default)]`, so that new fields can be added to existing settings.
+    ///
+    /// This is available with the `serde` feature.
+    pub fn deserialize_group<T>(
+        mut self: Pin<&mut Self>,
+        group: &QString,
+    ) -> Result<T, QSettingsSerdeError>
+    where
+        T: DeserializeOwned,
+    {
+        self.as_mut().begin_group(group);
+        let result = T::deserialize(Deserializer {
+            settings: self.as_mut(),
+            target: Target::Group,
+        });
+        self.end_group();
+        result
+    }
+}
+
+/// Where a value is stored relative to the current group
+enum Target {
+    /// The current group itself, which can only contain a struct or a map
+    Group,
+    /// The current entry of a settings array
+    Element,
+    /// A key of the current group
+    Key(QString),
+}
+
+impl Target {
+    /// The key for a scalar value
+    fn scalar_key(&self) -> Result<QString, QSettingsSerdeError> {
+        match self {
+            Self::Group => Err(QSettingsSerdeError::new(
+                "only structs and maps can be stored as a settings group",
+            )),
+            Self::Element => Ok(QString::from(ELEMENT_KEY)),
+            Self::Key(key) => Ok(key.clone()),
+        }
+    }
+
+    /// The key for a settings array, which cannot be nested directly in another array
+    fn array_key(&self) -> Result<&QString, QSettingsSerdeError> {
+        match self {
+            Self::Key(key) => Ok(key),
+            _ => Err(QSettingsSerdeError::new(
+                "sequences can only be stored under a key of a struct or map",
+            )),
+        }
+    }
+}
+
+/// Enter the group for a key, returning the number of groups which were entered
+fn begin_target_group(settings: Pin<&mut QSettings>, target: &Target) -> usize {
+    match target {
+        Target::Key(key) => {
+            settings.begin_group(key);
+            1
+        }
+        _ => 0,
+    }
+}
+
+fn end_groups(mut settings: Pin<&mut QSettings>, count: usize) {
+    for _ in 0..count {
+        settings.as_mut().end_group();
+    }
+}
+
+/// The keys and subgroups of the current group
+fn group_keys(settings: &QSettings) -> Vec<QString> {
+    let mut keys: Vec<QString> = settings.child_keys().iter().cloned().collect();
+    for group in settings.child_groups().iter() {
+        if !keys.contains(group) {
+            keys.push(group.clone());
+        }
+    }
+    keys
+}
+
+/// The values of a serialized group, keyed by their path relative to the group
+type Entries = Vec<(QString, QVariant)>;
+
+struct Serializer<'a> {
+    entries: &'a mut Entries,
+    /// The path of the group containing the target, which is either empty or ends with a `/`
+    group: String,
+    target: Target,
+}
+
+impl Serializer<'_> {
+    fn set(self, value: QVariant) -> Result<(), QSettingsSerdeError> {
+        let key = self.target.scalar_key()?;
+        self.entries
+            .push((QString::from(&format!("{}{key}", self.group)), value));
+        Ok(())
+    }
+
+    /// The path of the group which a struct, map or variant of the target is stored in
+    fn target_group(&self) -> String {
+        match &self.target {
+            Target::Key(key) => format!("{}{key}/", self.group),
+            _ => self.group.clone(),
+        }
+    }
+}
+
+impl<'a> ser::Serializer for Serializer<'a> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    type SerializeSeq = ArraySerializer<'a>;
+    type SerializeTuple = ArraySerializer<'a>;
+    type SerializeTupleStruct = ArraySerializer<'a>;
+    type SerializeTupleVariant = ArraySerializer<'a>;
+    type SerializeMap = GroupSerializer<'a>;
+    type SerializeStruct = GroupSerializer<'a>;
+    type SerializeStructVariant = GroupSerializer<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        self.set(QVariant::from(&v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        self.set(QVariant::from(&v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        self.set(QVariant::from(&v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        self.set(QVariant::from(&v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        self.set(QVariant::from(&v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        self.set(QVariant::from(&v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        self.set(QVariant::from(&v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        self.set(QVariant::from(&v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        self.set(QVariant::from(&v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        self.set(QVariant::from(&v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        self.set(QVariant::from(&v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        self.set(QVariant::from(&QString::from(v)))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        self.set(QVariant::from(&QByteArray::from(v)))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        // The group is cleared before it is written, so the missing key is read back as None or ()
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let group = self.target_group();
+        value.serialize(Serializer {
+            entries: self.entries,
+            group,
+            target: Target::Key(QString::from(variant)),
+        })
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        let group = format!("{}{}/", self.group, self.target.array_key()?);
+        Ok(ArraySerializer::new(self.entries, group))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        let group = format!("{}{variant}/", self.target_group());
+        Ok(ArraySerializer::new(self.entries, group))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        let group = self.target_group();
+        Ok(GroupSerializer::new(self.entries, group))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        let group = format!("{}{variant}/", self.target_group());
+        Ok(GroupSerializer::new(self.entries, group))
+    }
+}
+
+/// Writes a settings array in the layout of [QSettings::begin_write_array],
+/// where the elements are in groups numbered from one and followed by a `size` key
+struct ArraySerializer<'a> {
+    entries: &'a mut Entries,
+    group: String,
+    size: i32,
+}
+
+impl<'a> ArraySerializer<'a> {
+    fn new(entries: &'a mut Entries, group: String) -> Self {
+        Self {
+            entries,
+            group,
+            size: 0,
+        }
+    }
+
+    fn push<T>(&mut self, value: &T) -> Result<(), QSettingsSerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.size += 1;
+        value.serialize(Serializer {
+            entries: self.entries,
+            group: format!("{}{}/", self.group, self.size),
+            target: Target::Element,
+        })
+    }
+
+    fn finish(self) -> Result<(), QSettingsSerdeError> {
+        self.entries.push((
+            QString::from(&format!("{}size", self.group)),
+            QVariant::from(&self.size),
+        ));
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for ArraySerializer<'_> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for ArraySerializer<'_> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for ArraySerializer<'_> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for ArraySerializer<'_> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+struct GroupSerializer<'a> {
+    entries: &'a mut Entries,
+    group: String,
+    key: Option<QString>,
+}
+
+impl<'a> GroupSerializer<'a> {
+    fn new(entries: &'a mut Entries, group: String) -> Self {
+        Self {
+            entries,
+            group,
+            key: None,
+        }
+    }
+
+    fn insert<T>(&mut self, key: QString, value: &T) -> Result<(), QSettingsSerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(Serializer {
+            entries: self.entries,
+            group: self.group.clone(),
+            target: Target::Key(key),
+        })
+    }
+
+    fn finish(self) -> Result<(), QSettingsSerdeError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for GroupSerializer<'_> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.key = Some(key.serialize(KeySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = self.key.take().ok_or_else(|| {
+            QSettingsSerdeError::new("serialize_value called before serialize_key")
+        })?;
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for GroupSerializer<'_> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.insert(QString::from(key), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for GroupSerializer<'_> {
+    type Ok = ();
+    type Error = QSettingsSerdeError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.insert(QString::from(key), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+/// Converts the keys of a map into a settings key, only strings, characters and integers are supported
+struct KeySerializer;
+
+impl KeySerializer {
+    fn unsupported<T>() -> Result<T, QSettingsSerdeError> {
+        Err(QSettingsSerdeError::new(
+            "map keys must be strings, characters or integers",
+        ))
+    }
+
+    fn display(value: impl fmt::Display) -> Result<QString, QSettingsSerdeError> {
+        Ok(QString::from(&value.to_string()))
+    }
+}
+
+impl ser::Serializer for KeySerializer {
+    type Ok = QString;
+    type Error = QSettingsSerdeError;
+
+    type SerializeSeq = ser::Impossible<QString, QSettingsSerdeError>;
+    type SerializeTuple = ser::Impossible<QString, QSettingsSerdeError>;
+    type SerializeTupleStruct = ser::Impossible<QString, QSettingsSerdeError>;
+    type SerializeTupleVariant = ser::Impossible<QString, QSettingsSerdeError>;
+    type SerializeMap = ser::Impossible<QString, QSettingsSerdeError>;
+    type SerializeStruct = ser::Impossible<QString, QSettingsSerdeError>;
+    type SerializeStructVariant = ser::Impossible<QString, QSettingsSerdeError>;
+
+    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
+        Self::unsupported()
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        Self::display(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        Self::display(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        Self::display(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        Self::display(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        Self::display(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        Self::display(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        Self::display(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        Self::display(v)
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok, Self::Error> {
+        Self::unsupported()
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Self::Error> {
+        Self::unsupported()
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        Self::display(v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(QString::from(v))
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Self::unsupported()
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Self::unsupported()
+    }
+
+    fn serialize_some<T>(self, _value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        Self::unsupported()
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Self::unsupported()
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Self::unsupported()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        Self::unsupported()
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Self::unsupported()
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Self::unsupported()
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Self::unsupported()
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Self::unsupported()
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Self::unsupported()
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Self::unsupported()
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Self::unsupported()
+    }
+}
+
+struct Deserializer<'a> {
+    settings: Pin<&'a mut QSettings>,
+    target: Target,
+}
+
+impl Deserializer<'_> {
+    /// The full key of the target, for error messages
+    fn describe(&self) -> String {
+        let group = self.settings.group().to_string();
+        let key = self
+            .target
+            .scalar_key()
+            .map(|key| key.to_string())
+            .unwrap_or_default();
+        match (group.is_empty(), key.is_empty()) {
+            (true, _) => key,
+            (false, true) => group,
+            (false, false) => format!("{group}/{key}"),
+        }
+    }
+
+    /// Returns true if the target is a value rather than a group
+    fn is_value(&self) -> bool {
+        self.target
+            .scalar_key()
+            .is_ok_and(|key| self.settings.contains(&key))
+    }
+
+    /// Returns true if there is a value or a group for the target
+    fn exists(&self) -> bool {
+        match &self.target {
+            Target::Group => true,
+            Target::Element => {
+                self.is_value()
+                    || !self.settings.child_keys().is_empty()
+                    || !self.settings.child_groups().is_empty()
+            }
+            Target::Key(key) => self.is_value() || self.settings.child_groups().contains(key),
+        }
+    }
+
+    fn read_value(&self) -> Result<QVariant, QSettingsSerdeError> {
+        let key = self.target.scalar_key()?;
+        if self.settings.contains(&key) {
+            Ok(self.settings.value(&key))
+        } else {
+            Err(QSettingsSerdeError::new(format!(
+                "missing value for the key {}",
+                self.describe()
+            )))
+        }
+    }
+
+    fn read_string(&self) -> Result<String, QSettingsSerdeError> {
+        Ok(self.read_value()?.value_or_default::<QString>().to_string())
+    }
+
+    fn parse<T>(&self) -> Result<T, QSettingsSerdeError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let value = self.read_string()?;
+        value.trim().parse().map_err(|err| {
+            QSettingsSerdeError::new(format!(
+                "invalid value {value:?} for the key {}: {err}",
+                self.describe()
+            ))
+        })
+    }
+
+    /// Visit the keys and subgroups of the group of the target
+    fn visit_group<'de, V>(mut self, visitor: V) -> Result<V::Value, QSettingsSerdeError>
+    where
+        V: de::Visitor<'de>,
+    {
+        let groups = begin_target_group(self.settings.as_mut(), &self.target);
+        let keys = group_keys(&self.settings);
+        let result = visitor.visit_map(GroupAccess {
+            settings: self.settings.as_mut(),
+            keys,
+            index: 0,
+        });
+        end_groups(self.settings, groups);
+        result
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: de::Visitor<'de>,
+            {
+                visitor.$visit(self.parse()?)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'_> {
+    type Error = QSettingsSerdeError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        // The type is not known, so values are strings and groups are maps
+        if self.is_value() {
+            visitor.visit_string(self.read_string()?)
+        } else if self.exists() {
+            self.visit_group(visitor)
+        } else {
+            Err(QSettingsSerdeError::new(format!(
+                "missing value for the key {}",
+                self.describe()
+            )))
+        }
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_string(self.read_string()?)
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_string(self.read_string()?)
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        let bytes = self.read_value()?.value_or_default::<QByteArray>();
+        visitor.visit_byte_buf(Vec::from(&bytes))
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        if self.exists() {
+            visitor.visit_some(self)
+        } else {
+            visitor.visit_none()
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(mut self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        let size = self
+            .settings
+            .as_mut()
+            .begin_read_array(self.target.array_key()?);
+        let result = visitor.visit_seq(ArrayAccess {
+            settings: self.settings.as_mut(),
+            index: 0,
+            size,
+        });
+        self.settings.end_array();
+        result
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.visit_group(visitor)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.visit_group(visitor)
+    }
+
+    fn deserialize_enum<V>(
+        mut self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        if self.is_value() {
+            return visitor.visit_enum(self.read_string()?.into_deserializer());
+        }
+
+        let description = self.describe();
+        let groups = begin_target_group(self.settings.as_mut(), &self.target);
+        let keys = group_keys(&self.settings);
+        let result = match keys.as_slice() {
+            [variant] => visitor.visit_enum(EnumAccess {
+                settings: self.settings.as_mut(),
+                variant: variant.clone(),
+            }),
+            _ => Err(QSettingsSerdeError::new(format!(
+                "expected a string or a group with a single key for an enum at {description}"
+            ))),
+        };
+        end_groups(self.settings, groups);
+        result
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+}
+
+struct ArrayAccess<'a> {
+    settings: Pin<&'a mut QSettings>,
+    index: i32,
+    size: i32,
+}
+
+impl<'de> de::SeqAccess<'de> for ArrayAccess<'_> {
+    type Error = QSettingsSerdeError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        if self.index >= self.size {
+            return Ok(None);
+        }
+
+        self.settings.as_mut().set_array_index(self.index);
+        self.index += 1;
+        seed.deserialize(Deserializer {
+            settings: self.settings.as_mut(),
+            target: Target::Element,
+        })
+        .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        usize::try_from(self.size - self.index).ok()
+    }
+}
+
+struct GroupAccess<'a> {
+    settings: Pin<&'a mut QSettings>,
+    keys: Vec<QString>,
+    index: usize,
+}
+
+impl<'de> de::MapAccess<'de> for GroupAccess<'_> {
+    type Error = QSettingsSerdeError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        match self.keys.get(self.index) {
+            Some(key) => seed.deserialize(KeyDeserializer(key.to_string())).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let key = self
+            .keys
+            .get(self.index)
+            .cloned()
+            .ok_or_else(|| QSettingsSerdeError::new("next_value called before next_key"))?;
+        self.index += 1;
+        seed.deserialize(Deserializer {
+            settings: self.settings.as_mut(),
+            target: Target::Key(key),
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.keys.len() - self.index)
+    }
+}
+
+/// Converts a settings key into the key of a map, parsing it for integer keys
+struct KeyDeserializer(String);
+
+macro_rules! deserialize_parsed_key {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: de::Visitor<'de>,
+            {
+                let value = self.0.parse().map_err(|err| {
+                    QSettingsSerdeError::new(format!("invalid map key {:?}: {err}", self.0))
+                })?;
+                visitor.$visit(value)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for KeyDeserializer {
+    type Error = QSettingsSerdeError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_string(self.0)
+    }
+
+    deserialize_parsed_key! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i128 u128 f32 f64 str string bytes byte_buf option unit unit_struct
+        seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct EnumAccess<'a> {
+    settings: Pin<&'a mut QSettings>,
+    variant: QString,
+}
+
+impl<'de, 'a> de::EnumAccess<'de> for EnumAccess<'a> {
+    type Error = QSettingsSerdeError;
+    type Variant = Deserializer<'a>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(
+            IntoDeserializer::<QSettingsSerdeError>::into_deserializer(self.variant.to_string()),
+        )?;
+        Ok((
+            variant,
+            Deserializer {
+                settings: self.settings,
+                target: Target::Key(self.variant),
+            },
+        ))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for Deserializer<'_> {
+    type Error = QSettingsSerdeError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.visit_group(visitor)
+    }
+}
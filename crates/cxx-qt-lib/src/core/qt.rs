@@ -95,6 +95,15 @@ mod ffi {
         MPenJoinStyle = 0x1c0,
     }
 
+    /// This enum specifies the options available for finding children of a QObject.
+    #[repr(i32)]
+    enum FindChildOption {
+        /// Looks only at the direct children of the object.
+        FindDirectChildrenOnly = 0,
+        /// Looks at all children of the object (recursive search).
+        FindChildrenRecursively = 1,
+    }
+
     #[repr(i32)]
     enum FillRule {
         /// Specifies that the region is filled using the odd even fill rule.
@@ -145,6 +154,104 @@ mod ffi {
         RelativeSize,
     }
 
+    /// This enum type specifies the accuracy of a timer.
+    #[repr(i32)]
+    enum TimerType {
+        /// Precise timers try to keep millisecond accuracy.
+        PreciseTimer,
+        /// Coarse timers try to keep accuracy within 5% of the desired interval.
+        CoarseTimer,
+        /// Very coarse timers only keep full second accuracy.
+        VeryCoarseTimer,
+    }
+
+    /// This enum type describes the different mouse buttons.
+    #[repr(i32)]
+    enum MouseButton {
+        /// The button state does not refer to any button.
+        NoButton = 0x00000000,
+        /// The left button is pressed, or an event refers to the left button.
+        LeftButton = 0x00000001,
+        /// The right button.
+        RightButton = 0x00000002,
+        /// The middle button.
+        MiddleButton = 0x00000004,
+        /// The 'Back' button. (Typically present on the 'thumb' side of a mouse with extra buttons.)
+        BackButton = 0x00000008,
+        /// The 'Forward' button. (Typically present beside the 'Back' button.)
+        ForwardButton = 0x00000010,
+        /// This value corresponds to a mask of all possible mouse buttons.
+        AllButtons = 0x07ffffff,
+    }
+
+    /// This enum describes the modifier keys.
+    #[repr(i32)]
+    enum KeyboardModifier {
+        /// No modifier key is pressed.
+        NoModifier = 0x00000000,
+        /// A Shift key on the keyboard is pressed.
+        ShiftModifier = 0x02000000,
+        /// A Ctrl key on the keyboard is pressed.
+        ControlModifier = 0x04000000,
+        /// An Alt key on the keyboard is pressed.
+        AltModifier = 0x08000000,
+        /// A Meta key on the keyboard is pressed.
+        MetaModifier = 0x10000000,
+        /// A keypad button is pressed.
+        KeypadModifier = 0x20000000,
+        /// X11 only (unless activated on Windows by a command line argument). A Mode_switch key on the keyboard is pressed.
+        GroupSwitchModifier = 0x40000000,
+    }
+
+    /// This enum type is used to specify various window-system properties for the window.
+    ///
+    /// A window has a single type, and then any number of hints which can be combined.
+    #[repr(i32)]
+    enum WindowType {
+        /// This is the default type for a window, it is a top-level window with a window-system frame.
+        Window = 0x00000001,
+        /// Indicates that the window is a dialog.
+        Dialog = 0x00000003,
+        /// Indicates that the window is a sheet on macOS.
+        Sheet = 0x00000005,
+        /// Indicates that the window is a drawer on macOS.
+        Drawer = 0x00000007,
+        /// Indicates that the window is a pop-up top-level window.
+        Popup = 0x00000009,
+        /// Indicates that the window is a tool window, such as a floating toolbar.
+        Tool = 0x0000000b,
+        /// Indicates that the window is a tooltip.
+        ToolTip = 0x0000000d,
+        /// Indicates that the window is a splash screen.
+        SplashScreen = 0x0000000f,
+        /// Indicates that the window is a sub-window.
+        SubWindow = 0x00000012,
+        /// Bypass the window manager completely, which results in a borderless window that is not managed at all.
+        BypassWindowManagerHint = 0x00000400,
+        /// Produces a borderless window.
+        FramelessWindowHint = 0x00000800,
+        /// Gives the window a title bar.
+        WindowTitleHint = 0x00001000,
+        /// Adds a window system menu, and possibly a close button.
+        WindowSystemMenuHint = 0x00002000,
+        /// Adds a minimize button.
+        WindowMinimizeButtonHint = 0x00004000,
+        /// Adds a maximize button.
+        WindowMaximizeButtonHint = 0x00008000,
+        /// Informs the window system that this window should stay on top of all other windows.
+        WindowStaysOnTopHint = 0x00040000,
+        /// Informs the window system that this window is used only for output and does not take input.
+        WindowTransparentForInput = 0x00080000,
+        /// Informs the window system that this window should not receive the input focus.
+        WindowDoesNotAcceptFocus = 0x00200000,
+        /// Turns off the default window title hints.
+        CustomizeWindowHint = 0x02000000,
+        /// Informs the window system that this window should stay below all other windows.
+        WindowStaysOnBottomHint = 0x04000000,
+        /// Adds a close button.
+        WindowCloseButtonHint = 0x08000000,
+    }
+
     unsafe extern "C++" {
         include!("cxx-qt-lib/qt.h");
         type AspectRatioMode;
@@ -157,17 +264,22 @@ mod ffi {
         type PenCapStyle;
         type PenJoinStyle;
         type FillRule;
+        type FindChildOption;
         type LayoutDirection;
         type BGMode;
         type ClipOperation;
         type SizeMode;
+        type TimerType;
+        type MouseButton;
+        type KeyboardModifier;
+        type WindowType;
     }
 }
 
 pub use ffi::{
-    AspectRatioMode, BGMode, CaseSensitivity, ClipOperation, DateFormat, FillRule, LayoutDirection,
-    PenCapStyle, PenJoinStyle, PenStyle, SizeMode, SplitBehaviorFlags, TimeSpec,
-    TransformationMode,
+    AspectRatioMode, BGMode, CaseSensitivity, ClipOperation, DateFormat, FillRule, FindChildOption,
+    KeyboardModifier, LayoutDirection, MouseButton, PenCapStyle, PenJoinStyle, PenStyle, SizeMode,
+    SplitBehaviorFlags, TimeSpec, TimerType, TransformationMode, WindowType,
 };
 
 // Reexport ConnectionType from cxx-qt
@@ -0,0 +1,64 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qthread.h"
+
+#include <climits>
+#include <limits>
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+#include <QtCore/QDeadlineTimer>
+#endif
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QThread*
+qthreadCurrentThread()
+{
+  return QThread::currentThread();
+}
+
+::std::int32_t
+qthreadIdealThreadCount()
+{
+  return static_cast<::std::int32_t>(QThread::idealThreadCount());
+}
+
+::std::unique_ptr<QThread>
+qthreadNew()
+{
+  return ::std::make_unique<QThread>();
+}
+
+void
+qthreadStart(QThread& thread)
+{
+  thread.start();
+}
+
+bool
+qthreadWait(QThread& thread, ::std::int64_t msecs)
+{
+  // A negative duration means that the wait never times out
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  if (msecs < 0) {
+    return thread.wait(QDeadlineTimer(QDeadlineTimer::Forever));
+  }
+  return thread.wait(QDeadlineTimer(static_cast<qint64>(msecs)));
+#else
+  if (msecs < 0) {
+    return thread.wait(ULONG_MAX);
+  }
+  // ULONG_MAX is treated as forever, so clamp below it
+  const auto max = ::std::numeric_limits<unsigned long>::max() - 1;
+  const auto time = static_cast<::std::uint64_t>(msecs);
+  return thread.wait(time < max ? static_cast<unsigned long>(time) : max);
+#endif
+}
+
+}
+}
@@ -0,0 +1,155 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qthread.h");
+        /// The QThread class provides a platform-independent way to manage threads.
+        #[qobject]
+        type QThread;
+    }
+
+    unsafe extern "C++" {
+        /// Tells the thread's event loop to exit with a return code.
+        ///
+        /// After calling this function, the thread leaves the event loop and returns from the call to QEventLoop::exec().
+        /// By convention, a return code of 0 means success, any non-zero value indicates an error.
+        fn exit(self: Pin<&mut QThread>, return_code: i32);
+
+        /// Returns true if the thread is finished; otherwise returns false.
+        #[rust_name = "is_finished"]
+        fn isFinished(self: &QThread) -> bool;
+
+        /// Return true if the task running on this thread should be stopped.
+        /// An interruption can be requested by [QThread::request_interruption].
+        #[rust_name = "is_interruption_requested"]
+        fn isInterruptionRequested(self: &QThread) -> bool;
+
+        /// Returns true if the thread is running; otherwise returns false.
+        #[rust_name = "is_running"]
+        fn isRunning(self: &QThread) -> bool;
+
+        /// Returns the current event loop level for the thread.
+        ///
+        /// Note that this can only be called within the thread itself, i.e. when it is the current thread.
+        #[rust_name = "loop_level"]
+        fn loopLevel(self: &QThread) -> i32;
+
+        /// Tells the thread's event loop to exit with return code 0 (success).
+        ///
+        /// This function does nothing if the thread does not have an event loop.
+        fn quit(self: Pin<&mut QThread>);
+
+        /// Request the interruption of the thread.
+        ///
+        /// That request is advisory and it is up to code running on the thread to decide if and how it should act upon such request.
+        #[rust_name = "request_interruption"]
+        fn requestInterruption(self: Pin<&mut QThread>);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        type QObject = cxx_qt::QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "upcast_qthread"]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn upcast(thiz: *const QThread) -> *const QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "downcast_qobject"]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn downcast(base: *const QObject) -> *const QThread;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qthread_current_thread"]
+        fn qthreadCurrentThread() -> *mut QThread;
+        #[doc(hidden)]
+        #[rust_name = "qthread_ideal_thread_count"]
+        fn qthreadIdealThreadCount() -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qthread_new"]
+        fn qthreadNew() -> UniquePtr<QThread>;
+        #[doc(hidden)]
+        #[rust_name = "qthread_start"]
+        fn qthreadStart(thread: Pin<&mut QThread>);
+        #[doc(hidden)]
+        #[rust_name = "qthread_wait"]
+        fn qthreadWait(thread: Pin<&mut QThread>, msecs: i64) -> bool;
+    }
+
+    // QThread is not trivial to CXX and is not relocatable in Qt
+    // so we need to use references or pointers.
+    impl UniquePtr<QThread> {}
+}
+
+use core::pin::Pin;
+use core::time::Duration;
+use cxx_qt::{QObject, Upcast};
+
+pub use ffi::QThread;
+
+impl Upcast<QObject> for QThread {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QObject {
+        ffi::upcast_qthread(this)
+    }
+
+    unsafe fn from_base_ptr(base: *const QObject) -> *const Self {
+        ffi::downcast_qobject(base)
+    }
+}
+
+impl QThread {
+    /// Returns a pointer to a QThread which manages the currently executing thread.
+    pub fn current_thread() -> *mut Self {
+        ffi::qthread_current_thread()
+    }
+
+    /// Returns the ideal number of threads that this process can run in parallel.
+    ///
+    /// This is done by querying the number of logical processors available to this process
+    /// (if supported by this OS) or the total number of logical processors in the system.
+    /// This function returns 1 if neither value could be determined.
+    pub fn ideal_thread_count() -> i32 {
+        ffi::qthread_ideal_thread_count()
+    }
+
+    /// Create a new QThread, which does not start executing until [QThread::start] is called.
+    ///
+    /// QObjects, including those defined in Rust, can then be moved to the thread with `QObjectExt::move_to_thread`,
+    /// their queued closures, queued signal connections and timers are then executed by the event loop of the thread.
+    ///
+    /// Note that the thread must be finished before it is dropped, for example with [QThread::quit] and [QThread::wait].
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qthread_new()
+    }
+
+    /// Begins execution of the thread, which runs an event loop until [QThread::quit] or [QThread::exit] is called.
+    ///
+    /// If the thread is already running, this function does nothing.
+    pub fn start(self: Pin<&mut Self>) {
+        ffi::qthread_start(self)
+    }
+
+    /// Blocks the calling thread until the thread has finished execution and returns true,
+    /// this also returns true if the thread has not been started.
+    ///
+    /// Note that this must not be called from the thread itself, as it would never return.
+    pub fn wait(self: Pin<&mut Self>) -> bool {
+        ffi::qthread_wait(self, -1)
+    }
+
+    /// Blocks the calling thread until either the thread has finished execution and returns true,
+    /// or the timeout has elapsed and returns false.
+    pub fn wait_timeout(self: Pin<&mut Self>, timeout: Duration) -> bool {
+        ffi::qthread_wait(self, i64::try_from(timeout.as_millis()).unwrap_or(i64::MAX))
+    }
+}
@@ -0,0 +1,184 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type TimerType = crate::TimerType;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qtimer.h");
+        /// The QTimer class provides repetitive and single-shot timers.
+        ///
+        /// The timeout signal is emitted from the event loop of the thread the timer lives in,
+        /// closures can be connected to it with [QTimer::connect_timeout] or [QTimer::on_timeout].
+        #[qobject]
+        type QTimer;
+
+        /// This signal is emitted when the timer times out.
+        #[qsignal]
+        fn timeout(self: Pin<&mut QTimer>);
+    }
+
+    unsafe extern "C++" {
+        /// Returns the timeout interval in milliseconds.
+        ///
+        /// The default value is 0, a QTimer with a timeout interval of 0 will time out as soon as all the events in the window system's event queue have been processed.
+        fn interval(self: &QTimer) -> i32;
+
+        /// Returns true if the timer is running (pending); otherwise returns false.
+        #[rust_name = "is_active"]
+        fn isActive(self: &QTimer) -> bool;
+
+        /// Returns whether the timer is a single-shot timer.
+        ///
+        /// A single-shot timer fires only once, non-single-shot timers fire every interval milliseconds.
+        #[rust_name = "is_single_shot"]
+        fn isSingleShot(self: &QTimer) -> bool;
+
+        /// Returns the timer's remaining value in milliseconds left until the timeout.
+        ///
+        /// If the timer is inactive, the returned value will be -1. If the timer is overdue, the returned value will be 0.
+        #[rust_name = "remaining_time"]
+        fn remainingTime(self: &QTimer) -> i32;
+
+        /// Sets the timeout interval in milliseconds.
+        #[rust_name = "set_interval"]
+        fn setInterval(self: Pin<&mut QTimer>, msec: i32);
+
+        /// Sets whether the timer is a single-shot timer.
+        #[rust_name = "set_single_shot"]
+        fn setSingleShot(self: Pin<&mut QTimer>, single_shot: bool);
+
+        /// Sets the accuracy of the timer.
+        #[rust_name = "set_timer_type"]
+        fn setTimerType(self: Pin<&mut QTimer>, timer_type: TimerType);
+
+        /// Starts or restarts the timer with the timeout specified in [QTimer::interval].
+        ///
+        /// If the timer is already running, it will be stopped and restarted.
+        fn start(self: Pin<&mut QTimer>);
+
+        /// Starts or restarts the timer with a timeout interval of msec milliseconds.
+        ///
+        /// If the timer is already running, it will be stopped and restarted.
+        #[rust_name = "start_with_interval"]
+        fn start(self: Pin<&mut QTimer>, msec: i32);
+
+        /// Stops the timer.
+        fn stop(self: Pin<&mut QTimer>);
+
+        /// Returns the ID of the timer if the timer is running; otherwise returns -1.
+        #[rust_name = "timer_id"]
+        fn timerId(self: &QTimer) -> i32;
+
+        /// Returns the accuracy of the timer, the default value is [TimerType::CoarseTimer].
+        #[rust_name = "timer_type"]
+        fn timerType(self: &QTimer) -> TimerType;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        type QObject = cxx_qt::QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "upcast_qtimer"]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn upcast(thiz: *const QTimer) -> *const QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "downcast_qobject"]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn downcast(base: *const QObject) -> *const QTimer;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qtimer_new"]
+        fn qtimerNew() -> UniquePtr<QTimer>;
+        #[doc(hidden)]
+        #[rust_name = "qtimer_single_shot"]
+        fn qtimerSingleShot(
+            msec: i32,
+            func: fn(Box<QTimerSingleShotFn>),
+            arg: Box<QTimerSingleShotFn>,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QTimerSingleShotFn;
+    }
+
+    // QTimer is not trivial to CXX and is not relocatable in Qt
+    // so we need to use references or pointers.
+    impl UniquePtr<QTimer> {}
+}
+
+use core::pin::Pin;
+use cxx_qt::{QBox, QObject, Upcast};
+
+pub use ffi::QTimer;
+
+impl Upcast<QObject> for QTimer {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QObject {
+        ffi::upcast_qtimer(this)
+    }
+
+    unsafe fn from_base_ptr(base: *const QObject) -> *const Self {
+        ffi::downcast_qobject(base)
+    }
+}
+
+#[doc(hidden)]
+pub struct QTimerSingleShotFn {
+    inner: Box<dyn FnOnce()>,
+}
+
+#[allow(clippy::boxed_local)]
+fn call_single_shot_fn(arg: Box<QTimerSingleShotFn>) {
+    (arg.inner)()
+}
+
+impl QTimer {
+    /// Create a new QTimer, which does not start until [QTimer::start] is called.
+    ///
+    /// The timer lives in the thread it is created in and times out from the event loop of that thread.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qtimer_new()
+    }
+
+    /// Create a new QTimer which is owned by the given parent, so that it is destroyed with the parent.
+    ///
+    /// The returned [QBox] can be used to access the timer until either the parent is destroyed,
+    /// or the timer is deleted, dropping the [QBox] does not destroy the timer.
+    pub fn new_with_parent(parent: Pin<&mut QObject>) -> QBox<Self> {
+        QBox::new_with_parent(Self::new(), parent)
+    }
+
+    /// Call the given closure once after the given interval in milliseconds.
+    ///
+    /// The closure is called from the event loop of the current thread,
+    /// if the event loop is not running the closure is not called.
+    pub fn single_shot<F>(msec: i32, closure: F)
+    where
+        F: FnOnce() + 'static,
+    {
+        ffi::qtimer_single_shot(
+            msec,
+            call_single_shot_fn,
+            Box::new(QTimerSingleShotFn {
+                inner: Box::new(closure),
+            }),
+        );
+    }
+}
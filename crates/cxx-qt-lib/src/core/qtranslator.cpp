@@ -0,0 +1,60 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qtranslator.h"
+
+#include <QtCore/QByteArray>
+#include <QtCore/QLocale>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qtranslatorLoad(QTranslator& translator,
+                const QString& filename,
+                const QString& directory)
+{
+  return translator.load(filename, directory);
+}
+
+bool
+qtranslatorLoadLocale(QTranslator& translator,
+                      const QString& filename,
+                      const QString& prefix,
+                      const QString& directory)
+{
+  return translator.load(QLocale(), filename, prefix, directory);
+}
+
+::std::unique_ptr<QTranslator>
+qtranslatorNew()
+{
+  return ::std::make_unique<QTranslator>();
+}
+
+QString
+qtranslatorTranslate(const QTranslator& translator,
+                     ::rust::Str context,
+                     ::rust::Str sourceText,
+                     ::rust::Str disambiguation,
+                     ::std::int32_t n)
+{
+  // QTranslator requires null terminated strings
+  const auto contextBytes =
+    QByteArray(context.data(), static_cast<qsizetype>(context.size()));
+  const auto sourceTextBytes =
+    QByteArray(sourceText.data(), static_cast<qsizetype>(sourceText.size()));
+  const auto disambiguationBytes = QByteArray(
+    disambiguation.data(), static_cast<qsizetype>(disambiguation.size()));
+  return translator.translate(contextBytes.constData(),
+                              sourceTextBytes.constData(),
+                              disambiguationBytes.constData(),
+                              static_cast<int>(n));
+}
+
+}
+}
@@ -0,0 +1,154 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qtranslator.h");
+        /// The QTranslator class provides internationalization support for text output.
+        ///
+        /// A translator loads a `.qm` file, which is created from a `.ts` file with `lrelease`,
+        /// and is then installed with [QCoreApplication::install_translator](crate::QCoreApplication::install_translator)
+        /// so that strings from [qs_tr](crate::qs_tr) and `qsTr` in QML are translated.
+        #[qobject]
+        type QTranslator;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Returns the path of the loaded translation file, or an empty string if no file was loaded.
+        #[rust_name = "file_path"]
+        fn filePath(self: &QTranslator) -> QString;
+
+        /// Returns true if this translator is empty, otherwise returns false.
+        #[rust_name = "is_empty"]
+        fn isEmpty(self: &QTranslator) -> bool;
+
+        /// Returns the target language as stored in the translation file.
+        fn language(self: &QTranslator) -> QString;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        type QObject = cxx_qt::QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "upcast_qtranslator"]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn upcast(thiz: *const QTranslator) -> *const QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "downcast_qobject"]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn downcast(base: *const QObject) -> *const QTranslator;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qtranslator_load"]
+        fn qtranslatorLoad(
+            translator: Pin<&mut QTranslator>,
+            filename: &QString,
+            directory: &QString,
+        ) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qtranslator_load_locale"]
+        fn qtranslatorLoadLocale(
+            translator: Pin<&mut QTranslator>,
+            filename: &QString,
+            prefix: &QString,
+            directory: &QString,
+        ) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qtranslator_new"]
+        fn qtranslatorNew() -> UniquePtr<QTranslator>;
+        #[doc(hidden)]
+        #[rust_name = "qtranslator_translate"]
+        fn qtranslatorTranslate(
+            translator: &QTranslator,
+            context: &str,
+            source_text: &str,
+            disambiguation: &str,
+            n: i32,
+        ) -> QString;
+    }
+
+    // QTranslator is not trivial to CXX and is not relocatable in Qt
+    // so we need to use references or pointers.
+    impl UniquePtr<QTranslator> {}
+}
+
+use crate::QString;
+use core::pin::Pin;
+use cxx_qt::{QObject, Upcast};
+
+pub use ffi::QTranslator;
+
+impl Upcast<QObject> for QTranslator {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QObject {
+        ffi::upcast_qtranslator(this)
+    }
+
+    unsafe fn from_base_ptr(base: *const QObject) -> *const Self {
+        ffi::downcast_qobject(base)
+    }
+}
+
+impl QTranslator {
+    /// Loads the translation file with the given filename, returning true if the translation was loaded.
+    ///
+    /// If the filename is relative it is resolved against the directory, which can be empty.
+    /// If the file is not found the suffix `.qm` is tried, and then parts of the filename are removed
+    /// after each `_`, so that `app_de_AT` falls back to `app_de.qm` and then `app.qm`.
+    ///
+    /// Translations which are embedded by cxx-qt-build are loaded from the `:/i18n` resource directory.
+    pub fn load(self: Pin<&mut Self>, filename: &QString, directory: &QString) -> bool {
+        ffi::qtranslator_load(self, filename, directory)
+    }
+
+    /// Loads the translation file for the default locale, which is the system locale unless it has been changed,
+    /// returning true if a translation was loaded.
+    ///
+    /// The file name is built from the filename, the prefix and each of the UI languages of the locale,
+    /// for example a filename of `app` and a prefix of `_` loads `app_de.qm` for German.
+    pub fn load_locale(
+        self: Pin<&mut Self>,
+        filename: &QString,
+        prefix: &QString,
+        directory: &QString,
+    ) -> bool {
+        ffi::qtranslator_load_locale(self, filename, prefix, directory)
+    }
+
+    /// Create a new QTranslator which is empty until a translation file is loaded.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qtranslator_new()
+    }
+
+    /// Returns the translation for the source text in the given context from this translator,
+    /// or an empty string if there is no translation.
+    ///
+    /// When n is not -1 the plural form for n is chosen, otherwise pass -1.
+    pub fn translate(
+        &self,
+        context: &str,
+        source_text: &str,
+        disambiguation: Option<&str>,
+        n: i32,
+    ) -> QString {
+        ffi::qtranslator_translate(
+            self,
+            context,
+            source_text,
+            disambiguation.unwrap_or_default(),
+            n,
+        )
+    }
+}
@@ -36,6 +36,9 @@ pub use qfont::{
     QFontStyleHint, QFontStyleStrategy,
 };
 
+mod qmouseevent;
+pub use qmouseevent::{QHoverEvent, QMouseEvent};
+
 mod qpainterpath;
 pub use qpainterpath::QPainterPath;
 
@@ -4,7 +4,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{QByteArray, QFont, QString, QStringList, QVector};
+use crate::{QByteArray, QFont, QString, QStringList, QTranslator, QVector};
 use core::pin::Pin;
 use cxx_qt::Upcast;
 
@@ -21,6 +21,8 @@ mod ffi {
         type QVector_QByteArray = crate::QVector<QByteArray>;
         include!("cxx-qt-lib/qfont.h");
         type QFont = crate::QFont;
+        include!("cxx-qt-lib/qtranslator.h");
+        type QTranslator = crate::QTranslator;
 
         include!("cxx-qt-lib/qguiapplication.h");
         type QGuiApplication;
@@ -66,12 +68,24 @@ mod ffi {
         #[rust_name = "qguiapplication_remove_library_path"]
         fn qapplicationRemoveLibraryPath(app: &QGuiApplication, path: &QString);
         #[doc(hidden)]
+        #[rust_name = "qguiapplication_remove_translator"]
+        fn qapplicationRemoveTranslator(
+            app: Pin<&mut QGuiApplication>,
+            translator: Pin<&mut QTranslator>,
+        ) -> bool;
+        #[doc(hidden)]
         #[rust_name = "qguiapplication_application_version"]
         fn qapplicationApplicationVersion(app: &QGuiApplication) -> QString;
         #[doc(hidden)]
         #[rust_name = "qguiapplication_exec"]
         fn qapplicationExec(app: Pin<&mut QGuiApplication>) -> i32;
         #[doc(hidden)]
+        #[rust_name = "qguiapplication_install_translator"]
+        fn qapplicationInstallTranslator(
+            app: Pin<&mut QGuiApplication>,
+            translator: Pin<&mut QTranslator>,
+        ) -> bool;
+        #[doc(hidden)]
         #[rust_name = "qguiapplication_library_paths"]
         fn qapplicationLibraryPaths(app: &QGuiApplication) -> QStringList;
         #[doc(hidden)]
@@ -159,6 +173,17 @@ impl QGuiApplication {
         ffi::qguiapplication_font(self)
     }
 
+    /// Adds the translator to the list of translation files to be used for translations,
+    /// returning true if the translator was installed.
+    ///
+    /// Translators are searched in the reverse order in which they were installed, so the most recently
+    /// installed translator is searched first. A translator is removed automatically when it is destroyed.
+    ///
+    /// To update the `qsTr` strings of QML which has already been loaded, call `QQmlEngine::retranslate` afterwards.
+    pub fn install_translator(self: Pin<&mut Self>, translator: Pin<&mut QTranslator>) -> bool {
+        ffi::qguiapplication_install_translator(self, translator)
+    }
+
     /// Returns a list of paths that the application will search when dynamically loading libraries.
     pub fn library_paths(&self) -> QStringList {
         ffi::qguiapplication_library_paths(self)
@@ -209,6 +234,11 @@ impl QGuiApplication {
         ffi::qguiapplication_remove_library_path(self, path)
     }
 
+    /// Removes the translator from the list of translation files used, returning true if it was removed.
+    pub fn remove_translator(self: Pin<&mut Self>, translator: Pin<&mut QTranslator>) -> bool {
+        ffi::qguiapplication_remove_translator(self, translator)
+    }
+
     /// Set the version of this application
     pub fn set_application_version(self: Pin<&mut Self>, version: &QString) {
         ffi::qguiapplication_set_application_version(self, version);
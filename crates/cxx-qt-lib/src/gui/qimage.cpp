@@ -55,5 +55,30 @@ qimageCacheKey(const QImage& image)
 {
   return static_cast<::std::int64_t>(image.cacheKey());
 }
+
+QImage
+qimageConvertToFormat(const QImage& image, QImageFormat format)
+{
+  return image.convertToFormat(format);
+}
+
+::rust::Vec<::std::uint8_t>
+qimageToRgba8888Bytes(const QImage& image)
+{
+  const QImage converted = image.convertToFormat(QImage::Format_RGBA8888);
+  const auto rowLength = static_cast<::std::size_t>(converted.width()) * 4;
+
+  ::rust::Vec<::std::uint8_t> bytes;
+  bytes.reserve(rowLength * static_cast<::std::size_t>(converted.height()));
+  // Copy each line separately, as the bytes per line of an image can be larger
+  // than its width
+  for (int y = 0; y < converted.height(); ++y) {
+    const auto* line = converted.constScanLine(y);
+    for (::std::size_t x = 0; x < rowLength; ++x) {
+      bytes.push_back(line[x]);
+    }
+  }
+  return bytes;
+}
 }
 }
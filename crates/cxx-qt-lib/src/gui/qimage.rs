@@ -263,6 +263,15 @@ mod ffi {
         #[rust_name = "qimage_cache_key"]
         fn qimageCacheKey(image: &QImage) -> i64;
 
+        #[doc(hidden)]
+        #[rust_name = "qimage_convert_to_format"]
+        fn qimageConvertToFormat(image: &QImage, format: QImageFormat) -> QImage;
+
+        #[doc(hidden)]
+        #[rust_name = "qimage_to_rgba8888_bytes"]
+        #[cfg(any(feature = "image-v0-24", feature = "image-v0-25"))]
+        fn qimageToRgba8888Bytes(image: &QImage) -> Vec<u8>;
+
         #[doc(hidden)]
         #[rust_name = "qimage_eq"]
         fn operatorEq(a: &QImage, b: &QImage) -> bool;
@@ -462,6 +471,11 @@ impl QImage {
         ffi::qimage_cache_key(self)
     }
 
+    /// Returns a copy of the image in the given format.
+    pub fn convert_to_format(&self, format: QImageFormat) -> Self {
+        ffi::qimage_convert_to_format(self, format)
+    }
+
     /// Construct a Rust QImage from a given width, height, and QImage Format
     pub fn from_width_height_and_format(
         width: i32,
@@ -492,6 +506,17 @@ macro_rules! from_image {
                 }
             }
         }
+
+        impl From<&QImage> for $crt::RgbaImage {
+            /// Converts the image to RGBA8888, a null image is converted to an empty image.
+            fn from(image: &QImage) -> $crt::RgbaImage {
+                let width = image.width().max(0) as u32;
+                let height = image.height().max(0) as u32;
+                // The bytes contain exactly four bytes for each pixel without any padding
+                $crt::RgbaImage::from_raw(width, height, ffi::qimage_to_rgba8888_bytes(image))
+                    .unwrap_or_default()
+            }
+        }
     };
 }
 
@@ -586,6 +611,18 @@ mod tests {
                     qimage.pixel_color(1, 1),
                     QColor::from_rgba(255, 255, 0, 255)
                 ); // Yellow pixel
+
+                // Convert the QImage back to a RgbaImage, which must be identical
+                let round_trip = RgbaImage::from(&qimage);
+                assert_eq!(round_trip, rgba_image);
+
+                // Images in other formats are converted to RGBA8888
+                let mut qimage =
+                    QImage::from_width_height_and_format(3, 1, QImageFormat::Format_RGB32);
+                qimage.fill(&QColor::from_rgb(1, 2, 3));
+                let converted = RgbaImage::from(&qimage);
+                assert_eq!(converted.dimensions(), (3, 1));
+                assert_eq!(converted.get_pixel(2, 0), &Rgba([1, 2, 3, 255]));
             }
         };
     }
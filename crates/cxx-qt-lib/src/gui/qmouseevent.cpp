@@ -0,0 +1,56 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qmouseevent.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qmouseeventIsButtonPressed(const QMouseEvent& event, Qt::MouseButton button)
+{
+  return event.buttons().testFlag(button);
+}
+
+QPointF
+qmouseeventPosition(const QMouseEvent& event)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return event.position();
+#else
+  return event.localPos();
+#endif
+}
+
+QPointF
+qmouseeventScenePosition(const QMouseEvent& event)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return event.scenePosition();
+#else
+  return event.windowPos();
+#endif
+}
+
+QPointF
+qhovereventOldPosition(const QHoverEvent& event)
+{
+  return event.oldPosF();
+}
+
+QPointF
+qhovereventPosition(const QHoverEvent& event)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return event.position();
+#else
+  return event.posF();
+#endif
+}
+
+}
+}
@@ -0,0 +1,119 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type MouseButton = crate::MouseButton;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+
+        include!("cxx-qt-lib/qmouseevent.h");
+        /// The QMouseEvent class contains parameters that describe a mouse event.
+        ///
+        /// Mouse events are passed to the mouse event handlers of a QQuickItem, such as `mousePressEvent`,
+        /// the event is accepted by default and can be ignored with [QMouseEvent::ignore] to propagate it to the parent item.
+        type QMouseEvent;
+
+        /// The QHoverEvent class contains parameters that describe a mouse event.
+        ///
+        /// Hover events are passed to the hover event handlers of a QQuickItem, such as `hoverMoveEvent`,
+        /// once the item accepts hover events.
+        type QHoverEvent;
+
+        /// Sets the accept flag of the event object, the equivalent of calling set_accepted(true).
+        fn accept(self: Pin<&mut QMouseEvent>);
+
+        /// Returns the button that caused the event.
+        ///
+        /// Note that the returned value is always [MouseButton::NoButton] for mouse move events.
+        fn button(self: &QMouseEvent) -> MouseButton;
+
+        /// Clears the accept flag parameter of the event object, the equivalent of calling set_accepted(false).
+        fn ignore(self: Pin<&mut QMouseEvent>);
+
+        /// Returns true if the event has been accepted.
+        #[rust_name = "is_accepted"]
+        fn isAccepted(self: &QMouseEvent) -> bool;
+
+        /// Sets the accept flag of the event object.
+        #[rust_name = "set_accepted"]
+        fn setAccepted(self: Pin<&mut QMouseEvent>, accepted: bool);
+
+        /// Sets the accept flag of the event object, the equivalent of calling set_accepted(true).
+        #[rust_name = "accept"]
+        fn accept(self: Pin<&mut QHoverEvent>);
+
+        /// Clears the accept flag parameter of the event object, the equivalent of calling set_accepted(false).
+        #[rust_name = "ignore"]
+        fn ignore(self: Pin<&mut QHoverEvent>);
+
+        /// Returns true if the event has been accepted.
+        #[rust_name = "is_accepted"]
+        fn isAccepted(self: &QHoverEvent) -> bool;
+
+        /// Sets the accept flag of the event object.
+        #[rust_name = "set_accepted"]
+        fn setAccepted(self: Pin<&mut QHoverEvent>, accepted: bool);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmouseevent_is_button_pressed"]
+        fn qmouseeventIsButtonPressed(event: &QMouseEvent, button: MouseButton) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmouseevent_position"]
+        fn qmouseeventPosition(event: &QMouseEvent) -> QPointF;
+        #[doc(hidden)]
+        #[rust_name = "qmouseevent_scene_position"]
+        fn qmouseeventScenePosition(event: &QMouseEvent) -> QPointF;
+
+        #[doc(hidden)]
+        #[rust_name = "qhoverevent_old_position"]
+        fn qhovereventOldPosition(event: &QHoverEvent) -> QPointF;
+        #[doc(hidden)]
+        #[rust_name = "qhoverevent_position"]
+        fn qhovereventPosition(event: &QHoverEvent) -> QPointF;
+    }
+}
+
+use crate::{MouseButton, QPointF};
+
+pub use ffi::{QHoverEvent, QMouseEvent};
+
+impl QMouseEvent {
+    /// Returns true if the given button was pressed when the event was generated.
+    pub fn is_button_pressed(&self, button: MouseButton) -> bool {
+        ffi::qmouseevent_is_button_pressed(self, button)
+    }
+
+    /// Returns the position of the mouse cursor relative to the item that received the event.
+    pub fn position(&self) -> QPointF {
+        ffi::qmouseevent_position(self)
+    }
+
+    /// Returns the position of the mouse cursor relative to the window or scene that received the event.
+    pub fn scene_position(&self) -> QPointF {
+        ffi::qmouseevent_scene_position(self)
+    }
+}
+
+impl QHoverEvent {
+    /// Returns the previous position of the mouse cursor, relative to the item that received the event.
+    pub fn old_position(&self) -> QPointF {
+        ffi::qhoverevent_old_position(self)
+    }
+
+    /// Returns the position of the mouse cursor, relative to the item that received the event.
+    pub fn position(&self) -> QPointF {
+        ffi::qhoverevent_position(self)
+    }
+}
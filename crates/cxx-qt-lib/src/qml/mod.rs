@@ -3,8 +3,34 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod qjscallback;
+pub use qjscallback::QJSCallback;
+
+mod qjsengine;
+pub use qjsengine::QJSEngine;
+
+mod qjsvalue;
+pub use qjsvalue::QJSValue;
+
+#[cfg(feature = "serde")]
+mod qjsvalue_serde;
+#[cfg(feature = "serde")]
+pub use qjsvalue_serde::QJSValueSerdeError;
+
 mod qqmlapplicationengine;
 pub use qqmlapplicationengine::QQmlApplicationEngine;
 
+mod qqmlcomponent;
+pub use qqmlcomponent::{QQmlComponent, QQmlComponentError, QQmlComponentStatus};
+
+mod qqmlcontext;
+pub use qqmlcontext::QQmlContext;
+
 mod qqmlengine;
 pub use qqmlengine::QQmlEngine;
+
+mod qqmlerror;
+pub use qqmlerror::{QQmlError, QQmlErrorList};
+
+mod qqmlincubator;
+pub use qqmlincubator::{QQmlIncubator, QQmlIncubatorIncubationMode, QQmlIncubatorStatus};
@@ -0,0 +1,57 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qjscallback.h"
+
+#include <QtCore/QThread>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+CxxQtJSCallback::CxxQtJSCallback(QJSEngine* engine, const QJSValue& callback)
+  : m_engine(engine)
+  , m_callback(callback)
+{
+}
+
+::std::unique_ptr<QJSValue>
+CxxQtJSCallback::call(const QJSValueList& args) const
+{
+  if (!isCallable()) {
+    return nullptr;
+  }
+
+  return ::std::make_unique<QJSValue>(m_callback.call(args));
+}
+
+QJSEngine*
+CxxQtJSCallback::engine() const
+{
+  return m_engine.data();
+}
+
+bool
+CxxQtJSCallback::isCallable() const
+{
+  // The JavaScript function can only be called while the engine is alive
+  // and from the thread that the engine lives in
+  return !m_engine.isNull() && m_engine->thread() == QThread::currentThread();
+}
+
+::std::unique_ptr<CxxQtJSCallback>
+qjscallbackNew(const QObject& context, const QJSValue& callback)
+{
+  auto* engine = qjsEngine(&context);
+  if (engine == nullptr || !callback.isCallable()) {
+    return nullptr;
+  }
+
+  return ::std::make_unique<CxxQtJSCallback>(engine, callback);
+}
+
+}
+}
@@ -0,0 +1,120 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsengine.h");
+        type QJSEngine = crate::QJSEngine;
+        include!("cxx-qt-lib/qjsvalue.h");
+        type QJSValue = crate::QJSValue;
+        type QJSValueList = crate::qml::qjsvalue::QJSValueList;
+        include!(<QtCore/QObject>);
+        type QObject = cxx_qt::QObject;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qjscallback.h");
+        type CxxQtJSCallback;
+
+        fn call(self: &CxxQtJSCallback, args: &QJSValueList) -> UniquePtr<QJSValue>;
+        fn engine(self: &CxxQtJSCallback) -> *mut QJSEngine;
+        #[rust_name = "is_callable"]
+        fn isCallable(self: &CxxQtJSCallback) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qjscallback_new"]
+        fn qjscallbackNew(context: &QObject, callback: &QJSValue) -> UniquePtr<CxxQtJSCallback>;
+    }
+
+    impl UniquePtr<CxxQtJSCallback> {}
+}
+
+use crate::qml::qjsvalue::qjsvalue_list;
+use crate::QJSValue;
+use cxx::UniquePtr;
+use cxx_qt::{QObject, Upcast};
+
+/// A JavaScript function which can be stored in Rust and called later.
+///
+/// This is typically created from a callable [QJSValue] parameter of an invokable,
+/// so that QML can pass a callback such as `backend.fetch(url, function(result) { ... })`.
+/// The callback is stored in the Rust struct of the QObject and can later be called from
+/// a closure queued with [CxxQtThread::queue](cxx_qt::CxxQtThread::queue).
+///
+/// A JavaScript function can only be called from the thread of its engine, so QJSCallback is not [Send],
+/// and calling it does nothing once the engine has been destroyed.
+pub struct QJSCallback {
+    inner: UniquePtr<ffi::CxxQtJSCallback>,
+}
+
+impl QJSCallback {
+    /// Create a callback from the given JavaScript function, using the engine that the context object belongs to.
+    ///
+    /// Returns None if the value is not callable or if the context object is not associated with an engine,
+    /// for example as it was not created by or exposed to QML.
+    pub fn new<T>(context: &T, callback: &QJSValue) -> Option<Self>
+    where
+        T: Upcast<QObject>,
+    {
+        let inner = ffi::qjscallback_new(context.upcast(), callback);
+        if inner.is_null() {
+            None
+        } else {
+            Some(Self { inner })
+        }
+    }
+
+    /// Call the JavaScript function with the given arguments, returning the value returned by the function.
+    ///
+    /// Returns None, without calling the function, if the engine has been destroyed
+    /// or if this is not called from the thread of the engine.
+    pub fn call(&self, args: &[&QJSValue]) -> Option<UniquePtr<QJSValue>> {
+        let result = self.inner.call(&qjsvalue_list(args));
+        if result.is_null() {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Call the JavaScript function with the given Rust value converted into a single argument.
+    ///
+    /// Returns `Ok(None)`, without calling the function, if the engine has been destroyed
+    /// or if this is not called from the thread of the engine.
+    ///
+    /// This is available with the `serde` feature.
+    ///
+    /// The engine of the callback is mutably borrowed while the argument is serialized,
+    /// so this takes `&mut self` to ensure the callback is not called re-entrantly meanwhile.
+    #[cfg(feature = "serde")]
+    pub fn call_with<T>(
+        &mut self,
+        arg: &T,
+    ) -> Result<Option<UniquePtr<QJSValue>>, crate::QJSValueSerdeError>
+    where
+        T: serde::Serialize + ?Sized,
+    {
+        if !self.is_callable() {
+            return Ok(None);
+        }
+
+        let arg = {
+            // Safety: is_callable has checked that the engine is alive and that this is the thread
+            // of the engine. The engine is only borrowed to create the argument, the borrow ends
+            // before the JavaScript function is called and so before anything else can use it.
+            let engine = unsafe { core::pin::Pin::new_unchecked(&mut *self.inner.engine()) };
+            engine.serialize(arg)?
+        };
+        Ok(self.call(&[&arg]))
+    }
+
+    /// Returns true if the engine is still alive and this is called from the thread of the engine,
+    /// so that the JavaScript function can be called.
+    pub fn is_callable(&self) -> bool {
+        self.inner.is_callable()
+    }
+}
@@ -0,0 +1,61 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qjsengine.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QJSValue>
+qjsengineEvaluate(QJSEngine& engine,
+                  const QString& program,
+                  const QString& fileName,
+                  ::std::int32_t lineNumber)
+{
+  return ::std::make_unique<QJSValue>(
+    engine.evaluate(program, fileName, static_cast<int>(lineNumber)));
+}
+
+::std::unique_ptr<QJSValue>
+qjsengineGlobalObject(const QJSEngine& engine)
+{
+  return ::std::make_unique<QJSValue>(engine.globalObject());
+}
+
+::std::unique_ptr<QJSEngine>
+qjsengineNew()
+{
+  return ::std::make_unique<QJSEngine>();
+}
+
+::std::unique_ptr<QJSValue>
+qjsengineNewArray(QJSEngine& engine, ::std::uint32_t length)
+{
+  return ::std::make_unique<QJSValue>(
+    engine.newArray(static_cast<uint>(length)));
+}
+
+::std::unique_ptr<QJSValue>
+qjsengineNewObject(QJSEngine& engine)
+{
+  return ::std::make_unique<QJSValue>(engine.newObject());
+}
+
+::std::unique_ptr<QJSValue>
+qjsengineNewQObject(QJSEngine& engine, QObject* object)
+{
+  return ::std::make_unique<QJSValue>(engine.newQObject(object));
+}
+
+::std::unique_ptr<QJSValue>
+qjsengineToScriptValue(QJSEngine& engine, const QVariant& value)
+{
+  return ::std::make_unique<QJSValue>(engine.toScriptValue(value));
+}
+
+}
+}
@@ -0,0 +1,169 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qjsengine.h");
+        /// The QJSEngine class provides an environment for evaluating JavaScript code.
+        ///
+        /// Note that [QQmlEngine](crate::QQmlEngine) is a QJSEngine, so the engine of a QML application
+        /// can be used to create values which are passed to QML.
+        #[qobject]
+        type QJSEngine;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsvalue.h");
+        type QJSValue = crate::QJSValue;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        /// Runs the garbage collector.
+        ///
+        /// The garbage collector will attempt to reclaim memory by locating and disposing of objects that are no longer reachable in the script environment.
+        #[rust_name = "collect_garbage"]
+        fn collectGarbage(self: Pin<&mut QJSEngine>);
+
+        /// Throws a run-time error (exception) with the given message.
+        ///
+        /// This method is the C++ counterpart of a throw() expression in JavaScript,
+        /// it should only be called while JavaScript is calling into Rust, such as from an invokable.
+        #[rust_name = "throw_error"]
+        fn throwError(self: Pin<&mut QJSEngine>, message: &QString);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        type QObject = cxx_qt::QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "upcast_qjsengine"]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn upcast(thiz: *const QJSEngine) -> *const QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "downcast_qobject"]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn downcast(base: *const QObject) -> *const QJSEngine;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_evaluate"]
+        fn qjsengineEvaluate(
+            engine: Pin<&mut QJSEngine>,
+            program: &QString,
+            file_name: &QString,
+            line_number: i32,
+        ) -> UniquePtr<QJSValue>;
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_global_object"]
+        fn qjsengineGlobalObject(engine: &QJSEngine) -> UniquePtr<QJSValue>;
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_new"]
+        fn qjsengineNew() -> UniquePtr<QJSEngine>;
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_new_array"]
+        fn qjsengineNewArray(engine: Pin<&mut QJSEngine>, length: u32) -> UniquePtr<QJSValue>;
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_new_object"]
+        fn qjsengineNewObject(engine: Pin<&mut QJSEngine>) -> UniquePtr<QJSValue>;
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_new_qobject"]
+        unsafe fn qjsengineNewQObject(
+            engine: Pin<&mut QJSEngine>,
+            object: *mut QObject,
+        ) -> UniquePtr<QJSValue>;
+        #[doc(hidden)]
+        #[rust_name = "qjsengine_to_script_value"]
+        fn qjsengineToScriptValue(
+            engine: Pin<&mut QJSEngine>,
+            value: &QVariant,
+        ) -> UniquePtr<QJSValue>;
+    }
+
+    // QJSEngine is not trivial to CXX and is not relocatable in Qt
+    // so we need to use references or pointers.
+    impl UniquePtr<QJSEngine> {}
+}
+
+use crate::{QJSValue, QString, QVariant};
+use core::pin::Pin;
+use cxx::UniquePtr;
+use cxx_qt::{QObject, Upcast};
+
+pub use ffi::QJSEngine;
+
+impl Upcast<QObject> for QJSEngine {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QObject {
+        ffi::upcast_qjsengine(this)
+    }
+
+    unsafe fn from_base_ptr(base: *const QObject) -> *const Self {
+        ffi::downcast_qobject(base)
+    }
+}
+
+impl QJSEngine {
+    /// Create a new QJSEngine
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qjsengine_new()
+    }
+
+    /// Evaluates program, using line_number as the base line number, and returns the result of the evaluation.
+    ///
+    /// The file_name is used for error reporting. If the evaluation throws an exception,
+    /// the returned value is the error which was thrown, which can be checked with [QJSValue::is_error].
+    pub fn evaluate(
+        self: Pin<&mut Self>,
+        program: &QString,
+        file_name: &QString,
+        line_number: i32,
+    ) -> UniquePtr<QJSValue> {
+        ffi::qjsengine_evaluate(self, program, file_name, line_number)
+    }
+
+    /// Returns this engine's Global Object.
+    pub fn global_object(&self) -> UniquePtr<QJSValue> {
+        ffi::qjsengine_global_object(self)
+    }
+
+    /// Creates a JavaScript object of class Array with the given length.
+    pub fn new_array(self: Pin<&mut Self>, length: u32) -> UniquePtr<QJSValue> {
+        ffi::qjsengine_new_array(self, length)
+    }
+
+    /// Creates a JavaScript object of class Object.
+    ///
+    /// The prototype of the created object will be the Object prototype object.
+    pub fn new_object(self: Pin<&mut Self>) -> UniquePtr<QJSValue> {
+        ffi::qjsengine_new_object(self)
+    }
+
+    /// Creates a JavaScript object that wraps the given QObject, using JavaScript ownership.
+    ///
+    /// Signals and slots, properties and children of object are available as properties of the created QJSValue.
+    ///
+    /// # Safety
+    ///
+    /// If the object does not have a parent, the engine takes ownership and may delete the object
+    /// once it is garbage collected, so the object must not also be owned by Rust.
+    pub unsafe fn new_qobject(self: Pin<&mut Self>, object: *mut QObject) -> UniquePtr<QJSValue> {
+        ffi::qjsengine_new_qobject(self, object)
+    }
+
+    /// Creates a QJSValue with the contents of the given QVariant.
+    ///
+    /// A QVariantList is converted to an array and a QVariantMap to an object.
+    pub fn to_script_value(self: Pin<&mut Self>, value: &QVariant) -> UniquePtr<QJSValue> {
+        ffi::qjsengine_to_script_value(self, value)
+    }
+}
@@ -0,0 +1,120 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qjsvalue.h"
+
+#include <QtQml/QJSValueIterator>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QJSValue>
+qjsvalueCall(const QJSValue& value, const QJSValueList& args)
+{
+  return ::std::make_unique<QJSValue>(value.call(args));
+}
+
+::std::unique_ptr<QJSValue>
+qjsvalueCallAsConstructor(const QJSValue& value, const QJSValueList& args)
+{
+  return ::std::make_unique<QJSValue>(value.callAsConstructor(args));
+}
+
+::std::unique_ptr<QJSValue>
+qjsvalueCallWithInstance(const QJSValue& value,
+                         const QJSValue& instance,
+                         const QJSValueList& args)
+{
+  return ::std::make_unique<QJSValue>(value.callWithInstance(instance, args));
+}
+
+::std::unique_ptr<QJSValue>
+qjsvalueClone(const QJSValue& value)
+{
+  return ::std::make_unique<QJSValue>(value);
+}
+
+QStringList
+qjsvalueKeys(const QJSValue& value)
+{
+  QStringList keys;
+  QJSValueIterator it(value);
+  while (it.hasNext()) {
+    it.next();
+    keys.append(it.name());
+  }
+  return keys;
+}
+
+void
+qjsvalueListAppend(QJSValueList& list, const QJSValue& value)
+{
+  list.append(value);
+}
+
+::std::unique_ptr<QJSValueList>
+qjsvalueListNew()
+{
+  return ::std::make_unique<QJSValueList>();
+}
+
+::std::unique_ptr<QJSValue>
+qjsvalueNewBool(bool value)
+{
+  return ::std::make_unique<QJSValue>(value);
+}
+
+::std::unique_ptr<QJSValue>
+qjsvalueNewF64(double value)
+{
+  return ::std::make_unique<QJSValue>(value);
+}
+
+::std::unique_ptr<QJSValue>
+qjsvalueNewI32(::std::int32_t value)
+{
+  return ::std::make_unique<QJSValue>(static_cast<int>(value));
+}
+
+::std::unique_ptr<QJSValue>
+qjsvalueNewNull()
+{
+  return ::std::make_unique<QJSValue>(QJSValue::NullValue);
+}
+
+::std::unique_ptr<QJSValue>
+qjsvalueNewQString(const QString& value)
+{
+  return ::std::make_unique<QJSValue>(value);
+}
+
+::std::unique_ptr<QJSValue>
+qjsvalueNewU32(::std::uint32_t value)
+{
+  return ::std::make_unique<QJSValue>(static_cast<uint>(value));
+}
+
+::std::unique_ptr<QJSValue>
+qjsvalueNewUndefined()
+{
+  return ::std::make_unique<QJSValue>(QJSValue::UndefinedValue);
+}
+
+::std::unique_ptr<QJSValue>
+qjsvalueProperty(const QJSValue& value, const QString& name)
+{
+  return ::std::make_unique<QJSValue>(value.property(name));
+}
+
+::std::unique_ptr<QJSValue>
+qjsvaluePropertyAt(const QJSValue& value, ::std::uint32_t index)
+{
+  return ::std::make_unique<QJSValue>(value.property(index));
+}
+
+}
+}
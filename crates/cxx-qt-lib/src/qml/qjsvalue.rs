@@ -0,0 +1,337 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+        include!(<QtCore/QObject>);
+        type QObject = cxx_qt::QObject;
+
+        include!("cxx-qt-lib/qjsvalue.h");
+        /// The QJSValue class acts as a container for Qt/JavaScript data types.
+        ///
+        /// A QJSValue can be a primitive such as a number or a string, or a reference to a
+        /// JavaScript object, array or function. Values are created with the `new_` constructors
+        /// or by a [QJSEngine](crate::QJSEngine), and are returned as a `UniquePtr<QJSValue>`.
+        type QJSValue;
+
+        #[doc(hidden)]
+        type QJSValueList;
+
+        /// Deletes the property with the given name from this object.
+        ///
+        /// Returns true if the property was deleted, otherwise returns false.
+        #[rust_name = "delete_property"]
+        fn deleteProperty(self: Pin<&mut QJSValue>, name: &QString) -> bool;
+
+        /// Returns true if this QJSValue is equal to other, otherwise returns false.
+        ///
+        /// The comparison follows the behavior described in ECMA-262 section 11.9.3, "The Abstract Equality Comparison Algorithm".
+        fn equals(self: &QJSValue, other: &QJSValue) -> bool;
+
+        /// Returns true if this object has an own (not prototype-inherited) property of the given name, otherwise returns false.
+        #[rust_name = "has_own_property"]
+        fn hasOwnProperty(self: &QJSValue, name: &QString) -> bool;
+
+        /// Returns true if this object has a property of the given name, otherwise returns false.
+        #[rust_name = "has_property"]
+        fn hasProperty(self: &QJSValue, name: &QString) -> bool;
+
+        /// Returns true if this QJSValue is an object of the Array class, otherwise returns false.
+        #[rust_name = "is_array"]
+        fn isArray(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is of the primitive type Boolean, otherwise returns false.
+        #[rust_name = "is_bool"]
+        fn isBool(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is a function, otherwise returns false.
+        #[rust_name = "is_callable"]
+        fn isCallable(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is an object of the Date class, otherwise returns false.
+        #[rust_name = "is_date"]
+        fn isDate(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is an object of the Error class, otherwise returns false.
+        #[rust_name = "is_error"]
+        fn isError(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is of the primitive type Null, otherwise returns false.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is of the primitive type Number, otherwise returns false.
+        #[rust_name = "is_number"]
+        fn isNumber(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is of the Object type, otherwise returns false.
+        ///
+        /// Note that function, array, date and QObject values are also objects.
+        #[rust_name = "is_object"]
+        fn isObject(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is a QObject, otherwise returns false.
+        #[rust_name = "is_qobject"]
+        fn isQObject(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is an object of the RegExp class, otherwise returns false.
+        #[rust_name = "is_regexp"]
+        fn isRegExp(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is of the primitive type String, otherwise returns false.
+        #[rust_name = "is_string"]
+        fn isString(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is of the primitive type Undefined or if the managed value has been cleared, otherwise returns false.
+        #[rust_name = "is_undefined"]
+        fn isUndefined(self: &QJSValue) -> bool;
+
+        /// Returns true if this QJSValue is a variant value, otherwise returns false.
+        #[rust_name = "is_variant"]
+        fn isVariant(self: &QJSValue) -> bool;
+
+        /// Sets the value of this QJSValue's property with the given name to the given value.
+        ///
+        /// If this QJSValue is not an object, this function does nothing.
+        #[rust_name = "set_property"]
+        fn setProperty(self: Pin<&mut QJSValue>, name: &QString, value: &QJSValue);
+
+        /// Sets the property at the given array index of this QJSValue to the given value.
+        ///
+        /// If this QJSValue is not an object, this function does nothing.
+        #[rust_name = "set_property_at"]
+        fn setProperty(self: Pin<&mut QJSValue>, index: u32, value: &QJSValue);
+
+        /// Returns true if this QJSValue is equal to other using strict comparison (no conversion), otherwise returns false.
+        #[rust_name = "strictly_equals"]
+        fn strictlyEquals(self: &QJSValue, other: &QJSValue) -> bool;
+
+        /// Returns the boolean value of this QJSValue, using the conversion rules described in ECMA-262 section 9.2, "ToBoolean".
+        #[rust_name = "to_bool"]
+        fn toBool(self: &QJSValue) -> bool;
+
+        /// Returns the signed 32-bit integer value of this QJSValue, using the conversion rules described in ECMA-262 section 9.5, "ToInt32".
+        #[rust_name = "to_int"]
+        fn toInt(self: &QJSValue) -> i32;
+
+        /// Returns the number value of this QJSValue, as defined in ECMA-262 section 9.3, "ToNumber".
+        #[rust_name = "to_number"]
+        fn toNumber(self: &QJSValue) -> f64;
+
+        /// If this QJSValue is a QObject, returns the QObject pointer that the QJSValue represents, otherwise returns a null pointer.
+        #[rust_name = "to_qobject"]
+        fn toQObject(self: &QJSValue) -> *mut QObject;
+
+        /// Returns the string value of this QJSValue, as defined in ECMA-262 section 9.8, "ToString".
+        #[rust_name = "to_qstring"]
+        fn toString(self: &QJSValue) -> QString;
+
+        /// Returns the unsigned 32-bit integer value of this QJSValue, using the conversion rules described in ECMA-262 section 9.6, "ToUint32".
+        #[rust_name = "to_uint"]
+        fn toUInt(self: &QJSValue) -> u32;
+
+        /// Returns the QVariant value of this QJSValue.
+        ///
+        /// Arrays are converted to a QVariantList and objects to a QVariantMap.
+        #[rust_name = "to_variant"]
+        fn toVariant(self: &QJSValue) -> QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_call"]
+        fn qjsvalueCall(value: &QJSValue, args: &QJSValueList) -> UniquePtr<QJSValue>;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_call_as_constructor"]
+        fn qjsvalueCallAsConstructor(value: &QJSValue, args: &QJSValueList) -> UniquePtr<QJSValue>;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_call_with_instance"]
+        fn qjsvalueCallWithInstance(
+            value: &QJSValue,
+            instance: &QJSValue,
+            args: &QJSValueList,
+        ) -> UniquePtr<QJSValue>;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_clone"]
+        fn qjsvalueClone(value: &QJSValue) -> UniquePtr<QJSValue>;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_keys"]
+        fn qjsvalueKeys(value: &QJSValue) -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_list_append"]
+        fn qjsvalueListAppend(list: Pin<&mut QJSValueList>, value: &QJSValue);
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_list_new"]
+        fn qjsvalueListNew() -> UniquePtr<QJSValueList>;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_new_bool"]
+        fn qjsvalueNewBool(value: bool) -> UniquePtr<QJSValue>;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_new_f64"]
+        fn qjsvalueNewF64(value: f64) -> UniquePtr<QJSValue>;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_new_i32"]
+        fn qjsvalueNewI32(value: i32) -> UniquePtr<QJSValue>;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_new_null"]
+        fn qjsvalueNewNull() -> UniquePtr<QJSValue>;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_new_qstring"]
+        fn qjsvalueNewQString(value: &QString) -> UniquePtr<QJSValue>;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_new_u32"]
+        fn qjsvalueNewU32(value: u32) -> UniquePtr<QJSValue>;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_new_undefined"]
+        fn qjsvalueNewUndefined() -> UniquePtr<QJSValue>;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_property"]
+        fn qjsvalueProperty(value: &QJSValue, name: &QString) -> UniquePtr<QJSValue>;
+        #[doc(hidden)]
+        #[rust_name = "qjsvalue_property_at"]
+        fn qjsvaluePropertyAt(value: &QJSValue, index: u32) -> UniquePtr<QJSValue>;
+    }
+
+    // QJSValue references values which are owned by a JavaScript engine
+    // so we use it behind references or pointers.
+    impl UniquePtr<QJSValue> {}
+    impl UniquePtr<QJSValueList> {}
+}
+
+use crate::{QString, QStringList};
+use cxx::UniquePtr;
+use std::fmt;
+
+pub use ffi::QJSValue;
+pub(crate) use ffi::QJSValueList;
+
+pub(crate) fn qjsvalue_list(args: &[&QJSValue]) -> UniquePtr<ffi::QJSValueList> {
+    let mut list = ffi::qjsvalue_list_new();
+    for arg in args {
+        ffi::qjsvalue_list_append(list.pin_mut(), arg);
+    }
+    list
+}
+
+impl QJSValue {
+    /// Constructs a new QJSValue with a boolean value.
+    pub fn new_bool(value: bool) -> UniquePtr<Self> {
+        ffi::qjsvalue_new_bool(value)
+    }
+
+    /// Constructs a new QJSValue with a number value.
+    pub fn new_f64(value: f64) -> UniquePtr<Self> {
+        ffi::qjsvalue_new_f64(value)
+    }
+
+    /// Constructs a new QJSValue with a number value.
+    pub fn new_i32(value: i32) -> UniquePtr<Self> {
+        ffi::qjsvalue_new_i32(value)
+    }
+
+    /// Constructs a new QJSValue with the primitive value null.
+    pub fn new_null() -> UniquePtr<Self> {
+        ffi::qjsvalue_new_null()
+    }
+
+    /// Constructs a new QJSValue with a string value.
+    pub fn new_qstring(value: &QString) -> UniquePtr<Self> {
+        ffi::qjsvalue_new_qstring(value)
+    }
+
+    /// Constructs a new QJSValue with a number value.
+    pub fn new_u32(value: u32) -> UniquePtr<Self> {
+        ffi::qjsvalue_new_u32(value)
+    }
+
+    /// Constructs a new QJSValue with the primitive value undefined.
+    pub fn new_undefined() -> UniquePtr<Self> {
+        ffi::qjsvalue_new_undefined()
+    }
+
+    /// Returns the length of this array, or 0 if this QJSValue is not an array.
+    pub fn array_len(&self) -> u32 {
+        if self.is_array() {
+            self.property(&QString::from("length")).to_uint()
+        } else {
+            0
+        }
+    }
+
+    /// An iterator visiting the elements of this array, in order.
+    ///
+    /// If this QJSValue is not an array, the iterator is empty.
+    pub fn array_iter(&self) -> impl Iterator<Item = UniquePtr<QJSValue>> + '_ {
+        (0..self.array_len()).map(|index| self.property_at(index))
+    }
+
+    /// Calls this QJSValue as a function, passing args as arguments to the function, and using the globalObject() as the "this"-object.
+    ///
+    /// Returns the value returned from the function. If this QJSValue is not callable, an undefined value is returned,
+    /// if calling the function throws an exception, the returned value is the error which was thrown.
+    pub fn call(&self, args: &[&QJSValue]) -> UniquePtr<QJSValue> {
+        ffi::qjsvalue_call(self, &qjsvalue_list(args))
+    }
+
+    /// Creates a new Object and calls this QJSValue as a constructor, using the created object as the "this"-object and passing args as arguments.
+    pub fn call_as_constructor(&self, args: &[&QJSValue]) -> UniquePtr<QJSValue> {
+        ffi::qjsvalue_call_as_constructor(self, &qjsvalue_list(args))
+    }
+
+    /// Calls this QJSValue as a function, using instance as the "this"-object in the function call, and passing args as arguments to the function.
+    pub fn call_with_instance(
+        &self,
+        instance: &QJSValue,
+        args: &[&QJSValue],
+    ) -> UniquePtr<QJSValue> {
+        ffi::qjsvalue_call_with_instance(self, instance, &qjsvalue_list(args))
+    }
+
+    /// Returns a copy of this QJSValue, which refers to the same JavaScript object if this is an object.
+    pub fn clone_value(&self) -> UniquePtr<QJSValue> {
+        ffi::qjsvalue_clone(self)
+    }
+
+    /// Returns the names of the enumerable own properties of this object,
+    /// or an empty list if this QJSValue is not an object.
+    pub fn keys(&self) -> QStringList {
+        ffi::qjsvalue_keys(self)
+    }
+
+    /// Returns the value of this QJSValue's property with the given name.
+    ///
+    /// If no such property exists, an undefined QJSValue is returned.
+    pub fn property(&self, name: &QString) -> UniquePtr<QJSValue> {
+        ffi::qjsvalue_property(self, name)
+    }
+
+    /// Returns the property at the given array index.
+    ///
+    /// If this QJSValue is not an array or the index is out of range, an undefined QJSValue is returned.
+    pub fn property_at(&self, index: u32) -> UniquePtr<QJSValue> {
+        ffi::qjsvalue_property_at(self, index)
+    }
+}
+
+impl fmt::Display for QJSValue {
+    /// Format the QJSValue with the JavaScript `toString` conversion.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_qstring())
+    }
+}
+
+impl fmt::Debug for QJSValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "QJSValue({self})")
+    }
+}
@@ -0,0 +1,713 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Conversions between Rust types implementing serde traits and [QJSValue]
+//!
+//! Structs and maps become JavaScript objects, sequences and tuples become arrays,
+//! and enums use the externally tagged representation, so a unit variant is a string
+//! and other variants are an object with the variant name as the only key.
+
+use crate::{QJSEngine, QJSValue, QString};
+use core::pin::Pin;
+use cxx::UniquePtr;
+use serde::de::{self, DeserializeOwned, IntoDeserializer};
+use serde::ser::{self, Serialize};
+use std::fmt;
+
+/// The error returned when a Rust value cannot be converted to or from a [QJSValue].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QJSValueSerdeError {
+    message: String,
+}
+
+impl QJSValueSerdeError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for QJSValueSerdeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for QJSValueSerdeError {}
+
+impl ser::Error for QJSValueSerdeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::new(msg.to_string())
+    }
+}
+
+impl de::Error for QJSValueSerdeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::new(msg.to_string())
+    }
+}
+
+impl QJSEngine {
+    /// Convert a Rust value into a [QJSValue] created by this engine.
+    ///
+    /// This is available with the `serde` feature.
+    pub fn serialize<T>(
+        self: Pin<&mut Self>,
+        value: &T,
+    ) -> Result<UniquePtr<QJSValue>, QJSValueSerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(Serializer { engine: self })
+    }
+}
+
+impl QJSValue {
+    /// Convert this [QJSValue] into a Rust value.
+    ///
+    /// Integral numbers can be deserialized into any integer type they fit in,
+    /// `null` and `undefined` deserialize into `None` or `()`.
+    /// Functions cannot be deserialized.
+    ///
+    /// This is available with the `serde` feature.
+    pub fn deserialize<T>(&self) -> Result<T, QJSValueSerdeError>
+    where
+        T: DeserializeOwned,
+    {
+        T::deserialize(Deserializer {
+            value: self.clone_value(),
+        })
+    }
+}
+
+fn wrap_variant(
+    engine: Pin<&mut QJSEngine>,
+    variant: Option<&'static str>,
+    value: UniquePtr<QJSValue>,
+) -> UniquePtr<QJSValue> {
+    match variant {
+        Some(variant) => {
+            let mut object = engine.new_object();
+            object
+                .pin_mut()
+                .set_property(&QString::from(variant), &value);
+            object
+        }
+        None => value,
+    }
+}
+
+struct Serializer<'a> {
+    engine: Pin<&'a mut QJSEngine>,
+}
+
+impl<'a> ser::Serializer for Serializer<'a> {
+    type Ok = UniquePtr<QJSValue>;
+    type Error = QJSValueSerdeError;
+
+    type SerializeSeq = ArraySerializer<'a>;
+    type SerializeTuple = ArraySerializer<'a>;
+    type SerializeTupleStruct = ArraySerializer<'a>;
+    type SerializeTupleVariant = ArraySerializer<'a>;
+    type SerializeMap = ObjectSerializer<'a>;
+    type SerializeStruct = ObjectSerializer<'a>;
+    type SerializeStructVariant = ObjectSerializer<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(QJSValue::new_bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i32(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i32(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        Ok(QJSValue::new_i32(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        // JavaScript numbers are doubles, so large integers lose precision
+        Ok(i32::try_from(v)
+            .map(QJSValue::new_i32)
+            .unwrap_or_else(|_| QJSValue::new_f64(v as f64)))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        self.serialize_u32(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        self.serialize_u32(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        Ok(QJSValue::new_u32(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        // JavaScript numbers are doubles, so large integers lose precision
+        Ok(u32::try_from(v)
+            .map(QJSValue::new_u32)
+            .unwrap_or_else(|_| QJSValue::new_f64(v as f64)))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        Ok(QJSValue::new_f64(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(QJSValue::new_qstring(&QString::from(v)))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        ser::Serializer::collect_seq(self, v)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(QJSValue::new_null())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        mut self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = value.serialize(Serializer {
+            engine: self.engine.as_mut(),
+        })?;
+        Ok(wrap_variant(self.engine, Some(variant), value))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(ArraySerializer::new(self.engine, len.unwrap_or(0), None))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(ArraySerializer::new(self.engine, len, None))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(ArraySerializer::new(self.engine, len, None))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(ArraySerializer::new(self.engine, len, Some(variant)))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(ObjectSerializer::new(self.engine, None))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(ObjectSerializer::new(self.engine, None))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(ObjectSerializer::new(self.engine, Some(variant)))
+    }
+}
+
+struct ArraySerializer<'a> {
+    engine: Pin<&'a mut QJSEngine>,
+    array: UniquePtr<QJSValue>,
+    index: u32,
+    variant: Option<&'static str>,
+}
+
+impl<'a> ArraySerializer<'a> {
+    fn new(mut engine: Pin<&'a mut QJSEngine>, len: usize, variant: Option<&'static str>) -> Self {
+        let array = engine
+            .as_mut()
+            .new_array(u32::try_from(len).unwrap_or(u32::MAX));
+        Self {
+            engine,
+            array,
+            index: 0,
+            variant,
+        }
+    }
+
+    fn push<T>(&mut self, value: &T) -> Result<(), QJSValueSerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = value.serialize(Serializer {
+            engine: self.engine.as_mut(),
+        })?;
+        self.array.pin_mut().set_property_at(self.index, &value);
+        self.index += 1;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<UniquePtr<QJSValue>, QJSValueSerdeError> {
+        Ok(wrap_variant(self.engine, self.variant, self.array))
+    }
+}
+
+impl ser::SerializeSeq for ArraySerializer<'_> {
+    type Ok = UniquePtr<QJSValue>;
+    type Error = QJSValueSerdeError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for ArraySerializer<'_> {
+    type Ok = UniquePtr<QJSValue>;
+    type Error = QJSValueSerdeError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for ArraySerializer<'_> {
+    type Ok = UniquePtr<QJSValue>;
+    type Error = QJSValueSerdeError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for ArraySerializer<'_> {
+    type Ok = UniquePtr<QJSValue>;
+    type Error = QJSValueSerdeError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+struct ObjectSerializer<'a> {
+    engine: Pin<&'a mut QJSEngine>,
+    object: UniquePtr<QJSValue>,
+    key: Option<QString>,
+    variant: Option<&'static str>,
+}
+
+impl<'a> ObjectSerializer<'a> {
+    fn new(mut engine: Pin<&'a mut QJSEngine>, variant: Option<&'static str>) -> Self {
+        let object = engine.as_mut().new_object();
+        Self {
+            engine,
+            object,
+            key: None,
+            variant,
+        }
+    }
+
+    fn insert<T>(&mut self, key: &QString, value: &T) -> Result<(), QJSValueSerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = value.serialize(Serializer {
+            engine: self.engine.as_mut(),
+        })?;
+        self.object.pin_mut().set_property(key, &value);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<UniquePtr<QJSValue>, QJSValueSerdeError> {
+        Ok(wrap_variant(self.engine, self.variant, self.object))
+    }
+}
+
+impl ser::SerializeMap for ObjectSerializer<'_> {
+    type Ok = UniquePtr<QJSValue>;
+    type Error = QJSValueSerdeError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = key.serialize(Serializer {
+            engine: self.engine.as_mut(),
+        })?;
+        if !key.is_string() && !key.is_number() {
+            return Err(QJSValueSerdeError::new(
+                "object keys must be strings or numbers",
+            ));
+        }
+        self.key = Some(key.to_qstring());
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = self.key.take().ok_or_else(|| {
+            QJSValueSerdeError::new("serialize_value called before serialize_key")
+        })?;
+        self.insert(&key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for ObjectSerializer<'_> {
+    type Ok = UniquePtr<QJSValue>;
+    type Error = QJSValueSerdeError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.insert(&QString::from(key), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for ObjectSerializer<'_> {
+    type Ok = UniquePtr<QJSValue>;
+    type Error = QJSValueSerdeError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.insert(&QString::from(key), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+struct Deserializer {
+    value: UniquePtr<QJSValue>,
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer {
+    type Error = QJSValueSerdeError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        let value = &self.value;
+        if value.is_undefined() || value.is_null() {
+            visitor.visit_unit()
+        } else if value.is_bool() {
+            visitor.visit_bool(value.to_bool())
+        } else if value.is_number() {
+            let number = value.to_number();
+            // JavaScript only has doubles, so visit integral numbers as integers
+            // so that they can be deserialized into integer types
+            if number.fract() == 0.0 && number >= i64::MIN as f64 && number < i64::MAX as f64 {
+                visitor.visit_i64(number as i64)
+            } else {
+                visitor.visit_f64(number)
+            }
+        } else if value.is_string() || value.is_date() {
+            visitor.visit_string(value.to_qstring().to_string())
+        } else if value.is_array() {
+            let len = value.array_len();
+            visitor.visit_seq(ArrayAccess {
+                array: self.value,
+                index: 0,
+                len,
+            })
+        } else if value.is_callable() {
+            Err(QJSValueSerdeError::new(
+                "JavaScript functions cannot be deserialized",
+            ))
+        } else if value.is_object() {
+            let keys = value.keys().iter().cloned().collect();
+            visitor.visit_map(ObjectAccess {
+                object: self.value,
+                keys,
+                index: 0,
+            })
+        } else {
+            Err(QJSValueSerdeError::new(format!(
+                "unsupported JavaScript value {}",
+                value.to_qstring()
+            )))
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        if self.value.is_undefined() || self.value.is_null() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        if self.value.is_string() {
+            return visitor.visit_enum(self.value.to_qstring().to_string().into_deserializer());
+        }
+
+        let keys = self.value.keys();
+        match keys.get(0) {
+            Some(variant) if keys.len() == 1 && !self.value.is_array() => {
+                visitor.visit_enum(EnumAccess {
+                    variant: variant.to_string(),
+                    value: self.value.property(variant),
+                })
+            }
+            _ => Err(QJSValueSerdeError::new(
+                "expected a string or an object with a single key for an enum",
+            )),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+struct ArrayAccess {
+    array: UniquePtr<QJSValue>,
+    index: u32,
+    len: u32,
+}
+
+impl<'de> de::SeqAccess<'de> for ArrayAccess {
+    type Error = QJSValueSerdeError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        if self.index >= self.len {
+            return Ok(None);
+        }
+
+        let value = self.array.property_at(self.index);
+        self.index += 1;
+        seed.deserialize(Deserializer { value }).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        usize::try_from(self.len - self.index).ok()
+    }
+}
+
+struct ObjectAccess {
+    object: UniquePtr<QJSValue>,
+    keys: Vec<QString>,
+    index: usize,
+}
+
+impl<'de> de::MapAccess<'de> for ObjectAccess {
+    type Error = QJSValueSerdeError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        match self.keys.get(self.index) {
+            Some(key) => seed
+                .deserialize(key.to_string().into_deserializer())
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let key = self
+            .keys
+            .get(self.index)
+            .ok_or_else(|| QJSValueSerdeError::new("next_value called before next_key"))?;
+        let value = self.object.property(key);
+        self.index += 1;
+        seed.deserialize(Deserializer { value })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.keys.len() - self.index)
+    }
+}
+
+struct EnumAccess {
+    variant: String,
+    value: UniquePtr<QJSValue>,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumAccess {
+    type Error = QJSValueSerdeError;
+    type Variant = Deserializer;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(
+            IntoDeserializer::<QJSValueSerdeError>::into_deserializer(self.variant),
+        )?;
+        Ok((variant, Deserializer { value: self.value }))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for Deserializer {
+    type Error = QJSValueSerdeError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        de::Deserializer::deserialize_map(self, visitor)
+    }
+}
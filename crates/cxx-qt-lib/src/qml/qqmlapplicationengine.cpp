@@ -16,5 +16,18 @@ qqmlapplicationengineNew()
   return ::std::make_unique<QQmlApplicationEngine>();
 }
 
+QObject*
+qqmlapplicationengineRootObjectAt(const QQmlApplicationEngine& engine,
+                                  ::std::size_t index)
+{
+  return engine.rootObjects().at(static_cast<QList<QObject*>::size_type>(index));
+}
+
+::std::size_t
+qqmlapplicationengineRootObjectsLen(const QQmlApplicationEngine& engine)
+{
+  return static_cast<::std::size_t>(engine.rootObjects().size());
+}
+
 }
 }
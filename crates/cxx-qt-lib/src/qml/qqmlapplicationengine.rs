@@ -3,9 +3,30 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-#[cxx::bridge]
+#[cxx_qt::bridge]
 mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qqmlapplicationengine.h");
+        /// QQmlApplicationEngine provides a convenient way to load an application from a single QML file.
+        #[qobject]
+        type QQmlApplicationEngine;
+
+        /// This signal is emitted when an object finishes loading.
+        ///
+        /// If loading was successful, object contains a pointer to the loaded object, otherwise the pointer is null.
+        /// The url to the component the object came from is also provided.
+        #[qsignal]
+        #[cxx_name = "objectCreated"]
+        unsafe fn object_created(
+            self: Pin<&mut QQmlApplicationEngine>,
+            object: *mut QObject,
+            url: &QUrl,
+        );
+    }
+
     unsafe extern "C++" {
+        include!("cxx-qt-lib/qmap.h");
+        type QMap_QString_QVariant = crate::QMap<crate::QMapPair_QString_QVariant>;
         include!("cxx-qt-lib/qstring.h");
         type QString = crate::QString;
         include!("cxx-qt-lib/qstringlist.h");
@@ -13,10 +34,8 @@ mod ffi {
         include!("cxx-qt-lib/qurl.h");
         type QUrl = crate::QUrl;
 
-        include!("cxx-qt-lib/qqmlapplicationengine.h");
-        type QQmlApplicationEngine;
-
         include!("cxx-qt/casting.h");
+        type QObject = cxx_qt::QObject;
 
         #[doc(hidden)]
         #[rust_name = "upcast_qqmlapplication_engine"]
@@ -62,6 +81,15 @@ mod ffi {
         #[rust_name = "set_base_url"]
         fn setBaseUrl(self: Pin<&mut QQmlApplicationEngine>, url: &QUrl);
 
+        /// Sets the initial properties with which the QML component gets initialized after it gets loaded.
+        ///
+        /// Unlike context properties these are set directly on the root object, so they must be declared as properties in the QML.
+        #[rust_name = "set_initial_properties"]
+        fn setInitialProperties(
+            self: Pin<&mut QQmlApplicationEngine>,
+            initial_properties: &QMap_QString_QVariant,
+        );
+
         /// Sets paths as the list of directories where the engine searches for installed modules in a URL-based directory structure.
         #[rust_name = "set_import_path_list"]
         fn setImportPathList(self: Pin<&mut QQmlApplicationEngine>, paths: &QStringList);
@@ -85,6 +113,15 @@ mod ffi {
         #[doc(hidden)]
         #[rust_name = "qqmlapplicationengine_new"]
         fn qqmlapplicationengineNew() -> UniquePtr<QQmlApplicationEngine>;
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_root_object_at"]
+        fn qqmlapplicationengineRootObjectAt(
+            engine: &QQmlApplicationEngine,
+            index: usize,
+        ) -> *mut QObject;
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_root_objects_len"]
+        fn qqmlapplicationengineRootObjectsLen(engine: &QQmlApplicationEngine) -> usize;
     }
 
     // QQmlApplicationEngine is not a trivial to CXX and is not relocatable in Qt
@@ -94,8 +131,9 @@ mod ffi {
     impl UniquePtr<QQmlApplicationEngine> {}
 }
 
-use crate::QQmlEngine;
-use cxx_qt::Upcast;
+use crate::{FindChildOption, QObjectExt, QQmlContext, QQmlEngine, QString};
+use core::pin::Pin;
+use cxx_qt::{QObject, Upcast};
 
 pub use ffi::QQmlApplicationEngine;
 
@@ -114,4 +152,53 @@ impl QQmlApplicationEngine {
     pub fn new() -> cxx::UniquePtr<Self> {
         ffi::qqmlapplicationengine_new()
     }
+
+    /// Returns the first object called name that can be cast to type `T`, searching the root objects
+    /// and then recursively their children, or a null pointer if there is no such object.
+    ///
+    /// This can be used to find an object which was loaded from QML by its `objectName`.
+    pub fn find_object<T>(&self, name: &QString) -> *mut T
+    where
+        T: Upcast<QObject>,
+    {
+        for root_ptr in self.root_objects() {
+            // Safety: root objects are valid for as long as the engine is borrowed
+            let root = unsafe { &*root_ptr };
+            if root.object_name() == *name {
+                // Downcast the pointer from rootObjects() rather than the shared reference,
+                // so that the returned pointer can be used to mutate the object
+                //
+                // Safety: the root object is a valid QObject
+                let object = unsafe { T::from_base_ptr(root_ptr) } as *mut T;
+                if !object.is_null() {
+                    return object;
+                }
+            }
+
+            let child = root.find_child::<T>(name, FindChildOption::FindChildrenRecursively);
+            if !child.is_null() {
+                return child;
+            }
+        }
+
+        core::ptr::null_mut()
+    }
+
+    /// Returns a list of all the root objects instantiated by the engine.
+    ///
+    /// This will only contain objects loaded via [QQmlApplicationEngine::load] or a convenience constructor,
+    /// properties and invokables of these objects can then be accessed through [QObjectExt] and
+    /// [QMetaObject::invoke_method](crate::QMetaObject::invoke_method).
+    pub fn root_objects(&self) -> Vec<*mut QObject> {
+        (0..ffi::qqmlapplicationengine_root_objects_len(self))
+            .map(|index| ffi::qqmlapplicationengine_root_object_at(self, index))
+            .collect()
+    }
+
+    /// Returns the engine's root context.
+    ///
+    /// Context properties set on the root context before [QQmlApplicationEngine::load] is called are available to the loaded QML.
+    pub fn root_context(self: Pin<&mut Self>) -> Pin<&mut QQmlContext> {
+        self.upcast_pin().root_context()
+    }
 }
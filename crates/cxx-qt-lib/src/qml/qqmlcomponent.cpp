@@ -0,0 +1,45 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qqmlcomponent.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QObject*
+qqmlcomponentCreate(QQmlComponent& component,
+                    const QVariantMap& initialProperties,
+                    QObject* parent)
+{
+  auto* object = component.createWithInitialProperties(initialProperties);
+  if (object != nullptr && parent != nullptr) {
+    object->setParent(parent);
+  }
+  return object;
+}
+
+void
+qqmlcomponentCreateIncubated(QQmlComponent& component,
+                             CxxQtQmlIncubator& incubator)
+{
+  component.create(incubator);
+}
+
+::std::unique_ptr<QList_QQmlError>
+qqmlcomponentErrors(const QQmlComponent& component)
+{
+  return ::std::make_unique<QList_QQmlError>(component.errors());
+}
+
+::std::unique_ptr<QQmlComponent>
+qqmlcomponentNew(QQmlEngine& engine)
+{
+  return ::std::make_unique<QQmlComponent>(&engine);
+}
+
+}
+}
@@ -0,0 +1,252 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// Specifies the loading status of the QQmlComponent.
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum QQmlComponentStatus {
+        /// This QQmlComponent has no data. Call [QQmlComponent::load_url] or [QQmlComponent::set_data] to add QML content.
+        Null,
+        /// This QQmlComponent is ready and [QQmlComponent::create] may be called.
+        Ready,
+        /// This QQmlComponent is loading network data.
+        Loading,
+        /// An error has occurred. Call [QQmlComponent::errors] to retrieve a list of errors.
+        Error,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qqmlcomponent.h");
+        /// Components are reusable, encapsulated QML types with well-defined interfaces.
+        ///
+        /// A QQmlComponent instance can be created from a QML file or from QML source in memory,
+        /// and then used to create instances of the QML type from Rust.
+        #[qobject]
+        type QQmlComponent;
+
+        /// Emitted whenever the component's status changes.
+        #[qsignal]
+        #[cxx_name = "statusChanged"]
+        fn status_changed(self: Pin<&mut QQmlComponent>, status: QQmlComponentStatus);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qmap.h");
+        type QMap_QString_QVariant = crate::QMap<crate::QMapPair_QString_QVariant>;
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = crate::QQmlEngine;
+        include!("cxx-qt-lib/qqmlerror.h");
+        type QList_QQmlError = crate::QQmlErrorList;
+        include!("cxx-qt-lib/qqmlincubator.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type CxxQtQmlIncubator = crate::QQmlIncubator;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+
+        type QQmlComponentStatus;
+
+        /// Return the QQmlEngine this component belongs to, or a null pointer if the engine has been destroyed.
+        fn engine(self: &QQmlComponent) -> *mut QQmlEngine;
+
+        /// Returns true if status() == [QQmlComponentStatus::Error].
+        #[rust_name = "is_error"]
+        fn isError(self: &QQmlComponent) -> bool;
+
+        /// Returns true if status() == [QQmlComponentStatus::Loading].
+        #[rust_name = "is_loading"]
+        fn isLoading(self: &QQmlComponent) -> bool;
+
+        /// Returns true if status() == [QQmlComponentStatus::Null].
+        #[rust_name = "is_null"]
+        fn isNull(self: &QQmlComponent) -> bool;
+
+        /// Returns true if status() == [QQmlComponentStatus::Ready].
+        #[rust_name = "is_ready"]
+        fn isReady(self: &QQmlComponent) -> bool;
+
+        /// Load the QQmlComponent from the provided url.
+        ///
+        /// Ensure that the URL provided is full and correct, in particular, use `QUrl::from_local_file`
+        /// when loading a file from the local filesystem.
+        #[rust_name = "load_url"]
+        fn loadUrl(self: Pin<&mut QQmlComponent>, url: &QUrl);
+
+        /// The progress of loading the component, from 0.0 (nothing loaded) to 1.0 (finished).
+        fn progress(self: &QQmlComponent) -> f64;
+
+        /// Sets the QQmlComponent to use the given QML data.
+        ///
+        /// If url is provided, it is used to set the component name and to provide a base path for items resolved by this component.
+        #[rust_name = "set_data"]
+        fn setData(self: Pin<&mut QQmlComponent>, data: &QByteArray, url: &QUrl);
+
+        /// The component's current status.
+        fn status(self: &QQmlComponent) -> QQmlComponentStatus;
+
+        /// The component URL. This is the URL passed to either the constructor, or the [QQmlComponent::load_url] or [QQmlComponent::set_data] methods.
+        fn url(self: &QQmlComponent) -> QUrl;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        type QObject = cxx_qt::QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "upcast_qqmlcomponent"]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn upcast(thiz: *const QQmlComponent) -> *const QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "downcast_qobject"]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn downcast(base: *const QObject) -> *const QQmlComponent;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_create"]
+        unsafe fn qqmlcomponentCreate(
+            component: Pin<&mut QQmlComponent>,
+            initial_properties: &QMap_QString_QVariant,
+            parent: *mut QObject,
+        ) -> *mut QObject;
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_create_incubated"]
+        fn qqmlcomponentCreateIncubated(
+            component: Pin<&mut QQmlComponent>,
+            incubator: Pin<&mut CxxQtQmlIncubator>,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_errors"]
+        fn qqmlcomponentErrors(component: &QQmlComponent) -> UniquePtr<QList_QQmlError>;
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_new"]
+        fn qqmlcomponentNew(engine: Pin<&mut QQmlEngine>) -> UniquePtr<QQmlComponent>;
+    }
+
+    // QQmlComponent is not trivial to CXX and is not relocatable in Qt
+    // so we need to use references or pointers.
+    impl UniquePtr<QQmlComponent> {}
+}
+
+use crate::{QMap, QMapPair_QString_QVariant, QQmlEngine, QQmlError, QQmlIncubator};
+use core::pin::Pin;
+use cxx_qt::{QBox, QObject, Upcast};
+use std::fmt;
+
+pub use ffi::{QQmlComponent, QQmlComponentStatus};
+
+impl Upcast<QObject> for QQmlComponent {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QObject {
+        ffi::upcast_qqmlcomponent(this)
+    }
+
+    unsafe fn from_base_ptr(base: *const QObject) -> *const Self {
+        ffi::downcast_qobject(base)
+    }
+}
+
+/// The error returned when a [QQmlComponent] fails to create an object,
+/// containing the errors which occurred during the last compile or create operation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QQmlComponentError {
+    errors: Vec<QQmlError>,
+}
+
+impl QQmlComponentError {
+    /// Returns the errors which caused the component to fail.
+    ///
+    /// This is empty if the component was not ready, for example as it is still loading.
+    pub fn errors(&self) -> &[QQmlError] {
+        &self.errors
+    }
+}
+
+impl fmt::Display for QQmlComponentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.errors.is_empty() {
+            return write!(f, "QQmlComponent could not create an object");
+        }
+
+        for (index, error) in self.errors.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for QQmlComponentError {}
+
+impl QQmlComponent {
+    /// Create a QQmlComponent with no data for the given engine,
+    /// set the data with [QQmlComponent::load_url] or [QQmlComponent::set_data].
+    ///
+    /// # Safety
+    ///
+    /// The component must be dropped before the engine is destroyed.
+    pub unsafe fn new(engine: Pin<&mut QQmlEngine>) -> cxx::UniquePtr<Self> {
+        ffi::qqmlcomponent_new(engine)
+    }
+
+    /// Create an object instance from this component.
+    ///
+    /// The returned [QBox] owns the object, so the object is destroyed when the [QBox] is dropped.
+    pub fn create(self: Pin<&mut Self>) -> Result<QBox<QObject>, QQmlComponentError> {
+        self.create_with_initial_properties(&QMap::default(), None)
+    }
+
+    /// Start creating an object instance from this component using the given incubator.
+    ///
+    /// The status of the creation can then be followed with [QQmlIncubator::on_status_changed].
+    pub fn create_incubated(self: Pin<&mut Self>, incubator: Pin<&mut QQmlIncubator>) {
+        ffi::qqmlcomponent_create_incubated(self, incubator)
+    }
+
+    /// Create an object instance of this component, with the given initial properties set on the object.
+    ///
+    /// If a parent is given the object is owned by the parent, otherwise the returned [QBox] owns the object.
+    pub fn create_with_initial_properties(
+        mut self: Pin<&mut Self>,
+        initial_properties: &QMap<QMapPair_QString_QVariant>,
+        parent: Option<Pin<&mut QObject>>,
+    ) -> Result<QBox<QObject>, QQmlComponentError> {
+        let parent = parent.map_or(core::ptr::null_mut(), |parent| {
+            // Safety: the parent is not moved, only its address is passed to Qt
+            unsafe { parent.get_unchecked_mut() as *mut QObject }
+        });
+        // Safety: the parent is either null or a valid QObject
+        let object =
+            unsafe { ffi::qqmlcomponent_create(self.as_mut(), initial_properties, parent) };
+        if object.is_null() {
+            Err(QQmlComponentError {
+                errors: self.errors(),
+            })
+        } else {
+            // Safety: the caller takes ownership of the object from QQmlComponent::create
+            // or it is owned by the parent
+            Ok(unsafe { QBox::from_raw(object) })
+        }
+    }
+
+    /// Returns the list of errors that occurred during the last compile or create operation.
+    ///
+    /// An empty list is returned if [QQmlComponent::is_error] is not set.
+    pub fn errors(&self) -> Vec<QQmlError> {
+        ffi::qqmlcomponent_errors(self)
+            .as_ref()
+            .map(Vec::<QQmlError>::from)
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,123 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qqmlcontext.h");
+        /// The QQmlContext class defines a context within a QML engine.
+        ///
+        /// Contexts hold the properties which are visible by name to the QML that is instantiated within them,
+        /// the root context of an engine can be retrieved with [QQmlEngine::root_context](crate::QQmlEngine::root_context).
+        #[qobject]
+        type QQmlContext;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = crate::QQmlEngine;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        /// Returns the base url of the component, or the containing component if none is set.
+        #[rust_name = "base_url"]
+        fn baseUrl(self: &QQmlContext) -> QUrl;
+
+        /// Returns the context object, or a null pointer if there is no context object.
+        #[rust_name = "context_object"]
+        fn contextObject(self: &QQmlContext) -> *mut QObject;
+
+        /// Returns the value of the name property for this context as a QVariant.
+        #[rust_name = "context_property"]
+        fn contextProperty(self: &QQmlContext, name: &QString) -> QVariant;
+
+        /// Return the context's QQmlEngine, or a null pointer if the context has no QQmlEngine or the QQmlEngine was destroyed.
+        fn engine(self: &QQmlContext) -> *mut QQmlEngine;
+
+        /// Returns whether the context is valid.
+        ///
+        /// To be valid, a context must have a engine, and it's contextObject(), if any, must not have been deleted.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QQmlContext) -> bool;
+
+        /// Return the context's parent QQmlContext, or a null pointer if this context has no parent or if the parent has been destroyed.
+        #[rust_name = "parent_context"]
+        fn parentContext(self: &QQmlContext) -> *mut QQmlContext;
+
+        /// Explicitly sets the url resolvedUrl() will use for relative references to base_url.
+        #[rust_name = "set_base_url"]
+        fn setBaseUrl(self: Pin<&mut QQmlContext>, base_url: &QUrl);
+
+        /// Set the context object.
+        ///
+        /// The properties of the context object are visible by name to the QML in this context.
+        ///
+        /// # Safety
+        ///
+        /// The object must be either null or valid for as long as the context is used,
+        /// ownership of the object is not transferred to the context.
+        #[rust_name = "set_context_object"]
+        unsafe fn setContextObject(self: Pin<&mut QQmlContext>, object: *mut QObject);
+
+        /// Set the value of the name property on this context.
+        ///
+        /// Properties must be set before the QML which uses them is loaded,
+        /// as changing a context property invalidates all bindings in the context.
+        #[rust_name = "set_context_property"]
+        fn setContextProperty(self: Pin<&mut QQmlContext>, name: &QString, value: &QVariant);
+
+        /// Set the value of the name property on this context to the given QObject,
+        /// this can be used to expose a Rust defined QObject to QML.
+        ///
+        /// Properties must be set before the QML which uses them is loaded,
+        /// as changing a context property invalidates all bindings in the context.
+        ///
+        /// # Safety
+        ///
+        /// The object must be either null or valid for as long as the context is used,
+        /// ownership of the object is not transferred to the context.
+        #[rust_name = "set_context_property_object"]
+        unsafe fn setContextProperty(
+            self: Pin<&mut QQmlContext>,
+            name: &QString,
+            value: *mut QObject,
+        );
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        type QObject = cxx_qt::QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "upcast_qqmlcontext"]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn upcast(thiz: *const QQmlContext) -> *const QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "downcast_qobject"]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn downcast(base: *const QObject) -> *const QQmlContext;
+    }
+}
+
+use cxx_qt::{QObject, Upcast};
+
+pub use ffi::QQmlContext;
+
+impl Upcast<QObject> for QQmlContext {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QObject {
+        ffi::upcast_qqmlcontext(this)
+    }
+
+    unsafe fn from_base_ptr(base: *const QObject) -> *const Self {
+        ffi::downcast_qobject(base)
+    }
+}
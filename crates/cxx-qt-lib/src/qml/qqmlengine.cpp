@@ -16,5 +16,12 @@ qqmlengineNew()
   return ::std::make_unique<QQmlEngine>();
 }
 
+QQmlContext&
+qqmlengineRootContext(QQmlEngine& engine)
+{
+  // The root context is created with the engine and is never null
+  return *engine.rootContext();
+}
+
 }
 }
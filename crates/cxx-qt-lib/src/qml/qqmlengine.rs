@@ -17,9 +17,17 @@ mod ffi {
         /// This signal is emitted when the QML loaded by the engine would like to quit.
         #[qsignal]
         fn quit(self: Pin<&mut QQmlEngine>);
+
+        /// This signal is emitted when warning messages are generated by QML.
+        #[qsignal]
+        fn warnings(self: Pin<&mut QQmlEngine>, warnings: &QList_QQmlError);
     }
 
     unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlcontext.h");
+        type QQmlContext = crate::QQmlContext;
+        include!("cxx-qt-lib/qqmlerror.h");
+        type QList_QQmlError = crate::QQmlErrorList;
         include!("cxx-qt-lib/qstring.h");
         type QString = crate::QString;
         include!("cxx-qt-lib/qstringlist.h");
@@ -56,6 +64,13 @@ mod ffi {
         #[rust_name = "plugin_path_list"]
         fn pluginPathList(self: &QQmlEngine) -> QStringList;
 
+        /// Refreshes all binding expressions that use strings marked for translation.
+        ///
+        /// Call this after a new translator has been installed with
+        /// [QGuiApplication::install_translator](crate::QGuiApplication::install_translator)
+        /// to ensure that the user interface shows up-to-date translations.
+        fn retranslate(self: Pin<&mut QQmlEngine>);
+
         /// Set the base URL for this engine to url.
         #[rust_name = "set_base_url"]
         fn setBaseUrl(self: Pin<&mut QQmlEngine>, url: &QUrl);
@@ -77,11 +92,33 @@ mod ffi {
         fn setPluginPathList(self: Pin<&mut QQmlEngine>, paths: &QStringList);
     }
 
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        include!("cxx-qt-lib/qjsengine.h");
+        type QJSEngine = crate::QJSEngine;
+
+        #[doc(hidden)]
+        #[rust_name = "upcast_qqmlengine"]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn upcast(thiz: *const QQmlEngine) -> *const QJSEngine;
+
+        #[doc(hidden)]
+        #[rust_name = "downcast_qjsengine"]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn downcast(base: *const QJSEngine) -> *const QQmlEngine;
+    }
+
     #[namespace = "rust::cxxqtlib1"]
     unsafe extern "C++" {
         #[doc(hidden)]
         #[rust_name = "qqmlengine_new"]
         fn qqmlengineNew() -> UniquePtr<QQmlEngine>;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_root_context"]
+        fn qqmlengineRootContext(engine: Pin<&mut QQmlEngine>) -> Pin<&mut QQmlContext>;
     }
 
     // QQmlEngine is not a trivial to CXX and is not relocatable in Qt
@@ -91,11 +128,33 @@ mod ffi {
     impl UniquePtr<QQmlEngine> {}
 }
 
+use crate::{QJSEngine, QQmlContext};
+use core::pin::Pin;
+use cxx_qt::Upcast;
+
 pub use ffi::QQmlEngine;
 
+impl Upcast<QJSEngine> for QQmlEngine {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QJSEngine {
+        ffi::upcast_qqmlengine(this)
+    }
+
+    unsafe fn from_base_ptr(base: *const QJSEngine) -> *const Self {
+        ffi::downcast_qjsengine(base)
+    }
+}
+
 impl QQmlEngine {
     /// Create a new QQmlEngine
     pub fn new() -> cxx::UniquePtr<Self> {
         ffi::qqmlengine_new()
     }
+
+    /// Returns the engine's root context.
+    ///
+    /// The root context is automatically created by the engine,
+    /// data that should be available to all QML component instances instantiated by the engine should be put in the root context.
+    pub fn root_context(self: Pin<&mut Self>) -> Pin<&mut QQmlContext> {
+        ffi::qqmlengine_root_context(self)
+    }
 }
@@ -0,0 +1,26 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qqmlerror.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+const QQmlError&
+qqmlerrorListAt(const QList_QQmlError& list, ::std::size_t index)
+{
+  return list.at(static_cast<QList_QQmlError::size_type>(index));
+}
+
+::std::size_t
+qqmlerrorListLen(const QList_QQmlError& list)
+{
+  return static_cast<::std::size_t>(list.size());
+}
+
+}
+}
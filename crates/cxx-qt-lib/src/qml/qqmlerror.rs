@@ -0,0 +1,144 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+
+        include!("cxx-qt-lib/qqmlerror.h");
+        #[doc(hidden)]
+        type QQmlError;
+
+        /// A list of the errors or warnings reported by the QML engine.
+        #[cxx_name = "QList_QQmlError"]
+        type QQmlErrorList;
+
+        fn column(self: &QQmlError) -> i32;
+        fn description(self: &QQmlError) -> QString;
+        fn line(self: &QQmlError) -> i32;
+        fn url(self: &QQmlError) -> QUrl;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qqmlerror_list_at"]
+        fn qqmlerrorListAt(list: &QQmlErrorList, index: usize) -> &QQmlError;
+        #[doc(hidden)]
+        #[rust_name = "qqmlerror_list_len"]
+        fn qqmlerrorListLen(list: &QQmlErrorList) -> usize;
+    }
+
+    impl UniquePtr<QQmlErrorList> {}
+}
+
+use crate::{QString, QUrl};
+use std::fmt;
+
+pub use ffi::QQmlErrorList;
+
+/// The QQmlError class encapsulates a QML error or warning, such as a syntax error in a QML file.
+///
+/// This can be used as a Rust error type, the [Display](fmt::Display) implementation matches
+/// the output of `QQmlError::toString()` in C++.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QQmlError {
+    column: i32,
+    description: QString,
+    line: i32,
+    url: QUrl,
+}
+
+impl QQmlError {
+    /// Returns the error column number, or -1 if the column is not known.
+    pub fn column(&self) -> i32 {
+        self.column
+    }
+
+    /// Returns the error description.
+    pub fn description(&self) -> &QString {
+        &self.description
+    }
+
+    /// Returns the error line number, or -1 if the line is not known.
+    pub fn line(&self) -> i32 {
+        self.line
+    }
+
+    /// Returns the url for the file that caused this error.
+    pub fn url(&self) -> &QUrl {
+        &self.url
+    }
+}
+
+impl From<&ffi::QQmlError> for QQmlError {
+    fn from(error: &ffi::QQmlError) -> Self {
+        Self {
+            column: error.column(),
+            description: error.description(),
+            line: error.line(),
+            url: error.url(),
+        }
+    }
+}
+
+impl fmt::Display for QQmlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let url = self.url.to_string();
+        if url.is_empty() {
+            write!(f, "<Unknown File>")?;
+        } else {
+            write!(f, "{url}")?;
+        }
+
+        if self.line != -1 {
+            write!(f, ":{}", self.line)?;
+            if self.column != -1 {
+                write!(f, ":{}", self.column)?;
+            }
+        }
+
+        write!(f, ": {}", self.description)
+    }
+}
+
+impl std::error::Error for QQmlError {}
+
+impl QQmlErrorList {
+    /// Returns the error at the given index, or None if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<QQmlError> {
+        if index < self.len() {
+            Some(QQmlError::from(ffi::qqmlerror_list_at(self, index)))
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if there are no errors in the list.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// An iterator visiting all errors in the list.
+    pub fn iter(&self) -> impl Iterator<Item = QQmlError> + '_ {
+        (0..self.len()).map(|index| QQmlError::from(ffi::qqmlerror_list_at(self, index)))
+    }
+
+    /// Returns the number of errors in the list.
+    pub fn len(&self) -> usize {
+        ffi::qqmlerror_list_len(self)
+    }
+}
+
+impl From<&QQmlErrorList> for Vec<QQmlError> {
+    /// Convert a reference to a [QQmlErrorList] into a [Vec] of [QQmlError]
+    fn from(list: &QQmlErrorList) -> Self {
+        list.iter().collect()
+    }
+}
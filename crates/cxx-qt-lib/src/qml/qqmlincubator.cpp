@@ -0,0 +1,26 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qqmlincubator.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QList_QQmlError>
+qqmlincubatorErrors(const CxxQtQmlIncubator& incubator)
+{
+  return ::std::make_unique<QList_QQmlError>(incubator.errors());
+}
+
+::std::unique_ptr<CxxQtQmlIncubator>
+qqmlincubatorNew(QQmlIncubatorIncubationMode mode)
+{
+  return ::std::make_unique<CxxQtQmlIncubator>(mode);
+}
+
+}
+}
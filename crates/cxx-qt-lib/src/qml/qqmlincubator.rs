@@ -0,0 +1,183 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// Specifies the mode the incubator operates in.
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum QQmlIncubatorIncubationMode {
+        /// The object will be created asynchronously.
+        Asynchronous,
+        /// If the object is being created in a context that is already part of an asynchronous creation,
+        /// this incubator will join that existing incubation and execute asynchronously.
+        /// Otherwise the object is created synchronously.
+        AsynchronousIfNested,
+        /// The object will be created synchronously.
+        Synchronous,
+    }
+
+    /// Specifies the status of the incubator.
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum QQmlIncubatorStatus {
+        /// Incubation is not in progress.
+        Null,
+        /// Incubation has completed successfully.
+        Ready,
+        /// Incubation is in progress.
+        Loading,
+        /// Incubation has failed.
+        Error,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmap.h");
+        type QMap_QString_QVariant = crate::QMap<crate::QMapPair_QString_QVariant>;
+        include!("cxx-qt-lib/qqmlerror.h");
+        type QList_QQmlError = crate::QQmlErrorList;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt::QObject;
+
+        include!("cxx-qt-lib/qqmlincubator.h");
+        type QQmlIncubatorIncubationMode;
+        type QQmlIncubatorStatus;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        /// The QQmlIncubator class allows QML objects to be created asynchronously.
+        ///
+        /// Creation of QML objects can take time, to avoid blocking the event loop a component can instead
+        /// be created with [QQmlComponent::create_incubated](crate::QQmlComponent::create_incubated).
+        /// The object is then created over multiple iterations of the event loop, or immediately with
+        /// [QQmlIncubator::force_completion].
+        #[cxx_name = "CxxQtQmlIncubator"]
+        type QQmlIncubator;
+
+        /// Clears the incubator.
+        ///
+        /// Any in-progress incubation is aborted. If the incubator is in the Ready state, the created object is not deleted.
+        fn clear(self: Pin<&mut QQmlIncubator>);
+
+        /// Force any in-progress incubation to finish synchronously.
+        ///
+        /// Once this call returns, the incubator will not be in the Loading state.
+        #[rust_name = "force_completion"]
+        fn forceCompletion(self: Pin<&mut QQmlIncubator>);
+
+        /// Return the incubation mode passed to [QQmlIncubator::new].
+        #[rust_name = "incubation_mode"]
+        fn incubationMode(self: &QQmlIncubator) -> QQmlIncubatorIncubationMode;
+
+        /// Returns true if the incubator's status is Error.
+        #[rust_name = "is_error"]
+        fn isError(self: &QQmlIncubator) -> bool;
+
+        /// Returns true if the incubator's status is Loading.
+        #[rust_name = "is_loading"]
+        fn isLoading(self: &QQmlIncubator) -> bool;
+
+        /// Returns true if the incubator's status is Null.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QQmlIncubator) -> bool;
+
+        /// Returns true if the incubator's status is Ready.
+        #[rust_name = "is_ready"]
+        fn isReady(self: &QQmlIncubator) -> bool;
+
+        /// Return the incubated object if the status is Ready, otherwise a null pointer.
+        ///
+        /// The caller takes ownership of the object, unless it has been given a parent.
+        fn object(self: &QQmlIncubator) -> *mut QObject;
+
+        /// Stores a mapping from property names to initial values, with which the incubated component will be initialized.
+        #[rust_name = "set_initial_properties"]
+        fn setInitialProperties(
+            self: Pin<&mut QQmlIncubator>,
+            initial_properties: &QMap_QString_QVariant,
+        );
+
+        /// Return the current status of the incubator.
+        fn status(self: &QQmlIncubator) -> QQmlIncubatorStatus;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qqmlincubator_errors"]
+        fn qqmlincubatorErrors(incubator: &QQmlIncubator) -> UniquePtr<QList_QQmlError>;
+        #[doc(hidden)]
+        #[rust_name = "qqmlincubator_new"]
+        fn qqmlincubatorNew(mode: QQmlIncubatorIncubationMode) -> UniquePtr<QQmlIncubator>;
+        #[doc(hidden)]
+        #[rust_name = "qqmlincubator_set_status_changed"]
+        fn qqmlincubatorSetStatusChanged(
+            incubator: Pin<&mut QQmlIncubator>,
+            func: fn(&mut QQmlIncubatorStatusChangedFn, &QQmlIncubator, QQmlIncubatorStatus),
+            arg: Box<QQmlIncubatorStatusChangedFn>,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QQmlIncubatorStatusChangedFn;
+    }
+
+    // QQmlIncubator is not trivial to CXX and is not relocatable in Qt
+    // so we need to use references or pointers.
+    impl UniquePtr<QQmlIncubator> {}
+}
+
+use crate::QQmlError;
+use core::pin::Pin;
+
+pub use ffi::{QQmlIncubator, QQmlIncubatorIncubationMode, QQmlIncubatorStatus};
+
+#[doc(hidden)]
+pub struct QQmlIncubatorStatusChangedFn {
+    inner: Box<dyn FnMut(&QQmlIncubator, QQmlIncubatorStatus)>,
+}
+
+fn call_status_changed_fn(
+    arg: &mut QQmlIncubatorStatusChangedFn,
+    incubator: &QQmlIncubator,
+    status: QQmlIncubatorStatus,
+) {
+    (arg.inner)(incubator, status)
+}
+
+impl QQmlIncubator {
+    /// Create a new incubator with the specified mode.
+    pub fn new(mode: QQmlIncubatorIncubationMode) -> cxx::UniquePtr<Self> {
+        ffi::qqmlincubator_new(mode)
+    }
+
+    /// Return the list of errors encountered while incubating the object.
+    pub fn errors(&self) -> Vec<QQmlError> {
+        ffi::qqmlincubator_errors(self)
+            .as_ref()
+            .map(Vec::<QQmlError>::from)
+            .unwrap_or_default()
+    }
+
+    /// Set the closure which is called when the status of the incubator changes,
+    /// replacing any previously set closure.
+    ///
+    /// Once the status is [QQmlIncubatorStatus::Ready] the created object can be retrieved with [QQmlIncubator::object].
+    pub fn on_status_changed<F>(self: Pin<&mut Self>, closure: F)
+    where
+        F: FnMut(&QQmlIncubator, QQmlIncubatorStatus) + 'static,
+    {
+        ffi::qqmlincubator_set_status_changed(
+            self,
+            call_status_changed_fn,
+            Box::new(QQmlIncubatorStatusChangedFn {
+                inner: Box::new(closure),
+            }),
+        );
+    }
+}
@@ -0,0 +1,39 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qquickimageprovider;
+pub use qquickimageprovider::{QQuickAsyncImageProvider, QQuickImageProvider, QQuickImageResponse};
+
+mod qquickitem;
+pub use qquickitem::{
+    QQuickItem, QQuickItemBase, QQuickItemFlag, QQuickItemOverrides, QQuickItemUpdatePaintNodeData,
+};
+
+mod qquickpainteditem;
+pub use qquickpainteditem::{
+    QQuickPaintedItem, QQuickPaintedItemOverrides, QQuickPaintedItemPerformanceHint,
+    QQuickPaintedItemRenderTarget,
+};
+
+mod qquickview;
+pub use qquickview::{QQuickView, QQuickViewResizeMode, QQuickViewStatus};
+
+mod qquickwindow;
+pub use qquickwindow::{QQuickCloseEvent, QQuickWindow};
+
+mod qsgflatcolormaterial;
+pub use qsgflatcolormaterial::{QSGFlatColorMaterial, QSGMaterial};
+
+mod qsggeometry;
+pub use qsggeometry::{QSGGeometry, QSGGeometryDrawingMode};
+
+mod qsggeometrynode;
+pub use qsggeometrynode::QSGGeometryNode;
+
+mod qsgnode;
+pub use qsgnode::{QSGNode, QSGNodeDirtyStateBit, QSGNodeFlag};
+
+mod qsgsimplerectnode;
+pub use qsgsimplerectnode::QSGSimpleRectNode;
@@ -0,0 +1,140 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qquickimageprovider.h"
+
+#include <QtCore/QMetaObject>
+#include <QtCore/QMutexLocker>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+CxxQtQuickImageResponse::~CxxQtQuickImageResponse()
+{
+  if (!m_finished) {
+    finish(QImage(),
+           QStringLiteral("The image response was dropped without finishing"));
+  }
+}
+
+void
+CxxQtQuickImageResponse::finish(const QImage& image,
+                                const QString& errorString) const
+{
+  const QMutexLocker locker(&m_mutex);
+  if (m_finished) {
+    return;
+  }
+  m_finished = true;
+
+  // The response object may already have been deleted if it was cancelled
+  if (m_object != nullptr) {
+    m_object->m_image = image;
+    m_object->m_errorString = errorString;
+
+    // Emit finished from the thread of the response object, as the response
+    // may be finished before it has been returned to Qt Quick
+    QMetaObject::invokeMethod(
+      m_object,
+      [object = m_object]() { Q_EMIT object->finished(); },
+      Qt::QueuedConnection);
+  }
+}
+
+bool
+CxxQtQuickImageResponse::isCancelled() const
+{
+  return m_cancelled.load();
+}
+
+bool
+CxxQtQuickImageResponse::isFinished() const
+{
+  const QMutexLocker locker(&m_mutex);
+  return m_finished;
+}
+
+CxxQtQuickImageResponseObject::CxxQtQuickImageResponseObject(
+  const ::std::shared_ptr<CxxQtQuickImageResponse>& state)
+  : m_state(state)
+{
+  const QMutexLocker locker(&state->m_mutex);
+  state->m_object = this;
+}
+
+CxxQtQuickImageResponseObject::~CxxQtQuickImageResponseObject()
+{
+  if (auto state = m_state.lock()) {
+    const QMutexLocker locker(&state->m_mutex);
+    state->m_object = nullptr;
+  }
+}
+
+void
+CxxQtQuickImageResponseObject::cancel()
+{
+  if (auto state = m_state.lock()) {
+    state->m_cancelled.store(true);
+  }
+}
+
+QString
+CxxQtQuickImageResponseObject::errorString() const
+{
+  return m_errorString;
+}
+
+QQuickTextureFactory*
+CxxQtQuickImageResponseObject::textureFactory() const
+{
+  return QQuickTextureFactory::textureFactoryForImage(m_image);
+}
+
+CxxQtQuickImageProvider::CxxQtQuickImageProvider(RequestImage requestImage)
+  : QQuickImageProvider(QQmlImageProviderBase::Image)
+  , m_requestImage(::std::move(requestImage))
+{
+}
+
+QImage
+CxxQtQuickImageProvider::requestImage(const QString& id,
+                                      QSize* size,
+                                      const QSize& requestedSize)
+{
+  QImage image = m_requestImage(id, requestedSize);
+  if (size != nullptr) {
+    *size = image.size();
+  }
+  return image;
+}
+
+CxxQtQuickAsyncImageProvider::CxxQtQuickAsyncImageProvider(
+  RequestImageResponse requestImageResponse)
+  : m_requestImageResponse(::std::move(requestImageResponse))
+{
+}
+
+QQuickImageResponse*
+CxxQtQuickAsyncImageProvider::requestImageResponse(const QString& id,
+                                                   const QSize& requestedSize)
+{
+  auto state = ::std::make_shared<CxxQtQuickImageResponse>();
+  auto* response = new CxxQtQuickImageResponseObject(state);
+  m_requestImageResponse(id, requestedSize, ::std::move(state));
+  return response;
+}
+
+void
+qquickimageresponseFinish(const CxxQtQuickImageResponse& response,
+                          const QImage& image,
+                          const QString& errorString)
+{
+  response.finish(image, errorString);
+}
+
+}
+}
@@ -0,0 +1,229 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qimage.h");
+        type QImage = crate::QImage;
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = crate::QQmlEngine;
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = crate::QSize;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qquickimageprovider.h");
+        /// The QQuickImageResponse class provides an interface for asynchronous image loading
+        /// in a [QQuickAsyncImageProvider].
+        ///
+        /// The response is shared with the provider and can be finished from any thread,
+        /// once it is finished Qt Quick is notified from the thread which requested the image.
+        /// If every reference to the response is dropped before it is finished,
+        /// then the response is finished with an error.
+        #[cxx_name = "CxxQtQuickImageResponse"]
+        type QQuickImageResponse;
+
+        /// Returns true if Qt Quick has cancelled the request, eg because the image is no longer needed.
+        ///
+        /// The provider can use this to stop loading the image early, the response should still be finished.
+        #[rust_name = "is_cancelled"]
+        fn isCancelled(self: &QQuickImageResponse) -> bool;
+
+        /// Returns true if the response has been finished.
+        #[rust_name = "is_finished"]
+        fn isFinished(self: &QQuickImageResponse) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_add_async_image_provider"]
+        fn qqmlengineAddAsyncImageProvider(
+            engine: Pin<&mut QQmlEngine>,
+            provider_id: &QString,
+            func: fn(&QQuickAsyncImageProviderFn, &QString, &QSize, SharedPtr<QQuickImageResponse>),
+            arg: Box<QQuickAsyncImageProviderFn>,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_add_image_provider"]
+        fn qqmlengineAddImageProvider(
+            engine: Pin<&mut QQmlEngine>,
+            provider_id: &QString,
+            func: fn(&QQuickImageProviderFn, &QString, &QSize) -> QImage,
+            arg: Box<QQuickImageProviderFn>,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qquickimageresponse_finish"]
+        fn qquickimageresponseFinish(
+            response: &QQuickImageResponse,
+            image: &QImage,
+            error_string: &QString,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QQuickAsyncImageProviderFn;
+        #[doc(hidden)]
+        type QQuickImageProviderFn;
+    }
+
+    impl SharedPtr<QQuickImageResponse> {}
+}
+
+use crate::{QImage, QQmlEngine, QSize, QString};
+use core::pin::Pin;
+use cxx::SharedPtr;
+
+pub use ffi::QQuickImageResponse;
+
+// The response only contains a mutex protected state which is designed to be finished from any thread
+unsafe impl Send for QQuickImageResponse {}
+unsafe impl Sync for QQuickImageResponse {}
+
+impl QQuickImageResponse {
+    /// Finish the response with the given image.
+    ///
+    /// If the response has already been finished this does nothing.
+    pub fn finish(&self, image: &QImage) {
+        ffi::qquickimageresponse_finish(self, image, &QString::default())
+    }
+
+    /// Finish the response with an error, the image will fail to load with the given error string.
+    ///
+    /// If the response has already been finished this does nothing.
+    pub fn finish_with_error(&self, error_string: &QString) {
+        ffi::qquickimageresponse_finish(self, &QImage::default(), error_string)
+    }
+}
+
+/// A provider of images which are requested by `image://` URLs in QML.
+///
+/// This is the Rust equivalent of a
+/// [QQuickImageProvider](https://doc.qt.io/qt/qquickimageprovider.html) with the Image type,
+/// and is added to an engine with [QQmlEngine::add_image_provider].
+///
+/// Images may be requested from the image loading thread of Qt Quick, so the provider must be [Send] and [Sync].
+/// Any closure of the form `Fn(&QString, &QSize) -> QImage` is also an image provider.
+pub trait QQuickImageProvider: Send + Sync + 'static {
+    /// Return the image for the given id.
+    ///
+    /// The requested size is the `sourceSize` of the Image element in QML,
+    /// it is invalid when no size has been requested.
+    /// Return a null image if the id cannot be provided.
+    fn request_image(&self, id: &QString, requested_size: &QSize) -> QImage;
+}
+
+impl<F> QQuickImageProvider for F
+where
+    F: Fn(&QString, &QSize) -> QImage + Send + Sync + 'static,
+{
+    fn request_image(&self, id: &QString, requested_size: &QSize) -> QImage {
+        self(id, requested_size)
+    }
+}
+
+/// A provider of images which are loaded asynchronously, eg on a worker thread.
+///
+/// This is the Rust equivalent of a
+/// [QQuickAsyncImageProvider](https://doc.qt.io/qt/qquickasyncimageprovider.html),
+/// and is added to an engine with [QQmlEngine::add_async_image_provider].
+///
+/// Any closure of the form `Fn(&QString, &QSize, SharedPtr<QQuickImageResponse>)` is also an async image provider.
+pub trait QQuickAsyncImageProvider: Send + Sync + 'static {
+    /// Start loading the image for the given id.
+    ///
+    /// This should return quickly, the image is then given to Qt Quick with [QQuickImageResponse::finish]
+    /// which can be called from any thread.
+    fn request_image_response(
+        &self,
+        id: &QString,
+        requested_size: &QSize,
+        response: SharedPtr<QQuickImageResponse>,
+    );
+}
+
+impl<F> QQuickAsyncImageProvider for F
+where
+    F: Fn(&QString, &QSize, SharedPtr<QQuickImageResponse>) + Send + Sync + 'static,
+{
+    fn request_image_response(
+        &self,
+        id: &QString,
+        requested_size: &QSize,
+        response: SharedPtr<QQuickImageResponse>,
+    ) {
+        self(id, requested_size, response)
+    }
+}
+
+#[doc(hidden)]
+pub struct QQuickAsyncImageProviderFn {
+    inner: Box<dyn QQuickAsyncImageProvider>,
+}
+
+fn call_async_image_provider_fn(
+    arg: &QQuickAsyncImageProviderFn,
+    id: &QString,
+    requested_size: &QSize,
+    response: SharedPtr<QQuickImageResponse>,
+) {
+    arg.inner
+        .request_image_response(id, requested_size, response)
+}
+
+#[doc(hidden)]
+pub struct QQuickImageProviderFn {
+    inner: Box<dyn QQuickImageProvider>,
+}
+
+fn call_image_provider_fn(
+    arg: &QQuickImageProviderFn,
+    id: &QString,
+    requested_size: &QSize,
+) -> QImage {
+    arg.inner.request_image(id, requested_size)
+}
+
+impl QQmlEngine {
+    /// Sets the provider to use for images requested via the `image://` url scheme, with host `provider_id`.
+    ///
+    /// The engine takes ownership of the provider, any existing provider with the same id is not replaced.
+    pub fn add_async_image_provider<T>(self: Pin<&mut Self>, provider_id: &QString, provider: T)
+    where
+        T: QQuickAsyncImageProvider,
+    {
+        ffi::qqmlengine_add_async_image_provider(
+            self,
+            provider_id,
+            call_async_image_provider_fn,
+            Box::new(QQuickAsyncImageProviderFn {
+                inner: Box::new(provider),
+            }),
+        );
+    }
+
+    /// Sets the provider to use for images requested via the `image://` url scheme, with host `provider_id`.
+    ///
+    /// The engine takes ownership of the provider, any existing provider with the same id is not replaced.
+    pub fn add_image_provider<T>(self: Pin<&mut Self>, provider_id: &QString, provider: T)
+    where
+        T: QQuickImageProvider,
+    {
+        ffi::qqmlengine_add_image_provider(
+            self,
+            provider_id,
+            call_image_provider_fn,
+            Box::new(QQuickImageProviderFn {
+                inner: Box::new(provider),
+            }),
+        );
+    }
+}
@@ -0,0 +1,36 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qquickitem.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qquickitemAcceptsMouseButton(const QQuickItem& item, Qt::MouseButton button)
+{
+  return item.acceptedMouseButtons().testFlag(button);
+}
+
+bool
+qquickitemHasFlag(const QQuickItem& item, QQuickItemFlag flag)
+{
+  return item.flags().testFlag(flag);
+}
+
+void
+qquickitemSetAcceptedMouseButton(QQuickItem& item,
+                                 Qt::MouseButton button,
+                                 bool enabled)
+{
+  Qt::MouseButtons buttons = item.acceptedMouseButtons();
+  buttons.setFlag(button, enabled);
+  item.setAcceptedMouseButtons(buttons);
+}
+
+}
+}
@@ -0,0 +1,462 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// Flags which specify the features of a [QQuickItem].
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum QQuickItemFlag {
+        /// Indicates this item should visually clip its children so that they are rendered only within the boundaries of this item.
+        ItemClipsChildrenToShape = 0x01,
+        /// Indicates the item supports text input methods.
+        ItemAcceptsInputMethod = 0x02,
+        /// Indicates the item is a focus scope.
+        ItemIsFocusScope = 0x04,
+        /// Indicates the item has visual content and should be rendered by the scene graph.
+        ItemHasContents = 0x08,
+        /// Indicates the item accepts drag and drop events.
+        ItemAcceptsDrops = 0x10,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qquickitem.h");
+        /// The QQuickItem class provides the most basic of all visual items in Qt Quick.
+        ///
+        /// To implement a custom item in Rust, use `#[base = QQuickItem]` on a QObject
+        /// and reach the methods of the base class with [Upcast](cxx_qt::Upcast).
+        ///
+        /// To override virtual methods such as `geometryChange` or `mousePressEvent`,
+        /// declare `impl cxx_qt_lib::QQuickItemOverrides for MyItem {}` in the bridge
+        /// and implement [QQuickItemOverrides] for the QObject.
+        #[qobject]
+        type QQuickItem;
+
+        /// This signal is emitted when the enabled property changes.
+        #[qsignal]
+        #[cxx_name = "enabledChanged"]
+        fn enabled_changed(self: Pin<&mut QQuickItem>);
+
+        /// This signal is emitted when the height property changes.
+        #[qsignal]
+        #[cxx_name = "heightChanged"]
+        fn height_changed(self: Pin<&mut QQuickItem>);
+
+        /// This signal is emitted when the visible property changes.
+        #[qsignal]
+        #[cxx_name = "visibleChanged"]
+        fn visible_changed(self: Pin<&mut QQuickItem>);
+
+        /// This signal is emitted when the width property changes.
+        #[qsignal]
+        #[cxx_name = "widthChanged"]
+        fn width_changed(self: Pin<&mut QQuickItem>);
+
+        /// This signal is emitted when the x property changes.
+        #[qsignal]
+        #[cxx_name = "xChanged"]
+        fn x_changed(self: Pin<&mut QQuickItem>);
+
+        /// This signal is emitted when the y property changes.
+        #[qsignal]
+        #[cxx_name = "yChanged"]
+        fn y_changed(self: Pin<&mut QQuickItem>);
+    }
+
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type MouseButton = crate::MouseButton;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qimage.h");
+        type QImage = crate::QImage;
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+        include!("cxx-qt-lib/qrectf.h");
+        type QRectF = crate::QRectF;
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = crate::QSize;
+        include!("cxx-qt-lib/qsizef.h");
+        type QSizeF = crate::QSizeF;
+
+        type QQuickItemFlag;
+
+        /// Contains the transform node of the item, which is passed to `updatePaintNode`.
+        ///
+        /// To render scene graph nodes from Rust, enable [QQuickItemFlag::ItemHasContents] and override
+        /// `updatePaintNode` with `#[cxx_override]`, taking a `*mut QSGNode` and `*mut QQuickItemUpdatePaintNodeData`
+        /// and returning a `*mut QSGNode` which is created with [QSGNode::into_raw](crate::QSGNode::into_raw).
+        type QQuickItemUpdatePaintNodeData;
+
+        /// Returns whether hover events are accepted by this item.
+        #[rust_name = "accept_hover_events"]
+        fn acceptHoverEvents(self: &QQuickItem) -> bool;
+
+        /// Returns the extents of the item in its own coordinate system: a rectangle from (0, 0) to [QQuickItem::width] and [QQuickItem::height].
+        #[rust_name = "bounding_rect"]
+        fn boundingRect(self: &QQuickItem) -> QRectF;
+
+        /// Returns whether clipping is enabled, which restricts painting of the item and its children to its bounding rectangle.
+        fn clip(self: &QQuickItem) -> bool;
+
+        /// Forces active focus on the item.
+        ///
+        /// This method sets focus on the item and ensures that all ancestor focus scopes in the object hierarchy are also given focus.
+        #[rust_name = "force_active_focus"]
+        fn forceActiveFocus(self: Pin<&mut QQuickItem>);
+
+        /// Returns whether the item has focus within the enclosing focus scope.
+        #[rust_name = "has_focus"]
+        fn hasFocus(self: &QQuickItem) -> bool;
+
+        /// Returns the height of the item.
+        fn height(self: &QQuickItem) -> f64;
+
+        /// Returns the height of the item that is implied by its content, used when no height is specified.
+        #[rust_name = "implicit_height"]
+        fn implicitHeight(self: &QQuickItem) -> f64;
+
+        /// Returns the width of the item that is implied by its content, used when no width is specified.
+        #[rust_name = "implicit_width"]
+        fn implicitWidth(self: &QQuickItem) -> f64;
+
+        /// Returns whether the item receives mouse, keyboard and hover events.
+        #[rust_name = "is_enabled"]
+        fn isEnabled(self: &QQuickItem) -> bool;
+
+        /// Returns whether the item is visible.
+        ///
+        /// Note that an item is not visible if any of its ancestors are not visible.
+        #[rust_name = "is_visible"]
+        fn isVisible(self: &QQuickItem) -> bool;
+
+        /// Returns the opacity of the item, from 0.0 (fully transparent) to 1.0 (fully opaque).
+        fn opacity(self: &QQuickItem) -> f64;
+
+        /// Returns the visual parent of the item, or a null pointer if it has none.
+        #[rust_name = "parent_item"]
+        fn parentItem(self: &QQuickItem) -> *mut QQuickItem;
+
+        /// Schedules a polish event for this item.
+        ///
+        /// When the scene graph processes the request, it will call `updatePolish()` on this item.
+        fn polish(self: Pin<&mut QQuickItem>);
+
+        /// Returns the position of the item relative to its parent.
+        fn position(self: &QQuickItem) -> QPointF;
+
+        /// Returns the rotation of the item in degrees clockwise around its transform origin.
+        fn rotation(self: &QQuickItem) -> f64;
+
+        /// Returns the scale factor of the item.
+        fn scale(self: &QQuickItem) -> f64;
+
+        /// Sets whether hover events are accepted by this item.
+        ///
+        /// The default value is false, when it is false the item will not receive any hover events.
+        #[rust_name = "set_accept_hover_events"]
+        fn setAcceptHoverEvents(self: Pin<&mut QQuickItem>, enabled: bool);
+
+        /// Sets whether clipping is enabled.
+        #[rust_name = "set_clip"]
+        fn setClip(self: Pin<&mut QQuickItem>, clip: bool);
+
+        /// Sets whether the item receives mouse, keyboard and hover events.
+        #[rust_name = "set_enabled"]
+        fn setEnabled(self: Pin<&mut QQuickItem>, enabled: bool);
+
+        /// Enables the specified flag for this item if enabled is true, otherwise disables it.
+        #[rust_name = "set_flag"]
+        fn setFlag(self: Pin<&mut QQuickItem>, flag: QQuickItemFlag, enabled: bool);
+
+        /// Sets whether the item has focus within the enclosing focus scope.
+        #[rust_name = "set_focus"]
+        fn setFocus(self: Pin<&mut QQuickItem>, focus: bool);
+
+        /// Sets the height of the item.
+        #[rust_name = "set_height"]
+        fn setHeight(self: Pin<&mut QQuickItem>, height: f64);
+
+        /// Sets the height of the item that is implied by its content.
+        #[rust_name = "set_implicit_height"]
+        fn setImplicitHeight(self: Pin<&mut QQuickItem>, height: f64);
+
+        /// Sets the width of the item that is implied by its content.
+        #[rust_name = "set_implicit_width"]
+        fn setImplicitWidth(self: Pin<&mut QQuickItem>, width: f64);
+
+        /// Sets the opacity of the item.
+        #[rust_name = "set_opacity"]
+        fn setOpacity(self: Pin<&mut QQuickItem>, opacity: f64);
+
+        /// Sets the visual parent of the item.
+        ///
+        /// # Safety
+        ///
+        /// The parent must be a valid pointer to a QQuickItem or a null pointer.
+        #[rust_name = "set_parent_item"]
+        unsafe fn setParentItem(self: Pin<&mut QQuickItem>, parent: *mut QQuickItem);
+
+        /// Sets the position of the item relative to its parent.
+        #[rust_name = "set_position"]
+        fn setPosition(self: Pin<&mut QQuickItem>, position: &QPointF);
+
+        /// Sets the rotation of the item in degrees clockwise around its transform origin.
+        #[rust_name = "set_rotation"]
+        fn setRotation(self: Pin<&mut QQuickItem>, rotation: f64);
+
+        /// Sets the scale factor of the item.
+        #[rust_name = "set_scale"]
+        fn setScale(self: Pin<&mut QQuickItem>, scale: f64);
+
+        /// Sets the size of the item.
+        #[rust_name = "set_size"]
+        fn setSize(self: Pin<&mut QQuickItem>, size: &QSizeF);
+
+        /// Sets whether the item is visible.
+        #[rust_name = "set_visible"]
+        fn setVisible(self: Pin<&mut QQuickItem>, visible: bool);
+
+        /// Sets the width of the item.
+        #[rust_name = "set_width"]
+        fn setWidth(self: Pin<&mut QQuickItem>, width: f64);
+
+        /// Sets the x position of the item relative to its parent.
+        #[rust_name = "set_x"]
+        fn setX(self: Pin<&mut QQuickItem>, x: f64);
+
+        /// Sets the y position of the item relative to its parent.
+        #[rust_name = "set_y"]
+        fn setY(self: Pin<&mut QQuickItem>, y: f64);
+
+        /// Sets the stacking order of sibling items.
+        #[rust_name = "set_z"]
+        fn setZ(self: Pin<&mut QQuickItem>, z: f64);
+
+        /// Returns the size of the item.
+        fn size(self: &QQuickItem) -> QSizeF;
+
+        /// Schedules a call to `updatePaintNode()` for this item.
+        ///
+        /// The call to `updatePaintNode()` will always happen if the item is showing in a QQuickWindow.
+        /// Only items which specify [QQuickItemFlag::ItemHasContents] are allowed to call this method.
+        fn update(self: Pin<&mut QQuickItem>);
+
+        /// Returns the width of the item.
+        fn width(self: &QQuickItem) -> f64;
+
+        /// Returns the x position of the item relative to its parent.
+        fn x(self: &QQuickItem) -> f64;
+
+        /// Returns the y position of the item relative to its parent.
+        fn y(self: &QQuickItem) -> f64;
+
+        /// Returns the stacking order of sibling items.
+        ///
+        /// By default the stacking order is 0, items with a higher stacking value are drawn on top of siblings with a lower stacking order.
+        fn z(self: &QQuickItem) -> f64;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        type QObject = cxx_qt::QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "upcast_qquickitem"]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn upcast(thiz: *const QQuickItem) -> *const QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "downcast_qobject"]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn downcast(base: *const QObject) -> *const QQuickItem;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qquickitem_accepts_mouse_button"]
+        fn qquickitemAcceptsMouseButton(item: &QQuickItem, button: MouseButton) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qquickitem_grab_to_image"]
+        fn qquickitemGrabToImage(
+            item: Pin<&mut QQuickItem>,
+            target_size: &QSize,
+            func: fn(Box<QQuickItemGrabToImageFn>, &QImage),
+            arg: Box<QQuickItemGrabToImageFn>,
+        ) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qquickitem_has_flag"]
+        fn qquickitemHasFlag(item: &QQuickItem, flag: QQuickItemFlag) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qquickitem_set_accepted_mouse_button"]
+        fn qquickitemSetAcceptedMouseButton(
+            item: Pin<&mut QQuickItem>,
+            button: MouseButton,
+            enabled: bool,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QQuickItemGrabToImageFn;
+    }
+
+    // QQuickItem is not trivial to CXX and is not relocatable in Qt
+    // so we need to use references or pointers.
+    impl UniquePtr<QQuickItem> {}
+}
+
+use crate::{MouseButton, QImage, QMouseEvent, QRectF, QSize};
+use core::pin::Pin;
+use cxx_qt::{QObject, Upcast};
+
+pub use ffi::{QQuickItem, QQuickItemFlag, QQuickItemUpdatePaintNodeData};
+
+impl Upcast<QObject> for QQuickItem {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QObject {
+        ffi::upcast_qquickitem(this)
+    }
+
+    unsafe fn from_base_ptr(base: *const QObject) -> *const Self {
+        ffi::downcast_qobject(base)
+    }
+}
+
+#[doc(hidden)]
+pub struct QQuickItemGrabToImageFn {
+    inner: Box<dyn FnOnce(&QImage)>,
+}
+
+#[allow(clippy::boxed_local)]
+fn call_grab_to_image_fn(arg: Box<QQuickItemGrabToImageFn>, image: &QImage) {
+    (arg.inner)(image)
+}
+
+impl QQuickItem {
+    /// Returns true if the item accepts mouse events for the given button.
+    pub fn accepts_mouse_button(&self, button: MouseButton) -> bool {
+        ffi::qquickitem_accepts_mouse_button(self, button)
+    }
+
+    /// Grabs the item into an in-memory image, the closure is called with the image once it is ready.
+    ///
+    /// The grab happens when the scene is next rendered, so the item must be in a window which is visible.
+    /// If the target size is invalid the size of the item is used.
+    ///
+    /// Returns false if the grab could not be started, for example if the item is not in a window.
+    pub fn grab_to_image<F>(self: Pin<&mut Self>, target_size: &QSize, closure: F) -> bool
+    where
+        F: FnOnce(&QImage) + 'static,
+    {
+        ffi::qquickitem_grab_to_image(
+            self,
+            target_size,
+            call_grab_to_image_fn,
+            Box::new(QQuickItemGrabToImageFn {
+                inner: Box::new(closure),
+            }),
+        )
+    }
+
+    /// Returns true if the given flag is enabled for this item.
+    pub fn has_flag(&self, flag: QQuickItemFlag) -> bool {
+        ffi::qquickitem_has_flag(self, flag)
+    }
+
+    /// Enables or disables mouse events for the given button.
+    ///
+    /// By default no mouse buttons are accepted, so an item will not receive mouse events.
+    pub fn set_accepted_mouse_button(self: Pin<&mut Self>, button: MouseButton, enabled: bool) {
+        ffi::qquickitem_set_accepted_mouse_button(self, button, enabled)
+    }
+
+    /// Sets the mouse buttons accepted by this item, replacing any previously accepted buttons.
+    pub fn set_accepted_mouse_buttons(mut self: Pin<&mut Self>, buttons: &[MouseButton]) {
+        self.as_mut()
+            .set_accepted_mouse_button(MouseButton::AllButtons, false);
+        for button in buttons {
+            self.as_mut().set_accepted_mouse_button(*button, true);
+        }
+    }
+}
+
+/// Calls the implementations of the virtual methods of [QQuickItem] in the base class.
+///
+/// This is implemented by CXX-Qt for a QObject which declares
+/// `impl cxx_qt_lib::QQuickItemOverrides for MyItem {}` in its bridge.
+pub trait QQuickItemBase {
+    /// Calls `geometryChange` (`geometryChanged` in Qt 5) of the base class.
+    fn base_geometry_change(self: Pin<&mut Self>, new_geometry: &QRectF, old_geometry: &QRectF);
+
+    /// Calls `mouseDoubleClickEvent` of the base class.
+    fn base_mouse_double_click_event(self: Pin<&mut Self>, event: Pin<&mut QMouseEvent>);
+
+    /// Calls `mouseMoveEvent` of the base class.
+    fn base_mouse_move_event(self: Pin<&mut Self>, event: Pin<&mut QMouseEvent>);
+
+    /// Calls `mousePressEvent` of the base class.
+    fn base_mouse_press_event(self: Pin<&mut Self>, event: Pin<&mut QMouseEvent>);
+
+    /// Calls `mouseReleaseEvent` of the base class.
+    fn base_mouse_release_event(self: Pin<&mut Self>, event: Pin<&mut QMouseEvent>);
+}
+
+/// Overrides the virtual methods of [QQuickItem] for a QObject with `#[base = QQuickItem]`.
+///
+/// Declare `impl cxx_qt_lib::QQuickItemOverrides for MyItem {}` in the bridge,
+/// then implement this trait for the QObject outside of the bridge.
+/// Methods which are not implemented call the implementation of the base class.
+///
+/// ```ignore
+/// impl cxx_qt_lib::QQuickItemOverrides for qobject::MyItem {
+///     fn mouse_press_event(self: Pin<&mut Self>, mut event: Pin<&mut QMouseEvent>) {
+///         event.as_mut().accept();
+///         self.clicked();
+///     }
+/// }
+/// ```
+pub trait QQuickItemOverrides: QQuickItemBase {
+    /// Called when the geometry of the item changes from the old geometry to the new geometry.
+    ///
+    /// This overrides `geometryChange`, or `geometryChanged` in Qt 5.
+    fn geometry_change(self: Pin<&mut Self>, new_geometry: &QRectF, old_geometry: &QRectF) {
+        self.base_geometry_change(new_geometry, old_geometry)
+    }
+
+    /// Called when a mouse button is double clicked on the item.
+    ///
+    /// This overrides `mouseDoubleClickEvent`.
+    fn mouse_double_click_event(self: Pin<&mut Self>, event: Pin<&mut QMouseEvent>) {
+        self.base_mouse_double_click_event(event)
+    }
+
+    /// Called when the mouse moves while a button is pressed on the item.
+    ///
+    /// This overrides `mouseMoveEvent`.
+    fn mouse_move_event(self: Pin<&mut Self>, event: Pin<&mut QMouseEvent>) {
+        self.base_mouse_move_event(event)
+    }
+
+    /// Called when an accepted mouse button is pressed on the item.
+    ///
+    /// The event must be accepted to receive the move and release events of this press.
+    /// This overrides `mousePressEvent`.
+    fn mouse_press_event(self: Pin<&mut Self>, event: Pin<&mut QMouseEvent>) {
+        self.base_mouse_press_event(event)
+    }
+
+    /// Called when a mouse button is released after it was pressed on the item.
+    ///
+    /// This overrides `mouseReleaseEvent`.
+    fn mouse_release_event(self: Pin<&mut Self>, event: Pin<&mut QMouseEvent>) {
+        self.base_mouse_release_event(event)
+    }
+}
@@ -0,0 +1,21 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qquickpainteditem.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qquickpainteditemHasPerformanceHint(const QQuickPaintedItem& item,
+                                    QQuickPaintedItemPerformanceHint hint)
+{
+  return item.performanceHints().testFlag(hint);
+}
+
+}
+}
@@ -0,0 +1,209 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// Describes which performance optimisations the [QQuickPaintedItem] may use.
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum QQuickPaintedItemPerformanceHint {
+        /// With this hint the internal framebuffer object will be resized only when the item size becomes larger than the framebuffer size.
+        FastFBOResizing = 0x1,
+    }
+
+    /// Describes the type of surface the [QQuickPaintedItem] renders into.
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum QQuickPaintedItemRenderTarget {
+        /// The default; QPainter paints into a QImage using the raster paint engine.
+        Image,
+        /// QPainter paints into a framebuffer object using the GL paint engine.
+        FramebufferObject,
+        /// QPainter paints into a framebuffer object using the GL paint engine, with the Y axis inverted.
+        InvertedYFramebufferObject,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qquickpainteditem.h");
+        /// The QQuickPaintedItem class provides a way to use the QPainter API in the QML Scene Graph.
+        ///
+        /// To implement a painted item in Rust, use `#[base = QQuickPaintedItem]` on a QObject,
+        /// declare `impl cxx_qt_lib::QQuickPaintedItemOverrides for MyItem {}` in its bridge
+        /// and implement [QQuickPaintedItemOverrides] for the QObject.
+        /// The methods of [QQuickItem] are reached with [Upcast](cxx_qt::Upcast).
+        #[qobject]
+        type QQuickPaintedItem;
+
+        /// This signal is emitted when the fill color changes.
+        #[qsignal]
+        #[cxx_name = "fillColorChanged"]
+        fn fill_color_changed(self: Pin<&mut QQuickPaintedItem>);
+
+        /// This signal is emitted when the render target changes.
+        #[qsignal]
+        #[cxx_name = "renderTargetChanged"]
+        fn render_target_changed(self: Pin<&mut QQuickPaintedItem>);
+
+        /// This signal is emitted when the texture size changes.
+        #[qsignal]
+        #[cxx_name = "textureSizeChanged"]
+        fn texture_size_changed(self: Pin<&mut QQuickPaintedItem>);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+        include!("cxx-qt-lib/qrect.h");
+        type QRect = crate::QRect;
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = crate::QSize;
+
+        type QQuickPaintedItemPerformanceHint;
+        type QQuickPaintedItemRenderTarget;
+
+        /// Returns true if the item's painter uses antialiasing, the default is false.
+        fn antialiasing(self: &QQuickPaintedItem) -> bool;
+
+        /// Returns the item's background fill color, the default is transparent.
+        #[rust_name = "fill_color"]
+        fn fillColor(self: &QQuickPaintedItem) -> QColor;
+
+        /// Returns true if mipmaps are enabled, the default is false.
+        fn mipmap(self: &QQuickPaintedItem) -> bool;
+
+        /// Returns true if the item is opaque, the default is false.
+        #[rust_name = "opaque_painting"]
+        fn opaquePainting(self: &QQuickPaintedItem) -> bool;
+
+        /// Returns the render target of the item.
+        #[rust_name = "render_target"]
+        fn renderTarget(self: &QQuickPaintedItem) -> QQuickPaintedItemRenderTarget;
+
+        /// Sets whether the item's painter uses antialiasing.
+        #[rust_name = "set_antialiasing"]
+        fn setAntialiasing(self: Pin<&mut QQuickPaintedItem>, enable: bool);
+
+        /// Sets the item's background fill color.
+        #[rust_name = "set_fill_color"]
+        fn setFillColor(self: Pin<&mut QQuickPaintedItem>, color: &QColor);
+
+        /// Sets whether mipmaps are used when the item is scaled.
+        #[rust_name = "set_mipmap"]
+        fn setMipmap(self: Pin<&mut QQuickPaintedItem>, enable: bool);
+
+        /// Sets whether the item is opaque, opaque items do not render the items behind them.
+        #[rust_name = "set_opaque_painting"]
+        fn setOpaquePainting(self: Pin<&mut QQuickPaintedItem>, opaque: bool);
+
+        /// Sets the given performance hint on the item if enabled is true, otherwise clears it.
+        #[rust_name = "set_performance_hint"]
+        fn setPerformanceHint(
+            self: Pin<&mut QQuickPaintedItem>,
+            hint: QQuickPaintedItemPerformanceHint,
+            enabled: bool,
+        );
+
+        /// Sets the render target of the item.
+        #[rust_name = "set_render_target"]
+        fn setRenderTarget(
+            self: Pin<&mut QQuickPaintedItem>,
+            target: QQuickPaintedItemRenderTarget,
+        );
+
+        /// Sets the size of the texture the item paints into.
+        ///
+        /// By default the texture size is the same as the item's size.
+        #[rust_name = "set_texture_size"]
+        fn setTextureSize(self: Pin<&mut QQuickPaintedItem>, size: &QSize);
+
+        /// Returns the size of the texture the item paints into.
+        #[rust_name = "texture_size"]
+        fn textureSize(self: &QQuickPaintedItem) -> QSize;
+
+        /// Schedules a redraw of the area covered by rect in this item.
+        ///
+        /// Use [QQuickItem::update](crate::QQuickItem::update) via upcasting to redraw the whole item.
+        #[rust_name = "update_rect"]
+        fn update(self: Pin<&mut QQuickPaintedItem>, rect: &QRect);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        include!("cxx-qt-lib/qquickitem.h");
+        type QQuickItem = crate::QQuickItem;
+
+        #[doc(hidden)]
+        #[rust_name = "upcast_qquickpainteditem"]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn upcast(thiz: *const QQuickPaintedItem) -> *const QQuickItem;
+
+        #[doc(hidden)]
+        #[rust_name = "downcast_qquickitem"]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn downcast(base: *const QQuickItem) -> *const QQuickPaintedItem;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qquickpainteditem_has_performance_hint"]
+        fn qquickpainteditemHasPerformanceHint(
+            item: &QQuickPaintedItem,
+            hint: QQuickPaintedItemPerformanceHint,
+        ) -> bool;
+    }
+
+    // QQuickPaintedItem is not trivial to CXX and is not relocatable in Qt
+    // so we need to use references or pointers.
+    impl UniquePtr<QQuickPaintedItem> {}
+}
+
+use crate::{QPainter, QQuickItem, QQuickItemOverrides};
+use core::pin::Pin;
+use cxx_qt::Upcast;
+
+pub use ffi::{QQuickPaintedItem, QQuickPaintedItemPerformanceHint, QQuickPaintedItemRenderTarget};
+
+impl Upcast<QQuickItem> for QQuickPaintedItem {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QQuickItem {
+        ffi::upcast_qquickpainteditem(this)
+    }
+
+    unsafe fn from_base_ptr(base: *const QQuickItem) -> *const Self {
+        ffi::downcast_qquickitem(base)
+    }
+}
+
+impl QQuickPaintedItem {
+    /// Returns true if the given performance hint is enabled for this item.
+    pub fn has_performance_hint(&self, hint: QQuickPaintedItemPerformanceHint) -> bool {
+        ffi::qquickpainteditem_has_performance_hint(self, hint)
+    }
+}
+
+/// Overrides the virtual methods of [QQuickPaintedItem] for a QObject with `#[base = QQuickPaintedItem]`.
+///
+/// Declare `impl cxx_qt_lib::QQuickPaintedItemOverrides for MyItem {}` in the bridge,
+/// then implement this trait and [QQuickItemOverrides] for the QObject outside of the bridge.
+///
+/// ```ignore
+/// impl cxx_qt_lib::QQuickItemOverrides for qobject::MyItem {}
+///
+/// impl cxx_qt_lib::QQuickPaintedItemOverrides for qobject::MyItem {
+///     fn paint(self: Pin<&mut Self>, painter: Pin<&mut QPainter>) {
+///         let size = self.upcast().upcast().size();
+///         painter.fill_rect(&QRectF::new(0.0, 0.0, size.width(), size.height()), self.color());
+///     }
+/// }
+/// ```
+pub trait QQuickPaintedItemOverrides: QQuickItemOverrides {
+    /// Called by the scene graph to paint the contents of the item in local coordinates.
+    ///
+    /// This is called on the render thread while the GUI thread is blocked.
+    /// This overrides the pure virtual `paint`.
+    fn paint(self: Pin<&mut Self>, painter: Pin<&mut QPainter>);
+}
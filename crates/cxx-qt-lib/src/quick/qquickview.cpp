@@ -0,0 +1,40 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qquickview.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QQmlEngine&
+qquickviewEngine(QQuickView& view)
+{
+  // The engine is created with the view and is never null
+  return *view.engine();
+}
+
+::std::unique_ptr<QList_QQmlError>
+qquickviewErrors(const QQuickView& view)
+{
+  return ::std::make_unique<QList_QQmlError>(view.errors());
+}
+
+::std::unique_ptr<QQuickView>
+qquickviewNew()
+{
+  return ::std::make_unique<QQuickView>();
+}
+
+QQmlContext&
+qquickviewRootContext(QQuickView& view)
+{
+  // The root context is created with the engine and is never null
+  return *view.rootContext();
+}
+
+}
+}
@@ -0,0 +1,193 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// Specifies how the root item of a [QQuickView] is resized.
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum QQuickViewResizeMode {
+        /// The view resizes with the root item in the QML.
+        SizeViewToRootObject,
+        /// The view will automatically resize the root item to the size of the view.
+        SizeRootObjectToView,
+    }
+
+    /// Specifies the loading status of the [QQuickView].
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum QQuickViewStatus {
+        /// This QQuickView has no source set.
+        Null,
+        /// This QQuickView has loaded and created the QML component.
+        Ready,
+        /// This QQuickView is loading network data.
+        Loading,
+        /// One or more errors has occurred. Call [QQuickView::errors] to retrieve a list of errors.
+        Error,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qquickview.h");
+        /// The QQuickView class provides a window for displaying a Qt Quick user interface.
+        ///
+        /// This is a convenience subclass of [QQuickWindow] which will automatically load and display a QML scene
+        /// when given the URL of the main source file, with an item as the root rather than a `Window`.
+        ///
+        /// The methods of the window, such as [QQuickWindow::show], are reached with [Upcast](cxx_qt::Upcast).
+        #[qobject]
+        type QQuickView;
+
+        /// This signal is emitted when the component's current status changes.
+        #[qsignal]
+        #[cxx_name = "statusChanged"]
+        fn status_changed(self: Pin<&mut QQuickView>, status: QQuickViewStatus);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmap.h");
+        type QMap_QString_QVariant = crate::QMap<crate::QMapPair_QString_QVariant>;
+        include!("cxx-qt-lib/qqmlcontext.h");
+        type QQmlContext = crate::QQmlContext;
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = crate::QQmlEngine;
+        include!("cxx-qt-lib/qqmlerror.h");
+        type QList_QQmlError = crate::QQmlErrorList;
+        include!("cxx-qt-lib/qquickitem.h");
+        type QQuickItem = crate::QQuickItem;
+        include!("cxx-qt-lib/qquickwindow.h");
+        type QQuickWindow = crate::QQuickWindow;
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = crate::QSize;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+
+        type QQuickViewResizeMode;
+        type QQuickViewStatus;
+
+        /// Returns the initial size of the root object.
+        ///
+        /// If the resize mode is [QQuickViewResizeMode::SizeRootObjectToView] the root object will be resized
+        /// to the size of the view, this function returns the size of the root object before it was resized.
+        #[rust_name = "initial_size"]
+        fn initialSize(self: &QQuickView) -> QSize;
+
+        /// Returns how the root item is resized.
+        #[rust_name = "resize_mode"]
+        fn resizeMode(self: &QQuickView) -> QQuickViewResizeMode;
+
+        /// Returns the view's root item, or a null pointer if no QML has been loaded or the root is not an item.
+        #[rust_name = "root_object"]
+        fn rootObject(self: &QQuickView) -> *mut QQuickItem;
+
+        /// Sets the initial properties with which the QML component gets initialized after calling [QQuickView::set_source].
+        ///
+        /// These must be set before the source, and must be declared as properties of the root object in the QML.
+        #[rust_name = "set_initial_properties"]
+        fn setInitialProperties(
+            self: Pin<&mut QQuickView>,
+            initial_properties: &QMap_QString_QVariant,
+        );
+
+        /// Sets how the root item is resized, by default this is [QQuickViewResizeMode::SizeViewToRootObject].
+        #[rust_name = "set_resize_mode"]
+        fn setResizeMode(self: Pin<&mut QQuickView>, mode: QQuickViewResizeMode);
+
+        /// Sets the source to the url, loads the QML component and instantiates it.
+        ///
+        /// Calling this method multiple times with the same url will result in the QML component being reinstantiated.
+        #[rust_name = "set_source"]
+        fn setSource(self: Pin<&mut QQuickView>, url: &QUrl);
+
+        /// Returns the source URL, if set.
+        fn source(self: &QQuickView) -> QUrl;
+
+        /// Returns the current status of the component.
+        fn status(self: &QQuickView) -> QQuickViewStatus;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+
+        #[doc(hidden)]
+        #[rust_name = "upcast_qquickview"]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn upcast(thiz: *const QQuickView) -> *const QQuickWindow;
+
+        #[doc(hidden)]
+        #[rust_name = "downcast_qquickwindow"]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn downcast(base: *const QQuickWindow) -> *const QQuickView;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qquickview_engine"]
+        fn qquickviewEngine(view: Pin<&mut QQuickView>) -> Pin<&mut QQmlEngine>;
+        #[doc(hidden)]
+        #[rust_name = "qquickview_errors"]
+        fn qquickviewErrors(view: &QQuickView) -> UniquePtr<QList_QQmlError>;
+        #[doc(hidden)]
+        #[rust_name = "qquickview_new"]
+        fn qquickviewNew() -> UniquePtr<QQuickView>;
+        #[doc(hidden)]
+        #[rust_name = "qquickview_root_context"]
+        fn qquickviewRootContext(view: Pin<&mut QQuickView>) -> Pin<&mut QQmlContext>;
+    }
+
+    // QQuickView is not trivial to CXX and is not relocatable in Qt
+    // so we need to use references or pointers.
+    impl UniquePtr<QQuickView> {}
+}
+
+use crate::{QQmlContext, QQmlEngine, QQmlError, QQuickWindow};
+use core::pin::Pin;
+use cxx_qt::Upcast;
+
+pub use ffi::{QQuickView, QQuickViewResizeMode, QQuickViewStatus};
+
+impl Upcast<QQuickWindow> for QQuickView {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QQuickWindow {
+        ffi::upcast_qquickview(this)
+    }
+
+    unsafe fn from_base_ptr(base: *const QQuickWindow) -> *const Self {
+        ffi::downcast_qquickwindow(base)
+    }
+}
+
+impl QQuickView {
+    /// Create a new QQuickView with its own engine, set the QML to show with [QQuickView::set_source].
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qquickview_new()
+    }
+
+    /// Returns the engine used for instantiating the QML components.
+    ///
+    /// This can be used to add import paths or image providers before [QQuickView::set_source] is called.
+    pub fn engine(self: Pin<&mut Self>) -> Pin<&mut QQmlEngine> {
+        ffi::qquickview_engine(self)
+    }
+
+    /// Return the list of errors that occurred during the last compile or create operation.
+    ///
+    /// When the status is not [QQuickViewStatus::Error], an empty list is returned.
+    pub fn errors(&self) -> Vec<QQmlError> {
+        ffi::qquickview_errors(self)
+            .as_ref()
+            .map(Vec::<QQmlError>::from)
+            .unwrap_or_default()
+    }
+
+    /// Returns the root context of the view's engine.
+    ///
+    /// Context properties set on the root context before [QQuickView::set_source] is called are available to the loaded QML.
+    pub fn root_context(self: Pin<&mut Self>) -> Pin<&mut QQmlContext> {
+        ffi::qquickview_root_context(self)
+    }
+}
@@ -0,0 +1,26 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qquickwindow.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qquickwindowHasFlag(const QQuickWindow& window, Qt::WindowType flag)
+{
+  return window.flags().testFlag(flag);
+}
+
+::std::unique_ptr<QQuickWindow>
+qquickwindowNew()
+{
+  return ::std::make_unique<QQuickWindow>();
+}
+
+}
+}
@@ -0,0 +1,218 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qquickwindow.h");
+        /// The QQuickWindow class provides the window for displaying a graphical QML scene.
+        ///
+        /// Items are added to the scene by parenting them to the [QQuickWindow::content_item],
+        /// to show a single QML file use a [QQuickView](crate::QQuickView) instead.
+        #[qobject]
+        type QQuickWindow;
+
+        /// This signal is emitted when the window receives a close event from the windowing system.
+        ///
+        /// The close event is opaque in the public Qt API, in QML the close can be prevented
+        /// by setting `close.accepted` to false in the `onClosing` handler.
+        #[qsignal]
+        unsafe fn closing(self: Pin<&mut QQuickWindow>, close: *mut QQuickCloseEvent);
+
+        /// This signal is emitted when the background color of the window changes.
+        #[qsignal]
+        #[cxx_name = "colorChanged"]
+        fn color_changed(self: Pin<&mut QQuickWindow>, color: &QColor);
+
+        /// This signal is emitted when a frame has been queued for presenting.
+        ///
+        /// With vertical synchronization enabled the signal is emitted at most once per vsync interval
+        /// in a continuously animating scene. This signal is emitted from the scene graph rendering thread.
+        ///
+        /// The handler is given the window, which is owned by the GUI thread, so it must not be called on the rendering thread.
+        /// Use `on_frame_swapped`, whose `AutoConnection` is queued when the signal is emitted from another thread,
+        /// or [ConnectionType::QueuedConnection](cxx_qt::ConnectionType::QueuedConnection) with `connect_frame_swapped`.
+        /// A `DirectConnection` would call the handler on the rendering thread while the GUI thread may be using the window.
+        #[qsignal]
+        #[cxx_name = "frameSwapped"]
+        fn frame_swapped(self: Pin<&mut QQuickWindow>);
+    }
+
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type WindowType = crate::WindowType;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+        include!("cxx-qt-lib/qimage.h");
+        type QImage = crate::QImage;
+        include!("cxx-qt-lib/qquickitem.h");
+        type QQuickItem = crate::QQuickItem;
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = crate::QSize;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// The event which is passed to [QQuickWindow::closing].
+        type QQuickCloseEvent;
+
+        /// Returns the item which currently has active focus or a null pointer if there is no item with active focus.
+        #[rust_name = "active_focus_item"]
+        fn activeFocusItem(self: &QQuickWindow) -> *mut QQuickItem;
+
+        /// Close the window, returning true if the window was closed.
+        fn close(self: Pin<&mut QQuickWindow>) -> bool;
+
+        /// Returns the color used to clear the background of the window, by default this is white.
+        fn color(self: &QQuickWindow) -> QColor;
+
+        /// Returns the invisible root item of the scene, which the items of the scene are parented to.
+        #[rust_name = "content_item"]
+        fn contentItem(self: &QQuickWindow) -> *mut QQuickItem;
+
+        /// Grabs the contents of the window and returns it as an image.
+        ///
+        /// It is possible to call this function when the window is not visible,
+        /// in which case the scene is rendered offscreen.
+        #[rust_name = "grab_window"]
+        fn grabWindow(self: Pin<&mut QQuickWindow>) -> QImage;
+
+        /// Returns the height of the window, excluding any window frame.
+        fn height(self: &QQuickWindow) -> i32;
+
+        /// Hides the window.
+        fn hide(self: Pin<&mut QQuickWindow>);
+
+        /// Returns true if the window is visible.
+        #[rust_name = "is_visible"]
+        fn isVisible(self: &QQuickWindow) -> bool;
+
+        /// Raise the window in the windowing system.
+        fn raise(self: Pin<&mut QQuickWindow>);
+
+        /// Requests the window to be activated, i.e. receive keyboard focus.
+        #[rust_name = "request_activate"]
+        fn requestActivate(self: Pin<&mut QQuickWindow>);
+
+        /// Set the size of the window, excluding any window frame.
+        fn resize(self: Pin<&mut QQuickWindow>, size: &QSize);
+
+        /// Sets the color used to clear the background of the window.
+        ///
+        /// Setting a transparent color only has an effect if the platform supports transparent windows.
+        #[rust_name = "set_color"]
+        fn setColor(self: Pin<&mut QQuickWindow>, color: &QColor);
+
+        /// Sets the given window flag if on is true, otherwise clears the flag.
+        #[rust_name = "set_flag"]
+        fn setFlag(self: Pin<&mut QQuickWindow>, flag: WindowType, on: bool);
+
+        /// Set the title of the window in the windowing system.
+        #[rust_name = "set_title"]
+        fn setTitle(self: Pin<&mut QQuickWindow>, title: &QString);
+
+        /// Sets whether the window is visible.
+        #[rust_name = "set_visible"]
+        fn setVisible(self: Pin<&mut QQuickWindow>, visible: bool);
+
+        /// Shows the window, the platform decides whether it is shown normally, maximized or full screen.
+        fn show(self: Pin<&mut QQuickWindow>);
+
+        /// Shows the window as full screen.
+        #[rust_name = "show_full_screen"]
+        fn showFullScreen(self: Pin<&mut QQuickWindow>);
+
+        /// Shows the window as maximized.
+        #[rust_name = "show_maximized"]
+        fn showMaximized(self: Pin<&mut QQuickWindow>);
+
+        /// Shows the window as minimized.
+        #[rust_name = "show_minimized"]
+        fn showMinimized(self: Pin<&mut QQuickWindow>);
+
+        /// Shows the window as normal, i.e. neither maximized, minimized, nor full screen.
+        #[rust_name = "show_normal"]
+        fn showNormal(self: Pin<&mut QQuickWindow>);
+
+        /// Returns the size of the window, excluding any window frame.
+        fn size(self: &QQuickWindow) -> QSize;
+
+        /// Returns the title of the window in the windowing system.
+        fn title(self: &QQuickWindow) -> QString;
+
+        /// Returns the type of the window, which is the part of the window flags that is not a hint.
+        #[cxx_name = "type"]
+        fn window_type(self: &QQuickWindow) -> WindowType;
+
+        /// Schedules the window to render another frame.
+        fn update(self: Pin<&mut QQuickWindow>);
+
+        /// Returns the width of the window, excluding any window frame.
+        fn width(self: &QQuickWindow) -> i32;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+        type QObject = cxx_qt::QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "upcast_qquickwindow"]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn upcast(thiz: *const QQuickWindow) -> *const QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "downcast_qobject"]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn downcast(base: *const QObject) -> *const QQuickWindow;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qquickwindow_has_flag"]
+        fn qquickwindowHasFlag(window: &QQuickWindow, flag: WindowType) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qquickwindow_new"]
+        fn qquickwindowNew() -> UniquePtr<QQuickWindow>;
+    }
+
+    // QQuickWindow is not trivial to CXX and is not relocatable in Qt
+    // so we need to use references or pointers.
+    impl UniquePtr<QQuickWindow> {}
+}
+
+use crate::WindowType;
+use cxx_qt::{QObject, Upcast};
+
+pub use ffi::{QQuickCloseEvent, QQuickWindow};
+
+impl Upcast<QObject> for QQuickWindow {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QObject {
+        ffi::upcast_qquickwindow(this)
+    }
+
+    unsafe fn from_base_ptr(base: *const QObject) -> *const Self {
+        ffi::downcast_qobject(base)
+    }
+}
+
+impl QQuickWindow {
+    /// Create a new QQuickWindow with an empty scene, which is not shown until [QQuickWindow::show] is called.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qquickwindow_new()
+    }
+
+    /// Returns true if the given flag is enabled for this window.
+    ///
+    /// As the types of windows share bits, use [QQuickWindow::window_type] to check the type of the window.
+    pub fn has_flag(&self, flag: WindowType) -> bool {
+        ffi::qquickwindow_has_flag(self, flag)
+    }
+}
@@ -0,0 +1,22 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qsgflatcolormaterial.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSGFlatColorMaterial>
+qsgflatcolormaterialNew(const QColor& color)
+{
+  auto material = ::std::make_unique<QSGFlatColorMaterial>();
+  material->setColor(color);
+  return material;
+}
+
+}
+}
@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+
+        include!("cxx-qt-lib/qsgflatcolormaterial.h");
+        /// The QSGMaterial class encapsulates rendering state for a shader program.
+        ///
+        /// This is the base class of the materials which can be set on a [QSGGeometryNode](crate::QSGGeometryNode).
+        type QSGMaterial;
+
+        /// The QSGFlatColorMaterial class provides a convenient way of rendering solid colored geometry in the scene graph.
+        ///
+        /// The geometry must use two dimensional points as vertices, such as [QSGGeometry::new_point_2d](crate::QSGGeometry::new_point_2d).
+        type QSGFlatColorMaterial;
+
+        /// Returns this flat color material's color. The default color is black.
+        fn color(self: &QSGFlatColorMaterial) -> &QColor;
+
+        /// Sets this flat color material's color.
+        ///
+        /// The node using the material must be marked with [QSGNodeDirtyStateBit::DirtyMaterial](crate::QSGNodeDirtyStateBit::DirtyMaterial)
+        /// for the change to be rendered.
+        #[rust_name = "set_color"]
+        fn setColor(self: Pin<&mut QSGFlatColorMaterial>, color: &QColor);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+
+        #[doc(hidden)]
+        #[rust_name = "upcast_qsgflatcolormaterial"]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn upcast(thiz: *const QSGFlatColorMaterial) -> *const QSGMaterial;
+
+        #[doc(hidden)]
+        #[rust_name = "downcast_qsgmaterial"]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn downcast(base: *const QSGMaterial) -> *const QSGFlatColorMaterial;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsgflatcolormaterial_new"]
+        fn qsgflatcolormaterialNew(color: &QColor) -> UniquePtr<QSGFlatColorMaterial>;
+    }
+
+    // QSGFlatColorMaterial and QSGMaterial are not trivial to CXX and are not relocatable in Qt
+    // so we need to use references or pointers.
+    impl UniquePtr<QSGFlatColorMaterial> {}
+    impl UniquePtr<QSGMaterial> {}
+}
+
+use crate::QColor;
+use cxx::UniquePtr;
+use cxx_qt::Upcast;
+
+pub use ffi::{QSGFlatColorMaterial, QSGMaterial};
+
+impl QSGFlatColorMaterial {
+    /// Create a new flat color material with the given color.
+    pub fn new(color: &QColor) -> UniquePtr<Self> {
+        ffi::qsgflatcolormaterial_new(color)
+    }
+}
+
+impl Upcast<QSGMaterial> for QSGFlatColorMaterial {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QSGMaterial {
+        ffi::upcast_qsgflatcolormaterial(this)
+    }
+
+    unsafe fn from_base_ptr(base: *const QSGMaterial) -> *const Self {
+        ffi::downcast_qsgmaterial(base)
+    }
+}
@@ -0,0 +1,63 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qsggeometry.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QSGGeometryDrawingMode
+qsggeometryDrawingMode(const QSGGeometry& geometry)
+{
+  return static_cast<QSGGeometryDrawingMode>(geometry.drawingMode());
+}
+
+bool
+qsggeometryIsPoint2D(const QSGGeometry& geometry)
+{
+  return geometry.attributeCount() == 1 &&
+         geometry.sizeOfVertex() ==
+           static_cast<int>(sizeof(QSGGeometry::Point2D));
+}
+
+::std::unique_ptr<QSGGeometry>
+qsggeometryNewPoint2D(::std::int32_t vertexCount)
+{
+  return ::std::make_unique<QSGGeometry>(
+    QSGGeometry::defaultAttributes_Point2D(), static_cast<int>(vertexCount));
+}
+
+QPointF
+qsggeometryPoint2DAt(const QSGGeometry& geometry, ::std::int32_t index)
+{
+  const auto& point = geometry.vertexDataAsPoint2D()[index];
+  return QPointF(point.x, point.y);
+}
+
+void
+qsggeometrySetDrawingMode(QSGGeometry& geometry, QSGGeometryDrawingMode mode)
+{
+  geometry.setDrawingMode(mode);
+}
+
+void
+qsggeometrySetPoints2D(QSGGeometry& geometry,
+                       ::rust::Slice<const QPointF> points)
+{
+  geometry.allocate(static_cast<int>(points.size()));
+
+  QSGGeometry::Point2D* vertices = geometry.vertexDataAsPoint2D();
+  for (::std::size_t i = 0; i < points.size(); ++i) {
+    vertices[i].set(static_cast<float>(points[i].x()),
+                    static_cast<float>(points[i].y()));
+  }
+
+  geometry.markVertexDataDirty();
+}
+
+}
+}
@@ -0,0 +1,137 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// Specifies how the vertices of a [QSGGeometry] are drawn.
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum QSGGeometryDrawingMode {
+        /// Each vertex is drawn as a point.
+        DrawPoints = 0x0000,
+        /// Each pair of vertices is drawn as a line.
+        DrawLines = 0x0001,
+        /// The vertices are drawn as a closed loop of lines.
+        DrawLineLoop = 0x0002,
+        /// The vertices are drawn as a connected strip of lines.
+        DrawLineStrip = 0x0003,
+        /// Each three vertices are drawn as a triangle.
+        DrawTriangles = 0x0004,
+        /// The vertices are drawn as a connected strip of triangles.
+        DrawTriangleStrip = 0x0005,
+        /// The vertices are drawn as a fan of triangles around the first vertex.
+        DrawTriangleFan = 0x0006,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+
+        include!("cxx-qt-lib/qsggeometry.h");
+        /// The QSGGeometry class provides low-level storage for graphics primitives in the Qt Quick Scene Graph.
+        ///
+        /// Geometry created from Rust uses two dimensional points as vertices,
+        /// and is owned by a [QSGGeometryNode](crate::QSGGeometryNode) once it is set on the node.
+        type QSGGeometry;
+
+        type QSGGeometryDrawingMode;
+
+        /// Returns the width of the lines when the geometry is drawn as lines.
+        #[rust_name = "line_width"]
+        fn lineWidth(self: &QSGGeometry) -> f32;
+
+        /// Notifies the renderer that the vertex data has changed and needs to be uploaded again.
+        #[rust_name = "mark_vertex_data_dirty"]
+        fn markVertexDataDirty(self: Pin<&mut QSGGeometry>);
+
+        /// Sets the width of the lines when the geometry is drawn as lines.
+        ///
+        /// Note that the supported line widths depend on the graphics backend,
+        /// widths other than 1 are not supported by all backends.
+        #[rust_name = "set_line_width"]
+        fn setLineWidth(self: Pin<&mut QSGGeometry>, width: f32);
+
+        /// Returns the number of vertices in the geometry.
+        #[rust_name = "vertex_count"]
+        fn vertexCount(self: &QSGGeometry) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_drawing_mode"]
+        fn qsggeometryDrawingMode(geometry: &QSGGeometry) -> QSGGeometryDrawingMode;
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_is_point_2d"]
+        fn qsggeometryIsPoint2D(geometry: &QSGGeometry) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_new_point_2d"]
+        fn qsggeometryNewPoint2D(vertex_count: i32) -> UniquePtr<QSGGeometry>;
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_point_2d_at"]
+        fn qsggeometryPoint2DAt(geometry: &QSGGeometry, index: i32) -> QPointF;
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_set_drawing_mode"]
+        fn qsggeometrySetDrawingMode(geometry: Pin<&mut QSGGeometry>, mode: QSGGeometryDrawingMode);
+        #[doc(hidden)]
+        #[rust_name = "qsggeometry_set_points_2d"]
+        fn qsggeometrySetPoints2D(geometry: Pin<&mut QSGGeometry>, points: &[QPointF]);
+    }
+
+    // QSGGeometry is not trivial to CXX and is not relocatable in Qt
+    // so we need to use references or pointers.
+    impl UniquePtr<QSGGeometry> {}
+}
+
+use crate::QPointF;
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+pub use ffi::{QSGGeometry, QSGGeometryDrawingMode};
+
+impl QSGGeometry {
+    /// Create a new geometry with the given number of two dimensional point vertices, which are initialised to zero.
+    pub fn new_point_2d(vertex_count: i32) -> UniquePtr<Self> {
+        ffi::qsggeometry_new_point_2d(vertex_count)
+    }
+
+    /// Returns the drawing mode of the geometry, the default is [QSGGeometryDrawingMode::DrawTriangleStrip].
+    pub fn drawing_mode(&self) -> QSGGeometryDrawingMode {
+        ffi::qsggeometry_drawing_mode(self)
+    }
+
+    /// Returns true if the vertices of this geometry are two dimensional points.
+    pub fn is_point_2d(&self) -> bool {
+        ffi::qsggeometry_is_point_2d(self)
+    }
+
+    /// Returns the vertex at the given index as a point,
+    /// or None if the index is out of range or the vertices are not two dimensional points.
+    pub fn point_2d_at(&self, index: i32) -> Option<QPointF> {
+        if self.is_point_2d() && (0..self.vertex_count()).contains(&index) {
+            Some(ffi::qsggeometry_point_2d_at(self, index))
+        } else {
+            None
+        }
+    }
+
+    /// Sets the drawing mode of the geometry.
+    pub fn set_drawing_mode(self: Pin<&mut Self>, mode: QSGGeometryDrawingMode) {
+        ffi::qsggeometry_set_drawing_mode(self, mode)
+    }
+
+    /// Resizes the geometry to the number of points given and copies the points into the vertex data,
+    /// then marks the vertex data as dirty.
+    ///
+    /// Returns false and leaves the geometry unchanged if the vertices are not two dimensional points.
+    pub fn set_points_2d(self: Pin<&mut Self>, points: &[QPointF]) -> bool {
+        if self.is_point_2d() {
+            ffi::qsggeometry_set_points_2d(self, points);
+            true
+        } else {
+            false
+        }
+    }
+}
@@ -0,0 +1,55 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qsggeometrynode.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QSGGeometry*
+qsggeometrynodeGeometry(QSGGeometryNode& node)
+{
+  return node.geometry();
+}
+
+QSGMaterial*
+qsggeometrynodeMaterial(QSGGeometryNode& node)
+{
+  return node.material();
+}
+
+::std::unique_ptr<QSGGeometryNode>
+qsggeometrynodeNew(QSGGeometry* geometry, QSGMaterial* material)
+{
+  // Rust has released ownership of the geometry and material,
+  // so ensure the node deletes them
+  auto node = ::std::make_unique<QSGGeometryNode>();
+  node->setGeometry(geometry);
+  node->setMaterial(material);
+  node->setFlags(QSGNode::OwnsGeometry | QSGNode::OwnsMaterial, true);
+  return node;
+}
+
+void
+qsggeometrynodeSetGeometry(QSGGeometryNode& node, QSGGeometry* geometry)
+{
+  // Rust has released ownership of the geometry, so ensure the node deletes it
+  //
+  // Note that setGeometry deletes the previous geometry if it was owned
+  node.setGeometry(geometry);
+  node.setFlag(QSGNode::OwnsGeometry, true);
+}
+
+void
+qsggeometrynodeSetMaterial(QSGGeometryNode& node, QSGMaterial* material)
+{
+  node.setMaterial(material);
+  node.setFlag(QSGNode::OwnsMaterial, true);
+}
+
+}
+}
@@ -0,0 +1,159 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsgflatcolormaterial.h");
+        type QSGMaterial = crate::QSGMaterial;
+        include!("cxx-qt-lib/qsggeometry.h");
+        type QSGGeometry = crate::QSGGeometry;
+        include!("cxx-qt-lib/qsgnode.h");
+        type QSGNode = crate::QSGNode;
+
+        include!("cxx-qt-lib/qsggeometrynode.h");
+        /// The QSGGeometryNode class is used for all rendered content in the scene graph.
+        ///
+        /// The node renders its [QSGGeometry] with its [QSGMaterial](crate::QSGMaterial),
+        /// both of which are given to the node when it is created and are owned by the node.
+        ///
+        /// Note that the software scene graph backend does not render custom geometry,
+        /// use a [QSGSimpleRectNode](crate::QSGSimpleRectNode) for content which must also be rendered there.
+        type QSGGeometryNode;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+
+        #[doc(hidden)]
+        #[rust_name = "upcast_qsggeometrynode"]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn upcast(thiz: *const QSGGeometryNode) -> *const QSGNode;
+
+        #[doc(hidden)]
+        #[rust_name = "downcast_qsgnode"]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn downcast(base: *const QSGNode) -> *const QSGGeometryNode;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsggeometrynode_geometry"]
+        fn qsggeometrynodeGeometry(node: Pin<&mut QSGGeometryNode>) -> *mut QSGGeometry;
+        #[doc(hidden)]
+        #[rust_name = "qsggeometrynode_material"]
+        fn qsggeometrynodeMaterial(node: Pin<&mut QSGGeometryNode>) -> *mut QSGMaterial;
+        #[doc(hidden)]
+        #[rust_name = "qsggeometrynode_new"]
+        unsafe fn qsggeometrynodeNew(
+            geometry: *mut QSGGeometry,
+            material: *mut QSGMaterial,
+        ) -> UniquePtr<QSGGeometryNode>;
+        #[doc(hidden)]
+        #[rust_name = "qsggeometrynode_set_geometry"]
+        unsafe fn qsggeometrynodeSetGeometry(
+            node: Pin<&mut QSGGeometryNode>,
+            geometry: *mut QSGGeometry,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qsggeometrynode_set_material"]
+        unsafe fn qsggeometrynodeSetMaterial(
+            node: Pin<&mut QSGGeometryNode>,
+            material: *mut QSGMaterial,
+        );
+    }
+
+    // QSGGeometryNode is not trivial to CXX and is not relocatable in Qt
+    // so we need to use references or pointers.
+    impl UniquePtr<QSGGeometryNode> {}
+}
+
+use crate::{QSGGeometry, QSGMaterial, QSGNode};
+use core::pin::Pin;
+use cxx::{memory::UniquePtrTarget, UniquePtr};
+use cxx_qt::{Downcast, Upcast};
+
+pub use ffi::QSGGeometryNode;
+
+impl QSGGeometryNode {
+    /// Create a new geometry node which renders the given geometry with the given material,
+    /// the node takes ownership of both.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the geometry or the material is null, as the scene graph cannot render such a node.
+    pub fn new<T: Upcast<QSGMaterial> + UniquePtrTarget>(
+        geometry: UniquePtr<QSGGeometry>,
+        material: UniquePtr<T>,
+    ) -> UniquePtr<Self> {
+        assert!(!geometry.is_null(), "QSGGeometryNode requires a geometry");
+        assert!(!material.is_null(), "QSGGeometryNode requires a material");
+
+        unsafe {
+            let material = T::upcast_ptr(material.into_raw()) as *mut QSGMaterial;
+            ffi::qsggeometrynode_new(geometry.into_raw(), material)
+        }
+    }
+
+    /// Returns the geometry of this node, or None if no geometry has been set.
+    pub fn geometry_mut(self: Pin<&mut Self>) -> Option<Pin<&mut QSGGeometry>> {
+        let geometry = ffi::qsggeometrynode_geometry(self);
+        unsafe {
+            geometry
+                .as_mut()
+                .map(|geometry| Pin::new_unchecked(geometry))
+        }
+    }
+
+    /// Returns the material of this node as the given material type,
+    /// or None if no material has been set or it is a different type of material.
+    pub fn material_mut<T: Upcast<QSGMaterial>>(self: Pin<&mut Self>) -> Option<Pin<&mut T>> {
+        let material = ffi::qsggeometrynode_material(self);
+        unsafe {
+            material
+                .as_mut()
+                .and_then(|material| Pin::new_unchecked(material).downcast_pin::<T>())
+        }
+    }
+
+    /// Sets the geometry of this node, the node takes ownership of the geometry
+    /// and deletes any geometry that it previously owned.
+    ///
+    /// The node is marked with [QSGNodeDirtyStateBit::DirtyGeometry](crate::QSGNodeDirtyStateBit::DirtyGeometry).
+    pub fn set_geometry(self: Pin<&mut Self>, geometry: UniquePtr<QSGGeometry>) {
+        if !geometry.is_null() {
+            unsafe { ffi::qsggeometrynode_set_geometry(self, geometry.into_raw()) }
+        }
+    }
+
+    /// Sets the material of this node, the node takes ownership of the material
+    /// and deletes any material that it previously owned.
+    ///
+    /// The node is marked with [QSGNodeDirtyStateBit::DirtyMaterial](crate::QSGNodeDirtyStateBit::DirtyMaterial).
+    pub fn set_material<T: Upcast<QSGMaterial> + UniquePtrTarget>(
+        self: Pin<&mut Self>,
+        material: UniquePtr<T>,
+    ) {
+        if !material.is_null() {
+            unsafe {
+                let material = T::upcast_ptr(material.into_raw()) as *mut QSGMaterial;
+                ffi::qsggeometrynode_set_material(self, material)
+            }
+        }
+    }
+}
+
+impl Upcast<QSGNode> for QSGGeometryNode {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QSGNode {
+        ffi::upcast_qsggeometrynode(this)
+    }
+
+    unsafe fn from_base_ptr(base: *const QSGNode) -> *const Self {
+        ffi::downcast_qsgnode(base)
+    }
+}
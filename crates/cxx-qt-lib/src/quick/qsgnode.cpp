@@ -0,0 +1,58 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qsgnode.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qsgnodeAppendChildNode(QSGNode& parent, QSGNode* node)
+{
+  // Rust has released ownership of the node, so ensure the parent deletes it
+  node->setFlag(QSGNode::OwnedByParent, true);
+  parent.appendChildNode(node);
+}
+
+void
+qsgnodeDeleteAllChildNodes(QSGNode& node)
+{
+  while (QSGNode* child = node.firstChild()) {
+    node.removeChildNode(child);
+    if (child->flags().testFlag(QSGNode::OwnedByParent)) {
+      delete child;
+    }
+  }
+}
+
+bool
+qsgnodeHasFlag(const QSGNode& node, QSGNodeFlag flag)
+{
+  return node.flags().testFlag(flag);
+}
+
+void
+qsgnodeMarkDirty(QSGNode& node, QSGNodeDirtyStateBit bit)
+{
+  node.markDirty(bit);
+}
+
+::std::unique_ptr<QSGNode>
+qsgnodeNew()
+{
+  return ::std::make_unique<QSGNode>();
+}
+
+void
+qsgnodePrependChildNode(QSGNode& parent, QSGNode* node)
+{
+  node->setFlag(QSGNode::OwnedByParent, true);
+  parent.prependChildNode(node);
+}
+
+}
+}
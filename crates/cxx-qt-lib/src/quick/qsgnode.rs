@@ -0,0 +1,198 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// Used in [QSGNode::mark_dirty] to indicate what has changed in the node.
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum QSGNodeDirtyStateBit {
+        /// The subtree of the node has been blocked or unblocked.
+        DirtySubtreeBlocked = 0x0080,
+        /// The matrix of a transform node has changed.
+        DirtyMatrix = 0x0100,
+        /// A child node has been added.
+        DirtyNodeAdded = 0x0400,
+        /// A child node has been removed.
+        DirtyNodeRemoved = 0x0800,
+        /// The geometry of a geometry node has changed.
+        DirtyGeometry = 0x1000,
+        /// The material of a geometry node has changed.
+        DirtyMaterial = 0x2000,
+        /// The opacity of an opacity node has changed.
+        DirtyOpacity = 0x4000,
+    }
+
+    /// Flags which control the ownership and behavior of a [QSGNode].
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum QSGNodeFlag {
+        /// The node is deleted when its parent is deleted.
+        OwnedByParent = 0x0001,
+        /// The node's `preprocess()` is called before rendering.
+        UsePreprocess = 0x0002,
+        /// The geometry node owns its geometry and deletes it when the node is deleted.
+        OwnsGeometry = 0x00010000,
+        /// The geometry node owns its material and deletes it when the node is deleted.
+        OwnsMaterial = 0x00020000,
+        /// The geometry node owns its opaque material and deletes it when the node is deleted.
+        OwnsOpaqueMaterial = 0x00040000,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsgnode.h");
+        /// The QSGNode class is the base class for all nodes in the scene graph.
+        ///
+        /// A tree of nodes is returned from the `updatePaintNode` method of a [QQuickItem](crate::QQuickItem),
+        /// which is called on the render thread while the GUI thread is blocked.
+        /// Nodes created in Rust are held in a `UniquePtr` until they are appended to a parent node
+        /// or released to the scene graph with [QSGNode::into_raw].
+        type QSGNode;
+
+        type QSGNodeDirtyStateBit;
+        type QSGNodeFlag;
+
+        /// Returns the child at the given index, or a null pointer if there is no child at that index.
+        #[rust_name = "child_at_index"]
+        fn childAtIndex(self: &QSGNode, i: i32) -> *mut QSGNode;
+
+        /// Returns the number of child nodes.
+        #[rust_name = "child_count"]
+        fn childCount(self: &QSGNode) -> i32;
+
+        /// Returns the first child of this node, or a null pointer if there are no children.
+        #[rust_name = "first_child"]
+        fn firstChild(self: &QSGNode) -> *mut QSGNode;
+
+        /// Returns true if this node and its subtree should not be rendered.
+        #[rust_name = "is_subtree_blocked"]
+        fn isSubtreeBlocked(self: &QSGNode) -> bool;
+
+        /// Returns the last child of this node, or a null pointer if there are no children.
+        #[rust_name = "last_child"]
+        fn lastChild(self: &QSGNode) -> *mut QSGNode;
+
+        /// Returns the node after this in the parent's list of children, or a null pointer if this is the last child.
+        #[rust_name = "next_sibling"]
+        fn nextSibling(self: &QSGNode) -> *mut QSGNode;
+
+        /// Returns the parent node of this node, or a null pointer if it has no parent.
+        fn parent(self: &QSGNode) -> *mut QSGNode;
+
+        /// Returns the node before this in the parent's list of children, or a null pointer if this is the first child.
+        #[rust_name = "previous_sibling"]
+        fn previousSibling(self: &QSGNode) -> *mut QSGNode;
+
+        /// Enables the given flag on this node if enabled is true, otherwise disables it.
+        ///
+        /// # Safety
+        ///
+        /// The ownership flags decide which nodes, geometries and materials are deleted with this node,
+        /// so changing them must not cause anything to be leaked, deleted twice or deleted while still in use.
+        #[rust_name = "set_flag"]
+        unsafe fn setFlag(self: Pin<&mut QSGNode>, flag: QSGNodeFlag, enabled: bool);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsgnode_append_child_node"]
+        unsafe fn qsgnodeAppendChildNode(parent: Pin<&mut QSGNode>, node: *mut QSGNode);
+        #[doc(hidden)]
+        #[rust_name = "qsgnode_delete_all_child_nodes"]
+        fn qsgnodeDeleteAllChildNodes(node: Pin<&mut QSGNode>);
+        #[doc(hidden)]
+        #[rust_name = "qsgnode_has_flag"]
+        fn qsgnodeHasFlag(node: &QSGNode, flag: QSGNodeFlag) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qsgnode_mark_dirty"]
+        fn qsgnodeMarkDirty(node: Pin<&mut QSGNode>, bit: QSGNodeDirtyStateBit);
+        #[doc(hidden)]
+        #[rust_name = "qsgnode_new"]
+        fn qsgnodeNew() -> UniquePtr<QSGNode>;
+        #[doc(hidden)]
+        #[rust_name = "qsgnode_prepend_child_node"]
+        unsafe fn qsgnodePrependChildNode(parent: Pin<&mut QSGNode>, node: *mut QSGNode);
+    }
+
+    // QSGNode is not trivial to CXX and is not relocatable in Qt
+    // so we need to use references or pointers.
+    impl UniquePtr<QSGNode> {}
+}
+
+use core::pin::Pin;
+use cxx::{memory::UniquePtrTarget, UniquePtr};
+use cxx_qt::Upcast;
+
+pub use ffi::{QSGNode, QSGNodeDirtyStateBit, QSGNodeFlag};
+
+impl QSGNode {
+    /// Create a new QSGNode which can be used to group other nodes.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qsgnode_new()
+    }
+
+    /// Appends node to this node's list of children, this node then owns the child.
+    pub fn append_child_node<T: Upcast<QSGNode> + UniquePtrTarget>(
+        self: Pin<&mut Self>,
+        node: UniquePtr<T>,
+    ) {
+        let node = Self::into_raw(node);
+        if !node.is_null() {
+            unsafe { ffi::qsgnode_append_child_node(self, node) }
+        }
+    }
+
+    /// Removes and deletes all the child nodes owned by this node.
+    ///
+    /// Child nodes which are not [QSGNodeFlag::OwnedByParent] are removed but not deleted.
+    pub fn delete_all_child_nodes(self: Pin<&mut Self>) {
+        ffi::qsgnode_delete_all_child_nodes(self)
+    }
+
+    /// Returns true if the given flag is enabled for this node.
+    pub fn has_flag(&self, flag: QSGNodeFlag) -> bool {
+        ffi::qsgnode_has_flag(self, flag)
+    }
+
+    /// Releases ownership of the node, so that it can be returned to the scene graph from `updatePaintNode`.
+    ///
+    /// The scene graph deletes the node once it is no longer needed,
+    /// or passes it back to the next call of `updatePaintNode` as the old node.
+    pub fn into_raw<T: Upcast<QSGNode> + UniquePtrTarget>(node: UniquePtr<T>) -> *mut QSGNode {
+        let node = node.into_raw();
+        if node.is_null() {
+            core::ptr::null_mut()
+        } else {
+            unsafe { T::upcast_ptr(node) as *mut QSGNode }
+        }
+    }
+
+    /// Notifies all connected renderers that the node has dirty state.
+    pub fn mark_dirty(self: Pin<&mut Self>, bit: QSGNodeDirtyStateBit) {
+        ffi::qsgnode_mark_dirty(self, bit)
+    }
+
+    /// Prepends node to this node's list of children, this node then owns the child.
+    pub fn prepend_child_node<T: Upcast<QSGNode> + UniquePtrTarget>(
+        self: Pin<&mut Self>,
+        node: UniquePtr<T>,
+    ) {
+        let node = Self::into_raw(node);
+        if !node.is_null() {
+            unsafe { ffi::qsgnode_prepend_child_node(self, node) }
+        }
+    }
+}
+
+impl Upcast<QSGNode> for QSGNode {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QSGNode {
+        this
+    }
+
+    unsafe fn from_base_ptr(base: *const QSGNode) -> *const Self {
+        base
+    }
+}
@@ -0,0 +1,20 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qsgsimplerectnode.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSGSimpleRectNode>
+qsgsimplerectnodeNew(const QRectF& rect, const QColor& color)
+{
+  return ::std::make_unique<QSGSimpleRectNode>(rect, color);
+}
+
+}
+}
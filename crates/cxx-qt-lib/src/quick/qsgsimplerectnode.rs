@@ -0,0 +1,87 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+        include!("cxx-qt-lib/qrectf.h");
+        type QRectF = crate::QRectF;
+        include!("cxx-qt-lib/qsgnode.h");
+        type QSGNode = crate::QSGNode;
+
+        include!("cxx-qt-lib/qsgsimplerectnode.h");
+        /// The QSGSimpleRectNode class is a convenience class for drawing solid filled rectangles using scenegraph.
+        ///
+        /// Unlike a [QSGGeometryNode](crate::QSGGeometryNode) with custom geometry,
+        /// this node is also rendered by the software scene graph backend.
+        type QSGSimpleRectNode;
+
+        /// Returns the color of this rectangle.
+        fn color(self: &QSGSimpleRectNode) -> QColor;
+
+        /// Returns the rectangle that this node covers.
+        fn rect(self: &QSGSimpleRectNode) -> QRectF;
+
+        /// Sets the color of this rectangle, the node is marked dirty automatically.
+        #[rust_name = "set_color"]
+        fn setColor(self: Pin<&mut QSGSimpleRectNode>, color: &QColor);
+
+        /// Sets the rectangle of this node, the node is marked dirty automatically.
+        #[rust_name = "set_rect"]
+        fn setRect(self: Pin<&mut QSGSimpleRectNode>, rect: &QRectF);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+
+        #[doc(hidden)]
+        #[rust_name = "upcast_qsgsimplerectnode"]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn upcast(thiz: *const QSGSimpleRectNode) -> *const QSGNode;
+
+        #[doc(hidden)]
+        #[rust_name = "downcast_qsgnode"]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn downcast(base: *const QSGNode) -> *const QSGSimpleRectNode;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsgsimplerectnode_new"]
+        fn qsgsimplerectnodeNew(rect: &QRectF, color: &QColor) -> UniquePtr<QSGSimpleRectNode>;
+    }
+
+    // QSGSimpleRectNode is not trivial to CXX and is not relocatable in Qt
+    // so we need to use references or pointers.
+    impl UniquePtr<QSGSimpleRectNode> {}
+}
+
+use crate::{QColor, QRectF, QSGNode};
+use cxx::UniquePtr;
+use cxx_qt::Upcast;
+
+pub use ffi::QSGSimpleRectNode;
+
+impl QSGSimpleRectNode {
+    /// Create a new rectangle node covering rect and filled with color.
+    pub fn new(rect: &QRectF, color: &QColor) -> UniquePtr<Self> {
+        ffi::qsgsimplerectnode_new(rect, color)
+    }
+}
+
+impl Upcast<QSGNode> for QSGSimpleRectNode {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QSGNode {
+        ffi::upcast_qsgsimplerectnode(this)
+    }
+
+    unsafe fn from_base_ptr(base: *const QSGNode) -> *const Self {
+        ffi::downcast_qsgnode(base)
+    }
+}
@@ -0,0 +1,64 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_build::CxxQtBuilder;
+use std::path::PathBuf;
+
+fn header_dir() -> PathBuf {
+    PathBuf::from(std::env::var("OUT_DIR").unwrap())
+        .join("include")
+        .join("cxx-qt-test")
+}
+
+fn write_headers() {
+    println!("cargo::rerun-if-changed=include/");
+    std::fs::create_dir_all(header_dir()).expect("Failed to create include directory");
+
+    for entry in std::fs::read_dir("include").expect("Failed to read include directory") {
+        let entry = entry.expect("Failed to read header file!");
+        let header_name = entry.file_name();
+        println!(
+            "cargo::rerun-if-changed=include/{header_name}",
+            header_name = header_name.to_string_lossy()
+        );
+
+        std::fs::copy(entry.path(), header_dir().join(header_name))
+            .expect("Failed to copy header file!");
+    }
+}
+
+fn main() {
+    write_headers();
+
+    let interface = cxx_qt_build::Interface::default()
+        // Disable exporting the standard include directory, as we are exporting custom headers
+        .export_include_prefixes([])
+        .export_include_directory(header_dir(), "cxx-qt-test")
+        .reexport_dependency("cxx-qt-lib");
+
+    let mut builder = CxxQtBuilder::library(interface)
+        .qt_module("Gui")
+        .qt_module("Qml")
+        .qt_module("Quick")
+        // QTest is used to send synthetic input events and wait for windows
+        .qt_module("Test");
+
+    let rust_bridges = vec!["qmltest"];
+
+    for rust_source in &rust_bridges {
+        builder = builder.file(format!("src/{rust_source}.rs"));
+    }
+
+    let cpp_files = vec!["qmltest"];
+
+    builder = builder.cc_builder(move |cc| {
+        for cpp_file in &cpp_files {
+            cc.file(format!("src/{cpp_file}.cpp"));
+            println!("cargo::rerun-if-changed=src/{cpp_file}.cpp");
+        }
+    });
+
+    builder.include_prefix("cxx-qt-test-internals").build();
+}
@@ -0,0 +1,86 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QObject>
+#include <QtCore/QPointF>
+#include <QtCore/QSizeF>
+#include <QtCore/QString>
+#include <QtGui/QGuiApplication>
+#include <QtQuick/QQuickWindow>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqttest1 {
+
+using Key = Qt::Key;
+
+using MessageHandler =
+  ::rust::Fn<void(bool isWarning, ::rust::Str category, const QString& message)>;
+
+::std::unique_ptr<QGuiApplication>
+qmltestApplicationNew();
+
+void
+qmltestInstallMessageHandler(MessageHandler handler);
+
+void
+qmltestProcessEvents(::std::int32_t maxTime);
+
+bool
+qmltestIsItem(const QObject& object);
+QPointF
+qmltestItemMapToScene(const QObject& object, const QPointF& point);
+QSizeF
+qmltestItemSize(const QObject& object);
+
+void
+qmltestKeyClick(QQuickWindow& window, Key key, ::std::int32_t modifiers);
+void
+qmltestKeyClicks(QQuickWindow& window,
+                 const QString& text,
+                 ::std::int32_t modifiers);
+void
+qmltestKeyPress(QQuickWindow& window, Key key, ::std::int32_t modifiers);
+void
+qmltestKeyRelease(QQuickWindow& window, Key key, ::std::int32_t modifiers);
+
+void
+qmltestMouseClick(QQuickWindow& window,
+                  Qt::MouseButton button,
+                  ::std::int32_t modifiers,
+                  const QPointF& position);
+void
+qmltestMouseDoubleClick(QQuickWindow& window,
+                        Qt::MouseButton button,
+                        ::std::int32_t modifiers,
+                        const QPointF& position);
+void
+qmltestMouseMove(QQuickWindow& window, const QPointF& position);
+void
+qmltestMousePress(QQuickWindow& window,
+                  Qt::MouseButton button,
+                  ::std::int32_t modifiers,
+                  const QPointF& position);
+void
+qmltestMouseRelease(QQuickWindow& window,
+                    Qt::MouseButton button,
+                    ::std::int32_t modifiers,
+                    const QPointF& position);
+
+QQuickWindow*
+qmltestWindowFromObject(QObject& object);
+::std::unique_ptr<QQuickWindow>
+qmltestWindowNewForItem(QObject& object);
+bool
+qmltestWindowShow(QQuickWindow& window, ::std::int32_t timeout);
+
+}
+}
@@ -0,0 +1,215 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::QImage;
+use image::{Rgba, RgbaImage};
+use std::path::{Path, PathBuf};
+
+/// The environment variable which, when set to `1`, writes the actual images as the golden images
+/// in [assert_image_matches] instead of comparing them.
+pub const UPDATE_GOLDEN_ENV: &str = "CXX_QT_TEST_UPDATE_GOLDEN";
+
+/// The difference between two images, compared pixel by pixel.
+///
+/// A pixel differs when any of its channels differs by more than the tolerance, which allows for
+/// small differences in anti-aliasing. If the sizes of the images differ then any pixel which is
+/// only in one of the images also differs.
+pub struct ImageDiff {
+    differing_pixels: u64,
+    image: RgbaImage,
+    max_difference: u8,
+    size_matches: bool,
+}
+
+impl ImageDiff {
+    /// Compare the actual image with the expected image, both are converted to RGBA8888.
+    pub fn new(actual: &QImage, expected: &QImage, tolerance: u8) -> Self {
+        Self::from_rgba(&actual.into(), &expected.into(), tolerance)
+    }
+
+    /// Compare the actual image with the expected image.
+    pub fn from_rgba(actual: &RgbaImage, expected: &RgbaImage, tolerance: u8) -> Self {
+        let width = actual.width().max(expected.width());
+        let height = actual.height().max(expected.height());
+        let mut differing_pixels = 0;
+        let mut max_difference = 0;
+
+        let image = RgbaImage::from_fn(width, height, |x, y| {
+            match (
+                actual.get_pixel_checked(x, y),
+                expected.get_pixel_checked(x, y),
+            ) {
+                (Some(actual), Some(expected)) => {
+                    let difference = actual
+                        .0
+                        .iter()
+                        .zip(expected.0.iter())
+                        .map(|(actual, expected)| actual.abs_diff(*expected))
+                        .max()
+                        .unwrap_or_default();
+                    max_difference = max_difference.max(difference);
+
+                    if difference <= tolerance {
+                        // Fade matching pixels, so that the differing pixels stand out
+                        let Rgba([red, green, blue, _]) = *expected;
+                        let fade = |channel: u8| channel / 4 + 191;
+                        return Rgba([fade(red), fade(green), fade(blue), 255]);
+                    }
+                }
+                // Pixels which are only in one of the images always differ
+                _ => max_difference = u8::MAX,
+            }
+
+            differing_pixels += 1;
+            Rgba([255, 0, 0, 255])
+        });
+
+        Self {
+            differing_pixels,
+            image,
+            max_difference,
+            size_matches: actual.dimensions() == expected.dimensions(),
+        }
+    }
+
+    /// Returns the number of pixels which differ by more than the tolerance.
+    pub fn differing_pixels(&self) -> u64 {
+        self.differing_pixels
+    }
+
+    /// Returns an image which highlights the differing pixels in red, with the matching pixels faded.
+    pub fn image(&self) -> &RgbaImage {
+        &self.image
+    }
+
+    /// Returns true if the images are the same size and no pixels differ by more than the tolerance.
+    pub fn is_match(&self) -> bool {
+        self.size_matches && self.differing_pixels == 0
+    }
+
+    /// Returns the largest difference of any channel of any pixel, ignoring the tolerance.
+    pub fn max_difference(&self) -> u8 {
+        self.max_difference
+    }
+
+    /// Returns true if the images are the same size.
+    pub fn size_matches(&self) -> bool {
+        self.size_matches
+    }
+}
+
+/// Assert that the image matches the golden image at the given path, with the given tolerance
+/// as in [ImageDiff].
+///
+/// If the environment variable [UPDATE_GOLDEN_ENV] is set to `1` then the image is written to the
+/// golden path instead, which is how golden images are created or updated.
+///
+/// # Panics
+///
+/// Panics if the golden image does not exist or does not match. The actual image and the difference
+/// are then written to the `cxx-qt-test` folder of the temporary directory, so they can be inspected.
+pub fn assert_image_matches(actual: &QImage, golden: impl AsRef<Path>, tolerance: u8) {
+    let golden = golden.as_ref();
+    let actual = RgbaImage::from(actual);
+
+    if std::env::var(UPDATE_GOLDEN_ENV).is_ok_and(|value| value == "1") {
+        if let Some(parent) = golden.parent() {
+            std::fs::create_dir_all(parent).expect("Failed to create the golden image folder");
+        }
+        actual
+            .save(golden)
+            .unwrap_or_else(|err| panic!("Failed to write the golden image {golden:?}: {err}"));
+        return;
+    }
+
+    let expected = match image::open(golden) {
+        Ok(expected) => expected.to_rgba8(),
+        Err(err) => panic!(
+            "Failed to read the golden image {golden:?}: {err}\nRun with {UPDATE_GOLDEN_ENV}=1 to create it"
+        ),
+    };
+
+    let diff = ImageDiff::from_rgba(&actual, &expected, tolerance);
+    if diff.is_match() {
+        return;
+    }
+
+    let actual_path = failure_path(golden, "actual");
+    let diff_path = failure_path(golden, "diff");
+    // Writing the failure images is best effort, the assertion fails either way
+    let _ = actual.save(&actual_path);
+    let _ = diff.image().save(&diff_path);
+
+    if diff.size_matches() {
+        panic!(
+            "The image does not match the golden image {golden:?}, {differing} pixels differ by up to {max} with a tolerance of {tolerance}\nActual: {actual_path:?}\nDifference: {diff_path:?}",
+            differing = diff.differing_pixels(),
+            max = diff.max_difference(),
+        );
+    } else {
+        panic!(
+            "The image size {actual_size:?} does not match the golden image {golden:?} size {expected_size:?}\nActual: {actual_path:?}\nDifference: {diff_path:?}",
+            actual_size = actual.dimensions(),
+            expected_size = expected.dimensions(),
+        );
+    }
+}
+
+fn failure_path(golden: &Path, suffix: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join("cxx-qt-test");
+    let _ = std::fs::create_dir_all(&directory);
+
+    let stem = golden
+        .file_stem()
+        .map_or_else(|| "image".into(), |stem| stem.to_string_lossy());
+    directory.join(format!("{stem}.{suffix}.png"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filled(width: u32, height: u32, color: [u8; 4]) -> RgbaImage {
+        RgbaImage::from_pixel(width, height, Rgba(color))
+    }
+
+    #[test]
+    fn identical_images_match() {
+        let image = filled(4, 4, [10, 20, 30, 255]);
+        let diff = ImageDiff::from_rgba(&image, &image, 0);
+        assert!(diff.is_match());
+        assert_eq!(diff.differing_pixels(), 0);
+        assert_eq!(diff.max_difference(), 0);
+    }
+
+    #[test]
+    fn differences_within_tolerance_match() {
+        let actual = filled(4, 4, [10, 20, 30, 255]);
+        let mut expected = actual.clone();
+        expected.put_pixel(1, 1, Rgba([12, 20, 30, 255]));
+
+        assert!(ImageDiff::from_rgba(&actual, &expected, 2).is_match());
+
+        let diff = ImageDiff::from_rgba(&actual, &expected, 1);
+        assert!(!diff.is_match());
+        assert_eq!(diff.differing_pixels(), 1);
+        assert_eq!(diff.max_difference(), 2);
+        assert_eq!(*diff.image().get_pixel(1, 1), Rgba([255, 0, 0, 255]));
+    }
+
+    #[test]
+    fn different_sizes_do_not_match() {
+        let diff = ImageDiff::from_rgba(
+            &filled(4, 4, [0, 0, 0, 255]),
+            &filled(4, 2, [0, 0, 0, 255]),
+            u8::MAX,
+        );
+        assert!(!diff.is_match());
+        assert!(!diff.size_matches());
+        // The pixels which are only in the actual image differ regardless of the tolerance
+        assert_eq!(diff.differing_pixels(), 8);
+        assert_eq!(diff.image().dimensions(), (4, 4));
+    }
+}
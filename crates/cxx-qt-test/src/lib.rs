@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Headless QML testing from `cargo test`.
+//!
+//! A [QmlTest] loads QML from a file or string into an offscreen window, it can then send synthetic
+//! key and mouse events, process the event loop with timeouts, and read or write properties of the
+//! objects in the QML. QML warnings, such as binding errors or `console.warn`, fail the test.
+//!
+//! ```no_run
+//! use cxx_qt_test::QmlTest;
+//!
+//! #[test]
+//! fn click_increments() {
+//!     QmlTest::run(|test| {
+//!         test.load_data(
+//!             r#"
+//!             import QtQuick 2.15
+//!
+//!             Rectangle {
+//!                 width: 100
+//!                 height: 100
+//!                 property int count: 0
+//!
+//!                 MouseArea {
+//!                     objectName: "area"
+//!                     anchors.fill: parent
+//!                     onClicked: parent.count += 1
+//!                 }
+//!             }
+//!             "#,
+//!         );
+//!
+//!         test.object("area").click();
+//!         assert!(test.wait_until(|test| test.root().property::<i32>("count") == Some(1)));
+//!     });
+//! }
+//! ```
+//!
+//! Rendered frames can be grabbed with [QmlTest::grab_window] or [QmlObject::grab_to_image], and then
+//! compared with a golden image using [assert_image_matches] or directly with an [ImageDiff].
+//!
+//! The platform defaults to `offscreen` and the Qt Quick backend to `software`,
+//! unless `QT_QPA_PLATFORM` or `QT_QUICK_BACKEND` are set in the environment.
+//!
+//! The `QGuiApplication` is created on a dedicated thread rather than the main thread,
+//! which Qt does not support on macOS, so QML tests cannot be run there.
+
+#[cfg(not(any(cxxqt_qt_version_major = "5", cxxqt_qt_version_major = "6")))]
+compile_error!("cxxqt_qt_version_major must be either \"5\" or \"6\"");
+
+mod imagediff;
+pub use imagediff::{assert_image_matches, ImageDiff, UPDATE_GOLDEN_ENV};
+
+mod qmlobject;
+pub use qmlobject::QmlObject;
+
+mod qmltest;
+pub use qmltest::{Key, QmlTest};
@@ -0,0 +1,119 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QmlTest;
+use core::pin::Pin;
+use cxx_qt::{Downcast, QObject, QPointer};
+use cxx_qt_lib::{
+    MouseButton, QImage, QObjectExt, QQuickItem, QSize, QString, QVariant, QVariantValue,
+};
+use std::{cell::RefCell, rc::Rc};
+
+/// An object from the QML loaded by a [QmlTest], which is found with [QmlTest::object] or [QmlTest::root].
+///
+/// The object is tracked with a [QPointer], as the QML can destroy it while events are processed,
+/// for example when a `Loader` changes its source. Using the object after it has been destroyed fails the test.
+pub struct QmlObject<'a> {
+    test: &'a mut QmlTest,
+    object: QPointer<QObject>,
+}
+
+impl<'a> QmlObject<'a> {
+    pub(crate) fn new(test: &'a mut QmlTest, object: *mut QObject) -> Self {
+        // Safety: the object has just been found in the loaded QML, so it is valid
+        let object = unsafe { QPointer::from_raw(object) };
+        Self { test, object }
+    }
+
+    /// Returns a reference to the object as a [QObject].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object has been destroyed.
+    pub fn as_qobject(&self) -> &QObject {
+        // Safety: the pointer is checked and events cannot be processed while the reference is borrowed
+        unsafe { self.object.as_ref() }.expect("The QML object has been destroyed")
+    }
+
+    fn as_qobject_mut(&mut self) -> Pin<&mut QObject> {
+        // Safety: the pointer is checked and events cannot be processed while the reference is borrowed
+        unsafe { self.object.as_pin_mut() }.expect("The QML object has been destroyed")
+    }
+
+    /// Click the center of this item with the left mouse button.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is not a QQuickItem.
+    pub fn click(&mut self) {
+        let position = self
+            .test
+            .item_center_in_scene(self.as_qobject())
+            .unwrap_or_else(|| panic!("{:?} is not a QQuickItem", self.object_name()));
+        self.test
+            .mouse_click(MouseButton::LeftButton, &[], &position);
+    }
+
+    /// Grab this item into an image, processing events until the scene has been rendered.
+    ///
+    /// Unlike [QmlTest::grab_window] only this item and its children are included in the image.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is not a QQuickItem or the image is not ready within the timeout.
+    pub fn grab_to_image(&mut self) -> QImage {
+        let name = self.object_name();
+        let item = self
+            .as_qobject_mut()
+            .downcast_pin::<QQuickItem>()
+            .unwrap_or_else(|| panic!("{name:?} is not a QQuickItem"));
+
+        let image = Rc::new(RefCell::new(None));
+        let ready = image.clone();
+        assert!(
+            item.grab_to_image(&QSize::default(), move |grabbed| {
+                *ready.borrow_mut() = Some(grabbed.clone());
+            }),
+            "Failed to grab {name:?}, the item must be in a visible window"
+        );
+        assert!(
+            self.test.wait_until(|_| image.borrow().is_some()),
+            "The grab of {name:?} was not ready within the timeout"
+        );
+
+        image.take().unwrap_or_default()
+    }
+
+    /// Returns true if this object is a QQuickItem, which can be clicked.
+    pub fn is_item(&self) -> bool {
+        self.test.item_center_in_scene(self.as_qobject()).is_some()
+    }
+
+    /// Returns the `objectName` of this object.
+    pub fn object_name(&self) -> String {
+        self.as_qobject().object_name().to_string()
+    }
+
+    /// Returns the value of the given property, or None if the property does not exist or is a different type.
+    pub fn property<T: QVariantValue>(&self, name: &str) -> Option<T> {
+        self.property_variant(name).value::<T>()
+    }
+
+    /// Returns the value of the given property as a [QVariant], which is invalid if the property does not exist.
+    pub fn property_variant(&self, name: &str) -> QVariant {
+        self.as_qobject().property(name)
+    }
+
+    /// Sets the value of the given property, returning false if the property could not be set.
+    pub fn set_property<T: QVariantValue>(&mut self, name: &str, value: T) -> bool {
+        self.as_qobject_mut()
+            .set_property(name, &QVariant::from(&value))
+    }
+
+    /// Returns the value of the given string property, or None if the property does not exist or is not a string.
+    pub fn text_property(&self, name: &str) -> Option<String> {
+        self.property::<QString>(name).map(|text| text.to_string())
+    }
+}
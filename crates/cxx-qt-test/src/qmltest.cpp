@@ -0,0 +1,242 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-test/qmltest.h"
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QEventLoop>
+#include <QtCore/QtMath>
+#include <QtQuick/QQuickItem>
+#include <QtTest/QTest>
+
+namespace {
+
+::rust::cxxqttest1::MessageHandler* messageHandler = nullptr;
+QtMessageHandler previousMessageHandler = nullptr;
+
+void
+qmltestMessageOutput(QtMsgType type,
+                     const QMessageLogContext& context,
+                     const QString& message)
+{
+  if (messageHandler != nullptr) {
+    const bool isWarning = type == QtWarningMsg || type == QtCriticalMsg ||
+                           type == QtFatalMsg;
+    const char* category =
+      context.category != nullptr ? context.category : "default";
+    (*messageHandler)(isWarning, ::rust::Str(category), message);
+  }
+
+  // Continue to print the messages so that they appear in the test output
+  if (previousMessageHandler != nullptr) {
+    previousMessageHandler(type, context, message);
+  }
+}
+
+// QTest takes integer positions, positions are rounded like QPointF::toPoint
+QPoint
+toPoint(const QPointF& position)
+{
+  return position.toPoint();
+}
+
+}
+
+namespace rust {
+namespace cxxqttest1 {
+
+::std::unique_ptr<QGuiApplication>
+qmltestApplicationNew()
+{
+  // QGuiApplication keeps a reference to argc and argv, so they must outlive it
+  static char program[] = "cxx-qt-test";
+  static char platform[] = "-platform";
+  static char offscreen[] = "offscreen";
+  static char* argv[] = { program, nullptr, nullptr, nullptr };
+  static int argc = 1;
+
+  // Tests are headless unless the environment requests otherwise
+  if (qEnvironmentVariableIsEmpty("QT_QPA_PLATFORM")) {
+    argv[argc++] = platform;
+    argv[argc++] = offscreen;
+  }
+  if (qEnvironmentVariableIsEmpty("QT_QUICK_BACKEND")) {
+    QQuickWindow::setSceneGraphBackend(QStringLiteral("software"));
+  }
+
+  return ::std::make_unique<QGuiApplication>(argc, argv);
+}
+
+void
+qmltestInstallMessageHandler(MessageHandler handler)
+{
+  if (messageHandler == nullptr) {
+    messageHandler = new MessageHandler(handler);
+    previousMessageHandler = qInstallMessageHandler(qmltestMessageOutput);
+  } else {
+    *messageHandler = handler;
+  }
+}
+
+void
+qmltestProcessEvents(::std::int32_t maxTime)
+{
+  QCoreApplication::processEvents(QEventLoop::AllEvents, maxTime);
+  // Process deleteLater calls as QTest::qWait does
+  QCoreApplication::sendPostedEvents(nullptr, QEvent::DeferredDelete);
+}
+
+bool
+qmltestIsItem(const QObject& object)
+{
+  return qobject_cast<const QQuickItem*>(&object) != nullptr;
+}
+
+QPointF
+qmltestItemMapToScene(const QObject& object, const QPointF& point)
+{
+  if (const auto* item = qobject_cast<const QQuickItem*>(&object)) {
+    return item->mapToScene(point);
+  }
+  return point;
+}
+
+QSizeF
+qmltestItemSize(const QObject& object)
+{
+  if (const auto* item = qobject_cast<const QQuickItem*>(&object)) {
+    return item->size();
+  }
+  return QSizeF();
+}
+
+void
+qmltestKeyClick(QQuickWindow& window, Key key, ::std::int32_t modifiers)
+{
+  QTest::keyClick(&window, key, Qt::KeyboardModifiers(modifiers));
+}
+
+void
+qmltestKeyClicks(QQuickWindow& window,
+                 const QString& text,
+                 ::std::int32_t modifiers)
+{
+  // QTest::keyClicks only supports widgets, so click each character
+  for (const QChar character : text) {
+    // Qt::Key values match the upper case of latin characters
+    const auto key = static_cast<Qt::Key>(character.toUpper().unicode());
+    QTest::sendKeyEvent(QTest::Click,
+                        &window,
+                        key,
+                        QString(character),
+                        Qt::KeyboardModifiers(modifiers));
+  }
+}
+
+void
+qmltestKeyPress(QQuickWindow& window, Key key, ::std::int32_t modifiers)
+{
+  QTest::keyPress(&window, key, Qt::KeyboardModifiers(modifiers));
+}
+
+void
+qmltestKeyRelease(QQuickWindow& window, Key key, ::std::int32_t modifiers)
+{
+  QTest::keyRelease(&window, key, Qt::KeyboardModifiers(modifiers));
+}
+
+void
+qmltestMouseClick(QQuickWindow& window,
+                  Qt::MouseButton button,
+                  ::std::int32_t modifiers,
+                  const QPointF& position)
+{
+  QTest::mouseClick(
+    &window, button, Qt::KeyboardModifiers(modifiers), toPoint(position));
+}
+
+void
+qmltestMouseDoubleClick(QQuickWindow& window,
+                        Qt::MouseButton button,
+                        ::std::int32_t modifiers,
+                        const QPointF& position)
+{
+  QTest::mouseDClick(
+    &window, button, Qt::KeyboardModifiers(modifiers), toPoint(position));
+}
+
+void
+qmltestMouseMove(QQuickWindow& window, const QPointF& position)
+{
+  QTest::mouseMove(&window, toPoint(position));
+}
+
+void
+qmltestMousePress(QQuickWindow& window,
+                  Qt::MouseButton button,
+                  ::std::int32_t modifiers,
+                  const QPointF& position)
+{
+  QTest::mousePress(
+    &window, button, Qt::KeyboardModifiers(modifiers), toPoint(position));
+}
+
+void
+qmltestMouseRelease(QQuickWindow& window,
+                    Qt::MouseButton button,
+                    ::std::int32_t modifiers,
+                    const QPointF& position)
+{
+  QTest::mouseRelease(
+    &window, button, Qt::KeyboardModifiers(modifiers), toPoint(position));
+}
+
+QQuickWindow*
+qmltestWindowFromObject(QObject& object)
+{
+  return qobject_cast<QQuickWindow*>(&object);
+}
+
+::std::unique_ptr<QQuickWindow>
+qmltestWindowNewForItem(QObject& object)
+{
+  auto* item = qobject_cast<QQuickItem*>(&object);
+  if (item == nullptr) {
+    return nullptr;
+  }
+
+  auto window = ::std::make_unique<QQuickWindow>();
+  item->setParentItem(window->contentItem());
+
+  // Size the window to the item, falling back to the implicit size
+  qreal width = item->width() > 0 ? item->width() : item->implicitWidth();
+  qreal height = item->height() > 0 ? item->height() : item->implicitHeight();
+  if (width <= 0 || height <= 0) {
+    width = 640;
+    height = 480;
+  }
+  window->resize(qCeil(width), qCeil(height));
+  return window;
+}
+
+bool
+qmltestWindowShow(QQuickWindow& window, ::std::int32_t timeout)
+{
+  window.show();
+  window.requestActivate();
+  if (!QTest::qWaitForWindowExposed(&window, timeout)) {
+    return false;
+  }
+
+  // Key events are delivered to the active focus item, which requires an
+  // active window, but not every platform supports activation
+  QTest::qWaitForWindowActive(&window, timeout);
+  return true;
+}
+
+}
+}
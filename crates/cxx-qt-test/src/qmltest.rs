@@ -0,0 +1,660 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// The keys which can be sent with [QmlTest::key_click](crate::QmlTest::key_click).
+    ///
+    /// Text can be typed with [QmlTest::key_clicks](crate::QmlTest::key_clicks) instead.
+    #[namespace = "rust::cxxqttest1"]
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum Key {
+        /// The Escape key.
+        Key_Escape = 0x01000000,
+        /// The Tab key.
+        Key_Tab = 0x01000001,
+        /// The Backtab key, which is sent for Shift+Tab.
+        Key_Backtab = 0x01000002,
+        /// The Backspace key.
+        Key_Backspace = 0x01000003,
+        /// The Return key.
+        Key_Return = 0x01000004,
+        /// The Enter key on the keypad.
+        Key_Enter = 0x01000005,
+        /// The Insert key.
+        Key_Insert = 0x01000006,
+        /// The Delete key.
+        Key_Delete = 0x01000007,
+        /// The Home key.
+        Key_Home = 0x01000010,
+        /// The End key.
+        Key_End = 0x01000011,
+        /// The Left arrow key.
+        Key_Left = 0x01000012,
+        /// The Up arrow key.
+        Key_Up = 0x01000013,
+        /// The Right arrow key.
+        Key_Right = 0x01000014,
+        /// The Down arrow key.
+        Key_Down = 0x01000015,
+        /// The Page Up key.
+        Key_PageUp = 0x01000016,
+        /// The Page Down key.
+        Key_PageDown = 0x01000017,
+        /// The Shift key.
+        Key_Shift = 0x01000020,
+        /// The Control key.
+        Key_Control = 0x01000021,
+        /// The Meta key.
+        Key_Meta = 0x01000022,
+        /// The Alt key.
+        Key_Alt = 0x01000023,
+        /// The Space key.
+        Key_Space = 0x20,
+    }
+
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type MouseButton = cxx_qt_lib::MouseButton;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qguiapplication.h");
+        type QGuiApplication = cxx_qt_lib::QGuiApplication;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt::QObject;
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = cxx_qt_lib::QPointF;
+        include!("cxx-qt-lib/qquickwindow.h");
+        type QQuickWindow = cxx_qt_lib::QQuickWindow;
+        include!("cxx-qt-lib/qsizef.h");
+        type QSizeF = cxx_qt_lib::QSizeF;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    #[namespace = "rust::cxxqttest1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-test/qmltest.h");
+        type Key;
+
+        #[doc(hidden)]
+        #[rust_name = "qmltest_application_new"]
+        fn qmltestApplicationNew() -> UniquePtr<QGuiApplication>;
+        #[doc(hidden)]
+        #[rust_name = "qmltest_install_message_handler"]
+        fn qmltestInstallMessageHandler(handler: fn(bool, &str, &QString));
+        #[doc(hidden)]
+        #[rust_name = "qmltest_process_events"]
+        fn qmltestProcessEvents(max_time: i32);
+
+        #[doc(hidden)]
+        #[rust_name = "qmltest_is_item"]
+        fn qmltestIsItem(object: &QObject) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmltest_item_map_to_scene"]
+        fn qmltestItemMapToScene(object: &QObject, point: &QPointF) -> QPointF;
+        #[doc(hidden)]
+        #[rust_name = "qmltest_item_size"]
+        fn qmltestItemSize(object: &QObject) -> QSizeF;
+
+        #[doc(hidden)]
+        #[rust_name = "qmltest_key_click"]
+        fn qmltestKeyClick(window: Pin<&mut QQuickWindow>, key: Key, modifiers: i32);
+        #[doc(hidden)]
+        #[rust_name = "qmltest_key_clicks"]
+        fn qmltestKeyClicks(window: Pin<&mut QQuickWindow>, text: &QString, modifiers: i32);
+        #[doc(hidden)]
+        #[rust_name = "qmltest_key_press"]
+        fn qmltestKeyPress(window: Pin<&mut QQuickWindow>, key: Key, modifiers: i32);
+        #[doc(hidden)]
+        #[rust_name = "qmltest_key_release"]
+        fn qmltestKeyRelease(window: Pin<&mut QQuickWindow>, key: Key, modifiers: i32);
+
+        #[doc(hidden)]
+        #[rust_name = "qmltest_mouse_click"]
+        fn qmltestMouseClick(
+            window: Pin<&mut QQuickWindow>,
+            button: MouseButton,
+            modifiers: i32,
+            position: &QPointF,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qmltest_mouse_double_click"]
+        fn qmltestMouseDoubleClick(
+            window: Pin<&mut QQuickWindow>,
+            button: MouseButton,
+            modifiers: i32,
+            position: &QPointF,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qmltest_mouse_move"]
+        fn qmltestMouseMove(window: Pin<&mut QQuickWindow>, position: &QPointF);
+        #[doc(hidden)]
+        #[rust_name = "qmltest_mouse_press"]
+        fn qmltestMousePress(
+            window: Pin<&mut QQuickWindow>,
+            button: MouseButton,
+            modifiers: i32,
+            position: &QPointF,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qmltest_mouse_release"]
+        fn qmltestMouseRelease(
+            window: Pin<&mut QQuickWindow>,
+            button: MouseButton,
+            modifiers: i32,
+            position: &QPointF,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qmltest_window_from_object"]
+        fn qmltestWindowFromObject(object: Pin<&mut QObject>) -> *mut QQuickWindow;
+        #[doc(hidden)]
+        #[rust_name = "qmltest_window_new_for_item"]
+        fn qmltestWindowNewForItem(object: Pin<&mut QObject>) -> UniquePtr<QQuickWindow>;
+        #[doc(hidden)]
+        #[rust_name = "qmltest_window_show"]
+        fn qmltestWindowShow(window: Pin<&mut QQuickWindow>, timeout: i32) -> bool;
+    }
+}
+
+use crate::QmlObject;
+use core::pin::Pin;
+use cxx::UniquePtr;
+use cxx_qt::QObject;
+use cxx_qt_lib::{
+    FindChildOption, KeyboardModifier, MouseButton, QByteArray, QImage, QObjectExt, QPointF,
+    QQmlComponent, QQmlComponentStatus, QQmlEngine, QQuickWindow, QString, QUrl,
+};
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    sync::{mpsc, Mutex, OnceLock},
+    time::{Duration, Instant},
+};
+
+pub use ffi::Key;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// The QML warnings which have been emitted during the current test.
+static WARNINGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn push_warning(warning: String) {
+    WARNINGS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(warning);
+}
+
+fn take_warnings() -> Vec<String> {
+    core::mem::take(
+        &mut *WARNINGS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+    )
+}
+
+fn message_handler(is_warning: bool, category: &str, message: &QString) {
+    // Warnings from QML and JavaScript, eg from console.warn, other Qt warnings are ignored
+    let is_qml = matches!(category, "qml" | "js")
+        || category.starts_with("qt.qml")
+        || category.starts_with("qt.quick");
+    if is_warning && is_qml {
+        push_warning(message.to_string());
+    }
+}
+
+/// Returns a sender to the thread which owns the QGuiApplication, starting it if needed.
+///
+/// Qt requires that the application is always used from the same thread,
+/// but `cargo test` runs each test on a new thread, so every test is run on this thread instead.
+///
+/// Note that this is not the main thread of the process, which Qt does not support on macOS.
+fn qt_thread() -> &'static Mutex<mpsc::Sender<Job>> {
+    static SENDER: OnceLock<Mutex<mpsc::Sender<Job>>> = OnceLock::new();
+    SENDER.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        std::thread::Builder::new()
+            .name("qt-main".to_owned())
+            .spawn(move || {
+                // The application is created with the offscreen platform and software backend,
+                // unless the environment requests otherwise
+                let _app = ffi::qmltest_application_new();
+                ffi::qmltest_install_message_handler(message_handler);
+
+                for job in receiver {
+                    job();
+                }
+            })
+            .expect("Failed to spawn the Qt thread");
+        Mutex::new(sender)
+    })
+}
+
+/// A headless QML test, which loads QML into an offscreen window and can interact with it.
+///
+/// Tests are started with [QmlTest::run], which executes the test on a dedicated thread that owns
+/// the `QGuiApplication`. This means that any number of tests can use `QmlTest` within `cargo test`.
+///
+/// Any QML warnings, such as binding errors or `console.warn`, cause the test to fail unless they are
+/// ignored with [QmlTest::ignore_warning].
+///
+/// # Platform support
+///
+/// The `QGuiApplication` lives on a dedicated thread rather than the main thread of the test binary.
+/// Qt requires the GUI to be used from the main thread on macOS, so `QmlTest` is not supported there.
+pub struct QmlTest {
+    // Objects are destroyed before their window and the window before the engine
+    root: Option<cxx_qt::QBox<QObject>>,
+    owned_window: UniquePtr<QQuickWindow>,
+    window: *mut QQuickWindow,
+    engine: UniquePtr<QQmlEngine>,
+    fail_on_warnings: bool,
+    ignored_warnings: Vec<String>,
+    timeout: Duration,
+}
+
+impl QmlTest {
+    /// Run the given test with a new [QmlTest], blocking until the test has finished.
+    ///
+    /// The test is executed on the Qt thread, and tests are executed one at a time.
+    /// If the test panics, or QML warnings were emitted, then the panic is resumed on the calling thread
+    /// so that the test fails.
+    pub fn run<F>(test: F)
+    where
+        F: FnOnce(&mut QmlTest) + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        let job: Job = Box::new(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                let mut qml_test = QmlTest::new();
+                test(&mut qml_test);
+                qml_test.finish();
+            }));
+            // Remove any warnings from a failed test, so that they do not affect the next test
+            take_warnings();
+            let _ = sender.send(result);
+        });
+
+        qt_thread()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .send(job)
+            .expect("The Qt thread has stopped");
+
+        match receiver.recv() {
+            Ok(Ok(())) => {}
+            Ok(Err(payload)) => panic::resume_unwind(payload),
+            Err(_) => panic!("The Qt thread stopped while running the test"),
+        }
+    }
+
+    fn new() -> Self {
+        take_warnings();
+
+        let mut engine = QQmlEngine::new();
+        if let Some(mut engine) = engine.as_mut() {
+            // Warnings are collected from the signal and printed by the test,
+            // so that they are not also printed to stderr by Qt
+            engine.as_mut().set_output_warnings_to_standard_error(false);
+            engine
+                .on_warnings(|_, warnings| {
+                    for warning in warnings.iter() {
+                        eprintln!("{warning}");
+                        push_warning(warning.to_string());
+                    }
+                })
+                .release();
+        }
+
+        Self {
+            root: None,
+            owned_window: UniquePtr::null(),
+            window: core::ptr::null_mut(),
+            engine,
+            fail_on_warnings: true,
+            ignored_warnings: Vec::new(),
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    fn finish(&mut self) {
+        // Deliver any pending events, so that their warnings are seen
+        ffi::qmltest_process_events(0);
+
+        let warnings = self.warnings();
+        if self.fail_on_warnings && !warnings.is_empty() {
+            panic!(
+                "QML warnings were emitted during the test:\n{}",
+                warnings.join("\n")
+            );
+        }
+    }
+
+    /// Returns the engine which is used to load QML.
+    ///
+    /// This can be used to add import paths or context properties before loading QML.
+    pub fn engine(&mut self) -> Pin<&mut QQmlEngine> {
+        self.engine.pin_mut()
+    }
+
+    /// Load QML from the given string and show it in an offscreen window.
+    ///
+    /// Relative URLs in the QML are resolved against the current directory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the QML cannot be created or the window is not exposed within the timeout.
+    pub fn load_data(&mut self, qml: &str) {
+        let directory = std::env::current_dir().unwrap_or_default();
+        let url = QUrl::from_local_file(&QString::from(
+            directory.join("qmltest.qml").to_string_lossy().as_ref(),
+        ));
+        self.load_with(|component| component.set_data(&QByteArray::from(qml), &url));
+    }
+
+    /// Load the given QML file and show it in an offscreen window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the QML cannot be created or the window is not exposed within the timeout.
+    pub fn load_file(&mut self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        let path = if path.is_relative() {
+            std::env::current_dir().unwrap_or_default().join(path)
+        } else {
+            path.to_path_buf()
+        };
+        self.load_url(&QUrl::from_local_file(&QString::from(
+            path.to_string_lossy().as_ref(),
+        )));
+    }
+
+    /// Load QML from the given URL, such as a `qrc:` URL, and show it in an offscreen window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the QML cannot be created or the window is not exposed within the timeout.
+    pub fn load_url(&mut self, url: &QUrl) {
+        self.load_with(|component| component.load_url(url));
+    }
+
+    fn load_with(&mut self, load: impl FnOnce(Pin<&mut QQmlComponent>)) {
+        // Destroy anything which was previously loaded
+        self.root = None;
+        self.owned_window = UniquePtr::null();
+        self.window = core::ptr::null_mut();
+
+        // Safety: the component is dropped at the end of this method, before the engine
+        let mut component = unsafe { QQmlComponent::new(self.engine.pin_mut()) };
+        load(component.pin_mut());
+
+        // Components from a network URL load asynchronously
+        let loaded = self.wait_until(|_| component.status() != QQmlComponentStatus::Loading);
+        assert!(loaded, "Timed out loading the QML component");
+
+        let mut root = match component.pin_mut().create() {
+            Ok(root) => root,
+            Err(error) => panic!("Failed to create the QML component:\n{error}"),
+        };
+
+        if let Some(mut object) = root.as_pin_mut() {
+            self.window = ffi::qmltest_window_from_object(object.as_mut());
+            if self.window.is_null() {
+                // Items are shown in a new window, as they cannot be shown by themselves
+                self.owned_window = ffi::qmltest_window_new_for_item(object);
+                self.window = self
+                    .owned_window
+                    .as_mut()
+                    .map_or(core::ptr::null_mut(), |window| unsafe {
+                        window.get_unchecked_mut() as *mut QQuickWindow
+                    });
+            }
+        }
+        self.root = Some(root);
+
+        let timeout = self.timeout_millis();
+        let window = self.window_mut();
+        assert!(
+            ffi::qmltest_window_show(window, timeout),
+            "The QML window was not exposed within the timeout"
+        );
+    }
+
+    /// Returns the root object of the loaded QML.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no QML has been loaded.
+    pub fn root(&mut self) -> QmlObject<'_> {
+        let root = self.root_ptr();
+        QmlObject::new(self, root)
+    }
+
+    fn root_ptr(&self) -> *mut QObject {
+        let root = self
+            .root
+            .as_ref()
+            .map_or(core::ptr::null_mut(), |root| root.as_mut_ptr());
+        assert!(!root.is_null(), "No QML has been loaded");
+        root
+    }
+
+    /// Returns the object with the given `objectName`, searching the root object and then recursively its children.
+    pub fn find(&mut self, object_name: &str) -> Option<QmlObject<'_>> {
+        let root = self.root_ptr();
+        let name = QString::from(object_name);
+        // Safety: the root object is owned by this test and is valid until other QML is loaded
+        let root_object = unsafe { &*root };
+        let object = if root_object.object_name() == name {
+            root
+        } else {
+            root_object.find_child::<QObject>(&name, FindChildOption::FindChildrenRecursively)
+        };
+
+        if object.is_null() {
+            None
+        } else {
+            Some(QmlObject::new(self, object))
+        }
+    }
+
+    /// Returns the object with the given `objectName`, as with [QmlTest::find].
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no object with the given name.
+    pub fn object(&mut self, object_name: &str) -> QmlObject<'_> {
+        self.find(object_name)
+            .unwrap_or_else(|| panic!("No QML object has the objectName {object_name:?}"))
+    }
+
+    /// Render the window and return its contents as an image, which can be compared with
+    /// [ImageDiff](crate::ImageDiff) or [assert_image_matches](crate::assert_image_matches).
+    ///
+    /// With the default `software` backend the window is rendered on the CPU, so no GPU is required.
+    pub fn grab_window(&mut self) -> QImage {
+        self.window_mut().grab_window()
+    }
+
+    fn window_mut(&mut self) -> Pin<&mut QQuickWindow> {
+        assert!(!self.window.is_null(), "No QML has been loaded");
+        // Safety: the window is valid while the loaded QML is, and the borrow of self prevents other QML being loaded
+        unsafe { Pin::new_unchecked(&mut *self.window) }
+    }
+
+    /// Click the given key with the given modifiers on the window, which is delivered to the item with active focus.
+    pub fn key_click(&mut self, key: Key, modifiers: &[KeyboardModifier]) {
+        ffi::qmltest_key_click(self.window_mut(), key, modifiers_to_i32(modifiers));
+    }
+
+    /// Type the given text into the window, by clicking a key for each character.
+    pub fn key_clicks(&mut self, text: &str) {
+        ffi::qmltest_key_clicks(self.window_mut(), &QString::from(text), 0);
+    }
+
+    /// Press the given key with the given modifiers on the window.
+    pub fn key_press(&mut self, key: Key, modifiers: &[KeyboardModifier]) {
+        ffi::qmltest_key_press(self.window_mut(), key, modifiers_to_i32(modifiers));
+    }
+
+    /// Release the given key with the given modifiers on the window.
+    pub fn key_release(&mut self, key: Key, modifiers: &[KeyboardModifier]) {
+        ffi::qmltest_key_release(self.window_mut(), key, modifiers_to_i32(modifiers));
+    }
+
+    /// Click the given mouse button at the given position in the window.
+    pub fn mouse_click(
+        &mut self,
+        button: MouseButton,
+        modifiers: &[KeyboardModifier],
+        position: &QPointF,
+    ) {
+        ffi::qmltest_mouse_click(
+            self.window_mut(),
+            button,
+            modifiers_to_i32(modifiers),
+            position,
+        );
+    }
+
+    /// Double click the given mouse button at the given position in the window.
+    pub fn mouse_double_click(
+        &mut self,
+        button: MouseButton,
+        modifiers: &[KeyboardModifier],
+        position: &QPointF,
+    ) {
+        ffi::qmltest_mouse_double_click(
+            self.window_mut(),
+            button,
+            modifiers_to_i32(modifiers),
+            position,
+        );
+    }
+
+    /// Move the mouse to the given position in the window.
+    pub fn mouse_move(&mut self, position: &QPointF) {
+        ffi::qmltest_mouse_move(self.window_mut(), position);
+    }
+
+    /// Press the given mouse button at the given position in the window.
+    pub fn mouse_press(
+        &mut self,
+        button: MouseButton,
+        modifiers: &[KeyboardModifier],
+        position: &QPointF,
+    ) {
+        ffi::qmltest_mouse_press(
+            self.window_mut(),
+            button,
+            modifiers_to_i32(modifiers),
+            position,
+        );
+    }
+
+    /// Release the given mouse button at the given position in the window.
+    pub fn mouse_release(
+        &mut self,
+        button: MouseButton,
+        modifiers: &[KeyboardModifier],
+        position: &QPointF,
+    ) {
+        ffi::qmltest_mouse_release(
+            self.window_mut(),
+            button,
+            modifiers_to_i32(modifiers),
+            position,
+        );
+    }
+
+    /// Ignore any QML warning which contains the given text, so that it does not fail the test.
+    pub fn ignore_warning(&mut self, text: &str) {
+        self.ignored_warnings.push(text.to_owned());
+    }
+
+    /// Set whether QML warnings cause the test to fail, by default they do.
+    pub fn set_fail_on_warnings(&mut self, fail_on_warnings: bool) {
+        self.fail_on_warnings = fail_on_warnings;
+    }
+
+    /// Set the timeout which is used when loading QML and by [QmlTest::wait_until], by default this is five seconds.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    fn timeout_millis(&self) -> i32 {
+        i32::try_from(self.timeout.as_millis()).unwrap_or(i32::MAX)
+    }
+
+    /// Process events for the given duration, so that animations and timers can run.
+    pub fn wait(&self, duration: Duration) {
+        let deadline = Instant::now() + duration;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            ffi::qmltest_process_events(i32::try_from(remaining.as_millis()).unwrap_or(i32::MAX));
+            std::thread::sleep(remaining.min(Duration::from_millis(10)));
+        }
+        ffi::qmltest_process_events(0);
+    }
+
+    /// Process events until the given condition is true, returning false if the timeout is reached first.
+    ///
+    /// This is similar to `QTRY_VERIFY` in QTest, and can be used to wait for asynchronous changes.
+    pub fn wait_until<F>(&mut self, mut condition: F) -> bool
+    where
+        F: FnMut(&mut QmlTest) -> bool,
+    {
+        let deadline = Instant::now() + self.timeout;
+        loop {
+            if condition(self) {
+                return true;
+            }
+            if Instant::now() >= deadline {
+                return false;
+            }
+            self.wait(Duration::from_millis(10));
+        }
+    }
+
+    /// Returns the QML warnings which have been emitted during this test and are not ignored.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = WARNINGS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        warnings.retain(|warning| {
+            !self
+                .ignored_warnings
+                .iter()
+                .any(|ignored| warning.contains(ignored.as_str()))
+        });
+        warnings.clone()
+    }
+
+    pub(crate) fn item_center_in_scene(&self, object: &QObject) -> Option<QPointF> {
+        if !ffi::qmltest_is_item(object) {
+            return None;
+        }
+
+        let size = ffi::qmltest_item_size(object);
+        Some(ffi::qmltest_item_map_to_scene(
+            object,
+            &QPointF::new(size.width() / 2.0, size.height() / 2.0),
+        ))
+    }
+}
+
+fn modifiers_to_i32(modifiers: &[KeyboardModifier]) -> i32 {
+    modifiers
+        .iter()
+        .fold(0, |flags, modifier| flags | modifier.repr)
+}
@@ -0,0 +1,256 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::QImage;
+use cxx_qt_test::{assert_image_matches, ImageDiff, Key, QmlTest};
+use image::{Rgba, RgbaImage};
+use std::time::Duration;
+
+const COUNTER: &str = r#"
+import QtQuick 2.15
+
+Rectangle {
+    objectName: "root"
+    width: 200
+    height: 100
+    property int count: 0
+
+    MouseArea {
+        objectName: "area"
+        anchors.fill: parent
+        onClicked: parent.count += 1
+    }
+}
+"#;
+
+#[test]
+fn click_changes_property() {
+    QmlTest::run(|test| {
+        test.load_data(COUNTER);
+
+        assert_eq!(test.root().object_name(), "root");
+        assert_eq!(test.root().property::<i32>("count"), Some(0));
+
+        test.object("area").click();
+        test.object("area").click();
+        assert!(test.wait_until(|test| test.root().property::<i32>("count") == Some(2)));
+    });
+}
+
+#[test]
+fn set_property() {
+    QmlTest::run(|test| {
+        test.load_data(COUNTER);
+
+        assert!(test.root().set_property("count", 5_i32));
+        assert_eq!(test.root().property::<i32>("count"), Some(5));
+        assert!(test.find("missing").is_none());
+    });
+}
+
+#[test]
+fn type_text() {
+    QmlTest::run(|test| {
+        test.load_data(
+            r#"
+            import QtQuick 2.15
+
+            Item {
+                width: 200
+                height: 50
+                property bool accepted: false
+
+                TextInput {
+                    objectName: "input"
+                    anchors.fill: parent
+                    focus: true
+                    onAccepted: parent.accepted = true
+                }
+            }
+            "#,
+        );
+
+        test.key_clicks("Hello");
+        test.key_click(Key::Key_Backspace, &[]);
+        test.key_click(Key::Key_Return, &[]);
+        assert_eq!(
+            test.object("input").text_property("text").as_deref(),
+            Some("Hell")
+        );
+        assert_eq!(test.root().property::<bool>("accepted"), Some(true));
+    });
+}
+
+#[test]
+fn wait_for_timer() {
+    QmlTest::run(|test| {
+        test.load_data(
+            r#"
+            import QtQuick 2.15
+
+            Item {
+                property bool triggered: false
+
+                Timer {
+                    interval: 50
+                    running: true
+                    onTriggered: parent.triggered = true
+                }
+            }
+            "#,
+        );
+
+        test.wait(Duration::from_millis(100));
+        assert_eq!(test.root().property::<bool>("triggered"), Some(true));
+    });
+}
+
+#[test]
+fn window_root() {
+    QmlTest::run(|test| {
+        test.load_data(
+            r#"
+            import QtQuick 2.15
+            import QtQuick.Window 2.15
+
+            Window {
+                width: 100
+                height: 100
+
+                Rectangle {
+                    objectName: "content"
+                    anchors.fill: parent
+                }
+            }
+            "#,
+        );
+
+        assert!(test.object("content").is_item());
+        assert_eq!(test.object("content").property::<f64>("width"), Some(100.0));
+    });
+}
+
+#[test]
+#[should_panic(expected = "QML warnings were emitted")]
+fn warning_fails_test() {
+    QmlTest::run(|test| {
+        test.load_data(
+            r#"
+            import QtQuick 2.15
+
+            Item {
+                Component.onCompleted: console.warn("Something went wrong")
+            }
+            "#,
+        );
+    });
+}
+
+#[test]
+fn warning_ignored() {
+    QmlTest::run(|test| {
+        test.ignore_warning("Something went wrong");
+        test.load_data(
+            r#"
+            import QtQuick 2.15
+
+            Item {
+                Component.onCompleted: console.warn("Something went wrong")
+            }
+            "#,
+        );
+        assert!(test.warnings().is_empty());
+    });
+}
+
+#[test]
+#[should_panic(expected = "Failed to create the QML component")]
+fn invalid_qml_fails_test() {
+    QmlTest::run(|test| {
+        test.load_data("import QtQuick 2.15\n\nItem { unknownProperty: 1 }");
+    });
+}
+
+const SQUARES: &str = r#"
+import QtQuick 2.15
+
+Rectangle {
+    width: 100
+    height: 50
+    color: "red"
+
+    Rectangle {
+        objectName: "square"
+        x: 50
+        width: 50
+        height: 50
+        color: "blue"
+    }
+}
+"#;
+
+#[test]
+fn grab_window() {
+    QmlTest::run(|test| {
+        test.load_data(SQUARES);
+
+        let image = RgbaImage::from(&test.grab_window());
+        assert_eq!(image.dimensions(), (100, 50));
+        assert_eq!(*image.get_pixel(10, 10), Rgba([255, 0, 0, 255]));
+        assert_eq!(*image.get_pixel(90, 40), Rgba([0, 0, 255, 255]));
+    });
+}
+
+#[test]
+fn grab_item_to_image() {
+    QmlTest::run(|test| {
+        test.load_data(SQUARES);
+
+        let image = RgbaImage::from(&test.object("square").grab_to_image());
+        assert_eq!(image.dimensions(), (50, 50));
+        assert_eq!(*image.get_pixel(25, 25), Rgba([0, 0, 255, 255]));
+    });
+}
+
+#[test]
+fn grab_matches_golden_image() {
+    QmlTest::run(|test| {
+        test.load_data(SQUARES);
+
+        let golden = std::env::temp_dir()
+            .join("cxx-qt-test")
+            .join("grab_matches_golden_image.png");
+        std::fs::create_dir_all(golden.parent().unwrap()).unwrap();
+        RgbaImage::from(&test.grab_window()).save(&golden).unwrap();
+
+        assert_image_matches(&test.grab_window(), &golden, 0);
+
+        // Moving the square swaps the colors of each half, so every pixel differs
+        assert!(test.object("square").set_property("x", 0.0_f64));
+        let expected = QImage::from(image::open(&golden).unwrap().to_rgba8());
+        let diff = ImageDiff::new(&test.grab_window(), &expected, 0);
+        assert!(!diff.is_match());
+        assert_eq!(diff.differing_pixels(), 100 * 50);
+        assert_eq!(diff.max_difference(), 255);
+    });
+}
+
+#[test]
+#[should_panic(expected = "does not match the golden image")]
+fn grab_differs_from_golden_image() {
+    QmlTest::run(|test| {
+        test.load_data(SQUARES);
+
+        let golden = std::env::temp_dir()
+            .join("cxx-qt-test")
+            .join("grab_differs_from_golden_image.png");
+        std::fs::create_dir_all(golden.parent().unwrap()).unwrap();
+        RgbaImage::from_pixel(100, 50, Rgba([0, 255, 0, 255]))
+            .save(&golden)
+            .unwrap();
+
+        assert_image_matches(&test.grab_window(), &golden, 0);
+    });
+}
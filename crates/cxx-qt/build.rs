@@ -20,6 +20,10 @@ fn write_headers() {
     for file_path in [
         "connection.h",
         "casting.h",
+        "executor.h",
+        "panic.h",
+        "qobject.h",
+        "qpointer.h",
         "signalhandler.h",
         "thread.h",
         "threading.h",
@@ -41,7 +45,14 @@ fn main() {
     let mut builder = CxxQtBuilder::library(interface);
 
     let cpp_files = ["src/connection.cpp"];
-    let rust_bridges = ["src/connection.rs", "src/qobject.rs"];
+    let rust_bridges = [
+        "src/connection.rs",
+        "src/executor.rs",
+        "src/panic.rs",
+        "src/qobject.rs",
+        "src/qpointer.rs",
+        "src/threading.rs",
+    ];
 
     for bridge in &rust_bridges {
         builder = builder.file(bridge);
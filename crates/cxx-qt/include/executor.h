@@ -0,0 +1,137 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+#include <mutex>
+#include <shared_mutex>
+
+#include <QtCore/QMetaObject>
+#include <QtCore/QObject>
+#include <QtCore/QThread>
+#include <QtCore/QTimer>
+
+#include "rust/cxx.h"
+
+namespace rust::cxxqt1 {
+
+// The context of an executor, this is a QObject living in the thread of the
+// executor which is used to post closures to the event loop of the thread.
+//
+// Posting is thread-safe, all other methods must be called from the thread of
+// the executor.
+class CxxQtExecutorContext final
+{
+public:
+  CxxQtExecutorContext()
+    : m_context(new QObject)
+  {
+  }
+
+  ~CxxQtExecutorContext()
+  {
+    const auto guard = ::std::unique_lock(m_mutex);
+    // Wakers on other threads may hold the last reference to the context
+    if (m_context->thread() == QThread::currentThread()) {
+      delete m_context;
+    } else {
+      m_context->deleteLater();
+    }
+    m_context = nullptr;
+  }
+
+  CxxQtExecutorContext(const CxxQtExecutorContext&) = delete;
+  CxxQtExecutorContext& operator=(const CxxQtExecutorContext&) = delete;
+
+  template<typename A>
+  QMetaObject::Connection onDestroyed(const QObject& qobject,
+                                      ::rust::Fn<void(::rust::Box<A> arg)> func,
+                                      ::rust::Box<A> arg) const
+  {
+    return QObject::connect(
+      &qobject,
+      &QObject::destroyed,
+      m_context,
+      [func = ::std::move(func), arg = ::std::move(arg)]() mutable {
+        func(::std::move(arg));
+      });
+  }
+
+  template<typename A>
+  bool post(::rust::Fn<void(::rust::Box<A> arg)> func,
+            ::rust::Box<A> arg) const
+  {
+    // Ensure that we can read the pointer and it's not being written to
+    const auto guard = ::std::shared_lock(m_mutex);
+    if (!m_context) {
+      return false;
+    }
+
+    return QMetaObject::invokeMethod(
+      m_context,
+      [func = ::std::move(func), arg = ::std::move(arg)]() mutable {
+        func(::std::move(arg));
+      },
+      Qt::QueuedConnection);
+  }
+
+  template<typename A>
+  void singleShot(::std::int32_t msec,
+                  ::rust::Fn<void(::rust::Box<A> arg)> func,
+                  ::rust::Box<A> arg) const
+  {
+    QTimer::singleShot(
+      static_cast<int>(msec),
+      Qt::PreciseTimer,
+      m_context,
+      [func = ::std::move(func), arg = ::std::move(arg)]() mutable {
+        func(::std::move(arg));
+      });
+  }
+
+private:
+  QObject* m_context;
+  mutable ::std::shared_mutex m_mutex;
+};
+
+inline ::std::shared_ptr<CxxQtExecutorContext>
+cxxQtExecutorContextNew()
+{
+  return ::std::make_shared<CxxQtExecutorContext>();
+}
+
+template<typename A>
+QMetaObject::Connection
+cxxQtExecutorContextOnDestroyed(const CxxQtExecutorContext& context,
+                                const QObject& qobject,
+                                ::rust::Fn<void(::rust::Box<A> arg)> func,
+                                ::rust::Box<A> arg)
+{
+  return context.onDestroyed(qobject, ::std::move(func), ::std::move(arg));
+}
+
+template<typename A>
+bool
+cxxQtExecutorContextPost(const CxxQtExecutorContext& context,
+                         ::rust::Fn<void(::rust::Box<A> arg)> func,
+                         ::rust::Box<A> arg)
+{
+  return context.post(::std::move(func), ::std::move(arg));
+}
+
+template<typename A>
+void
+cxxQtExecutorContextSingleShot(const CxxQtExecutorContext& context,
+                               ::std::int32_t msec,
+                               ::rust::Fn<void(::rust::Box<A> arg)> func,
+                               ::rust::Box<A> arg)
+{
+  context.singleShot(msec, ::std::move(func), ::std::move(arg));
+}
+
+} // namespace rust::cxxqt1
@@ -0,0 +1,23 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QDebug>
+#include <QtCore/QString>
+
+#include "rust/cxx.h"
+
+namespace rust::cxxqt1 {
+
+inline void
+panicLogCritical(::rust::Str message)
+{
+  qCritical().noquote() << QString::fromUtf8(
+    message.data(), static_cast<qsizetype>(message.size()));
+}
+
+}
@@ -0,0 +1,27 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstddef>
+
+#include <QtCore/QObject>
+
+namespace rust::cxxqt1 {
+
+inline QObject*
+qobjectChildAt(const QObject& qobject, ::std::size_t index)
+{
+  return qobject.children().at(static_cast<QObjectList::size_type>(index));
+}
+
+inline ::std::size_t
+qobjectChildrenLen(const QObject& qobject)
+{
+  return static_cast<::std::size_t>(qobject.children().size());
+}
+
+}
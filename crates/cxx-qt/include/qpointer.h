@@ -0,0 +1,81 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstddef>
+#include <type_traits>
+
+#include <QtCore/QObject>
+#include <QtCore/QPointer>
+#include <QtCore/QThread>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<::QPointer<::QObject>> : ::std::true_type
+{};
+
+} // namespace rust
+
+namespace rust::cxxqt1 {
+
+using QObjectPointer = ::QPointer<::QObject>;
+
+// QPointer is a QWeakPointer which is the size of two pointers
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/tools/qsharedpointer_impl.h?h=v5.15.6-lts-lgpl#n661
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/tools/qsharedpointer_impl.h?h=v6.2.4#n614
+static_assert(alignof(QObjectPointer) <= alignof(::std::size_t),
+              "unexpectedly large QPointer alignment!");
+static_assert(sizeof(QObjectPointer) == sizeof(::std::size_t[2]),
+              "unexpected QPointer size!");
+
+inline QObjectPointer
+qobjectpointerClone(const QObjectPointer& pointer)
+{
+  return pointer;
+}
+
+inline QObject*
+qobjectpointerData(const QObjectPointer& pointer)
+{
+  return pointer.data();
+}
+
+inline void
+qobjectpointerDeleteUnparented(const QObjectPointer& pointer)
+{
+  QObject* object = pointer.data();
+  if (object == nullptr || object->parent() != nullptr) {
+    return;
+  }
+
+  // An object must be deleted in the thread it lives in
+  if (object->thread() == QThread::currentThread()) {
+    delete object;
+  } else {
+    object->deleteLater();
+  }
+}
+
+inline void
+qobjectpointerDrop(QObjectPointer& pointer)
+{
+  pointer.~QObjectPointer();
+}
+
+inline QObjectPointer
+qobjectpointerNew(QObject* object)
+{
+  return QObjectPointer(object);
+}
+
+}
@@ -6,15 +6,25 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #pragma once
 
+#include <atomic>
+#include <cstddef>
 #include <cstdint>
+#include <functional>
 #include <iostream>
 #include <memory>
 #include <mutex>
 #include <shared_mutex>
 #include <stdexcept>
+#include <string>
+#include <type_traits>
+#include <unordered_map>
 
+#include <QtCore/QCoreApplication>
 #include <QtCore/QDebug>
+#include <QtCore/QEvent>
 #include <QtCore/QMetaObject>
+#include <QtCore/QObject>
+#include <QtCore/QThread>
 
 #include "rust/cxx.h"
 
@@ -32,8 +42,93 @@ public:
 
   T* ptr;
   ::std::shared_mutex mutex;
+
+  // The number of queued closures which have not been executed yet
+  ::std::atomic<::std::size_t> pending{ 0 };
+
+  // The latest pending closure for each coalescing key
+  ::std::mutex coalescedMutex;
+  ::std::unordered_map<::std::string, ::std::function<void(T&)>> coalesced;
 };
 
+// Counts a queued closure as pending until the closure has been destroyed
+template<typename T>
+class CxxQtPendingGuard final
+{
+public:
+  explicit CxxQtPendingGuard(::std::shared_ptr<CxxQtGuardedPointer<T>> obj)
+    : m_obj(::std::move(obj))
+  {
+    m_obj->pending++;
+  }
+
+  ~CxxQtPendingGuard()
+  {
+    if (m_obj) {
+      m_obj->pending--;
+    }
+  }
+
+  CxxQtPendingGuard(CxxQtPendingGuard<T>&& other) = default;
+
+private:
+  ::std::shared_ptr<CxxQtGuardedPointer<T>> m_obj;
+};
+
+inline QEvent::Type
+cxxQtQueuedEventType()
+{
+  static const auto type = static_cast<QEvent::Type>(QEvent::registerEventType());
+  return type;
+}
+
+// Receives a posted event in the thread of the QObject and calls the functor.
+//
+// This allows for posting with a Qt::EventPriority,
+// which is not possible with QMetaObject::invokeMethod.
+template<typename Functor>
+class CxxQtQueuedEventReceiver final : public QObject
+{
+public:
+  explicit CxxQtQueuedEventReceiver(Functor&& functor)
+    : m_functor(::std::move(functor))
+  {
+  }
+
+  bool event(QEvent* event) override
+  {
+    if (event->type() == cxxQtQueuedEventType()) {
+      // Move the functor out so that it is destroyed once it has been called
+      auto functor = ::std::move(m_functor);
+      functor();
+      deleteLater();
+      return true;
+    }
+
+    return QObject::event(event);
+  }
+
+private:
+  Functor m_functor;
+};
+
+template<typename Functor>
+bool
+cxxQtThreadPost(QObject* object, Functor&& functor, ::std::int32_t priority)
+{
+  if (priority == Qt::NormalEventPriority) {
+    return QMetaObject::invokeMethod(
+      object, ::std::move(functor), Qt::QueuedConnection);
+  }
+
+  auto receiver =
+    new CxxQtQueuedEventReceiver<::std::decay_t<Functor>>(::std::move(functor));
+  receiver->moveToThread(object->thread());
+  QCoreApplication::postEvent(
+    receiver, new QEvent(cxxQtQueuedEventType()), static_cast<int>(priority));
+  return true;
+}
+
 template<typename T>
 class CxxQtThread final
 {
@@ -47,15 +142,39 @@ public:
   CxxQtThread(const CxxQtThread<T>& other) = default;
   CxxQtThread(CxxQtThread<T>&& other) = default;
 
+  bool isCurrentThread() const
+  {
+    const auto guard = ::std::shared_lock(m_obj->mutex);
+    return m_obj->ptr != nullptr &&
+           m_obj->ptr->thread() == QThread::currentThread();
+  }
+
   bool isDestroyed() const
   {
     const auto guard = ::std::shared_lock(m_obj->mutex);
     return m_obj->ptr == nullptr;
   }
 
+  ::std::size_t pendingCount() const { return m_obj->pending.load(); }
+
   template<typename A>
   ::std::uint8_t queue(::rust::Fn<void(T& self, ::rust::Box<A> arg)> func,
                        ::rust::Box<A> arg) const
+  {
+    return queueWithOptions(::std::move(func),
+                            ::std::move(arg),
+                            false,
+                            ::rust::Str(),
+                            Qt::NormalEventPriority);
+  }
+
+  template<typename A>
+  ::std::uint8_t queueWithOptions(
+    ::rust::Fn<void(T& self, ::rust::Box<A> arg)> func,
+    ::rust::Box<A> arg,
+    bool coalesce,
+    ::rust::Str coalesceKey,
+    ::std::int32_t priority) const
   {
     const static ::std::uint8_t sObjectDestroyed = 1;
     const static ::std::uint8_t sInvokeMethodFailed = 2;
@@ -66,9 +185,15 @@ public:
       return sObjectDestroyed;
     }
 
+    if (coalesce) {
+      return queueCoalesced(
+        ::std::move(func), ::std::move(arg), coalesceKey, priority);
+    }
+
     // Construct the lambda
     auto obj = m_obj;
     auto lambda = [obj = ::std::move(obj),
+                   pending = CxxQtPendingGuard<T>(m_obj),
                    func = ::std::move(func),
                    arg = ::std::move(arg)]() mutable {
       // Ensure that we can read the pointer and it's not being written to
@@ -82,8 +207,7 @@ public:
     };
 
     // Add the lambda to the queue
-    if (!QMetaObject::invokeMethod(
-          m_obj->ptr, ::std::move(lambda), Qt::QueuedConnection)) {
+    if (!cxxQtThreadPost(m_obj->ptr, ::std::move(lambda), priority)) {
       return sInvokeMethodFailed;
     }
 
@@ -91,6 +215,69 @@ public:
   }
 
 private:
+  // Note that the shared lock of the guarded pointer must be held
+  template<typename A>
+  ::std::uint8_t queueCoalesced(
+    ::rust::Fn<void(T& self, ::rust::Box<A> arg)> func,
+    ::rust::Box<A> arg,
+    ::rust::Str coalesceKey,
+    ::std::int32_t priority) const
+  {
+    const static ::std::uint8_t sInvokeMethodFailed = 2;
+
+    // std::function requires a copyable closure, so share the box
+    auto sharedArg = ::std::make_shared<::rust::Box<A>>(::std::move(arg));
+    auto call = [func = ::std::move(func),
+                 arg = ::std::move(sharedArg)](T& self) {
+      func(self, ::std::move(*arg));
+    };
+
+    auto key = ::std::string(coalesceKey);
+    {
+      // Only the map is updated under the lock, posting takes the lock of the
+      // event queue and must not serialize other posters behind this lock
+      const auto coalescedGuard = ::std::lock_guard(m_obj->coalescedMutex);
+      if (!m_obj->coalesced.insert_or_assign(key, ::std::move(call)).second) {
+        // A closure with this key is already pending, which has now been
+        // replaced with the latest closure
+        return 0;
+      }
+    }
+
+    auto obj = m_obj;
+    auto lambda = [obj = ::std::move(obj),
+                   pending = CxxQtPendingGuard<T>(m_obj),
+                   key]() mutable {
+      // Take the latest closure for the key, so that the next closure with the
+      // key is queued again
+      auto node = [&]() {
+        const auto coalescedGuard = ::std::lock_guard(obj->coalescedMutex);
+        return obj->coalesced.extract(key);
+      }();
+      if (node.empty()) {
+        return;
+      }
+
+      // Ensure that we can read the pointer and it's not being written to
+      const auto guard = ::std::shared_lock(obj->mutex);
+      if (obj->ptr) {
+        node.mapped()(*obj->ptr);
+      } else {
+        qWarning()
+          << "Could not call the function pointer as object has been destroyed";
+      }
+    };
+
+    // Add the lambda to the queue
+    if (!cxxQtThreadPost(m_obj->ptr, ::std::move(lambda), priority)) {
+      const auto coalescedGuard = ::std::lock_guard(m_obj->coalescedMutex);
+      m_obj->coalesced.erase(key);
+      return sInvokeMethodFailed;
+    }
+
+    return 0;
+  }
+
   ::std::shared_ptr<CxxQtGuardedPointer<T>> m_obj;
 };
 
@@ -117,6 +304,26 @@ cxxQtThreadQueue(const CxxQtThread<T>& cxxQtThread,
   return cxxQtThread.queue(::std::move(func), ::std::move(arg));
 }
 
+template<typename A, typename T>
+::std::uint8_t
+cxxQtThreadQueueWithOptions(const CxxQtThread<T>& cxxQtThread,
+                            ::rust::Fn<void(T& self, ::rust::Box<A> arg)> func,
+                            ::rust::Box<A> arg,
+                            bool coalesce,
+                            ::rust::Str coalesceKey,
+                            ::std::int32_t priority)
+{
+  return cxxQtThread.queueWithOptions(
+    ::std::move(func), ::std::move(arg), coalesce, coalesceKey, priority);
+}
+
+template<typename T>
+bool
+cxxQtThreadIsCurrentThread(const CxxQtThread<T>& cxxQtThread)
+{
+  return cxxQtThread.isCurrentThread();
+}
+
 template<typename T>
 bool
 cxxQtThreadIsDestroyed(const CxxQtThread<T>& cxxQtThread)
@@ -124,6 +331,13 @@ cxxQtThreadIsDestroyed(const CxxQtThread<T>& cxxQtThread)
   return cxxQtThread.isDestroyed();
 }
 
+template<typename T>
+::std::size_t
+cxxQtThreadPendingCount(const CxxQtThread<T>& cxxQtThread)
+{
+  return cxxQtThread.pendingCount();
+}
+
 } // namespace cxxqt1
 } // namespace rust
 
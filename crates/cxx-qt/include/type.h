@@ -10,6 +10,11 @@
 #include <memory>
 #include <mutex>
 
+#include <QtCore/QDebug>
+#include <QtCore/QObject>
+#include <QtCore/QString>
+#include <QtCore/QThread>
+
 #include "rust/cxx.h"
 
 namespace rust::cxxqt1 {
@@ -46,4 +51,24 @@ unsafeRust(const Outer& outer)
   return static_cast<const CxxQtType<Inner>&>(outer).unsafeRust();
 }
 
+// Describes the mismatch between the thread the QObject lives in and the
+// current thread, or returns an empty string when they are the same.
+//
+// This is used by the thread-checks feature of cxx-qt.
+template<typename T>
+::rust::String
+threadAffinityMismatch(const T& outer)
+{
+  const auto* expected = static_cast<const QObject&>(outer).thread();
+  const auto* actual = QThread::currentThread();
+  if (expected == actual) {
+    return ::rust::String();
+  }
+
+  QString message;
+  QDebug(&message).nospace() << "expected thread " << expected
+                             << " but called from thread " << actual;
+  return ::rust::String(message.toStdString());
+}
+
 }
@@ -0,0 +1,598 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! A single threaded executor which runs Rust futures on the Qt event loop.
+//!
+//! Futures are spawned with [spawn_local] onto the event loop of the current thread, so they do not
+//! need to be [Send]. Futures can be tied to a QObject with [spawn_local_with_owner], which cancels the task
+//! when the QObject is destroyed.
+//!
+//! Other Qt callbacks, such as signal handlers and invokables, can use the QObject while a task is suspended
+//! at an await. So the owner is only borrowed within the synchronous closure of [TaskOwner::with_mut],
+//! which cannot be held across an await.
+//!
+//! The Qt event loop of the thread must be running for tasks to make progress,
+//! for the GUI thread this is the case once `QGuiApplication::exec` has been called.
+//! A task may also spin a nested event loop, for example by showing a modal dialog,
+//! in which case other tasks continue to be polled.
+//!
+//! ```rust,ignore
+//! impl qobject::MyObject {
+//!     fn start(self: Pin<&mut Self>) {
+//!         cxx_qt::executor::spawn_local_with_owner(&*self, |mut owner| async move {
+//!             owner.with_mut(|object| object.set_status(QString::from("Waiting")));
+//!             cxx_qt::executor::sleep(Duration::from_secs(1)).await;
+//!             owner.with_mut(|object| object.set_status(QString::from("Ready")));
+//!         });
+//!     }
+//! }
+//! ```
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!(<QtCore/QObject>);
+        type QObject = crate::QObject;
+    }
+
+    #[namespace = "rust::cxxqt1"]
+    unsafe extern "C++" {
+        include!("cxx-qt/connection.h");
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+
+        include!("cxx-qt/executor.h");
+        #[doc(hidden)]
+        type CxxQtExecutorContext;
+
+        #[doc(hidden)]
+        #[rust_name = "executor_context_new"]
+        fn cxxQtExecutorContextNew() -> SharedPtr<CxxQtExecutorContext>;
+        #[doc(hidden)]
+        #[rust_name = "executor_context_on_destroyed"]
+        fn cxxQtExecutorContextOnDestroyed(
+            context: &CxxQtExecutorContext,
+            qobject: &QObject,
+            func: fn(Box<CxxQtExecutorFn>),
+            arg: Box<CxxQtExecutorFn>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "executor_context_post"]
+        fn cxxQtExecutorContextPost(
+            context: &CxxQtExecutorContext,
+            func: fn(Box<CxxQtExecutorFn>),
+            arg: Box<CxxQtExecutorFn>,
+        ) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "executor_context_single_shot"]
+        fn cxxQtExecutorContextSingleShot(
+            context: &CxxQtExecutorContext,
+            msec: i32,
+            func: fn(Box<CxxQtExecutorFn>),
+            arg: Box<CxxQtExecutorFn>,
+        );
+    }
+
+    #[namespace = "rust::cxxqt1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type CxxQtExecutorFn;
+    }
+}
+
+use crate::{QMetaObjectConnectionGuard, QObject, QPointer, Upcast};
+use cxx::SharedPtr;
+use std::{
+    cell::{Cell, RefCell},
+    collections::{HashMap, VecDeque},
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll, Wake, Waker},
+    time::Duration,
+};
+
+// Safety:
+//
+// Posting to the context is guarded by a mutex on the C++ side, the other methods
+// are only called from the thread of the executor.
+unsafe impl Send for ffi::CxxQtExecutorContext {}
+unsafe impl Sync for ffi::CxxQtExecutorContext {}
+
+#[doc(hidden)]
+pub struct CxxQtExecutorFn {
+    inner: Box<dyn FnOnce() + Send>,
+}
+
+impl CxxQtExecutorFn {
+    fn new(inner: impl FnOnce() + Send + 'static) -> Box<Self> {
+        Box::new(Self {
+            inner: Box::new(inner),
+        })
+    }
+}
+
+#[allow(clippy::boxed_local)]
+fn call_executor_fn(arg: Box<CxxQtExecutorFn>) {
+    (arg.inner)()
+}
+
+type TaskId = u64;
+
+/// The state of the executor which is shared with wakers, which may be on other threads
+struct Shared {
+    context: SharedPtr<ffi::CxxQtExecutorContext>,
+    ready: Mutex<VecDeque<TaskId>>,
+    scheduled: AtomicBool,
+}
+
+impl Shared {
+    fn schedule(&self, id: TaskId) {
+        self.ready.lock().unwrap().push_back(id);
+
+        // Only post to the event loop once until the ready tasks have been polled
+        if !self.scheduled.swap(true, Ordering::AcqRel)
+            && !ffi::executor_context_post(
+                &self.context,
+                call_executor_fn,
+                CxxQtExecutorFn::new(|| {
+                    // The executor may have been destroyed if the thread is exiting
+                    let _ = EXECUTOR.try_with(Executor::run_ready);
+                }),
+            )
+        {
+            self.scheduled.store(false, Ordering::Release);
+        }
+    }
+}
+
+struct TaskWaker {
+    id: TaskId,
+    shared: Arc<Shared>,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.shared.schedule(self.id);
+    }
+}
+
+struct Task {
+    future: Pin<Box<dyn Future<Output = ()>>>,
+    // Disconnects from the destroyed signal of the owner when the task is dropped
+    _owner_connection: Option<QMetaObjectConnectionGuard>,
+}
+
+/// The state of a task which is being polled
+#[derive(Default)]
+struct PollState {
+    // The task cancelled itself while being polled
+    cancelled: bool,
+    // The task was woken while being polled, for example from a nested event loop
+    woken: bool,
+}
+
+struct Executor {
+    shared: Arc<Shared>,
+    tasks: RefCell<HashMap<TaskId, Task>>,
+    next_id: Cell<TaskId>,
+    // The tasks which are being polled, there is more than one when a task spins a nested event loop
+    polling: RefCell<HashMap<TaskId, PollState>>,
+}
+
+thread_local! {
+    static EXECUTOR: Executor = Executor::new();
+}
+
+impl Executor {
+    fn new() -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                context: ffi::executor_context_new(),
+                ready: Mutex::new(VecDeque::new()),
+                scheduled: AtomicBool::new(false),
+            }),
+            tasks: RefCell::new(HashMap::new()),
+            next_id: Cell::new(0),
+            polling: RefCell::new(HashMap::new()),
+        }
+    }
+
+    fn cancel(&self, id: TaskId) {
+        if let Some(state) = self.polling.borrow_mut().get_mut(&id) {
+            // The task is cancelling itself, drop it once the poll has finished
+            state.cancelled = true;
+        } else {
+            // Drop the task outside of the borrow as the task may cancel other tasks
+            let task = self.tasks.borrow_mut().remove(&id);
+            drop(task);
+        }
+    }
+
+    fn insert(&self, future: Pin<Box<dyn Future<Output = ()>>>) -> TaskId {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.tasks.borrow_mut().insert(
+            id,
+            Task {
+                future,
+                _owner_connection: None,
+            },
+        );
+        self.shared.schedule(id);
+        id
+    }
+
+    fn run_ready(&self) {
+        self.shared.scheduled.store(false, Ordering::Release);
+
+        let ready: Vec<TaskId> = self.shared.ready.lock().unwrap().drain(..).collect();
+        for id in ready {
+            if let Some(state) = self.polling.borrow_mut().get_mut(&id) {
+                // The task spun a nested event loop while being polled, poll it again once
+                // the outer poll has finished rather than polling it re-entrantly
+                state.woken = true;
+                continue;
+            }
+
+            // Take the task out so that it can spawn or cancel other tasks while being polled,
+            // the task may have already finished or been cancelled
+            let Some(mut task) = self.tasks.borrow_mut().remove(&id) else {
+                continue;
+            };
+
+            let waker = Waker::from(Arc::new(TaskWaker {
+                id,
+                shared: self.shared.clone(),
+            }));
+            self.polling.borrow_mut().insert(id, PollState::default());
+            let poll = task.future.as_mut().poll(&mut Context::from_waker(&waker));
+            let state = self.polling.borrow_mut().remove(&id).unwrap_or_default();
+
+            if poll.is_pending() && !state.cancelled {
+                self.tasks.borrow_mut().insert(id, task);
+                if state.woken {
+                    self.shared.schedule(id);
+                }
+            }
+        }
+    }
+}
+
+struct TaskState<T> {
+    output: Option<T>,
+    finished: bool,
+    waker: Option<Waker>,
+}
+
+/// Marks the task as finished when the future is completed or cancelled
+struct FinishGuard<T> {
+    state: Rc<RefCell<TaskState<T>>>,
+}
+
+impl<T> Drop for FinishGuard<T> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut state = self.state.borrow_mut();
+            state.finished = true;
+            state.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// A handle to a task spawned with [spawn_local] or [spawn_local_with_owner].
+///
+/// Awaiting the handle returns the output of the task, or None if the task was cancelled.
+/// Dropping the handle detaches the task, which then continues to run.
+pub struct TaskHandle<T> {
+    id: TaskId,
+    state: Rc<RefCell<TaskState<T>>>,
+}
+
+impl<T> TaskHandle<T> {
+    /// Cancel the task, the future of the task is dropped and is not polled again.
+    ///
+    /// This does nothing if the task has already finished.
+    pub fn abort(&self) {
+        if !self.is_finished() {
+            let _ = EXECUTOR.try_with(|executor| executor.cancel(self.id));
+        }
+    }
+
+    /// Returns true if the task has completed or has been cancelled, otherwise returns false.
+    pub fn is_finished(&self) -> bool {
+        self.state.borrow().finished
+    }
+}
+
+impl<T> Future for TaskHandle<T> {
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.borrow_mut();
+        if state.finished {
+            Poll::Ready(state.output.take())
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+fn spawn_task<F>(future: F) -> TaskHandle<F::Output>
+where
+    F: Future + 'static,
+{
+    let state = Rc::new(RefCell::new(TaskState {
+        output: None,
+        finished: false,
+        waker: None,
+    }));
+    let guard = FinishGuard {
+        state: state.clone(),
+    };
+    let future = Box::pin(async move {
+        let output = future.await;
+        guard.state.borrow_mut().output = Some(output);
+        drop(guard);
+    });
+
+    let id = EXECUTOR.with(|executor| executor.insert(future));
+    TaskHandle { id, state }
+}
+
+/// Spawn a future onto the Qt event loop of the current thread.
+///
+/// The future does not need to be [Send], it is always polled on the current thread.
+/// The future only makes progress while the Qt event loop of the thread is running.
+pub fn spawn_local<F>(future: F) -> TaskHandle<F::Output>
+where
+    F: Future + 'static,
+{
+    spawn_task(future)
+}
+
+/// Spawn a future onto the Qt event loop of the current thread, which is owned by the given QObject.
+///
+/// The future is created from the closure which is given a [TaskOwner] for the owner.
+/// When the owner is destroyed the task is cancelled and the future is dropped without being polled again,
+/// therefore within the future the owner is always alive.
+///
+/// Borrow the owner with [TaskOwner::with_mut] after each await, as other Qt callbacks
+/// may have used the owner while the task was suspended.
+///
+/// The owner must live in the current thread.
+pub fn spawn_local_with_owner<T, F, Fut>(owner: &T, f: F) -> TaskHandle<Fut::Output>
+where
+    T: Upcast<QObject>,
+    F: FnOnce(TaskOwner<T>) -> Fut,
+    Fut: Future + 'static,
+{
+    let handle = spawn_task(f(TaskOwner {
+        pointer: QPointer::new(owner),
+    }));
+    let id = handle.id;
+
+    EXECUTOR.with(|executor| {
+        let connection = ffi::executor_context_on_destroyed(
+            &executor.shared.context,
+            owner.upcast(),
+            call_executor_fn,
+            CxxQtExecutorFn::new(move || {
+                let _ = EXECUTOR.try_with(|executor| executor.cancel(id));
+            }),
+        );
+
+        if let Some(task) = executor.tasks.borrow_mut().get_mut(&id) {
+            task._owner_connection = Some(QMetaObjectConnectionGuard::from(connection));
+        }
+    });
+
+    handle
+}
+
+/// The owner of a task spawned with [spawn_local_with_owner].
+///
+/// As the task is cancelled when the owner is destroyed, the owner is alive whenever the task is polled.
+/// The owner is borrowed for the duration of a synchronous closure, so that the borrow
+/// cannot be held across an await while other Qt callbacks use the owner.
+pub struct TaskOwner<T>
+where
+    T: Upcast<QObject>,
+{
+    pointer: QPointer<T>,
+}
+
+impl<T> TaskOwner<T>
+where
+    T: Upcast<QObject>,
+{
+    /// Calls the closure with a pinned mutable reference to the owner.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the owner has been destroyed, which can only happen if the [TaskOwner] is moved out of the task,
+    /// or if the owner is already borrowed through a [QPointer] on this thread.
+    pub fn with_mut<R>(&mut self, f: impl FnOnce(Pin<&mut T>) -> R) -> R {
+        self.pointer
+            .with_pin_mut(f)
+            .expect("The owner of the task has been destroyed")
+    }
+
+    /// Calls the closure with a reference to the owner.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the owner has been destroyed, which can only happen if the [TaskOwner] is moved out of the task,
+    /// or if the owner is already mutably borrowed through a [QPointer] on this thread.
+    pub fn with_ref<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        self.pointer
+            .with_ref(f)
+            .expect("The owner of the task has been destroyed")
+    }
+
+    /// Returns the [QPointer] to the owner, for advanced use such as borrowing the owner without a closure.
+    pub fn as_qpointer(&self) -> &QPointer<T> {
+        &self.pointer
+    }
+
+    /// Converts the [TaskOwner] into a [QPointer] to the owner.
+    pub fn into_qpointer(self) -> QPointer<T> {
+        self.pointer
+    }
+}
+
+struct SleepState {
+    fired: bool,
+    waker: Option<Waker>,
+}
+
+/// A future returned by [sleep] which completes once the duration has elapsed.
+pub struct Sleep {
+    duration: Duration,
+    state: Option<Arc<Mutex<SleepState>>>,
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let duration = self.duration;
+        let state = self.state.get_or_insert_with(|| {
+            let state = Arc::new(Mutex::new(SleepState {
+                fired: false,
+                waker: None,
+            }));
+
+            // Start the timer when first polled, so that the duration is relative to the await
+            let timer_state = state.clone();
+            EXECUTOR.with(|executor| {
+                ffi::executor_context_single_shot(
+                    &executor.shared.context,
+                    i32::try_from(duration.as_millis()).unwrap_or(i32::MAX),
+                    call_executor_fn,
+                    CxxQtExecutorFn::new(move || {
+                        let mut state = timer_state.lock().unwrap();
+                        state.fired = true;
+                        if let Some(waker) = state.waker.take() {
+                            waker.wake();
+                        }
+                    }),
+                );
+            });
+            state
+        });
+
+        let mut state = state.lock().unwrap();
+        if state.fired {
+            Poll::Ready(())
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// Returns a future which completes after the given duration has elapsed.
+///
+/// This uses a precise QTimer on the Qt event loop of the current thread,
+/// note that the duration has a resolution of milliseconds.
+pub fn sleep(duration: Duration) -> Sleep {
+    Sleep {
+        duration,
+        state: None,
+    }
+}
+
+struct SignalState<T> {
+    value: Option<T>,
+    waker: Option<Waker>,
+}
+
+/// Sends the arguments of a signal emission to a [SignalFuture].
+///
+/// Only the first value sent is used, later values are ignored.
+pub struct SignalSender<T> {
+    state: Arc<Mutex<SignalState<T>>>,
+}
+
+impl<T> Clone for SignalSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T> SignalSender<T> {
+    /// Send the value to the [SignalFuture], completing the future.
+    pub fn send(&self, value: T) {
+        let mut state = self.state.lock().unwrap();
+        if state.value.is_none() {
+            state.value = Some(value);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// A future which completes with the arguments of the next emission of a signal.
+///
+/// The signal is disconnected once the future is dropped.
+///
+/// ```rust,ignore
+/// let value = SignalFuture::new(|sender| {
+///     my_object.as_mut().on_value_changed(move |_, value| sender.send(*value))
+/// })
+/// .await;
+/// ```
+pub struct SignalFuture<T> {
+    state: Arc<Mutex<SignalState<T>>>,
+    _connection: QMetaObjectConnectionGuard,
+}
+
+impl<T> SignalFuture<T> {
+    /// Create a [SignalFuture] from the given closure, which connects to the signal
+    /// using the given [SignalSender] and returns the connection.
+    pub fn new<C>(connect: C) -> Self
+    where
+        C: FnOnce(SignalSender<T>) -> QMetaObjectConnectionGuard,
+    {
+        let state = Arc::new(Mutex::new(SignalState {
+            value: None,
+            waker: None,
+        }));
+        let connection = connect(SignalSender {
+            state: state.clone(),
+        });
+
+        Self {
+            state,
+            _connection: connection,
+        }
+    }
+}
+
+impl<T> Future for SignalFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(value) = state.value.take() {
+            Poll::Ready(value)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
@@ -15,20 +15,30 @@ use std::{fs::File, io::Write, path::Path};
 
 mod connection;
 mod connectionguard;
+pub mod executor;
+pub mod panic;
+mod qbox;
 mod qobject;
+mod qpointer;
 #[doc(hidden)]
 pub mod signalhandler;
+mod threadaffinity;
 mod threading;
 
 pub use cxx_qt_macro::bridge;
 pub use cxx_qt_macro::init_crate;
 pub use cxx_qt_macro::init_qml_module;
 pub use cxx_qt_macro::qobject;
+pub use qbox::QBox;
 pub use qobject::QObject;
+pub use qpointer::QPointer;
 
 pub use connection::{ConnectionType, QMetaObjectConnection};
 pub use connectionguard::QMetaObjectConnectionGuard;
-pub use threading::{CxxQtThread, ThreadingQueueError};
+pub use threading::{CxxQtThread, EventPriority, QueueOptions, ThreadingQueueError};
+
+#[doc(hidden)]
+pub use threadaffinity::assert_thread_affinity;
 
 // Export static assertions that can then be used in cxx-qt-gen generation
 //
@@ -111,15 +121,32 @@ pub trait Threading: Sized {
     /// This allows for queueing closures onto the Qt event loop from a background thread.
     fn qt_thread(&self) -> CxxQtThread<Self>;
 
+    #[doc(hidden)]
+    fn is_current_thread(cxx_qt_thread: &CxxQtThread<Self>) -> bool;
+
     #[doc(hidden)]
     fn is_destroyed(cxx_qt_thread: &CxxQtThread<Self>) -> bool;
 
+    #[doc(hidden)]
+    fn pending_count(cxx_qt_thread: &CxxQtThread<Self>) -> usize;
+
     #[doc(hidden)]
     fn queue<F>(cxx_qt_thread: &CxxQtThread<Self>, f: F) -> Result<(), ThreadingQueueError>
     where
         F: FnOnce(core::pin::Pin<&mut Self>),
         F: Send + 'static;
 
+    #[doc(hidden)]
+    fn queue_with_options<F>(
+        cxx_qt_thread: &CxxQtThread<Self>,
+        coalesce_key: Option<&str>,
+        priority: i32,
+        f: F,
+    ) -> Result<(), ThreadingQueueError>
+    where
+        F: FnOnce(core::pin::Pin<&mut Self>),
+        F: Send + 'static;
+
     #[doc(hidden)]
     fn threading_clone(cxx_qt_thread: &CxxQtThread<Self>) -> CxxQtThread<Self>;
 
@@ -438,6 +465,9 @@ pub fn write_headers(directory: impl AsRef<Path>) {
     // Note ensure that the build script is consistent with files that are copied
     for (file_contents, file_name) in [
         (include_str!("../include/connection.h"), "connection.h"),
+        (include_str!("../include/executor.h"), "executor.h"),
+        (include_str!("../include/qobject.h"), "qobject.h"),
+        (include_str!("../include/qpointer.h"), "qpointer.h"),
         (
             include_str!("../include/signalhandler.h"),
             "signalhandler.h",
@@ -0,0 +1,265 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Control what happens when Rust code called from C++ panics.
+//!
+//! A panic must not unwind into C++, so CXX-Qt catches panics in the generated code which calls
+//! into Rust from C++. This covers invokables and other methods in `extern "RustQt"` blocks,
+//! closures connected to signals and closures queued with [CxxQtThread](crate::CxxQtThread).
+//!
+//! What happens next is decided by the [PanicPolicy] installed with [set_policy].
+//! By default the panic is logged with qCritical and the process is aborted.
+//!
+//! ```rust,ignore
+//! cxx_qt::panic::set_policy(cxx_qt::panic::PanicPolicy::Hook(Box::new(|context| {
+//!     crash_reporter::report(context.type_name(), context.method_name(), context.message());
+//! })));
+//! ```
+//!
+//! Note that an invokable which returns a value cannot continue after a panic,
+//! as there is no value to return to C++. The process is aborted in this case
+//! after the panic has been logged or passed to the hook.
+
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::cell::{Cell, RefCell};
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Once, RwLock};
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "rust::cxxqt1"]
+    unsafe extern "C++" {
+        include!("cxx-qt/panic.h");
+
+        #[doc(hidden)]
+        #[rust_name = "panic_log_critical"]
+        fn panicLogCritical(message: &str);
+    }
+}
+
+/// What to do when Rust code called from C++ panics.
+pub enum PanicPolicy {
+    /// Log the panic and its backtrace with qCritical and abort the process.
+    ///
+    /// This is the default policy.
+    Abort,
+    /// Log the panic with qCritical and continue, as if the call had returned.
+    LogAndContinue,
+    /// Call the given hook with the [PanicContext] and continue, as if the call had returned.
+    Hook(Box<dyn Fn(&PanicContext) + Send + Sync>),
+}
+
+/// Information about a panic which was caught at the boundary between Rust and C++.
+pub struct PanicContext<'a> {
+    type_name: &'a str,
+    method_name: &'a str,
+    message: &'a str,
+    backtrace: Option<&'a Backtrace>,
+}
+
+impl PanicContext<'_> {
+    /// The name of the QObject type which was being called
+    pub fn type_name(&self) -> &str {
+        self.type_name
+    }
+
+    /// The name of the invokable, signal or other method which was being called
+    pub fn method_name(&self) -> &str {
+        self.method_name
+    }
+
+    /// The message of the panic
+    pub fn message(&self) -> &str {
+        self.message
+    }
+
+    /// The backtrace of the panic
+    ///
+    /// This is only available once a policy has been installed with [set_policy],
+    /// as capturing the backtrace requires a panic hook.
+    /// The [PanicPolicy::Abort] policy always logs a backtrace, without the hook this is
+    /// the backtrace of the call from C++ rather than of the panic.
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        self.backtrace
+    }
+}
+
+impl std::fmt::Display for PanicContext<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}::{} panicked: {}",
+            self.type_name, self.method_name, self.message
+        )?;
+        if let Some(backtrace) = self.backtrace {
+            write!(f, "\n{backtrace}")?;
+        }
+        Ok(())
+    }
+}
+
+// The installed policy, or None for the default Abort policy
+//
+// The policy is shared with an Arc, so that the lock is not held while a panic is handled
+// and the hook can call set_policy or reach another guarded call.
+static POLICY: RwLock<Option<Arc<PanicPolicy>>> = RwLock::new(None);
+static INSTALL_HOOK: Once = Once::new();
+
+thread_local! {
+    // Whether the current thread is inside a call from C++
+    static GUARDED: Cell<bool> = const { Cell::new(false) };
+    // The backtrace of the last panic inside a call from C++
+    static BACKTRACE: RefCell<Option<Backtrace>> = const { RefCell::new(None) };
+}
+
+/// Set the [PanicPolicy] used when Rust code called from C++ panics.
+///
+/// This also installs a panic hook which captures the backtrace of panics inside calls from C++,
+/// the existing panic hook is still called afterwards.
+pub fn set_policy(policy: PanicPolicy) {
+    INSTALL_HOOK.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if GUARDED.get() {
+                BACKTRACE.set(Some(Backtrace::force_capture()));
+            }
+            previous(info);
+        }));
+    });
+
+    *POLICY.write().unwrap_or_else(|err| err.into_inner()) = Some(Arc::new(policy));
+}
+
+/// The current [PanicPolicy], the lock is released before this returns
+fn current_policy() -> Arc<PanicPolicy> {
+    POLICY
+        .read()
+        .unwrap_or_else(|err| err.into_inner())
+        .clone()
+        .unwrap_or_else(|| Arc::new(PanicPolicy::Abort))
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "Box<dyn Any>"
+    }
+}
+
+/// The backtrace to report for a panic, given the backtrace captured by the panic hook
+///
+/// The Abort policy ends the process, so if the panic hook has not been installed
+/// the backtrace of the call from C++ is captured instead.
+fn report_backtrace(policy: &PanicPolicy, captured: Option<Backtrace>) -> Option<Backtrace> {
+    match policy {
+        PanicPolicy::Abort => captured.or_else(|| Some(Backtrace::force_capture())),
+        _ => captured,
+    }
+}
+
+/// Run `f`, returning whether it completed without panicking
+fn guarded(type_name: &str, method_name: &str, can_continue: bool, f: impl FnOnce()) -> bool {
+    let was_guarded = GUARDED.replace(true);
+    let result = std::panic::catch_unwind(AssertUnwindSafe(f));
+    GUARDED.set(was_guarded);
+
+    let Err(payload) = result else {
+        return true;
+    };
+
+    let policy = current_policy();
+    let backtrace = report_backtrace(&policy, BACKTRACE.take());
+    let context = PanicContext {
+        type_name,
+        method_name,
+        message: payload_message(payload.as_ref()),
+        backtrace: backtrace.as_ref(),
+    };
+
+    let abort = match policy.as_ref() {
+        PanicPolicy::Abort => {
+            ffi::panic_log_critical(&format!("{context}\naborting"));
+            true
+        }
+        PanicPolicy::LogAndContinue => {
+            ffi::panic_log_critical(&context.to_string());
+            !can_continue
+        }
+        PanicPolicy::Hook(hook) => {
+            // A panic in the hook must not unwind into C++ either
+            std::panic::catch_unwind(AssertUnwindSafe(|| hook(&context))).is_err() || !can_continue
+        }
+    };
+
+    if abort {
+        std::process::abort();
+    }
+
+    false
+}
+
+/// Call `f` from a trampoline which is called by C++ and does not return a value,
+/// handling a panic according to the [PanicPolicy].
+#[doc(hidden)]
+pub fn call_guarded(type_name: &str, method_name: &str, f: impl FnOnce()) {
+    guarded(type_name, method_name, true, f);
+}
+
+/// Call `f` from a trampoline which is called by C++ and returns a value,
+/// handling a panic according to the [PanicPolicy] and then aborting.
+#[doc(hidden)]
+pub fn call_guarded_returning<R>(type_name: &str, method_name: &str, f: impl FnOnce() -> R) -> R {
+    let mut value = None;
+    guarded(type_name, method_name, false, || value = Some(f()));
+    // A panic either aborted or value has been set
+    value.unwrap_or_else(|| std::process::abort())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::backtrace::BacktraceStatus;
+
+    #[test]
+    fn report_backtrace_abort() {
+        // Without a captured backtrace one is captured for the abort report
+        let backtrace = report_backtrace(&PanicPolicy::Abort, None).unwrap();
+        assert_eq!(backtrace.status(), BacktraceStatus::Captured);
+    }
+
+    #[test]
+    fn report_backtrace_continue() {
+        assert!(report_backtrace(&PanicPolicy::LogAndContinue, None).is_none());
+        assert!(report_backtrace(&PanicPolicy::Hook(Box::new(|_| {})), None).is_none());
+
+        let captured = Backtrace::force_capture();
+        assert!(report_backtrace(&PanicPolicy::LogAndContinue, Some(captured)).is_some());
+    }
+
+    #[test]
+    fn hook_sets_policy() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static FIRST: AtomicUsize = AtomicUsize::new(0);
+        static SECOND: AtomicUsize = AtomicUsize::new(0);
+
+        // The hook replaces the policy while it is being called
+        set_policy(PanicPolicy::Hook(Box::new(|_| {
+            FIRST.fetch_add(1, Ordering::SeqCst);
+            set_policy(PanicPolicy::Hook(Box::new(|_| {
+                SECOND.fetch_add(1, Ordering::SeqCst);
+            })));
+        })));
+
+        assert!(!guarded("MyObject", "first", true, || panic!("first")));
+        assert!(!guarded("MyObject", "second", true, || panic!("second")));
+        assert_eq!(FIRST.load(Ordering::SeqCst), 1);
+        assert_eq!(SECOND.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,147 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QObject, QPointer, Upcast};
+use core::mem::ManuallyDrop;
+use core::pin::Pin;
+use cxx::memory::UniquePtrTarget;
+use cxx::UniquePtr;
+
+/// An owning pointer to a [QObject] which follows the ownership rules of Qt.
+///
+/// When the [QBox] is dropped the object is deleted, unless the object has a parent,
+/// in which case the parent owns the object and deletes it when the parent is destroyed.
+///
+/// The [QBox] tracks the lifetime of the object, so once the object has been destroyed,
+/// for example by its parent, the [QBox] becomes null rather than dangling.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let parent = QBox::new(ffi::create_parent());
+/// // The child is owned by the parent, dropping the QBox of the child does not delete it
+/// let child = QBox::new_with_parent(ffi::create_child(), parent.as_pin_mut().unwrap().upcast_pin());
+/// // Deletes the parent and therefore the child
+/// drop(parent);
+/// assert!(child.is_null());
+/// ```
+pub struct QBox<T>
+where
+    T: Upcast<QObject>,
+{
+    pointer: QPointer<T>,
+}
+
+impl<T> QBox<T>
+where
+    T: Upcast<QObject>,
+{
+    /// Take ownership of the object from the given [UniquePtr].
+    ///
+    /// If the object has a parent then the object remains owned by the parent.
+    pub fn new(object: UniquePtr<T>) -> Self
+    where
+        T: UniquePtrTarget,
+    {
+        // Safety: the pointer from a UniquePtr is either null or valid, and ownership has been released
+        unsafe { Self::from_raw(object.into_raw()) }
+    }
+
+    /// Take ownership of the object from the given [UniquePtr] and pass it to the parent.
+    ///
+    /// The parent deletes the object when the parent is destroyed, the returned [QBox] can be used
+    /// to access the object until then.
+    pub fn new_with_parent(object: UniquePtr<T>, parent: Pin<&mut QObject>) -> Self
+    where
+        T: UniquePtrTarget,
+    {
+        let mut qbox = Self::new(object);
+        qbox.set_parent(parent);
+        qbox
+    }
+
+    /// Take ownership of the object at the given pointer.
+    ///
+    /// # Safety
+    ///
+    /// The object must either be null or a valid pointer to a `T` which is not owned by anything
+    /// other than its parent.
+    pub unsafe fn from_raw(object: *mut T) -> Self {
+        Self {
+            pointer: QPointer::from_raw(object),
+        }
+    }
+
+    /// Returns a pointer to the object, or a null pointer if the object has been destroyed.
+    pub fn as_mut_ptr(&self) -> *mut T {
+        self.pointer.as_mut_ptr()
+    }
+
+    /// Returns a pinned mutable reference to the object, or None if the object has been destroyed.
+    pub fn as_pin_mut(&mut self) -> Option<Pin<&mut T>> {
+        // Safety: the QBox is the only owner of the object and the borrow is tied to the QBox,
+        // other references can only be created through the unsafe methods of a QPointer
+        unsafe { self.pointer.as_pin_mut() }
+    }
+
+    /// Returns a reference to the object, or None if the object has been destroyed.
+    pub fn as_ref(&self) -> Option<&T> {
+        // Safety: as for as_pin_mut, a mutable borrow through the QBox cannot exist at the same time
+        unsafe { self.pointer.as_ref() }
+    }
+
+    /// Release ownership of the object without deleting it and return a pointer to the object.
+    ///
+    /// The pointer is null if the object has already been destroyed.
+    pub fn into_raw(self) -> *mut T {
+        let object = self.as_mut_ptr();
+        let mut qbox = ManuallyDrop::new(self);
+        // Safety: the pointer is not used again as the QBox is not dropped
+        unsafe { core::ptr::drop_in_place(&mut qbox.pointer) };
+        object
+    }
+
+    /// Returns true if the object has been destroyed, otherwise returns false.
+    pub fn is_null(&self) -> bool {
+        self.pointer.is_null()
+    }
+
+    /// Returns true if the object is alive and owned by a parent, otherwise returns false.
+    ///
+    /// An object which is owned by a parent is not deleted when the [QBox] is dropped.
+    pub fn is_parent_owned(&self) -> bool {
+        self.as_ref()
+            .is_some_and(|object| !object.upcast().parent().is_null())
+    }
+
+    /// Pass ownership of the object to the parent, the object is then deleted when the parent is destroyed.
+    ///
+    /// This does nothing if the object has been destroyed.
+    pub fn set_parent(&mut self, parent: Pin<&mut QObject>) {
+        if let Some(object) = self.as_pin_mut() {
+            // Safety: the QBox does not delete objects that have a parent, and parent is a valid QObject
+            unsafe {
+                object
+                    .upcast_pin()
+                    .set_parent(parent.get_unchecked_mut() as *mut QObject);
+            }
+        }
+    }
+
+    /// Returns a [QPointer] to the object, which can be used to observe the object without owning it.
+    pub fn to_qpointer(&self) -> QPointer<T> {
+        self.pointer.clone()
+    }
+}
+
+impl<T> Drop for QBox<T>
+where
+    T: Upcast<QObject>,
+{
+    /// Delete the object if it has not been destroyed and it is not owned by a parent
+    fn drop(&mut self) {
+        self.pointer.delete_unparented();
+    }
+}
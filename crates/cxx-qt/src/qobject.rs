@@ -8,15 +8,96 @@
 #[cxx::bridge]
 mod ffi {
     unsafe extern "C++" {
-        // TODO! Implement QObject wrapper properly
         include!(<QtCore/QObject>);
         /// QObject type
+        ///
+        /// Methods which require Qt types such as `QString` or `QVariant` are provided
+        /// by the `QObjectExt` trait in `cxx-qt-lib`.
         type QObject;
 
+        /// If block is true, signals emitted by this object are blocked (i.e., emitting a signal will not invoke anything connected to it).
+        /// If block is false, no such blocking will occur.
+        ///
+        /// The return value is the previous value of signals_blocked().
+        #[cxx_name = "blockSignals"]
+        fn block_signals(self: Pin<&mut QObject>, block: bool) -> bool;
+
+        /// Schedules this object for deletion.
+        ///
+        /// The object will be deleted when control returns to the event loop.
+        #[cxx_name = "deleteLater"]
+        fn delete_later(self: Pin<&mut QObject>);
+
         #[cxx_name = "dumpObjectInfo"]
-        /// Dump Object info method, added so that upcast methods can be tested.
+        /// Dumps information about signal connections, etc. for this object to the debug output.
         fn dump_object_info(&self);
+
+        /// Dumps a tree of children to the debug output.
+        #[cxx_name = "dumpObjectTree"]
+        fn dump_object_tree(&self);
+
+        /// Returns true if the object is a widget; otherwise returns false.
+        #[cxx_name = "isWidgetType"]
+        fn is_widget_type(&self) -> bool;
+
+        /// Returns true if the object is a window; otherwise returns false.
+        #[cxx_name = "isWindowType"]
+        fn is_window_type(&self) -> bool;
+
+        /// Returns a pointer to the parent object, or a null pointer if the object has no parent.
+        fn parent(&self) -> *mut QObject;
+
+        /// Makes the object a child of parent.
+        ///
+        /// # Safety
+        ///
+        /// The parent takes ownership of this object, so this object must not also be owned by Rust,
+        /// and parent must either be null or a valid pointer to a QObject.
+        #[cxx_name = "setParent"]
+        unsafe fn set_parent(self: Pin<&mut QObject>, parent: *mut QObject);
+
+        /// Returns true if signals are blocked; otherwise returns false.
+        ///
+        /// Signals are not blocked by default.
+        #[cxx_name = "signalsBlocked"]
+        fn signals_blocked(&self) -> bool;
+    }
+
+    #[namespace = "rust::cxxqt1"]
+    unsafe extern "C++" {
+        include!("cxx-qt/qobject.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_child_at"]
+        fn qobjectChildAt(qobject: &QObject, index: usize) -> *mut QObject;
+        #[doc(hidden)]
+        #[rust_name = "qobject_children_len"]
+        fn qobjectChildrenLen(qobject: &QObject) -> usize;
     }
 }
 
 pub use ffi::QObject;
+
+use crate::Upcast;
+
+// QObject is trivially its own base class, this allows for generic code over any QObject
+impl Upcast<QObject> for QObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QObject {
+        this
+    }
+
+    unsafe fn from_base_ptr(base: *const QObject) -> *const Self {
+        base
+    }
+}
+
+impl QObject {
+    /// Returns a list of pointers to the direct children of this object.
+    ///
+    /// The first child added is the first object in the list and the last child added is the last object in the list.
+    pub fn children(&self) -> Vec<*mut QObject> {
+        (0..ffi::qobject_children_len(self))
+            .map(|index| ffi::qobject_child_at(self, index))
+            .collect()
+    }
+}
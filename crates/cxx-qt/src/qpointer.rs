@@ -0,0 +1,299 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::{cell::RefCell, mem::MaybeUninit};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!(<QtCore/QObject>);
+        type QObject = crate::QObject;
+    }
+
+    #[namespace = "rust::cxxqt1"]
+    unsafe extern "C++" {
+        include!("cxx-qt/qpointer.h");
+
+        #[doc(hidden)]
+        type QObjectPointer = super::QObjectPointer;
+
+        #[doc(hidden)]
+        #[rust_name = "qobjectpointer_clone"]
+        fn qobjectpointerClone(pointer: &QObjectPointer) -> QObjectPointer;
+        #[doc(hidden)]
+        #[rust_name = "qobjectpointer_data"]
+        fn qobjectpointerData(pointer: &QObjectPointer) -> *mut QObject;
+        #[doc(hidden)]
+        #[rust_name = "qobjectpointer_delete_unparented"]
+        fn qobjectpointerDeleteUnparented(pointer: &QObjectPointer);
+        #[doc(hidden)]
+        #[rust_name = "qobjectpointer_drop"]
+        fn qobjectpointerDrop(pointer: &mut QObjectPointer);
+        #[doc(hidden)]
+        #[rust_name = "qobjectpointer_new"]
+        unsafe fn qobjectpointerNew(object: *mut QObject) -> QObjectPointer;
+    }
+}
+
+use crate::{QObject, Upcast};
+use core::pin::Pin;
+
+/// A guarded pointer to a QObject, which is automatically set to null when the QObject is destroyed.
+///
+/// This allows for safely referencing QObjects that are owned by QML or other C++ code,
+/// as before the object is used the [QPointer] checks that the object is still alive.
+///
+/// Note that the [QPointer] only observes the object, to own a QObject from Rust use a [QBox](crate::QBox).
+///
+/// As a [QPointer] can be cloned and the object can be destroyed by C++ at any time,
+/// the object is borrowed for the duration of a closure with [QPointer::with_ref] or [QPointer::with_pin_mut].
+/// These return None if the object has been destroyed, and panic if the object is already
+/// borrowed incompatibly through another [QPointer] on this thread.
+///
+/// ```rust,ignore
+/// let mut pointer = QPointer::new(external);
+///
+/// // Later, the object may have been destroyed by QML
+/// pointer.with_pin_mut(|external| external.trigger(1));
+/// ```
+///
+/// [QPointer::as_ref] and [QPointer::as_pin_mut] return a reference which is not scoped to a closure,
+/// these are unsafe as the caller must ensure that the object is not destroyed or borrowed elsewhere
+/// while the reference is held.
+pub struct QPointer<T>
+where
+    T: Upcast<QObject>,
+{
+    pointer: QObjectPointer,
+    object: *mut T,
+}
+
+impl<T> Clone for QPointer<T>
+where
+    T: Upcast<QObject>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pointer: self.pointer.clone(),
+            object: self.object,
+        }
+    }
+}
+
+impl<T> Default for QPointer<T>
+where
+    T: Upcast<QObject>,
+{
+    /// Constructs a null QPointer.
+    fn default() -> Self {
+        // Safety: a null pointer is allowed
+        unsafe { Self::from_raw(core::ptr::null_mut()) }
+    }
+}
+
+impl<T> From<&T> for QPointer<T>
+where
+    T: Upcast<QObject>,
+{
+    fn from(object: &T) -> Self {
+        Self::new(object)
+    }
+}
+
+impl<T> QPointer<T>
+where
+    T: Upcast<QObject>,
+{
+    /// Constructs a QPointer which points to the given object.
+    pub fn new(object: &T) -> Self {
+        // Safety: the pointer comes from a valid reference
+        unsafe { Self::from_raw(object as *const T as *mut T) }
+    }
+
+    /// Constructs a QPointer which points to the object at the given pointer.
+    ///
+    /// # Safety
+    ///
+    /// The object must either be null or a valid pointer to a `T`.
+    pub unsafe fn from_raw(object: *mut T) -> Self {
+        let qobject = if object.is_null() {
+            core::ptr::null_mut()
+        } else {
+            T::upcast_ptr(object) as *mut QObject
+        };
+
+        Self {
+            pointer: QObjectPointer::new(qobject),
+            object,
+        }
+    }
+
+    /// Returns a pointer to the object, or a null pointer if the object has been destroyed.
+    pub fn as_mut_ptr(&self) -> *mut T {
+        if self.is_null() {
+            core::ptr::null_mut()
+        } else {
+            self.object
+        }
+    }
+
+    /// Returns a pinned mutable reference to the object, or None if the object has been destroyed.
+    ///
+    /// # Safety
+    ///
+    /// The object must not be destroyed while the reference is held,
+    /// and no other reference to the object, for example from a clone of this [QPointer], may exist at the same time.
+    pub unsafe fn as_pin_mut(&mut self) -> Option<Pin<&mut T>> {
+        // Safety: the guarded pointer is only non-null while the object is alive,
+        // and QObjects are never moved by C++
+        self.as_mut_ptr()
+            .as_mut()
+            .map(|object| Pin::new_unchecked(object))
+    }
+
+    /// Returns a reference to the object, or None if the object has been destroyed.
+    ///
+    /// # Safety
+    ///
+    /// The object must not be destroyed while the reference is held,
+    /// and no mutable reference to the object may exist at the same time.
+    pub unsafe fn as_ref(&self) -> Option<&T> {
+        // Safety: the guarded pointer is only non-null while the object is alive
+        self.as_mut_ptr().as_ref()
+    }
+
+    /// Calls the closure with a pinned mutable reference to the object,
+    /// returns None without calling the closure if the object has been destroyed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is already borrowed through a [QPointer] on this thread,
+    /// for example when this is called from within the closure of [QPointer::with_ref] on a clone.
+    pub fn with_pin_mut<R>(&mut self, f: impl FnOnce(Pin<&mut T>) -> R) -> Option<R> {
+        let _borrow = Borrow::new(self.pointer.data(), true)?;
+        // Safety: the object is alive as the pointer is not null, the reference cannot outlive the closure
+        // and no other reference is given out through a QPointer while the borrow is held
+        unsafe { self.as_pin_mut() }.map(f)
+    }
+
+    /// Calls the closure with a reference to the object,
+    /// returns None without calling the closure if the object has been destroyed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is already mutably borrowed through a [QPointer] on this thread,
+    /// for example when this is called from within the closure of [QPointer::with_pin_mut] on a clone.
+    pub fn with_ref<R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        let _borrow = Borrow::new(self.pointer.data(), false)?;
+        // Safety: the object is alive as the pointer is not null, the reference cannot outlive the closure
+        // and no mutable reference is given out through a QPointer while the borrow is held
+        unsafe { self.as_ref() }.map(f)
+    }
+
+    /// Returns true if the referenced object has been destroyed or if there is no referenced object; otherwise returns false.
+    pub fn is_null(&self) -> bool {
+        self.pointer.data().is_null()
+    }
+
+    /// Deletes the object if it has not been destroyed and it does not have a parent.
+    pub(crate) fn delete_unparented(&self) {
+        self.pointer.delete_unparented();
+    }
+}
+
+thread_local! {
+    // The objects which are borrowed through a QPointer on this thread, and whether the borrow is mutable
+    static BORROWS: RefCell<Vec<(*mut QObject, bool)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A borrow of an object through a [QPointer], which is released when dropped
+struct Borrow {
+    object: *mut QObject,
+}
+
+impl Borrow {
+    /// Borrow the object, returns None if the object is null
+    ///
+    /// Panics if the borrow conflicts with an existing borrow
+    fn new(object: *mut QObject, mutable: bool) -> Option<Self> {
+        if object.is_null() {
+            return None;
+        }
+
+        BORROWS.with(|borrows| {
+            let mut borrows = borrows.borrow_mut();
+            if borrows.iter().any(|(borrowed, borrowed_mutable)| {
+                *borrowed == object && (mutable || *borrowed_mutable)
+            }) {
+                panic!("The object of the QPointer is already borrowed");
+            }
+            borrows.push((object, mutable));
+        });
+        Some(Self { object })
+    }
+}
+
+impl Drop for Borrow {
+    fn drop(&mut self) {
+        // The thread local may have been destroyed if the thread is exiting
+        let _ = BORROWS.try_with(|borrows| {
+            let mut borrows = borrows.borrow_mut();
+            if let Some(index) = borrows
+                .iter()
+                .rposition(|(borrowed, _)| *borrowed == self.object)
+            {
+                borrows.remove(index);
+            }
+        });
+    }
+}
+
+/// A guarded pointer to a [QObject], which is automatically set to null when the QObject is destroyed.
+#[doc(hidden)]
+#[repr(C)]
+pub struct QObjectPointer {
+    _space: MaybeUninit<[usize; 2]>,
+}
+
+impl Clone for QObjectPointer {
+    fn clone(&self) -> Self {
+        ffi::qobjectpointer_clone(self)
+    }
+}
+
+impl Drop for QObjectPointer {
+    fn drop(&mut self) {
+        ffi::qobjectpointer_drop(self);
+    }
+}
+
+impl QObjectPointer {
+    /// Create a guarded pointer to the given QObject
+    ///
+    /// # Safety
+    ///
+    /// The object must either be null or a valid pointer to a QObject.
+    unsafe fn new(object: *mut QObject) -> Self {
+        ffi::qobjectpointer_new(object)
+    }
+
+    /// Returns a pointer to the QObject, or null if the QObject has been destroyed.
+    fn data(&self) -> *mut QObject {
+        ffi::qobjectpointer_data(self)
+    }
+
+    /// Deletes the QObject if it has not been destroyed and it does not have a parent.
+    fn delete_unparented(&self) {
+        ffi::qobjectpointer_delete_unparented(self);
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QObjectPointer {
+    type Id = type_id!("rust::cxxqt1::QObjectPointer");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,26 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+/// Assert that a QObject is being accessed from the thread it lives in.
+///
+/// `mismatch` returns a description of the expected and actual thread,
+/// or an empty string when the QObject is being accessed from its own thread.
+///
+/// This is called by the generated `rust_mut()`, property setters and signal emitters.
+/// When the `thread-checks` feature is disabled this does nothing and `mismatch` is never called.
+#[doc(hidden)]
+#[inline(always)]
+pub fn assert_thread_affinity<T: ?Sized>(type_name: &str, object: &T, mismatch: fn(&T) -> String) {
+    #[cfg(feature = "thread-checks")]
+    {
+        let mismatch = mismatch(object);
+        if !mismatch.is_empty() {
+            panic!("{type_name} was accessed from the wrong thread, {mismatch}");
+        }
+    }
+
+    #[cfg(not(feature = "thread-checks"))]
+    let _ = (type_name, object, mismatch);
+}
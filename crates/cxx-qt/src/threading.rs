@@ -5,10 +5,36 @@
 
 use core::{marker::PhantomData, mem::MaybeUninit, pin::Pin};
 use cxx::ExternType;
+use std::sync::mpsc::{sync_channel, Receiver};
 use thiserror::Error;
 
 use crate::Threading;
 
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the priority of a closure queued with [super::CxxQtThread::queue_with_options].
+    #[namespace = "Qt"]
+    #[repr(i32)]
+    enum EventPriority {
+        /// Closures with this priority are executed before closures with a normal or low priority.
+        HighEventPriority = 1,
+        /// Closures with this priority are executed in the order they were queued, this is the default.
+        NormalEventPriority = 0,
+        /// Closures with this priority are executed after closures with a high or normal priority.
+        LowEventPriority = -1,
+    }
+
+    // We need to tell CXX that the type already exists, otherwise the following error ocucrs
+    // "scoped/unscoped mismatch in enum"
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!(<QtCore/Qt>);
+        type EventPriority;
+    }
+}
+
+pub use ffi::EventPriority;
+
 /// Errors that can occur from CXX-Qt
 #[derive(Error, Debug)]
 #[non_exhaustive]
@@ -22,6 +48,9 @@ pub enum ThreadingQueueError {
     /// Threading failed with unknown error
     #[error("Cannot queue as an unknown error occurred")]
     Unknown,
+    /// Blocking failed as the object lives in the current thread, which would deadlock
+    #[error("Cannot block on queued function pointer as the object lives in the current thread")]
+    WouldDeadlock,
 }
 
 impl From<u8> for ThreadingQueueError {
@@ -34,6 +63,54 @@ impl From<u8> for ThreadingQueueError {
     }
 }
 
+/// Options for queueing a closure with [CxxQtThread::queue_with_options].
+///
+/// ```rust,ignore
+/// // Only the latest reading is applied if the Qt event loop falls behind
+/// let options = QueueOptions::new()
+///     .coalesce_key("temperature")
+///     .priority(EventPriority::HighEventPriority);
+/// qt_thread.queue_with_options(&options, move |qobject| qobject.set_temperature(reading))?;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QueueOptions {
+    coalesce_key: Option<String>,
+    priority: EventPriority,
+}
+
+impl Default for QueueOptions {
+    fn default() -> Self {
+        Self {
+            coalesce_key: None,
+            priority: EventPriority::NormalEventPriority,
+        }
+    }
+}
+
+impl QueueOptions {
+    /// Create options which queue the closure without coalescing and with the normal priority
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Coalesce the closure with other closures queued with the same key.
+    ///
+    /// If a closure with the same key is still pending, it is replaced by this closure,
+    /// so only the latest pending closure for each key is executed.
+    pub fn coalesce_key(mut self, key: impl Into<String>) -> Self {
+        self.coalesce_key = Some(key.into());
+        self
+    }
+
+    /// The Qt event priority of the closure, closures with a higher priority are executed first.
+    ///
+    /// Note that when a coalesced closure replaces a pending closure, the priority of the pending closure is used.
+    pub fn priority(mut self, priority: EventPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
 /// A threading helper which is created from a QObject that implements [Threading].
 ///
 /// This allows for queueing closures onto the Qt event loop from a background thread
@@ -107,6 +184,79 @@ where
         T::queue(self, f)
     }
 
+    /// Queue the given closure onto the Qt event loop for this QObject with the given [QueueOptions]
+    ///
+    /// This allows for coalescing closures by key, so that a producer which queues updates faster
+    /// than the Qt event loop can process them does not flood the event loop, and for setting the
+    /// Qt event priority of the closure.
+    pub fn queue_with_options<F>(
+        &self,
+        options: &QueueOptions,
+        f: F,
+    ) -> Result<(), crate::ThreadingQueueError>
+    where
+        F: FnOnce(Pin<&mut T>),
+        F: Send + 'static,
+    {
+        T::queue_with_options(
+            self,
+            options.coalesce_key.as_deref(),
+            options.priority.repr,
+            f,
+        )
+    }
+
+    /// Returns the number of closures which have been queued for this QObject but have not been executed yet.
+    ///
+    /// This is shared between all clones of the [CxxQtThread], so producers can use this to apply backpressure.
+    /// Note that coalesced closures which replaced a pending closure are not counted separately.
+    pub fn pending_count(&self) -> usize {
+        T::pending_count(self)
+    }
+
+    /// Queue the given closure onto the Qt event loop for this QObject and block until it has been executed,
+    /// returning the result of the closure.
+    ///
+    /// This is similar to a `Qt::BlockingQueuedConnection` and allows for a background thread
+    /// to read the current state of the QObject before continuing.
+    ///
+    /// If this is called from the thread the QObject lives in then [ThreadingQueueError::WouldDeadlock]
+    /// is returned, as the closure could never be executed. If the QObject is destroyed before the
+    /// closure is executed then [ThreadingQueueError::ObjectDestroyed] is returned.
+    pub fn queue_blocking<F, R>(&self, f: F) -> Result<R, crate::ThreadingQueueError>
+    where
+        F: FnOnce(Pin<&mut T>) -> R,
+        F: Send + 'static,
+        R: Send + 'static,
+    {
+        if T::is_current_thread(self) {
+            return Err(crate::ThreadingQueueError::WouldDeadlock);
+        }
+
+        self.queue_with_result(f)?
+            .recv()
+            .map_err(|_| crate::ThreadingQueueError::ObjectDestroyed)
+    }
+
+    /// Queue the given closure onto the Qt event loop for this QObject,
+    /// returning a [Receiver] for the result of the closure.
+    ///
+    /// The result is sent once the closure has been executed on the thread the QObject lives in.
+    /// If the QObject is destroyed before the closure is executed then the [Receiver] is disconnected.
+    pub fn queue_with_result<F, R>(&self, f: F) -> Result<Receiver<R>, crate::ThreadingQueueError>
+    where
+        F: FnOnce(Pin<&mut T>) -> R,
+        F: Send + 'static,
+        R: Send + 'static,
+    {
+        let (sender, receiver) = sync_channel(1);
+        self.queue(move |qobject| {
+            // The receiver may have been dropped if the result is not needed
+            let _ = sender.send(f(qobject));
+        })?;
+        Ok(receiver)
+    }
+
     /// Checks whether the associated `QObject` has been destroyed.
     ///
     /// This method only confirms if the `QObject` has already been destroyed.
@@ -18,6 +18,7 @@
 mod parse_cflags;
 
 use std::{
+    collections::HashMap,
     env,
     fs::File,
     io::Write,
@@ -233,6 +234,10 @@ pub struct QtBuild {
     qmltyperegistrar_executable: Option<String>,
     qmlcachegen_executable: Option<String>,
     rcc_executable: Option<String>,
+    lrelease_executable: Option<String>,
+    lupdate_executable: Option<String>,
+    // The .ts file which was compiled into each .qm file, to detect .ts files with the same name
+    lrelease_outputs: HashMap<PathBuf, PathBuf>,
     qt_modules: Vec<String>,
 }
 
@@ -337,6 +342,9 @@ impl QtBuild {
                         qmltyperegistrar_executable: None,
                         qmlcachegen_executable: None,
                         rcc_executable: None,
+                        lrelease_executable: None,
+                        lupdate_executable: None,
+                        lrelease_outputs: HashMap::new(),
                         version,
                         qt_modules,
                     });
@@ -361,6 +369,9 @@ impl QtBuild {
                         qmltyperegistrar_executable: None,
                         qmlcachegen_executable: None,
                         rcc_executable: None,
+                        lrelease_executable: None,
+                        lupdate_executable: None,
+                        lrelease_outputs: HashMap::new(),
                         version,
                         qt_modules,
                     });
@@ -1059,6 +1070,92 @@ Q_IMPORT_PLUGIN({plugin_class_name});
         }
     }
 
+    /// Run [lrelease](https://doc.qt.io/qt-6/linguist-lrelease.html) on a .ts file and save the output into [cargo's OUT_DIR](https://doc.rust-lang.org/cargo/reference/environment-variables.html).
+    /// The path to the generated .qm file is returned, which can then be listed in a .qrc file
+    /// and loaded at runtime with a QTranslator.
+    ///
+    /// The .qm file is named after the .ts file, so this panics if a .ts file with the same name
+    /// in another directory has already been compiled.
+    pub fn lrelease(&mut self, ts_file: impl AsRef<Path>) -> PathBuf {
+        if self.lrelease_executable.is_none() {
+            self.lrelease_executable = Some(
+                self.get_qt_tool("lrelease")
+                    .expect("Could not find lrelease"),
+            );
+        }
+
+        let input_path = ts_file.as_ref();
+        let output_folder = PathBuf::from(&format!(
+            "{}/qt-build-utils/translations",
+            env::var("OUT_DIR").unwrap()
+        ));
+        std::fs::create_dir_all(&output_folder).expect("Could not create translations dir");
+        let output_path = output_folder.join(format!(
+            "{}.qm",
+            input_path.file_stem().unwrap().to_string_lossy(),
+        ));
+        if let Some(existing) = self
+            .lrelease_outputs
+            .insert(output_path.clone(), input_path.to_path_buf())
+        {
+            if existing != input_path {
+                panic!(
+                    "lrelease cannot compile {} as {} is also compiled to {}, the .ts files must have unique file names",
+                    input_path.display(),
+                    existing.display(),
+                    output_path.display()
+                );
+            }
+        }
+
+        let cmd = Command::new(self.lrelease_executable.as_ref().unwrap())
+            .args([
+                "-silent",
+                input_path.to_str().unwrap(),
+                "-qm",
+                output_path.to_str().unwrap(),
+            ])
+            .output()
+            .unwrap_or_else(|_| panic!("lrelease failed for {}", input_path.display()));
+
+        if !cmd.status.success() {
+            panic!(
+                "lrelease failed for {}:\n{}",
+                input_path.display(),
+                String::from_utf8_lossy(&cmd.stderr)
+            );
+        }
+
+        output_path
+    }
+
+    /// Run [lupdate](https://doc.qt.io/qt-6/linguist-lupdate.html) to extract the translatable strings
+    /// of the source files into the .ts files, which are created if they do not exist.
+    ///
+    /// Existing translations in the .ts files are kept, strings which are no longer found are marked as obsolete.
+    /// Source locations are not written into the .ts files, so that they only change when the strings change.
+    pub fn lupdate(&mut self, sources: &[impl AsRef<Path>], ts_files: &[impl AsRef<Path>]) {
+        if self.lupdate_executable.is_none() {
+            self.lupdate_executable =
+                Some(self.get_qt_tool("lupdate").expect("Could not find lupdate"));
+        }
+
+        let mut cmd = Command::new(self.lupdate_executable.as_ref().unwrap());
+        cmd.args(["-silent", "-locations", "none"]);
+        for source in sources {
+            cmd.arg(source.as_ref());
+        }
+        cmd.arg("-ts");
+        for ts_file in ts_files {
+            cmd.arg(ts_file.as_ref());
+        }
+
+        let cmd = cmd.output().expect("lupdate failed");
+        if !cmd.status.success() {
+            panic!("lupdate failed:\n{}", String::from_utf8_lossy(&cmd.stderr));
+        }
+    }
+
     /// Run [rcc](https://doc.qt.io/qt-6/resources.html) on a .qrc file and save the output into [cargo's OUT_DIR](https://doc.rust-lang.org/cargo/reference/environment-variables.html).
     /// The path to the generated C++ file is returned, which can then be passed to [cc::Build::files](https://docs.rs/cc/latest/cc/struct.Build.html#method.file).
     /// This function also returns a String that contains the name of the resource initializer
@@ -6,7 +6,7 @@
 
 use super::sensors::{SensorHashMap, SensorsWorker};
 use crate::{constants::SENSOR_UPDATE_POLL_RATE, qobject};
-use cxx_qt::CxxQtThread;
+use cxx_qt::{CxxQtThread, QueueOptions};
 use futures_timer::Delay;
 use std::sync::{
     atomic::{AtomicBool, Ordering},
@@ -27,6 +27,9 @@ impl AccumulatorWorker {
         sensors_changed: Arc<AtomicBool>,
         qt_thread: CxxQtThread<qobject::EnergyUsage>,
     ) {
+        // Only the latest accumulated values need to be shown, so coalesce pending updates
+        let options = QueueOptions::new().coalesce_key("accumulated");
+
         loop {
             // Wait at the given poll rate
             Delay::new(SENSOR_UPDATE_POLL_RATE).await;
@@ -48,7 +51,7 @@ impl AccumulatorWorker {
 
                 // Send a request to Qt that it should update
                 qt_thread
-                    .queue(move |mut qobject_energy_usage| {
+                    .queue_with_options(&options, move |mut qobject_energy_usage| {
                         // Update the properties as the data has changed
                         qobject_energy_usage.as_mut().set_average_use(average_use);
                         qobject_energy_usage.as_mut().set_sensors(sensors);
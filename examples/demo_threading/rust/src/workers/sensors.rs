@@ -6,7 +6,7 @@
 
 use crate::{constants::SENSOR_MAXIMUM_COUNT, network::NetworkChannel, qobject};
 use core::pin::Pin;
-use cxx_qt::CxxQtThread;
+use cxx_qt::{CxxQtThread, QueueOptions};
 use cxx_qt_lib::QString;
 use std::{
     collections::HashMap,
@@ -115,8 +115,12 @@ impl SensorsWorker {
                             // Queue a Signal that there is a new sensor or an existing sensor has
                             // changed to Qt
                             if is_occupied {
+                                // Sensors update at a high rate, so only notify Qt once
+                                // for each sensor with changes that are still pending
+                                let options = QueueOptions::new().coalesce_key(uuid.to_string());
                                 qt_thread
-                                    .queue(
+                                    .queue_with_options(
+                                        &options,
                                         move |qobject_energy_usage: Pin<
                                             &mut qobject::EnergyUsage,
                                         >| {
@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtQuick.Controls 2.12
+import QtQuick.Layouts 1.12
+
+import com.kdab.cxx_qt.demo 1.0
+
+Page {
+    header: ToolBar {
+        RowLayout {
+            anchors.fill: parent
+
+            ToolButton {
+                text: qsTr("Randomise")
+
+                onClicked: {
+                    const values = [];
+                    for (let i = 0; i < 8; i++) {
+                        values.push(Math.random() * 100);
+                    }
+                    bars.values = values;
+                }
+            }
+
+            ToolButton {
+                text: qsTr("Red")
+
+                onClicked: bars.color = "red"
+            }
+
+            ToolButton {
+                text: qsTr("Blue")
+
+                onClicked: bars.color = "blue"
+            }
+
+            Item {
+                Layout.fillWidth: true
+            }
+        }
+    }
+
+    ColumnLayout {
+        anchors.left: parent.left
+        anchors.right: parent.right
+        anchors.verticalCenter: parent.verticalCenter
+
+        SceneGraphBars {
+            id: bars
+            color: "blue"
+            Layout.alignment: Qt.AlignHCenter
+            height: 200
+            width: 300
+            values: [10, 40, 25, 80, 60, 30, 90, 50]
+        }
+
+        Label {
+            Layout.fillWidth: true
+            horizontalAlignment: Text.AlignHCenter
+            text: qsTr("In this demo the bars are scene graph nodes which are created in Rust by overriding updatePaintNode.")
+            wrapMode: Text.Wrap
+        }
+    }
+}
@@ -25,6 +25,7 @@ fn main() {
                 "src/signals.rs",
                 "src/singleton.rs",
                 "src/properties.rs",
+                "src/scene_graph.rs",
                 "src/threading.rs",
                 "src/types.rs",
                 "src/uncreatable.rs",
@@ -40,6 +41,7 @@ fn main() {
                 "../qml/pages/NamingPage.qml",
                 "../qml/pages/NestedQObjectsPage.qml",
                 "../qml/pages/PropertiesPage.qml",
+                "../qml/pages/SceneGraphPage.qml",
                 "../qml/pages/SerialisationPage.qml",
                 "../qml/pages/SignalsPage.qml",
                 "../qml/pages/SingletonPage.qml",
@@ -13,24 +13,9 @@ pub mod qobject {
         type QColor = cxx_qt_lib::QColor;
         include!("cxx-qt-lib/qcolor.h");
 
-        /// QRectF from cxx_qt_lib
-        type QRectF = cxx_qt_lib::QRectF;
-        include!("cxx-qt-lib/qrectf.h");
-
-        /// QSizeF from cxx_qt_lib
-        type QSizeF = cxx_qt_lib::QSizeF;
-        include!("cxx-qt-lib/qsizef.h");
-
-        /// QPainter from cxx_qt_lib
-        type QPainter = cxx_qt_lib::QPainter;
-        include!("cxx-qt-lib/qpainter.h");
-    }
-
-    // Define the API from QtQuick that we need
-    unsafe extern "C++" {
-        include!(<QtQuick/QQuickPaintedItem>);
-        /// Base for Qt type
-        type QQuickPaintedItem;
+        /// QQuickPaintedItem from cxx_qt_lib
+        type QQuickPaintedItem = cxx_qt_lib::QQuickPaintedItem;
+        include!("cxx-qt-lib/qquickpainteditem.h");
     }
 
     unsafe extern "RustQt" {
@@ -40,25 +25,25 @@ pub mod qobject {
         #[qproperty(QColor, color)]
         type CustomParentClass = super::CustomParentClassRust;
 
-        /// Override QQuickPaintedItem::paint to draw two rectangles in Rust using QPainter
-        #[qinvokable]
-        #[cxx_override]
-        unsafe fn paint(self: Pin<&mut CustomParentClass>, painter: *mut QPainter);
-
-        /// Define that we need to inherit size() from the base class
-        #[inherit]
-        fn size(self: &CustomParentClass) -> QSizeF;
-
-        /// Define that we need to inherit update() from the base class
-        #[inherit]
-        fn update(self: Pin<&mut CustomParentClass>);
+        /// Emitted when the item is clicked with the left mouse button
+        #[qsignal]
+        fn clicked(self: Pin<&mut CustomParentClass>);
     }
 
+    // ANCHOR: book_overrides_declaration
+    // Override the virtual methods of QQuickPaintedItem with the traits from cxx_qt_lib
+    impl cxx_qt_lib::QQuickPaintedItemOverrides for CustomParentClass {}
+    // ANCHOR_END: book_overrides_declaration
+
     impl cxx_qt::Constructor<()> for CustomParentClass {}
 }
 
 use core::pin::Pin;
-use cxx_qt_lib::{QColor, QRectF};
+use cxx_qt::Upcast;
+use cxx_qt_lib::{
+    MouseButton, QColor, QMouseEvent, QPainter, QQuickItemOverrides, QQuickPaintedItemOverrides,
+    QRectF,
+};
 
 /// A struct which inherits from QQuickPaintedItem
 ///
@@ -68,35 +53,46 @@ pub struct CustomParentClassRust {
     color: QColor,
 }
 
-impl qobject::CustomParentClass {
-    /// Override QQuickPaintedItem::paint to draw two rectangles in Rust using QPainter
-    ///
-    /// # Safety
-    ///
-    /// As we deref a pointer in a public method this needs to be marked as unsafe
-    pub unsafe fn paint(self: Pin<&mut Self>, painter: *mut qobject::QPainter) {
-        // We need to convert the *mut QPainter to a Pin<&mut QPainter> so that we can reach the methods
-        if let Some(painter) = painter.as_mut() {
-            let mut pinned_painter = Pin::new_unchecked(painter);
-
-            // Now pinned painter can be used as normal
-            // to render a rectangle with two colours
-            let size = self.as_ref().size();
-            pinned_painter.as_mut().fill_rect(
-                &QRectF::new(0.0, 0.0, size.width() / 2.0, size.height()),
-                self.as_ref().color(),
-            );
-            let darker_color = self.as_ref().color().darker(150);
-            pinned_painter.as_mut().fill_rect(
-                &QRectF::new(size.width() / 2.0, 0.0, size.width() / 2.0, size.height()),
-                &darker_color,
-            );
+// ANCHOR: book_overrides_impl
+impl QQuickItemOverrides for qobject::CustomParentClass {
+    /// Override QQuickItem::mousePressEvent to emit clicked when the left button is pressed
+    fn mouse_press_event(self: Pin<&mut Self>, event: Pin<&mut QMouseEvent>) {
+        if event.button() == MouseButton::LeftButton {
+            event.accept();
+            self.clicked();
+        } else {
+            event.ignore();
         }
     }
 }
 
+impl QQuickPaintedItemOverrides for qobject::CustomParentClass {
+    /// Override QQuickPaintedItem::paint to draw two rectangles in Rust using QPainter
+    fn paint(self: Pin<&mut Self>, mut painter: Pin<&mut QPainter>) {
+        // Render a rectangle with two colours
+        let size = self.upcast().upcast().size();
+        painter.as_mut().fill_rect(
+            &QRectF::new(0.0, 0.0, size.width() / 2.0, size.height()),
+            self.as_ref().color(),
+        );
+        let darker_color = self.as_ref().color().darker(150);
+        painter.as_mut().fill_rect(
+            &QRectF::new(size.width() / 2.0, 0.0, size.width() / 2.0, size.height()),
+            &darker_color,
+        );
+    }
+}
+// ANCHOR_END: book_overrides_impl
+
 impl cxx_qt::Initialize for qobject::CustomParentClass {
-    fn initialize(self: core::pin::Pin<&mut Self>) {
-        self.on_color_changed(|qobject| qobject.update()).release();
+    fn initialize(mut self: core::pin::Pin<&mut Self>) {
+        // Only left clicks are delivered to mouse_press_event
+        self.as_mut()
+            .upcast_pin()
+            .upcast_pin()
+            .set_accepted_mouse_buttons(&[MouseButton::LeftButton]);
+
+        self.on_color_changed(|qobject| qobject.upcast_pin().upcast_pin().update())
+            .release();
     }
 }
@@ -19,6 +19,7 @@ pub mod multiple_qobjects;
 pub mod naming;
 pub mod nested_qobjects;
 pub mod properties;
+pub mod scene_graph;
 pub mod serialisation;
 pub mod signals;
 pub mod singleton;
@@ -0,0 +1,214 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This example shows how a QQuickItem can render scene graph nodes from Rust
+
+/// A CXX-Qt bridge which shows how to override updatePaintNode
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcolor.h");
+        /// QColor from cxx_qt_lib
+        type QColor = cxx_qt_lib::QColor;
+        include!("cxx-qt-lib/qlist.h");
+        /// QList<f64> from cxx_qt_lib
+        type QList_f64 = cxx_qt_lib::QList<f64>;
+        include!("cxx-qt-lib/qquickitem.h");
+        /// QQuickItem from cxx_qt_lib
+        type QQuickItem = cxx_qt_lib::QQuickItem;
+        /// QQuickItem::UpdatePaintNodeData from cxx_qt_lib
+        type QQuickItemUpdatePaintNodeData = cxx_qt_lib::QQuickItemUpdatePaintNodeData;
+        include!("cxx-qt-lib/qsgnode.h");
+        /// QSGNode from cxx_qt_lib
+        type QSGNode = cxx_qt_lib::QSGNode;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[base = QQuickItem]
+        #[qproperty(QColor, color)]
+        #[qproperty(QList_f64, values)]
+        type SceneGraphBars = super::SceneGraphBarsRust;
+
+        // ANCHOR: book_update_paint_node_signature
+        /// Override QQuickItem::updatePaintNode to build a tree of scene graph nodes in Rust
+        #[cxx_override]
+        #[cxx_name = "updatePaintNode"]
+        unsafe fn update_paint_node(
+            self: Pin<&mut SceneGraphBars>,
+            old_node: *mut QSGNode,
+            data: *mut QQuickItemUpdatePaintNodeData,
+        ) -> *mut QSGNode;
+        // ANCHOR_END: book_update_paint_node_signature
+    }
+
+    impl cxx_qt::Constructor<()> for SceneGraphBars {}
+}
+
+use core::pin::Pin;
+use cxx_qt::{Downcast, Upcast};
+use cxx_qt_lib::{
+    QColor, QList, QPointF, QQuickItemFlag, QRectF, QSGFlatColorMaterial, QSGGeometry,
+    QSGGeometryDrawingMode, QSGGeometryNode, QSGNode, QSGNodeDirtyStateBit, QSGSimpleRectNode,
+};
+
+/// A struct which renders a bar chart of its values with the scene graph
+#[derive(Default)]
+pub struct SceneGraphBarsRust {
+    color: QColor,
+    values: QList<f64>,
+}
+
+impl qobject::SceneGraphBars {
+    /// Override QQuickItem::updatePaintNode to build a tree of scene graph nodes in Rust
+    ///
+    /// This is called on the render thread while the GUI thread is blocked.
+    ///
+    /// # Safety
+    ///
+    /// As we deref a pointer in a public method this needs to be marked as unsafe
+    // ANCHOR: book_update_paint_node
+    pub unsafe fn update_paint_node(
+        self: Pin<&mut Self>,
+        old_node: *mut QSGNode,
+        _data: *mut qobject::QQuickItemUpdatePaintNodeData,
+    ) -> *mut QSGNode {
+        let width = self.upcast().width();
+        let height = self.upcast().height();
+        let count = self.values().len();
+        let max = self.values().iter().copied().fold(0.0, f64::max);
+        let bars: Vec<QRectF> = if count > 0 && max > 0.0 {
+            let bar_width = width / count as f64;
+            self.values()
+                .iter()
+                .enumerate()
+                .map(|(i, value)| {
+                    let bar_height = height * value.max(0.0) / max;
+                    QRectF::new(
+                        i as f64 * bar_width,
+                        height - bar_height,
+                        bar_width * 0.8,
+                        bar_height,
+                    )
+                })
+                .collect()
+        } else {
+            vec![]
+        };
+        let baseline = [QPointF::new(0.0, height), QPointF::new(width, height)];
+
+        // The node from the previous call is owned by the scene graph,
+        // if there is none then create a node and release it to the scene graph
+        let root = if old_node.is_null() {
+            QSGNode::into_raw(QSGNode::new())
+        } else {
+            old_node
+        };
+
+        if let Some(root) = root.as_mut() {
+            let mut root = Pin::new_unchecked(root);
+            // Update the existing nodes in place, unless the number of bars has changed
+            if !Self::update_nodes(root.as_mut(), &bars, &baseline, self.color()) {
+                root.as_mut().delete_all_child_nodes();
+                Self::append_nodes(root.as_mut(), &bars, &baseline, self.color());
+            }
+        }
+
+        // Ownership of the root node is given back to the scene graph
+        root
+    }
+
+    /// Append a rectangle node for each bar followed by the baseline node
+    fn append_nodes(
+        mut root: Pin<&mut QSGNode>,
+        bars: &[QRectF],
+        baseline: &[QPointF],
+        color: &QColor,
+    ) {
+        for bar in bars {
+            // Rectangle nodes are rendered by every scene graph backend, including software
+            root.as_mut()
+                .append_child_node(QSGSimpleRectNode::new(bar, color));
+        }
+
+        // Draw a baseline with custom geometry and a flat color material
+        let mut geometry = QSGGeometry::new_point_2d(2);
+        geometry
+            .pin_mut()
+            .set_drawing_mode(QSGGeometryDrawingMode::DrawLines);
+        geometry.pin_mut().set_points_2d(baseline);
+        root.append_child_node(QSGGeometryNode::new(
+            geometry,
+            QSGFlatColorMaterial::new(&color.darker(150)),
+        ));
+    }
+
+    /// Update the nodes created by [Self::append_nodes],
+    /// returns false if the existing nodes do not match the bars
+    ///
+    /// # Safety
+    ///
+    /// The children of root must be valid nodes
+    unsafe fn update_nodes(
+        mut root: Pin<&mut QSGNode>,
+        bars: &[QRectF],
+        baseline: &[QPointF],
+        color: &QColor,
+    ) -> bool {
+        if root.child_count() != bars.len() as i32 + 1 {
+            return false;
+        }
+
+        for (i, bar) in bars.iter().enumerate() {
+            let Some(node) = root.child_at_index(i as i32).as_mut() else {
+                return false;
+            };
+            let Some(mut node) = Pin::new_unchecked(node).downcast_pin::<QSGSimpleRectNode>()
+            else {
+                return false;
+            };
+            // Rectangle nodes mark themselves as dirty
+            node.as_mut().set_rect(bar);
+            node.set_color(color);
+        }
+
+        let Some(node) = root.as_mut().last_child().as_mut() else {
+            return false;
+        };
+        let Some(mut node) = Pin::new_unchecked(node).downcast_pin::<QSGGeometryNode>() else {
+            return false;
+        };
+        if let Some(geometry) = node.as_mut().geometry_mut() {
+            geometry.set_points_2d(baseline);
+        }
+        if let Some(material) = node.as_mut().material_mut::<QSGFlatColorMaterial>() {
+            material.set_color(&color.darker(150));
+        }
+
+        // Geometry nodes must be marked as dirty for changes to be rendered
+        let mut node = node.upcast_pin();
+        node.as_mut()
+            .mark_dirty(QSGNodeDirtyStateBit::DirtyGeometry);
+        node.mark_dirty(QSGNodeDirtyStateBit::DirtyMaterial);
+        true
+    }
+    // ANCHOR_END: book_update_paint_node
+}
+
+impl cxx_qt::Initialize for qobject::SceneGraphBars {
+    fn initialize(mut self: core::pin::Pin<&mut Self>) {
+        // Items must declare that they have contents for updatePaintNode to be called
+        self.as_mut()
+            .upcast_pin()
+            .set_flag(QQuickItemFlag::ItemHasContents, true);
+
+        self.as_mut()
+            .on_color_changed(|qobject| qobject.upcast_pin().update())
+            .release();
+        self.on_values_changed(|qobject| qobject.upcast_pin().update())
+            .release();
+    }
+}
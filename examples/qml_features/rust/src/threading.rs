@@ -19,6 +19,9 @@ pub mod qobject {
         include!("cxx-qt-lib/qurl.h");
         /// QUrl from cxx_qt_lib
         type QUrl = cxx_qt_lib::QUrl;
+        include!("cxx-qt-lib/qjsvalue.h");
+        /// QJSValue from cxx_qt_lib
+        type QJSValue = cxx_qt_lib::QJSValue;
     }
 
     extern "RustQt" {
@@ -44,12 +47,19 @@ pub mod qobject {
         #[qinvokable]
         #[cxx_name = "fetchTitle"]
         fn fetch_title(self: Pin<&mut ThreadingWebsite>);
+
+        // ANCHOR: book_js_callback_signature
+        /// Fetch the title of the website and pass it to the given JavaScript callback
+        #[qinvokable]
+        #[cxx_name = "fetchTitleWithCallback"]
+        fn fetch_title_with_callback(self: Pin<&mut ThreadingWebsite>, callback: &QJSValue);
+        // ANCHOR_END: book_js_callback_signature
     }
 }
 
 use core::pin::Pin;
 use cxx_qt::{CxxQtType, Threading};
-use cxx_qt_lib::{QString, QUrl};
+use cxx_qt_lib::{QJSCallback, QJSValue, QString, QUrl};
 
 /// A QObject which has threading
 pub struct ThreadingWebsiteRust {
@@ -59,6 +69,9 @@ pub struct ThreadingWebsiteRust {
     url: QUrl,
 
     pub(crate) loading: std::sync::atomic::AtomicBool,
+
+    /// JavaScript callbacks waiting for the title to be fetched
+    title_callbacks: Vec<QJSCallback>,
 }
 
 impl Default for ThreadingWebsiteRust {
@@ -68,6 +81,7 @@ impl Default for ThreadingWebsiteRust {
             title: QString::from("KDAB"),
 
             loading: std::sync::atomic::AtomicBool::new(false),
+            title_callbacks: vec![],
         }
     }
 }
@@ -135,10 +149,36 @@ impl qobject::ThreadingWebsite {
                         .rust()
                         .loading
                         .store(false, std::sync::atomic::Ordering::Relaxed);
+
+                    // ANCHOR: book_js_callback_call
+                    // Pass the title to any JavaScript callbacks, this is on the Qt thread
+                    // so the callbacks are called from the thread of the QML engine
+                    let callbacks =
+                        std::mem::take(&mut qobject_website.as_mut().rust_mut().title_callbacks);
+                    let title = QJSValue::new_qstring(&QString::from(&title));
+                    for callback in callbacks {
+                        callback.call(&[&title]);
+                    }
+                    // ANCHOR_END: book_js_callback_call
                 })
                 .unwrap();
             // ANCHOR_END: book_qt_thread_queue
         });
     }
+
+    // ANCHOR: book_js_callback_store
+    /// Fetch the title of the website and pass it to the given JavaScript callback
+    pub fn fetch_title_with_callback(mut self: Pin<&mut Self>, callback: &QJSValue) {
+        // Store the callback so that it can be called once the title has been fetched
+        let Some(callback) = QJSCallback::new(&*self, callback) else {
+            println!("The callback must be a JavaScript function.");
+            return;
+        };
+        self.as_mut().rust_mut().title_callbacks.push(callback);
+
+        // If a title is already being fetched the callback is called when it has finished
+        self.fetch_title();
+    }
+    // ANCHOR_END: book_js_callback_store
 }
 // ANCHOR_END: book_macro_code
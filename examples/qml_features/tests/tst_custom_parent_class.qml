@@ -9,11 +9,21 @@ import com.kdab.cxx_qt.demo 1.0
 
 TestCase {
     name: "CustomParentClassTests"
+    when: windowShown
 
     Component {
         id: componentCustomParentClass
 
         CustomParentClass {
+            height: 100
+            width: 100
+        }
+    }
+
+    Component {
+        id: componentSpy
+
+        SignalSpy {
 
         }
     }
@@ -22,4 +32,20 @@ TestCase {
         const item = createTemporaryObject(componentCustomParentClass, null, {});
         verify(item !== null);
     }
+
+    function test_clicked() {
+        const item = createTemporaryObject(componentCustomParentClass, this, {});
+        const clickedSpy = createTemporaryObject(componentSpy, null, {
+            signalName: "clicked",
+            target: item,
+        });
+        compare(clickedSpy.count, 0);
+
+        // Only the left button is accepted by the item
+        mouseClick(item, 50, 50, Qt.RightButton);
+        compare(clickedSpy.count, 0);
+
+        mouseClick(item, 50, 50, Qt.LeftButton);
+        compare(clickedSpy.count, 1);
+    }
 }
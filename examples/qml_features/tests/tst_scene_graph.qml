@@ -0,0 +1,46 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtTest 1.12
+
+import com.kdab.cxx_qt.demo 1.0
+
+TestCase {
+    name: "SceneGraphTests"
+    when: windowShown
+
+    Component {
+        id: componentSceneGraphBars
+
+        SceneGraphBars {
+            color: "red"
+            height: 100
+            width: 100
+        }
+    }
+
+    function test_create() {
+        const item = createTemporaryObject(componentSceneGraphBars, null, {});
+        verify(item !== null);
+        compare(item.values.length, 0);
+    }
+
+    function test_render() {
+        const item = createTemporaryObject(componentSceneGraphBars, this, {
+            values: [1, 2, 3],
+        });
+        compare(item.values.length, 3);
+        compare(item.values[2], 3);
+        // Ensure that updatePaintNode is called for the initial values
+        verify(waitForRendering(item));
+
+        // Changing the values rebuilds the nodes
+        item.values = [];
+        verify(waitForRendering(item));
+        item.values = [4, 5];
+        item.color = "blue";
+        verify(waitForRendering(item));
+    }
+}
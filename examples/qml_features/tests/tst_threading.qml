@@ -53,6 +53,25 @@ TestCase {
         compare(spy.count, 2);
     }
 
+    function test_title_callback() {
+        const website = createTemporaryObject(componentWebsite, null, {});
+        let titles = [];
+
+        website.fetchTitleWithCallback(function(title) {
+            titles.push(title);
+        });
+        // A callback added while fetching is called when the fetch finishes
+        website.fetchTitleWithCallback(function(title) {
+            titles.push(title);
+        });
+        compare(website.title, "Loading...");
+        compare(titles.length, 0);
+
+        tryVerify(() => titles.length === 2, 5000);
+        compare(titles[0], "KDAB");
+        compare(titles[1], "KDAB");
+    }
+
     function test_url_change() {
         const website = createTemporaryObject(componentWebsite, null, {});
         const titleSpy = createTemporaryObject(componentSpy, null, {
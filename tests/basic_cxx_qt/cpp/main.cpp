@@ -5,6 +5,8 @@
 // SPDX-FileContributor: Gerhard de Clercq <gerhard.declercq@kdab.com>
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
+#include <QtCore/QEventLoop>
+#include <QtCore/QPointer>
 #include <QtCore/QThread>
 #include <QtCore/QTimer>
 #include <QtTest/QSignalSpy>
@@ -14,6 +16,9 @@
 #include "basic_cxx_qt/src/empty.cxxqt.h"
 #include "basic_cxx_qt/src/lib.cxxqt.h"
 #include "basic_cxx_qt/src/naming.cxxqt.h"
+#include "basic_cxx_qt/src/panic.cxxqt.h"
+#include "basic_cxx_qt/src/qbox.cxx.h"
+#include "basic_cxx_qt/src/qthread.cxx.h"
 #include "basic_cxx_qt/src/types.cxxqt.h"
 
 class CxxQtTest : public QObject
@@ -129,6 +134,111 @@ private Q_SLOTS:
     QTRY_COMPARE(obj.fetchUpdateCallCount(), 100);
   }
 
+  // CXX-Qt allows Rust code to block on a request from another thread
+  void test_queue_blocking_request()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.setNumber(4);
+    obj.queueBlockingTest();
+    QTRY_COMPARE(obj.getNumber(), 8);
+  }
+
+  // CXX-Qt detects blocking on a request from the thread of the object
+  void test_queue_blocking_would_deadlock()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QVERIFY(obj.queueBlockingWouldDeadlock());
+  }
+
+  // CXX-Qt only executes the latest pending request for a coalescing key
+  void test_queue_coalesced_requests()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.queueCoalescedTest();
+    QCOMPARE(obj.fetchPendingCount(), 1);
+    QTRY_COMPARE(obj.fetchPendingCount(), 0);
+    QCOMPARE(obj.fetchUpdateCallCount(), 1);
+    QCOMPARE(obj.getNumber(), 3);
+  }
+
+  // CXX-Qt objects can be moved to a QThread created from Rust and requests
+  // are queued onto that thread
+  void test_queue_request_moved_to_thread()
+  {
+    auto thread = qthread_new_started();
+    QTRY_VERIFY(thread->isRunning());
+
+    auto obj = new cxx_qt::my_object::MyObject();
+    QVERIFY(qthread_move_object(*obj, thread.get()));
+    QCOMPARE(obj->thread(), thread.get());
+    QVERIFY(obj->thread() != QThread::currentThread());
+
+    obj->queueThreadAffinityTest();
+    QTRY_COMPARE(obj->getNumber(), 1);
+
+    QVERIFY(qthread_quit_and_wait(*thread));
+    delete obj;
+  }
+
+  // CXX-Qt allows Rust futures to sleep on the Qt event loop
+  void test_executor_sleep()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.setNumber(4);
+    obj.executorSleepTest();
+    QCOMPARE(obj.getNumber(), 4);
+    QTRY_COMPARE(obj.getNumber(), 8);
+  }
+
+  // CXX-Qt allows Rust futures to await signals
+  void test_executor_signal()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.executorSignalTest();
+    QTRY_COMPARE(obj.getString(), QStringLiteral("waiting"));
+    obj.setNumber(3);
+    QTRY_COMPARE(obj.getString(), QStringLiteral("changed 3"));
+  }
+
+  // CXX-Qt continues to poll Rust futures which spin a nested event loop
+  void test_executor_nested_event_loop()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QObject::connect(
+      &obj, &cxx_qt::my_object::MyObject::stringChanged, &obj, [&obj]() {
+        if (obj.getString() == QStringLiteral("nested")) {
+          // The task is woken by its timer within this event loop
+          QEventLoop loop;
+          QTimer::singleShot(20, &loop, &QEventLoop::quit);
+          loop.exec();
+        }
+      });
+    obj.executorNestedEventLoopTest();
+    QTRY_COMPARE(obj.getString(), QStringLiteral("done"));
+  }
+
+  // CXX-Qt cancels Rust futures when the owning object is destroyed
+  void test_executor_cancelled_on_destroy()
+  {
+    auto obj = new cxx_qt::my_object::MyObject();
+    obj->executorSleepTest();
+    delete obj;
+    // The task would use the destroyed object if it was not cancelled
+    QTest::qWait(50);
+  }
+
+  // CXX-Qt passes panics in invokables to the installed hook instead of
+  // unwinding into C++
+  void test_panic_hook()
+  {
+    panic_hook_install();
+
+    cxx_qt::panic_object::PanicObject obj;
+    obj.panicInvokable();
+    QCOMPARE(panic_hook_last(),
+             ::rust::String("PanicObject::panic_invokable: invokable panicked"));
+  }
+
   // CXX-Qt types are exposed to C++ correctly
   void test_primitive_types()
   {
@@ -202,6 +312,29 @@ private Q_SLOTS:
   // Tests that we can build an empty QObject end to end
   void testEmpty() { Empty empty; }
 
+  void testQBoxDeletesUnparented()
+  {
+    QPointer<QObject> object = new QObject;
+    drop_qbox(object.data());
+    QVERIFY(object.isNull());
+  }
+
+  void testQBoxParentOwned()
+  {
+    auto parent = new QObject;
+    QPointer<QObject> object = new QObject;
+    QVERIFY(drop_qbox_with_parent(object.data(), *parent));
+    QVERIFY(!object.isNull());
+
+    delete parent;
+    QVERIFY(object.isNull());
+  }
+
+  void testQPointerTracksDeletion()
+  {
+    QVERIFY(qpointer_tracks_deletion(new QObject));
+  }
+
 #ifndef RUST_CXX_NO_EXCEPTION
   void testThrowException()
   {
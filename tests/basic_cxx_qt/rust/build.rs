@@ -12,5 +12,8 @@ fn main() {
         .file("src/lib.rs")
         .file("src/types.rs")
         .file("src/naming.rs")
+        .file("src/panic.rs")
+        .file("src/qbox.rs")
+        .file("src/qthread.rs")
         .build();
 }
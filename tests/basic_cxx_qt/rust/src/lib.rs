@@ -7,6 +7,9 @@
 mod data;
 mod empty;
 mod naming;
+mod panic;
+mod qbox;
+mod qthread;
 mod types;
 
 #[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
@@ -44,6 +47,30 @@ mod qobject {
         #[cxx_name = "queueTestMultiThread"]
         fn queue_test_multi_thread(self: Pin<&mut MyObject>);
 
+        #[cxx_name = "queueBlockingTest"]
+        fn queue_blocking_test(self: Pin<&mut MyObject>);
+
+        #[cxx_name = "queueBlockingWouldDeadlock"]
+        fn queue_blocking_would_deadlock(self: &MyObject) -> bool;
+
+        #[cxx_name = "queueCoalescedTest"]
+        fn queue_coalesced_test(self: Pin<&mut MyObject>);
+
+        #[cxx_name = "fetchPendingCount"]
+        fn fetch_pending_count(self: &MyObject) -> i32;
+
+        #[cxx_name = "queueThreadAffinityTest"]
+        fn queue_thread_affinity_test(self: Pin<&mut MyObject>);
+
+        #[cxx_name = "executorSleepTest"]
+        fn executor_sleep_test(self: Pin<&mut MyObject>);
+
+        #[cxx_name = "executorSignalTest"]
+        fn executor_signal_test(self: Pin<&mut MyObject>);
+
+        #[cxx_name = "executorNestedEventLoopTest"]
+        fn executor_nested_event_loop_test(self: Pin<&mut MyObject>);
+
         #[cxx_name = "fetchUpdateCallCount"]
         fn fetch_update_call_count(self: &MyObject) -> i32;
 
@@ -54,8 +81,9 @@ mod qobject {
 }
 
 use core::pin::Pin;
-use cxx_qt::{CxxQtType, Threading};
-use cxx_qt_lib::QString;
+use cxx_qt::{CxxQtType, Threading, Upcast};
+use cxx_qt_lib::{QObjectExt, QString, QThread};
+use std::future::Future;
 
 pub struct MyObjectRust {
     number: i32,
@@ -126,6 +154,113 @@ impl qobject::MyObject {
         );
     }
 
+    fn queue_blocking_test(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        std::thread::spawn(move || {
+            // Read the current number from the Qt thread and then write back the doubled value
+            let number = qt_thread
+                .queue_blocking(|qobject| *qobject.number())
+                .unwrap();
+            qt_thread
+                .queue(move |qobject| qobject.set_number(number * 2))
+                .unwrap();
+        });
+    }
+
+    fn queue_blocking_would_deadlock(&self) -> bool {
+        matches!(
+            self.qt_thread().queue_blocking(|_| {}),
+            Err(cxx_qt::ThreadingQueueError::WouldDeadlock)
+        )
+    }
+
+    fn queue_coalesced_test(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        let options = cxx_qt::QueueOptions::new().coalesce_key("number");
+        for number in 1..=3 {
+            qt_thread
+                .queue_with_options(&options, move |mut qobject| {
+                    qobject.as_mut().set_number(number);
+                    qobject.rust_mut().update_call_count += 1;
+                })
+                .unwrap();
+        }
+    }
+
+    fn fetch_pending_count(&self) -> i32 {
+        self.qt_thread().pending_count() as i32
+    }
+
+    fn queue_thread_affinity_test(self: Pin<&mut Self>) {
+        self.qt_thread()
+            .queue(|qobject| {
+                // The closure is executed in the thread that the object lives in
+                let in_object_thread = QThread::current_thread() == qobject.upcast().thread();
+                qobject.set_number(if in_object_thread { 1 } else { -1 });
+            })
+            .unwrap();
+    }
+
+    fn executor_sleep_test(self: Pin<&mut Self>) {
+        cxx_qt::executor::spawn_local_with_owner(&*self, |mut owner| async move {
+            cxx_qt::executor::sleep(std::time::Duration::from_millis(10)).await;
+            // The task is cancelled if the object is destroyed, so the object is alive after the await
+            owner.with_mut(|mut qobject| {
+                let number = *qobject.number();
+                qobject.as_mut().set_number(number * 2);
+            });
+        });
+    }
+
+    fn executor_signal_test(self: Pin<&mut Self>) {
+        cxx_qt::executor::spawn_local_with_owner(&*self, |mut owner| async move {
+            let number_changed = owner.with_mut(|mut qobject| {
+                let number_changed = cxx_qt::executor::SignalFuture::new(|sender| {
+                    qobject
+                        .as_mut()
+                        .on_number_changed(move |qobject| sender.send(*qobject.number()))
+                });
+                qobject.as_mut().set_string(QString::from("waiting"));
+                number_changed
+            });
+
+            let number = number_changed.await;
+            owner.with_mut(|qobject| {
+                qobject.set_string(QString::from(&format!("changed {number}")))
+            });
+        });
+    }
+
+    fn executor_nested_event_loop_test(self: Pin<&mut Self>) {
+        cxx_qt::executor::spawn_local_with_owner(&*self, |mut owner| async move {
+            // Start a timer which fires, and so wakes this task, within the nested event loop
+            let mut timer = Box::pin(cxx_qt::executor::sleep(std::time::Duration::from_millis(1)));
+            std::future::poll_fn(|cx| {
+                let _ = timer.as_mut().poll(cx);
+                std::task::Poll::Ready(())
+            })
+            .await;
+
+            // The C++ test spins a nested event loop when the string changes
+            owner.with_mut(|qobject| qobject.set_string(QString::from("nested")));
+
+            // Yield without registering the waker again, so the task only continues
+            // if the wake from the nested event loop was not lost
+            let mut yielded = false;
+            std::future::poll_fn(|_| {
+                if yielded {
+                    std::task::Poll::Ready(())
+                } else {
+                    yielded = true;
+                    std::task::Poll::Pending
+                }
+            })
+            .await;
+
+            owner.with_mut(|qobject| qobject.set_string(QString::from("done")));
+        });
+    }
+
     fn fetch_update_call_count(&self) -> i32 {
         self.update_call_count
     }
@@ -0,0 +1,52 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::sync::Mutex;
+
+use cxx_qt::panic::{set_policy, PanicPolicy};
+
+#[cxx_qt::bridge]
+mod ffi {
+    extern "RustQt" {
+        #[qobject]
+        #[namespace = "cxx_qt::panic_object"]
+        type PanicObject = super::PanicObjectRust;
+
+        #[qinvokable]
+        #[cxx_name = "panicInvokable"]
+        fn panic_invokable(self: &PanicObject);
+    }
+
+    extern "Rust" {
+        fn panic_hook_install();
+        fn panic_hook_last() -> String;
+    }
+}
+
+#[derive(Default)]
+pub struct PanicObjectRust;
+
+impl ffi::PanicObject {
+    fn panic_invokable(&self) {
+        panic!("invokable panicked");
+    }
+}
+
+static LAST_PANIC: Mutex<String> = Mutex::new(String::new());
+
+fn panic_hook_install() {
+    set_policy(PanicPolicy::Hook(Box::new(|context| {
+        *LAST_PANIC.lock().unwrap() = format!(
+            "{}::{}: {}",
+            context.type_name(),
+            context.method_name(),
+            context.message()
+        );
+    })));
+}
+
+fn panic_hook_last() -> String {
+    LAST_PANIC.lock().unwrap().clone()
+}
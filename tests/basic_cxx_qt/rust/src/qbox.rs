@@ -0,0 +1,47 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt::{QBox, QObject, QPointer};
+
+#[cxx::bridge]
+mod qbox_cxx {
+    unsafe extern "C++" {
+        include!(<QtCore/QObject>);
+        type QObject = cxx_qt::QObject;
+    }
+
+    extern "Rust" {
+        unsafe fn drop_qbox(object: *mut QObject);
+        unsafe fn drop_qbox_with_parent(object: *mut QObject, parent: Pin<&mut QObject>) -> bool;
+        unsafe fn qpointer_tracks_deletion(object: *mut QObject) -> bool;
+    }
+}
+
+/// # Safety
+///
+/// The object must be a valid pointer to a QObject which is not owned by anything else
+unsafe fn drop_qbox(object: *mut QObject) {
+    drop(QBox::from_raw(object));
+}
+
+/// # Safety
+///
+/// The object must be a valid pointer to a QObject which is not owned by anything else
+unsafe fn drop_qbox_with_parent(object: *mut QObject, parent: Pin<&mut QObject>) -> bool {
+    let mut qbox = QBox::from_raw(object);
+    qbox.set_parent(parent);
+    qbox.is_parent_owned() && !qbox.is_null()
+}
+
+/// # Safety
+///
+/// The object must be a valid pointer to a QObject which is not owned by anything else
+unsafe fn qpointer_tracks_deletion(object: *mut QObject) -> bool {
+    let pointer = QPointer::from_raw(object);
+    let alive = pointer.with_ref(|_| ()).is_some();
+    drop(QBox::from_raw(object));
+    alive && pointer.is_null() && pointer.with_ref(|_| ()).is_none()
+}
@@ -0,0 +1,44 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+use cxx_qt::QObject;
+use cxx_qt_lib::{QObjectExt, QThread};
+
+#[cxx::bridge]
+mod qthread_cxx {
+    unsafe extern "C++" {
+        include!(<QtCore/QObject>);
+        type QObject = cxx_qt::QObject;
+
+        include!("cxx-qt-lib/qthread.h");
+        type QThread = cxx_qt_lib::QThread;
+    }
+
+    extern "Rust" {
+        fn qthread_new_started() -> UniquePtr<QThread>;
+        unsafe fn qthread_move_object(object: Pin<&mut QObject>, thread: *mut QThread) -> bool;
+        fn qthread_quit_and_wait(thread: Pin<&mut QThread>) -> bool;
+    }
+}
+
+fn qthread_new_started() -> UniquePtr<QThread> {
+    let mut thread = QThread::new();
+    thread.pin_mut().start();
+    thread
+}
+
+/// # Safety
+///
+/// The thread must be a valid pointer to a QThread
+unsafe fn qthread_move_object(object: Pin<&mut QObject>, thread: *mut QThread) -> bool {
+    object.move_to_thread(thread)
+}
+
+fn qthread_quit_and_wait(mut thread: Pin<&mut QThread>) -> bool {
+    thread.as_mut().quit();
+    thread.wait_timeout(std::time::Duration::from_secs(5))
+}
@@ -24,26 +24,36 @@
 #include "qmap.h"
 #include "qmargins.h"
 #include "qmarginsf.h"
+#include "qmetaobject.h"
 #include "qmetaobjectconnection.h"
 #include "qmodelindex.h"
+#include "qobject.h"
 #include "qpen.h"
 #include "qpersistentmodelindex.h"
 #include "qpoint.h"
 #include "qpointf.h"
 #include "qpolygon.h"
 #include "qpolygonf.h"
+#include "qjsvalue.h"
 #include "qqmlapplicationengine.h"
+#include "qqmlcomponent.h"
 #include "qqmlengine.h"
+#include "qquickimageprovider.h"
+#include "qquickview.h"
 #include "qrect.h"
 #include "qrectf.h"
 #include "qregion.h"
 #include "qset.h"
+#include "qsettings.h"
+#include "qsgnode.h"
 #include "qsize.h"
 #include "qsizef.h"
 #include "qstring.h"
 #include "qstringlist.h"
 #include "qtime.h"
+#include "qtimer.h"
 #include "qtimezone.h"
+#include "qtranslator.h"
 #include "qurl.h"
 #include "qvariant.h"
 #include "qvector.h"
@@ -79,22 +89,32 @@ main(int argc, char* argv[])
   runTest(QScopedPointer<QObject>(new QMapTest));
   runTest(QScopedPointer<QObject>(new QMarginsTest));
   runTest(QScopedPointer<QObject>(new QMarginsFTest));
+  runTest(QScopedPointer<QObject>(new QMetaObjectTest));
   runTest(QScopedPointer<QObject>(new QMetaObjectConnectionTest));
   runTest(QScopedPointer<QObject>(new QModelIndexTest));
+  runTest(QScopedPointer<QObject>(new QObjectTest));
   runTest(QScopedPointer<QObject>(new QPersistentModelIndexTest));
   runTest(QScopedPointer<QObject>(new QPointTest));
   runTest(QScopedPointer<QObject>(new QPointFTest));
+  runTest(QScopedPointer<QObject>(new QJSValueTest));
   runTest(QScopedPointer<QObject>(new QQmlApplicationEngineTest));
+  runTest(QScopedPointer<QObject>(new QQmlComponentTest));
   runTest(QScopedPointer<QObject>(new QQmlEngineTest));
+  runTest(QScopedPointer<QObject>(new QQuickImageProviderTest));
+  runTest(QScopedPointer<QObject>(new QQuickViewTest));
   runTest(QScopedPointer<QObject>(new QRectTest));
   runTest(QScopedPointer<QObject>(new QRectFTest));
   runTest(QScopedPointer<QObject>(new QSetTest));
+  runTest(QScopedPointer<QObject>(new QSettingsTest));
+  runTest(QScopedPointer<QObject>(new QSGNodeTest));
   runTest(QScopedPointer<QObject>(new QSizeTest));
   runTest(QScopedPointer<QObject>(new QSizeFTest));
   runTest(QScopedPointer<QObject>(new QStringTest));
   runTest(QScopedPointer<QObject>(new QStringListTest));
   runTest(QScopedPointer<QObject>(new QTimeTest));
+  runTest(QScopedPointer<QObject>(new QTimerTest));
   runTest(QScopedPointer<QObject>(new QTimeZoneTest));
+  runTest(QScopedPointer<QObject>(new QTranslatorTest));
   runTest(QScopedPointer<QObject>(new QUrlTest));
   runTest(QScopedPointer<QObject>(new QVariantTest));
   runTest(QScopedPointer<QObject>(new QVectorTest));
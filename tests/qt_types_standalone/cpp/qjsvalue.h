@@ -0,0 +1,55 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtQml/QJSEngine>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qjsvalue.cxx.h"
+
+class QJSValueTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void call()
+  {
+    QJSEngine engine;
+    QVERIFY(call_qjsvalue(engine));
+  }
+
+  void inspect()
+  {
+    QJSEngine engine;
+    QVERIFY(inspect_qjsvalue(engine));
+  }
+
+  void serde()
+  {
+    QJSEngine engine;
+    QVERIFY(serde_qjsvalue(engine));
+  }
+
+  void serdeNested()
+  {
+    QJSEngine engine;
+    QVERIFY(serde_nested_qjsvalue(engine));
+  }
+
+  void serdeOptional()
+  {
+    QJSEngine engine;
+    QVERIFY(serde_optional_qjsvalue(engine));
+  }
+
+  void serdeNumbers()
+  {
+    QJSEngine engine;
+    QVERIFY(serde_numbers_qjsvalue(engine));
+  }
+};
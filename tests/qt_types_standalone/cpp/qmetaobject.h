@@ -0,0 +1,39 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QTimer>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qmetaobject.cxx.h"
+
+class QMetaObjectTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void introspect()
+  {
+    QTimer timer;
+    QVERIFY(introspect_qmetaobject(timer));
+  }
+
+  void invokeMethod()
+  {
+    QTimer timer;
+    QVERIFY(invoke_qmetaobject_method(timer));
+    QVERIFY(timer.isActive());
+    QCOMPARE(timer.interval(), 100);
+  }
+
+  void writeProperty()
+  {
+    QTimer timer;
+    QVERIFY(write_qmetaproperty(timer));
+    QCOMPARE(timer.interval(), 50);
+  }
+};
@@ -0,0 +1,62 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QObject>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qobject.cxx.h"
+
+class QObjectTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void connectDynamic()
+  {
+    QObject obj;
+    QVERIFY(connect_qobject_dynamic(obj));
+  }
+
+  void disconnectDynamic()
+  {
+    QObject obj;
+    QVERIFY(disconnect_qobject_dynamic(obj));
+
+    // The receiver of the signal and the closure are deleted later
+    QCoreApplication::sendPostedEvents(nullptr, QEvent::DeferredDelete);
+    QVERIFY(dynamic_closure_dropped());
+  }
+
+  void findChildren()
+  {
+    QObject parent;
+    auto child = new QObject(&parent);
+    child->setObjectName(QStringLiteral("child"));
+    auto grandchild = new QObject(child);
+    grandchild->setObjectName(QStringLiteral("grandchild"));
+
+    QVERIFY(find_qobject_children(parent));
+  }
+
+  void objectName()
+  {
+    QObject obj;
+    set_qobject_object_name(obj);
+    QCOMPARE(obj.objectName(), QStringLiteral("kdab"));
+  }
+
+  void property()
+  {
+    QObject obj;
+    // A dynamic property is not defined with Q_PROPERTY so false is returned
+    QVERIFY(!set_qobject_property(obj));
+    QCOMPARE(obj.property("kdab").toInt(), 42);
+    QVERIFY(read_qobject_property(obj));
+  }
+};
@@ -6,6 +6,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #pragma once
 
+#include <QtCore/QByteArray>
 #include <QtCore/QCoreApplication>
 #include <QtQml/QQmlApplicationEngine>
 #include <QtTest/QTest>
@@ -44,4 +45,66 @@ private Q_SLOTS:
     engine.setBaseUrl(QUrl(QStringLiteral("qrc:/kdab.qml")));
     QVERIFY(read_qqmlapplicationengine(engine));
   }
+
+  void properties()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QObject object;
+    object.setObjectName(QStringLiteral("kdab"));
+
+    QQmlApplicationEngine engine;
+    set_qqmlapplicationengine_properties(engine, object);
+    QCOMPARE(engine.rootContext()->contextProperty(QStringLiteral("kdabValue"))
+               .toInt(),
+             42);
+
+    engine.loadData(QByteArrayLiteral("import QtQml 2.15\n"
+                                      "QtObject {\n"
+                                      "  property int value: kdabValue\n"
+                                      "  property string name: "
+                                      "kdabObject.objectName\n"
+                                      "  property int initial: 0\n"
+                                      "}\n"));
+    QCOMPARE(engine.rootObjects().size(), 1);
+    const auto root = engine.rootObjects().first();
+    QCOMPARE(root->property("value").toInt(), 42);
+    QCOMPARE(root->property("name").toString(), QStringLiteral("kdab"));
+    QCOMPARE(root->property("initial").toInt(), 7);
+  }
+
+  void rootObjects()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QQmlApplicationEngine engine;
+    connect_qqmlapplicationengine_signals(engine);
+    engine.loadData(QByteArrayLiteral("import QtQml 2.15\n"
+                                      "QtObject {\n"
+                                      "  objectName: \"root\"\n"
+                                      "  property int value: 1\n"
+                                      "  property QtObject child: QtObject {\n"
+                                      "    objectName: \"child\"\n"
+                                      "  }\n"
+                                      "  property int broken: undefinedName\n"
+                                      "  function greet(name) {\n"
+                                      "    return \"hello \" + name;\n"
+                                      "  }\n"
+                                      "}\n"));
+    QCOMPARE(qqmlapplicationengine_objects_created(), 1);
+    QTRY_VERIFY(qqmlapplicationengine_warnings() > 0);
+
+    QVERIFY(read_qqmlapplicationengine_root_objects(engine));
+    QCOMPARE(engine.rootObjects().first()->property("value").toInt(), 2);
+  }
 };
@@ -0,0 +1,87 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QGuiApplication>
+#include <QtQml/QQmlEngine>
+#include <QtQuick/QQuickImageProvider>
+#include <QtTest/QSignalSpy>
+#include <QtTest/QTest>
+
+#include <memory>
+
+#include "qt_types_standalone/src/qquickimageprovider.cxx.h"
+
+class QQuickImageProviderTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void requestImage()
+  {
+    // QQuickImageProvider requires a QGuiApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    add_qquickimageproviders(engine);
+
+    auto* provider =
+      dynamic_cast<QQuickImageProvider*>(engine.imageProvider("colors"));
+    QVERIFY(provider != nullptr);
+    QCOMPARE(provider->imageType(), QQmlImageProviderBase::Image);
+
+    QSize size;
+    const auto image =
+      provider->requestImage(QStringLiteral("red"), &size, QSize(3, 5));
+    QCOMPARE(size, QSize(3, 5));
+    QCOMPARE(image.size(), QSize(3, 5));
+    QCOMPARE(image.pixelColor(1, 1), QColor(255, 0, 0));
+
+    const auto unknown =
+      provider->requestImage(QStringLiteral("blue"), &size, QSize());
+    QVERIFY(unknown.isNull());
+  }
+
+  void requestImageResponse()
+  {
+    // QQuickImageProvider requires a QGuiApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    add_qquickimageproviders(engine);
+
+    auto* provider = dynamic_cast<QQuickAsyncImageProvider*>(
+      engine.imageProvider("async_colors"));
+    QVERIFY(provider != nullptr);
+
+    std::unique_ptr<QQuickImageResponse> response(
+      provider->requestImageResponse(QStringLiteral("green"), QSize(2, 2)));
+    QVERIFY(response != nullptr);
+    QSignalSpy finished(response.get(), &QQuickImageResponse::finished);
+    QVERIFY(finished.wait());
+    QVERIFY(response->errorString().isEmpty());
+
+    std::unique_ptr<QQuickTextureFactory> factory(response->textureFactory());
+    QVERIFY(factory != nullptr);
+    QCOMPARE(factory->image().size(), QSize(2, 2));
+    QCOMPARE(factory->image().pixelColor(0, 0), QColor(0, 255, 0));
+
+    std::unique_ptr<QQuickImageResponse> error(
+      provider->requestImageResponse(QStringLiteral("blue"), QSize(2, 2)));
+    QSignalSpy errorFinished(error.get(), &QQuickImageResponse::finished);
+    QVERIFY(errorFinished.wait());
+    QCOMPARE(error->errorString(), QStringLiteral("Unknown color"));
+  }
+};
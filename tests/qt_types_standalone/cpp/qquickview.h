@@ -0,0 +1,55 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QGuiApplication>
+#include <QtQuick/QQuickView>
+#include <QtTest/QTest>
+
+#include <memory>
+
+#include "qt_types_standalone/src/qquickview.cxx.h"
+
+class QQuickViewTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    // QQuickView requires a QGuiApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    const auto view = construct_qquickview();
+    QVERIFY(view != nullptr);
+    QCOMPARE(view->resizeMode(), QQuickView::SizeRootObjectToView);
+    QCOMPARE(view->status(), QQuickView::Null);
+    QCOMPARE(view->title(), QStringLiteral("cxx-qt"));
+    QCOMPARE(view->color(), QColor(0, 0, 255));
+    QCOMPARE(view->size(), QSize(200, 100));
+    QVERIFY(view->flags().testFlag(Qt::FramelessWindowHint));
+  }
+
+  void read()
+  {
+    // QQuickWindow requires a QGuiApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QGuiApplication app(argc, args.data());
+
+    QQuickWindow window;
+    QVERIFY(!read_qquickwindow_frameless(window));
+    window.setFlag(Qt::FramelessWindowHint, true);
+    QVERIFY(read_qquickwindow_frameless(window));
+  }
+};
@@ -0,0 +1,81 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QSettings>
+#include <QtCore/QTemporaryDir>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qsettings.cxx.h"
+
+class QSettingsTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    const auto fileName = dir.filePath(QStringLiteral("settings.ini"));
+
+    const auto settings = construct_qsettings_ini(fileName);
+    QVERIFY(settings != nullptr);
+    QCOMPARE(settings->format(), QSettings::IniFormat);
+    QCOMPARE(settings->fileName(), fileName);
+    QCOMPARE(settings->status(), QSettings::NoError);
+  }
+
+  void readWrite()
+  {
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    const auto fileName = dir.filePath(QStringLiteral("settings.ini"));
+
+    auto settings = construct_qsettings_ini(fileName);
+    write_qsettings(*settings);
+    settings->sync();
+    QCOMPARE(settings->status(), QSettings::NoError);
+
+    // The values written from Rust are visible in the INI file
+    QSettings cppSettings(fileName, QSettings::IniFormat);
+    QCOMPARE(cppSettings.value(QStringLiteral("name")).toString(),
+             QStringLiteral("cxx-qt"));
+    QCOMPARE(cppSettings.value(QStringLiteral("window/width")).toInt(), 800);
+    QCOMPARE(cppSettings.value(QStringLiteral("files/size")).toInt(), 2);
+    QCOMPARE(cppSettings.value(QStringLiteral("files/2/path")).toString(),
+             QStringLiteral("b.txt"));
+
+    // Values read back from the INI file are strings which are converted
+    settings = construct_qsettings_ini(fileName);
+    QVERIFY(read_qsettings(*settings));
+  }
+
+  void serde()
+  {
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    const auto fileName = dir.filePath(QStringLiteral("settings.ini"));
+
+    auto settings = construct_qsettings_ini(fileName);
+    QVERIFY(serde_qsettings(*settings));
+    settings->sync();
+    QCOMPARE(settings->status(), QSettings::NoError);
+
+    QSettings cppSettings(fileName, QSettings::IniFormat);
+    QCOMPARE(cppSettings.value(QStringLiteral("preferences/theme")).toString(),
+             QStringLiteral("Dark"));
+    QCOMPARE(
+      cppSettings.value(QStringLiteral("preferences/window/height")).toInt(),
+      600);
+    QCOMPARE(
+      cppSettings.value(QStringLiteral("preferences/recent_files/1/value"))
+        .toString(),
+      QStringLiteral("a.txt"));
+    QVERIFY(!cppSettings.contains(QStringLiteral("preferences/theme/Custom")));
+  }
+};
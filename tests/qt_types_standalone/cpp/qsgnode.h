@@ -0,0 +1,60 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtQuick/QSGFlatColorMaterial>
+#include <QtQuick/QSGGeometryNode>
+#include <QtQuick/QSGNode>
+#include <QtQuick/QSGSimpleRectNode>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qsgnode.cxx.h"
+
+class QSGNodeTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto root = construct_qsgnode_tree();
+    QVERIFY(root != nullptr);
+    QCOMPARE(root->childCount(), 2);
+
+    const auto* rect = dynamic_cast<QSGSimpleRectNode*>(root->firstChild());
+    QVERIFY(rect != nullptr);
+    QCOMPARE(rect->rect(), QRectF(1.0, 2.0, 3.0, 4.0));
+    QCOMPARE(rect->color(), QColor(255, 0, 0));
+
+    auto* line = dynamic_cast<QSGGeometryNode*>(root->lastChild());
+    QVERIFY(line != nullptr);
+    QVERIFY(line->flags().testFlag(QSGNode::OwnedByParent));
+    QVERIFY(line->flags().testFlag(QSGNode::OwnsGeometry));
+    QVERIFY(line->flags().testFlag(QSGNode::OwnsMaterial));
+    QCOMPARE(line->geometry()->vertexCount(), 3);
+    QCOMPARE(line->geometry()->vertexDataAsPoint2D()[1].x, 10.0f);
+    QCOMPARE(line->geometry()->vertexDataAsPoint2D()[1].y, 20.0f);
+
+    const auto* material =
+      dynamic_cast<QSGFlatColorMaterial*>(line->material());
+    QVERIFY(material != nullptr);
+    QCOMPARE(material->color(), QColor(0, 0, 255));
+  }
+
+  void deleteChildren()
+  {
+    const auto root = construct_qsgnode_tree();
+    delete_qsgnode_children(*root);
+    QCOMPARE(root->childCount(), 0);
+  }
+
+  void read()
+  {
+    const auto root = construct_qsgnode_tree();
+    QVERIFY(read_qsgnode_tree(*root));
+  }
+};
@@ -0,0 +1,67 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QObject>
+#include <QtCore/QTimer>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qtimer.cxx.h"
+
+class QTimerTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto timer = construct_qtimer();
+    QVERIFY(timer != nullptr);
+    QCOMPARE(timer->interval(), 10);
+    QVERIFY(timer->isSingleShot());
+    QCOMPARE(timer->timerType(), Qt::PreciseTimer);
+    QVERIFY(!timer->isActive());
+  }
+
+  void parent()
+  {
+    QObject parent;
+    QVERIFY(qtimer_parented(parent));
+    QVERIFY(parent.findChild<QTimer*>() != nullptr);
+  }
+
+  void timeout()
+  {
+    // Timers require a QCoreApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    const auto timer = construct_qtimer();
+    timer->setSingleShot(false);
+    connect_qtimer_timeout(*timer);
+    timer->start();
+    QTRY_COMPARE(qtimer_timeout_count(), 1);
+    QVERIFY(!timer->isActive());
+  }
+
+  void singleShot()
+  {
+    // Timers require a QCoreApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QVERIFY(qtimer_single_shot());
+    QTRY_VERIFY(qtimer_single_shot_called());
+  }
+};
@@ -0,0 +1,67 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QTranslator>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qtranslator.cxx.h"
+
+// Translates the strings of the qtranslator Rust module, which is the context used by qs_tr!
+class GermanTranslator : public QTranslator
+{
+public:
+  bool isEmpty() const override { return false; }
+
+  QString translate(const char* context,
+                    const char* sourceText,
+                    const char* disambiguation = nullptr,
+                    int n = -1) const override
+  {
+    Q_UNUSED(disambiguation);
+    Q_UNUSED(n);
+
+    if (qstrcmp(context, "qt_types_standalone::qtranslator") == 0 &&
+        qstrcmp(sourceText, "Hello") == 0) {
+      return QStringLiteral("Hallo");
+    }
+    return QString();
+  }
+};
+
+class QTranslatorTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto translator = construct_qtranslator();
+    QVERIFY(translator != nullptr);
+    QVERIFY(translator->isEmpty());
+    QVERIFY(translator->filePath().isEmpty());
+  }
+
+  void translate()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    // Without a translator the source text is used
+    QCOMPARE(translate_qs_tr(), QStringLiteral("Hello"));
+    QCOMPARE(translate_qs_tr_plural(3), QStringLiteral("3 file(s)"));
+
+    GermanTranslator translator;
+    QVERIFY(install_qtranslator(app, translator));
+    QCOMPARE(translate_qs_tr(), QStringLiteral("Hallo"));
+    QCOMPARE(translate_qs_tr_plural(1), QStringLiteral("1 file(s)"));
+  }
+};
@@ -23,26 +23,36 @@ fn main() {
         .file("src/qmap.rs")
         .file("src/qmargins.rs")
         .file("src/qmarginsf.rs")
+        .file("src/qmetaobject.rs")
         .file("src/qmetaobjectconnection.rs")
         .file("src/qmodelindex.rs")
+        .file("src/qobject.rs")
         .file("src/qpen.rs")
         .file("src/qpersistentmodelindex.rs")
         .file("src/qpoint.rs")
         .file("src/qpointf.rs")
         .file("src/qpolygon.rs")
         .file("src/qpolygonf.rs")
+        .file("src/qjsvalue.rs")
         .file("src/qqmlapplicationengine.rs")
+        .file("src/qqmlcomponent.rs")
         .file("src/qqmlengine.rs")
+        .file("src/qquickimageprovider.rs")
+        .file("src/qquickview.rs")
         .file("src/qrect.rs")
         .file("src/qrectf.rs")
         .file("src/qregion.rs")
         .file("src/qset.rs")
+        .file("src/qsettings.rs")
+        .file("src/qsgnode.rs")
         .file("src/qsize.rs")
         .file("src/qsizef.rs")
         .file("src/qstring.rs")
         .file("src/qstringlist.rs")
         .file("src/qtime.rs")
+        .file("src/qtimer.rs")
         .file("src/qtimezone.rs")
+        .file("src/qtranslator.rs")
         .file("src/qurl.rs")
         .file("src/qvariant.rs")
         .file("src/qvector.rs")
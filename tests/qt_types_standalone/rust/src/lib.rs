@@ -20,26 +20,36 @@ mod qlist;
 mod qmap;
 mod qmargins;
 mod qmarginsf;
+mod qmetaobject;
 mod qmetaobjectconnection;
 mod qmodelindex;
+mod qobject;
 mod qpen;
 mod qpersistentmodelindex;
 mod qpoint;
 mod qpointf;
 mod qpolygon;
 mod qpolygonf;
+mod qjsvalue;
 mod qqmlapplicationengine;
+mod qqmlcomponent;
 mod qqmlengine;
+mod qquickimageprovider;
+mod qquickview;
 mod qrect;
 mod qrectf;
 mod qregion;
 mod qset;
+mod qsettings;
+mod qsgnode;
 mod qsize;
 mod qsizef;
 mod qstring;
 mod qstringlist;
 mod qtime;
+mod qtimer;
 mod qtimezone;
+mod qtranslator;
 mod qurl;
 mod qvariant;
 mod qvector;
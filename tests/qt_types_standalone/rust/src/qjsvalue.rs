@@ -0,0 +1,244 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QJSEngine, QJSValue, QString};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[cxx::bridge]
+mod qjsvalue_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qjsengine.h");
+        type QJSEngine = cxx_qt_lib::QJSEngine;
+    }
+
+    extern "Rust" {
+        fn call_qjsvalue(engine: Pin<&mut QJSEngine>) -> bool;
+        fn inspect_qjsvalue(engine: Pin<&mut QJSEngine>) -> bool;
+        fn serde_qjsvalue(engine: Pin<&mut QJSEngine>) -> bool;
+        fn serde_nested_qjsvalue(engine: Pin<&mut QJSEngine>) -> bool;
+        fn serde_optional_qjsvalue(engine: Pin<&mut QJSEngine>) -> bool;
+        fn serde_numbers_qjsvalue(engine: Pin<&mut QJSEngine>) -> bool;
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+enum Kind {
+    Unit,
+    Newtype(String),
+    Tuple(i32, i32),
+    Struct { x: i32 },
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Data {
+    name: String,
+    values: Vec<u32>,
+    ratio: f64,
+    kinds: Vec<Kind>,
+    optional: Option<i64>,
+}
+
+type Nested = BTreeMap<String, Vec<BTreeMap<String, Vec<Vec<i32>>>>>;
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Optional {
+    value: Option<i32>,
+}
+
+fn evaluate(engine: Pin<&mut QJSEngine>, program: &str) -> cxx::UniquePtr<QJSValue> {
+    engine.evaluate(&QString::from(program), &QString::from("test.js"), 1)
+}
+
+fn call_qjsvalue(engine: Pin<&mut QJSEngine>) -> bool {
+    let function = evaluate(engine, "(function(a, b) { return a + b; })");
+    if !function.is_callable() {
+        return false;
+    }
+
+    let result = function.call(&[&QJSValue::new_i32(2), &QJSValue::new_i32(3)]);
+    let concatenated = function.call(&[
+        &QJSValue::new_qstring(&QString::from("kd")),
+        &QJSValue::new_qstring(&QString::from("ab")),
+    ]);
+    result.is_number() && result.to_int() == 5 && concatenated.to_qstring() == QString::from("kdab")
+}
+
+fn inspect_qjsvalue(engine: Pin<&mut QJSEngine>) -> bool {
+    let mut object = evaluate(
+        engine,
+        "({ name: 'kdab', values: [1, 2, 3], nested: { flag: true }, nothing: null })",
+    );
+    if !object.is_object() || object.is_array() {
+        return false;
+    }
+
+    let values = object.property(&QString::from("values"));
+    let sum: i32 = values.array_iter().map(|value| value.to_int()).sum();
+    let nested = object.property(&QString::from("nested"));
+
+    object
+        .pin_mut()
+        .set_property(&QString::from("added"), &QJSValue::new_bool(true));
+    let deleted = object.pin_mut().delete_property(&QString::from("name"));
+
+    values.is_array()
+        && values.array_len() == 3
+        && sum == 6
+        && nested.property(&QString::from("flag")).to_bool()
+        && object.property(&QString::from("nothing")).is_null()
+        && object.property(&QString::from("missing")).is_undefined()
+        && object.has_property(&QString::from("added"))
+        && deleted
+        && !object.has_own_property(&QString::from("name"))
+}
+
+fn serde_qjsvalue(mut engine: Pin<&mut QJSEngine>) -> bool {
+    let data = Data {
+        name: "kdab".to_owned(),
+        values: vec![1, 2, 3],
+        ratio: 0.5,
+        kinds: vec![
+            Kind::Unit,
+            Kind::Newtype("value".to_owned()),
+            Kind::Tuple(1, 2),
+            Kind::Struct { x: 3 },
+        ],
+        optional: None,
+    };
+
+    let Ok(value) = engine.as_mut().serialize(&data) else {
+        return false;
+    };
+    if value.property(&QString::from("values")).array_len() != 3
+        || value
+            .property(&QString::from("kinds"))
+            .property_at(0)
+            .to_qstring()
+            != QString::from("Unit")
+    {
+        return false;
+    }
+
+    // Values created from JavaScript can be converted into Rust types
+    let from_js = evaluate(
+        engine.as_mut(),
+        "({ name: 'qt', values: [4], ratio: 1.5, kinds: [{ Struct: { x: 7 } }], optional: 42 })",
+    )
+    .deserialize::<Data>();
+    let invalid = evaluate(engine, "({ name: 1 })").deserialize::<Data>();
+
+    value.deserialize::<Data>() == Ok(data)
+        && from_js
+            == Ok(Data {
+                name: "qt".to_owned(),
+                values: vec![4],
+                ratio: 1.5,
+                kinds: vec![Kind::Struct { x: 7 }],
+                optional: Some(42),
+            })
+        && invalid.is_err()
+}
+
+fn serde_nested_qjsvalue(mut engine: Pin<&mut QJSEngine>) -> bool {
+    let mut nested = Nested::new();
+    nested.insert(
+        "rows".to_owned(),
+        vec![
+            BTreeMap::from([("a".to_owned(), vec![vec![1, 2], vec![]])]),
+            BTreeMap::new(),
+        ],
+    );
+    nested.insert("empty".to_owned(), vec![]);
+
+    let Ok(value) = engine.as_mut().serialize(&nested) else {
+        return false;
+    };
+    let rows = value.property(&QString::from("rows"));
+    if !rows.is_array()
+        || rows.array_len() != 2
+        || !rows.property_at(0).property(&QString::from("a")).is_array()
+        || !value.property(&QString::from("empty")).is_array()
+    {
+        return false;
+    }
+
+    let from_js =
+        evaluate(engine, "({ rows: [{ a: [[3], [4, 5]] }], empty: [] })").deserialize::<Nested>();
+
+    value.deserialize::<Nested>() == Ok(nested)
+        && from_js
+            == Ok(BTreeMap::from([
+                (
+                    "rows".to_owned(),
+                    vec![BTreeMap::from([(
+                        "a".to_owned(),
+                        vec![vec![3], vec![4, 5]],
+                    )])],
+                ),
+                ("empty".to_owned(), vec![]),
+            ]))
+}
+
+fn serde_optional_qjsvalue(mut engine: Pin<&mut QJSEngine>) -> bool {
+    // None is serialized as null, which is distinct from undefined
+    let Ok(none) = engine.as_mut().serialize(&Optional { value: None }) else {
+        return false;
+    };
+    let Ok(some) = engine.as_mut().serialize(&Optional { value: Some(1) }) else {
+        return false;
+    };
+    let none_value = none.property(&QString::from("value"));
+    if !none_value.is_null() || none_value.is_undefined() {
+        return false;
+    }
+
+    // null, undefined and a missing property all deserialize into None
+    let null = evaluate(engine.as_mut(), "({ value: null })").deserialize::<Optional>();
+    let undefined = evaluate(engine.as_mut(), "({ value: undefined })").deserialize::<Optional>();
+    let missing = evaluate(engine.as_mut(), "({})").deserialize::<Optional>();
+    let unit = evaluate(engine, "undefined").deserialize::<()>();
+
+    none.deserialize::<Optional>() == Ok(Optional { value: None })
+        && some.deserialize::<Optional>() == Ok(Optional { value: Some(1) })
+        && null == Ok(Optional { value: None })
+        && undefined == Ok(Optional { value: None })
+        && missing == Ok(Optional { value: None })
+        && unit == Ok(())
+}
+
+fn serde_numbers_qjsvalue(mut engine: Pin<&mut QJSEngine>) -> bool {
+    // Integers beyond i32 are stored as doubles, which are exact up to 2^53
+    let large = 1_i64 << 40;
+    let Ok(large_value) = engine.as_mut().serialize(&large) else {
+        return false;
+    };
+    let Ok(negative_value) = engine.as_mut().serialize(&-large) else {
+        return false;
+    };
+    let Ok(nan_value) = engine.as_mut().serialize(&f64::NAN) else {
+        return false;
+    };
+
+    // Numbers which do not fit into the integer type are an error
+    let overflow_u8 = evaluate(engine.as_mut(), "300").deserialize::<u8>();
+    let overflow_i32 = evaluate(engine.as_mut(), "Math.pow(2, 40)").deserialize::<i32>();
+    let negative_u32 = evaluate(engine.as_mut(), "-1").deserialize::<u32>();
+    let fraction_i32 = evaluate(engine.as_mut(), "1.5").deserialize::<i32>();
+    let overflow_i64 = evaluate(engine.as_mut(), "1e300").deserialize::<i64>();
+    let nan_i32 = evaluate(engine, "NaN").deserialize::<i32>();
+
+    large_value.deserialize::<i64>() == Ok(large)
+        && negative_value.deserialize::<i64>() == Ok(-large)
+        && nan_value.is_number()
+        && nan_value.deserialize::<f64>().is_ok_and(f64::is_nan)
+        && overflow_u8.is_err()
+        && overflow_i32.is_err()
+        && negative_u32.is_err()
+        && fraction_i32.is_err()
+        && overflow_i64.is_err()
+        && nan_i32.is_err()
+}
@@ -0,0 +1,65 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{
+    ConnectionType, QByteArray, QList, QMetaMethodType, QMetaObject, QObject, QObjectExt, QString,
+    QVariant,
+};
+
+#[cxx::bridge]
+mod qmetaobject_cxx {
+    unsafe extern "C++" {
+        include!(<QtCore/QObject>);
+        type QObject = cxx_qt_lib::QObject;
+    }
+
+    extern "Rust" {
+        fn introspect_qmetaobject(obj: &QObject) -> bool;
+        fn invoke_qmetaobject_method(obj: Pin<&mut QObject>) -> bool;
+        fn write_qmetaproperty(obj: Pin<&mut QObject>) -> bool;
+    }
+}
+
+fn introspect_qmetaobject(obj: &QObject) -> bool {
+    let meta_object = obj.meta_object();
+    let property_index = meta_object.index_of_property("objectName");
+    let has_destroyed = meta_object.methods().any(|method| {
+        method.method_type() == QMetaMethodType::Signal
+            && method.method_signature() == QByteArray::from("destroyed(QObject*)")
+    });
+
+    meta_object.class_name() == QString::from("QTimer")
+        && property_index >= 0
+        && meta_object.property(property_index).name() == QByteArray::from("objectName")
+        && meta_object.index_of_slot("start(int)") >= 0
+        && has_destroyed
+}
+
+fn invoke_qmetaobject_method(mut obj: Pin<&mut QObject>) -> bool {
+    let mut args = QList::<QVariant>::default();
+    args.append(QVariant::from(&50));
+    // A signature is normalized before it is looked up
+    let by_signature = QMetaObject::invoke_method(
+        obj.as_mut(),
+        "start( int )",
+        ConnectionType::DirectConnection,
+        &args,
+    )
+    .is_some();
+
+    args.clear();
+    args.append(QVariant::from(&100));
+    by_signature
+        && QMetaObject::invoke_method(obj, "start", ConnectionType::DirectConnection, &args)
+            .is_some()
+}
+
+fn write_qmetaproperty(mut obj: Pin<&mut QObject>) -> bool {
+    let meta_object = obj.meta_object();
+    let property = meta_object.property(meta_object.index_of_property("interval"));
+    property.write(obj.as_mut(), &QVariant::from(&50))
+        && property.read(&obj).value::<i32>() == Some(50)
+}
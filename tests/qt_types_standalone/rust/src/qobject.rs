@@ -0,0 +1,97 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{ConnectionType, FindChildOption, QObject, QObjectExt, QString, QVariant};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+#[cxx::bridge]
+mod qobject_cxx {
+    unsafe extern "C++" {
+        include!(<QtCore/QObject>);
+        type QObject = cxx_qt_lib::QObject;
+    }
+
+    extern "Rust" {
+        fn connect_qobject_dynamic(obj: Pin<&mut QObject>) -> bool;
+        fn disconnect_qobject_dynamic(obj: Pin<&mut QObject>) -> bool;
+        fn dynamic_closure_dropped() -> bool;
+        fn find_qobject_children(obj: &QObject) -> bool;
+        fn read_qobject_property(obj: &QObject) -> bool;
+        fn set_qobject_object_name(obj: Pin<&mut QObject>);
+        fn set_qobject_property(obj: Pin<&mut QObject>) -> bool;
+    }
+}
+
+fn connect_qobject_dynamic(mut obj: Pin<&mut QObject>) -> bool {
+    if obj.as_mut().on_dynamic("unknownSignal()", |_| {}).is_some() {
+        return false;
+    }
+
+    let called = Arc::new(AtomicBool::new(false));
+    let called_closure = called.clone();
+    let connection = obj.as_mut().connect_dynamic(
+        "objectNameChanged(QString)",
+        move |args| {
+            let name = args.first().and_then(|arg| arg.value::<QString>());
+            called_closure.store(name == Some(QString::from("kdab")), Ordering::SeqCst);
+        },
+        ConnectionType::DirectConnection,
+    );
+
+    obj.set_object_name(&QString::from("kdab"));
+    connection.is_some() && called.load(Ordering::SeqCst)
+}
+
+static DYNAMIC_CLOSURE_DROPPED: AtomicBool = AtomicBool::new(false);
+
+struct DropFlag;
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        DYNAMIC_CLOSURE_DROPPED.store(true, Ordering::SeqCst);
+    }
+}
+
+fn disconnect_qobject_dynamic(mut obj: Pin<&mut QObject>) -> bool {
+    let flag = DropFlag;
+    let connection = obj
+        .as_mut()
+        .on_dynamic("objectNameChanged(QString)", move |_| {
+            let _ = &flag;
+        });
+    connection.is_some_and(|connection| connection.disconnect())
+        && !DYNAMIC_CLOSURE_DROPPED.load(Ordering::SeqCst)
+}
+
+fn dynamic_closure_dropped() -> bool {
+    DYNAMIC_CLOSURE_DROPPED.load(Ordering::SeqCst)
+}
+
+fn find_qobject_children(obj: &QObject) -> bool {
+    let grandchild = QString::from("grandchild");
+    let direct = obj.find_child::<QObject>(&grandchild, FindChildOption::FindDirectChildrenOnly);
+    let recursive =
+        obj.find_child::<QObject>(&grandchild, FindChildOption::FindChildrenRecursively);
+    let all = obj.find_children::<QObject>(
+        &QString::default(),
+        FindChildOption::FindChildrenRecursively,
+    );
+
+    direct.is_null() && !recursive.is_null() && all.len() == 2 && obj.children().len() == 1
+}
+
+fn read_qobject_property(obj: &QObject) -> bool {
+    obj.property("kdab").value::<i32>() == Some(42)
+}
+
+fn set_qobject_object_name(obj: Pin<&mut QObject>) {
+    obj.set_object_name(&QString::from("kdab"));
+}
+
+fn set_qobject_property(obj: Pin<&mut QObject>) -> bool {
+    obj.set_property("kdab", &QVariant::from(&42))
+}
@@ -3,21 +3,63 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use cxx_qt_lib::{QQmlApplicationEngine, QUrl};
+use core::pin::Pin;
+use cxx_qt::Upcast;
+use cxx_qt_lib::{
+    ConnectionType, QList, QMap, QMapPair_QString_QVariant, QMetaObject, QObject, QObjectExt,
+    QQmlApplicationEngine, QQmlEngine, QString, QUrl, QVariant,
+};
+use std::sync::atomic::{AtomicI32, Ordering};
 
 #[cxx::bridge]
 mod qqmlapplicationengine_cxx {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qqmlapplicationengine.h");
         type QQmlApplicationEngine = cxx_qt_lib::QQmlApplicationEngine;
+        include!(<QtCore/QObject>);
+        type QObject = cxx_qt_lib::QObject;
     }
 
     extern "Rust" {
+        fn connect_qqmlapplicationengine_signals(engine: Pin<&mut QQmlApplicationEngine>);
         fn construct_qqmlapplicationengine() -> UniquePtr<QQmlApplicationEngine>;
+        fn qqmlapplicationengine_objects_created() -> i32;
+        fn qqmlapplicationengine_warnings() -> i32;
+        fn read_qqmlapplicationengine_root_objects(engine: Pin<&mut QQmlApplicationEngine>)
+            -> bool;
         fn read_qqmlapplicationengine(c: &QQmlApplicationEngine) -> bool;
+        fn set_qqmlapplicationengine_properties(
+            engine: Pin<&mut QQmlApplicationEngine>,
+            object: Pin<&mut QObject>,
+        );
     }
 }
 
+static OBJECTS_CREATED: AtomicI32 = AtomicI32::new(0);
+static WARNINGS: AtomicI32 = AtomicI32::new(0);
+
+fn connect_qqmlapplicationengine_signals(mut engine: Pin<&mut QQmlApplicationEngine>) {
+    engine
+        .as_mut()
+        .on_object_created(|_, object, _| {
+            if !object.is_null() {
+                OBJECTS_CREATED.fetch_add(1, Ordering::SeqCst);
+            }
+        })
+        .release();
+
+    let engine: Pin<&mut QQmlEngine> = engine.upcast_pin();
+    engine
+        .connect_warnings(
+            |_, warnings| {
+                let warnings = warnings.len() as i32;
+                WARNINGS.fetch_add(warnings, Ordering::SeqCst);
+            },
+            ConnectionType::DirectConnection,
+        )
+        .release();
+}
+
 fn construct_qqmlapplicationengine() -> cxx::UniquePtr<QQmlApplicationEngine> {
     let mut engine = QQmlApplicationEngine::new();
     if let Some(engine) = engine.as_mut() {
@@ -29,3 +71,66 @@ fn construct_qqmlapplicationengine() -> cxx::UniquePtr<QQmlApplicationEngine> {
 fn read_qqmlapplicationengine(engine: &QQmlApplicationEngine) -> bool {
     engine.base_url().to_string() == "qrc:/kdab.qml"
 }
+
+fn set_qqmlapplicationengine_properties(
+    mut engine: Pin<&mut QQmlApplicationEngine>,
+    object: Pin<&mut QObject>,
+) {
+    let mut context = engine.as_mut().root_context();
+    context
+        .as_mut()
+        .set_context_property(&QString::from("kdabValue"), &QVariant::from(&42));
+    // Safety: the object outlives the engine in the test
+    unsafe {
+        context
+            .as_mut()
+            .set_context_property_object(&QString::from("kdabObject"), object.get_unchecked_mut());
+    }
+
+    let mut initial_properties = QMap::<QMapPair_QString_QVariant>::default();
+    initial_properties.insert(QString::from("initial"), QVariant::from(&7));
+    engine.set_initial_properties(&initial_properties);
+}
+
+fn qqmlapplicationengine_objects_created() -> i32 {
+    OBJECTS_CREATED.load(Ordering::SeqCst)
+}
+
+fn qqmlapplicationengine_warnings() -> i32 {
+    WARNINGS.load(Ordering::SeqCst)
+}
+
+fn read_qqmlapplicationengine_root_objects(engine: Pin<&mut QQmlApplicationEngine>) -> bool {
+    let roots = engine.root_objects();
+    if roots.len() != 1 {
+        return false;
+    }
+
+    let child = engine.find_object::<QObject>(&QString::from("child"));
+    if child.is_null()
+        || !engine
+            .find_object::<QObject>(&QString::from("missing"))
+            .is_null()
+    {
+        return false;
+    }
+
+    // Safety: the root object is valid for as long as the engine is borrowed
+    let mut root = unsafe { Pin::new_unchecked(&mut *roots[0]) };
+    if root.property("value").value::<i32>() != Some(1) {
+        return false;
+    }
+    root.as_mut().set_property("value", &QVariant::from(&2));
+
+    let mut args = QList::<QVariant>::default();
+    args.append(QVariant::from(&QString::from("kdab")));
+    let greeting = QMetaObject::invoke_method(
+        root.as_mut(),
+        "greet",
+        ConnectionType::DirectConnection,
+        &args,
+    );
+
+    greeting.and_then(|greeting| greeting.value::<QString>()) == Some(QString::from("hello kdab"))
+        && root.property("value").value::<i32>() == Some(2)
+}
@@ -0,0 +1,133 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{
+    QByteArray, QMap, QMapPair_QString_QVariant, QObject, QObjectExt, QQmlComponent,
+    QQmlComponentStatus, QQmlEngine, QQmlIncubator, QQmlIncubatorIncubationMode,
+    QQmlIncubatorStatus, QString, QUrl, QVariant,
+};
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[cxx::bridge]
+mod qqmlcomponent_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = cxx_qt_lib::QQmlEngine;
+        include!(<QtCore/QObject>);
+        type QObject = cxx_qt_lib::QObject;
+    }
+
+    extern "Rust" {
+        fn create_qqmlcomponent(engine: Pin<&mut QQmlEngine>, parent: Pin<&mut QObject>) -> bool;
+        fn create_qqmlcomponent_error(engine: Pin<&mut QQmlEngine>) -> bool;
+        fn create_qqmlcomponent_incubated(engine: Pin<&mut QQmlEngine>) -> bool;
+    }
+}
+
+const QML: &str = "import QtQml 2.15\nQtObject {\n  property int value: 1\n}\n";
+
+/// # Safety
+///
+/// The component must be dropped before the engine is destroyed
+unsafe fn new_component(engine: Pin<&mut QQmlEngine>, data: &str) -> cxx::UniquePtr<QQmlComponent> {
+    let mut component = QQmlComponent::new(engine);
+    if let Some(component) = component.as_mut() {
+        component.set_data(
+            &QByteArray::from(data),
+            &QUrl::from("file:///kdab/component.qml"),
+        );
+    }
+    component
+}
+
+fn create_qqmlcomponent(engine: Pin<&mut QQmlEngine>, mut parent: Pin<&mut QObject>) -> bool {
+    // Safety: the engine outlives this function, which drops the component
+    let mut component = unsafe { new_component(engine, QML) };
+    let Some(mut component) = component.as_mut() else {
+        return false;
+    };
+    if component.status() != QQmlComponentStatus::Ready {
+        return false;
+    }
+
+    let mut initial_properties = QMap::<QMapPair_QString_QVariant>::default();
+    initial_properties.insert(QString::from("value"), QVariant::from(&42));
+    let Ok(object) = component
+        .as_mut()
+        .create_with_initial_properties(&initial_properties, Some(parent.as_mut()))
+    else {
+        return false;
+    };
+    let value = object
+        .as_ref()
+        .and_then(|object| object.property("value").value::<i32>());
+
+    // The object is owned by the parent, so it remains after the QBox is dropped
+    object.is_parent_owned() && value == Some(42) && parent.children().len() == 1
+}
+
+fn create_qqmlcomponent_error(engine: Pin<&mut QQmlEngine>) -> bool {
+    // Safety: the engine outlives this function, which drops the component
+    let mut component =
+        unsafe { new_component(engine, "import QtQml 2.15\nQtObject {\n  invalid\n}\n") };
+    let Some(mut component) = component.as_mut() else {
+        return false;
+    };
+    if !component.is_error() {
+        return false;
+    }
+
+    match component.as_mut().create() {
+        Ok(_) => false,
+        Err(error) => {
+            error.errors().len() == component.errors().len()
+                && error
+                    .errors()
+                    .first()
+                    .map(|error| error.line() == 3 && error.to_string().contains("component.qml:3"))
+                    .unwrap_or(false)
+        }
+    }
+}
+
+fn create_qqmlcomponent_incubated(engine: Pin<&mut QQmlEngine>) -> bool {
+    // Safety: the engine outlives this function, which drops the component
+    let mut component = unsafe { new_component(engine, QML) };
+    let mut incubator = QQmlIncubator::new(QQmlIncubatorIncubationMode::Asynchronous);
+    let (Some(component), Some(mut incubator)) = (component.as_mut(), incubator.as_mut()) else {
+        return false;
+    };
+
+    let value = Rc::new(Cell::new(None));
+    let value_closure = value.clone();
+    incubator
+        .as_mut()
+        .on_status_changed(move |incubator, status| {
+            if status == QQmlIncubatorStatus::Ready {
+                // Safety: the object is valid as the incubator is ready
+                let object = unsafe { &*incubator.object() };
+                value_closure.set(object.property("value").value::<i32>());
+            }
+        });
+
+    let mut initial_properties = QMap::<QMapPair_QString_QVariant>::default();
+    initial_properties.insert(QString::from("value"), QVariant::from(&7));
+    incubator
+        .as_mut()
+        .set_initial_properties(&initial_properties);
+
+    component.create_incubated(incubator.as_mut());
+    incubator.as_mut().force_completion();
+
+    let ready = incubator.is_ready() && value.get() == Some(7);
+    // The incubated object is owned by the caller
+    if ready {
+        // Safety: the object was created by the incubator and is not owned by anything else
+        drop(unsafe { cxx_qt::QBox::from_raw(incubator.object()) });
+    }
+    ready
+}
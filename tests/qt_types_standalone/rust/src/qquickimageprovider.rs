@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::SharedPtr;
+use cxx_qt_lib::{
+    QColor, QImage, QImageFormat, QQmlEngine, QQuickImageProvider, QQuickImageResponse, QSize,
+    QString,
+};
+
+#[cxx::bridge]
+mod qquickimageprovider_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = cxx_qt_lib::QQmlEngine;
+    }
+
+    extern "Rust" {
+        fn add_qquickimageproviders(engine: Pin<&mut QQmlEngine>);
+    }
+}
+
+struct ColorImageProvider;
+
+impl QQuickImageProvider for ColorImageProvider {
+    fn request_image(&self, id: &QString, requested_size: &QSize) -> QImage {
+        color_image(id, requested_size)
+    }
+}
+
+fn color_image(id: &QString, requested_size: &QSize) -> QImage {
+    let size = if requested_size.is_valid() {
+        requested_size.clone()
+    } else {
+        QSize::new(4, 2)
+    };
+    let mut image = QImage::from_width_height_and_format(
+        size.width(),
+        size.height(),
+        QImageFormat::Format_ARGB32,
+    );
+    match id.to_string().as_str() {
+        "red" => image.fill(&QColor::from_rgb(255, 0, 0)),
+        "green" => image.fill(&QColor::from_rgb(0, 255, 0)),
+        _ => return QImage::default(),
+    }
+    image
+}
+
+fn add_qquickimageproviders(mut engine: Pin<&mut QQmlEngine>) {
+    engine
+        .as_mut()
+        .add_image_provider(&QString::from("colors"), ColorImageProvider);
+    engine.add_async_image_provider(
+        &QString::from("async_colors"),
+        |id: &QString, requested_size: &QSize, response: SharedPtr<QQuickImageResponse>| {
+            let id = id.to_string();
+            let (width, height) = (requested_size.width(), requested_size.height());
+            // Finish the response from a worker thread
+            std::thread::spawn(move || {
+                if let Some(response) = response.as_ref() {
+                    let image = color_image(&QString::from(&id), &QSize::new(width, height));
+                    if image.is_null() {
+                        response.finish_with_error(&QString::from("Unknown color"));
+                    } else {
+                        response.finish(&image);
+                    }
+                }
+            });
+        },
+    );
+}
@@ -0,0 +1,45 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt::Upcast;
+use cxx_qt_lib::{
+    QColor, QQuickView, QQuickViewResizeMode, QQuickWindow, QSize, QString, WindowType,
+};
+
+#[cxx::bridge]
+mod qquickview_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qquickview.h");
+        type QQuickView = cxx_qt_lib::QQuickView;
+        include!("cxx-qt-lib/qquickwindow.h");
+        type QQuickWindow = cxx_qt_lib::QQuickWindow;
+    }
+
+    extern "Rust" {
+        fn construct_qquickview() -> UniquePtr<QQuickView>;
+        fn read_qquickwindow_frameless(window: &QQuickWindow) -> bool;
+    }
+}
+
+fn construct_qquickview() -> cxx::UniquePtr<QQuickView> {
+    let mut view = QQuickView::new();
+    if let Some(mut view) = view.as_mut() {
+        view.as_mut()
+            .set_resize_mode(QQuickViewResizeMode::SizeRootObjectToView);
+
+        let mut window = view.upcast_pin();
+        window.as_mut().set_title(&QString::from("cxx-qt"));
+        window.as_mut().set_color(&QColor::from_rgb(0, 0, 255));
+        window
+            .as_mut()
+            .set_flag(WindowType::FramelessWindowHint, true);
+        window.resize(&QSize::new(200, 100));
+    }
+    view
+}
+
+fn read_qquickwindow_frameless(window: &QQuickWindow) -> bool {
+    window.has_flag(WindowType::FramelessWindowHint) && window.window_type() == WindowType::Window
+}
@@ -0,0 +1,176 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QSettings, QSettingsFormat, QString};
+use serde::{Deserialize, Serialize};
+
+#[cxx::bridge]
+mod qsettings_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsettings.h");
+        type QSettings = cxx_qt_lib::QSettings;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn construct_qsettings_ini(file_name: &QString) -> UniquePtr<QSettings>;
+        fn read_qsettings(settings: Pin<&mut QSettings>) -> bool;
+        fn serde_qsettings(settings: Pin<&mut QSettings>) -> bool;
+        fn write_qsettings(settings: Pin<&mut QSettings>);
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+enum Theme {
+    Light,
+    Dark,
+    Custom(String),
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Window {
+    width: i32,
+    height: i32,
+    maximized: bool,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Preferences {
+    name: String,
+    scale: f64,
+    theme: Theme,
+    window: Window,
+    recent_files: Vec<String>,
+    last_opened: Option<String>,
+}
+
+/// Nested sequences cannot be stored in settings
+#[derive(Serialize)]
+struct Unsupported {
+    name: String,
+    nested: Vec<Vec<i32>>,
+}
+
+fn construct_qsettings_ini(file_name: &QString) -> cxx::UniquePtr<QSettings> {
+    QSettings::from_file(file_name, QSettingsFormat::IniFormat)
+}
+
+fn write_qsettings(mut settings: Pin<&mut QSettings>) {
+    settings
+        .as_mut()
+        .set(&QString::from("name"), &QString::from("cxx-qt"));
+
+    settings.as_mut().begin_group(&QString::from("window"));
+    settings.as_mut().set(&QString::from("width"), &800);
+    settings.as_mut().set(&QString::from("maximized"), &true);
+    settings.as_mut().end_group();
+
+    let files = ["a.txt", "b.txt"];
+    settings
+        .as_mut()
+        .begin_write_array(&QString::from("files"), files.len() as i32);
+    for (index, file) in files.iter().enumerate() {
+        settings.as_mut().set_array_index(index as i32);
+        settings
+            .as_mut()
+            .set(&QString::from("path"), &QString::from(*file));
+    }
+    settings.as_mut().end_array();
+}
+
+fn read_qsettings(mut settings: Pin<&mut QSettings>) -> bool {
+    if settings.get::<QString>(&QString::from("name")) != Some(QString::from("cxx-qt")) {
+        return false;
+    }
+    if settings.get::<i32>(&QString::from("missing")).is_some() {
+        return false;
+    }
+
+    settings.as_mut().begin_group(&QString::from("window"));
+    let window_ok = settings.get::<i32>(&QString::from("width")) == Some(800)
+        && settings.get::<bool>(&QString::from("maximized")) == Some(true);
+    settings.as_mut().end_group();
+    if !window_ok {
+        return false;
+    }
+
+    let size = settings.as_mut().begin_read_array(&QString::from("files"));
+    let mut files = vec![];
+    for index in 0..size {
+        settings.as_mut().set_array_index(index);
+        files.push(
+            settings
+                .get::<QString>(&QString::from("path"))
+                .unwrap_or_default()
+                .to_string(),
+        );
+    }
+    settings.as_mut().end_array();
+
+    files == ["a.txt", "b.txt"]
+}
+
+fn serde_qsettings(mut settings: Pin<&mut QSettings>) -> bool {
+    let preferences = Preferences {
+        name: "cxx-qt".to_owned(),
+        scale: 1.5,
+        theme: Theme::Custom("solarized".to_owned()),
+        window: Window {
+            width: 800,
+            height: 600,
+            maximized: false,
+        },
+        recent_files: vec!["a.txt".to_owned(), "b.txt".to_owned()],
+        last_opened: None,
+    };
+    let group = QString::from("preferences");
+    if settings
+        .as_mut()
+        .serialize_group(&group, &preferences)
+        .is_err()
+    {
+        return false;
+    }
+
+    let read_back = settings.as_mut().deserialize_group::<Preferences>(&group);
+    if read_back.as_ref() != Ok(&preferences) {
+        return false;
+    }
+
+    // Storing a unit variant replaces the previous values of the group
+    let preferences = Preferences {
+        theme: Theme::Dark,
+        last_opened: Some("a.txt".to_owned()),
+        ..preferences
+    };
+    if settings
+        .as_mut()
+        .serialize_group(&group, &preferences)
+        .is_err()
+    {
+        return false;
+    }
+    let read_back = settings.as_mut().deserialize_group::<Preferences>(&group);
+    if read_back.as_ref() != Ok(&preferences) {
+        return false;
+    }
+
+    // A value which cannot be stored leaves the previous values of the group
+    let unsupported = Unsupported {
+        name: "unsupported".to_owned(),
+        nested: vec![vec![1]],
+    };
+    if settings
+        .as_mut()
+        .serialize_group(&group, &unsupported)
+        .is_ok()
+    {
+        return false;
+    }
+    let read_back = settings.as_mut().deserialize_group::<Preferences>(&group);
+    read_back == Ok(preferences)
+}
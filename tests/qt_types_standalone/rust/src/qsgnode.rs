@@ -0,0 +1,106 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+use cxx_qt::Downcast;
+use cxx_qt_lib::{
+    QColor, QPointF, QRectF, QSGFlatColorMaterial, QSGGeometry, QSGGeometryDrawingMode,
+    QSGGeometryNode, QSGNode, QSGNodeFlag, QSGSimpleRectNode,
+};
+
+#[cxx::bridge]
+mod qsgnode_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsgnode.h");
+        type QSGNode = cxx_qt_lib::QSGNode;
+    }
+
+    extern "Rust" {
+        fn construct_qsgnode_tree() -> UniquePtr<QSGNode>;
+        fn delete_qsgnode_children(node: Pin<&mut QSGNode>);
+        unsafe fn read_qsgnode_tree(node: Pin<&mut QSGNode>) -> bool;
+    }
+}
+
+fn construct_qsgnode_tree() -> UniquePtr<QSGNode> {
+    let mut root = QSGNode::new();
+    root.pin_mut().append_child_node(QSGSimpleRectNode::new(
+        &QRectF::new(1.0, 2.0, 3.0, 4.0),
+        &QColor::from_rgb(255, 0, 0),
+    ));
+
+    let mut geometry = QSGGeometry::new_point_2d(0);
+    geometry
+        .pin_mut()
+        .set_drawing_mode(QSGGeometryDrawingMode::DrawLineStrip);
+    geometry.pin_mut().set_line_width(2.0);
+    geometry.pin_mut().set_points_2d(&[
+        QPointF::new(0.0, 0.0),
+        QPointF::new(10.0, 20.0),
+        QPointF::new(30.0, 40.0),
+    ]);
+
+    let line = QSGGeometryNode::new(
+        geometry,
+        QSGFlatColorMaterial::new(&QColor::from_rgb(0, 0, 255)),
+    );
+    root.pin_mut().append_child_node(line);
+
+    root
+}
+
+fn delete_qsgnode_children(node: Pin<&mut QSGNode>) {
+    node.delete_all_child_nodes();
+}
+
+unsafe fn read_qsgnode_tree(mut node: Pin<&mut QSGNode>) -> bool {
+    if node.child_count() != 2 {
+        return false;
+    }
+
+    let Some(rect) = node.first_child().as_ref() else {
+        return false;
+    };
+    let Some(rect) = rect.downcast::<QSGSimpleRectNode>() else {
+        return false;
+    };
+    if rect.rect() != QRectF::new(1.0, 2.0, 3.0, 4.0) || rect.color() != QColor::from_rgb(255, 0, 0)
+    {
+        return false;
+    }
+
+    // The second child is not a rectangle node
+    let Some(line) = node.as_mut().child_at_index(1).as_mut() else {
+        return false;
+    };
+    if line.downcast::<QSGSimpleRectNode>().is_some()
+        || !line.has_flag(QSGNodeFlag::OwnedByParent)
+        || !line.has_flag(QSGNodeFlag::OwnsGeometry)
+        || !line.has_flag(QSGNodeFlag::OwnsMaterial)
+    {
+        return false;
+    }
+
+    let Some(mut line) = Pin::new_unchecked(line).downcast_pin::<QSGGeometryNode>() else {
+        return false;
+    };
+    let Some(geometry) = line.as_mut().geometry_mut() else {
+        return false;
+    };
+    if geometry.vertex_count() != 3
+        || geometry.drawing_mode() != QSGGeometryDrawingMode::DrawLineStrip
+        || geometry.line_width() != 2.0
+        || geometry.point_2d_at(2) != Some(QPointF::new(30.0, 40.0))
+        || geometry.point_2d_at(3).is_some()
+    {
+        return false;
+    }
+
+    let Some(material) = line.material_mut::<QSGFlatColorMaterial>() else {
+        return false;
+    };
+    material.color() == &QColor::from_rgb(0, 0, 255)
+}
@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+use cxx_qt_lib::{ConnectionType, QObject, QTimer, TimerType};
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+
+#[cxx::bridge]
+mod qtimer_cxx {
+    unsafe extern "C++" {
+        include!(<QtCore/QObject>);
+        type QObject = cxx_qt_lib::QObject;
+
+        include!("cxx-qt-lib/qtimer.h");
+        type QTimer = cxx_qt_lib::QTimer;
+    }
+
+    extern "Rust" {
+        fn construct_qtimer() -> UniquePtr<QTimer>;
+        fn connect_qtimer_timeout(timer: Pin<&mut QTimer>);
+        fn qtimer_timeout_count() -> i32;
+        fn qtimer_parented(parent: Pin<&mut QObject>) -> bool;
+        fn qtimer_single_shot() -> bool;
+        fn qtimer_single_shot_called() -> bool;
+    }
+}
+
+static SINGLE_SHOT_CALLED: AtomicBool = AtomicBool::new(false);
+static TIMEOUT_COUNT: AtomicI32 = AtomicI32::new(0);
+
+fn construct_qtimer() -> UniquePtr<QTimer> {
+    let mut timer = QTimer::new();
+    if let Some(mut timer) = timer.as_mut() {
+        timer.as_mut().set_interval(10);
+        timer.as_mut().set_single_shot(true);
+        timer.as_mut().set_timer_type(TimerType::PreciseTimer);
+    }
+    timer
+}
+
+fn connect_qtimer_timeout(mut timer: Pin<&mut QTimer>) {
+    timer
+        .as_mut()
+        .on_timeout(|_| {
+            TIMEOUT_COUNT.fetch_add(1, Ordering::SeqCst);
+        })
+        .release();
+    timer
+        .connect_timeout(
+            |timer| {
+                // The second closure stops the timer so that it is not restarted
+                timer.stop();
+            },
+            ConnectionType::DirectConnection,
+        )
+        .release();
+}
+
+fn qtimer_timeout_count() -> i32 {
+    TIMEOUT_COUNT.load(Ordering::SeqCst)
+}
+
+fn qtimer_parented(mut parent: Pin<&mut QObject>) -> bool {
+    let timer = QTimer::new_with_parent(parent.as_mut());
+    let owned = timer.is_parent_owned();
+    // Dropping the QBox leaves the timer owned by the parent
+    drop(timer);
+
+    let children = parent.children();
+    owned && children.len() == 1 && !children[0].is_null()
+}
+
+fn qtimer_single_shot() -> bool {
+    QTimer::single_shot(0, || SINGLE_SHOT_CALLED.store(true, Ordering::SeqCst));
+    // The closure is only called from the event loop
+    !qtimer_single_shot_called()
+}
+
+fn qtimer_single_shot_called() -> bool {
+    SINGLE_SHOT_CALLED.load(Ordering::SeqCst)
+}
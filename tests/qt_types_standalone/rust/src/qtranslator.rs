@@ -0,0 +1,52 @@
+// SPDX-FileCopyrightText: 2025 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{qs_tr, QCoreApplication, QString, QTranslator};
+
+#[cxx::bridge]
+mod qtranslator_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcoreapplication.h");
+        type QCoreApplication = cxx_qt_lib::QCoreApplication;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qtranslator.h");
+        type QTranslator = cxx_qt_lib::QTranslator;
+    }
+
+    extern "Rust" {
+        fn construct_qtranslator() -> UniquePtr<QTranslator>;
+        fn install_qtranslator(
+            app: Pin<&mut QCoreApplication>,
+            translator: Pin<&mut QTranslator>,
+        ) -> bool;
+        fn translate_qs_tr() -> QString;
+        fn translate_qs_tr_plural(n: i32) -> QString;
+    }
+}
+
+fn construct_qtranslator() -> cxx::UniquePtr<QTranslator> {
+    let mut translator = QTranslator::new();
+    if let Some(translator) = translator.as_mut() {
+        // Loading a missing file fails and leaves the translator empty
+        if translator.load(&QString::from("missing.qm"), &QString::from(":/i18n")) {
+            return cxx::UniquePtr::null();
+        }
+    }
+    translator
+}
+
+fn install_qtranslator(app: Pin<&mut QCoreApplication>, translator: Pin<&mut QTranslator>) -> bool {
+    app.install_translator(translator)
+}
+
+fn translate_qs_tr() -> QString {
+    qs_tr!("Hello")
+}
+
+fn translate_qs_tr_plural(n: i32) -> QString {
+    qs_tr!("%n file(s)", "", n)
+}